@@ -1,27 +1,34 @@
-//! CDP WebSocket connection management.
+//! CDP connection management.
+//!
+//! A connection exchanges JSON messages with the browser over a transport:
+//! either a WebSocket, or the pipes Chromium opens when launched with
+//! `--remote-debugging-pipe`.
 
 mod discovery;
+mod pipe;
+mod read_loop;
+mod websocket;
 
 pub use discovery::{BrowserVersion, CdpConnectionOptions, discover_websocket_url};
 
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
-use futures_util::{SinkExt, StreamExt};
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
-use tokio::sync::{Mutex, broadcast, mpsc, oneshot};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::{Mutex, broadcast, mpsc, oneshot, watch};
 use tokio::time::timeout;
 use tokio_tungstenite::MaybeTlsStream;
-use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 use tracing::{debug, error, info, instrument, trace, warn};
 
 use crate::error::CdpError;
-use crate::transport::{CdpEvent, CdpMessage, CdpRequest, CdpResponse};
+use crate::transport::{CdpEvent, CdpRequest, CdpResponse};
 
 /// Default timeout for CDP commands.
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
@@ -29,15 +36,40 @@ const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 /// Buffer size for the event broadcast channel.
 const EVENT_CHANNEL_SIZE: usize = 256;
 
+/// A command waiting for its response.
+#[derive(Debug)]
+struct PendingCommand {
+    /// Session the command was sent to.
+    session_id: Option<String>,
+    /// Sender for the response.
+    tx: oneshot::Sender<CdpResponse>,
+}
+
+/// Pending commands keyed by message ID.
+type PendingCommands = Arc<Mutex<HashMap<u64, PendingCommand>>>;
+
+/// Sessions whose target crashed.
+type CrashedSessions = Arc<Mutex<HashSet<String>>>;
+
+/// Sender for incoming events, taken when the transport closes.
+type EventSender = Arc<RwLock<Option<broadcast::Sender<CdpEvent>>>>;
+
 /// A CDP connection to a browser.
 #[derive(Debug)]
 pub struct CdpConnection {
     /// Sender for outgoing messages.
     tx: mpsc::Sender<CdpRequest>,
-    /// Receiver for incoming events.
-    event_rx: broadcast::Sender<CdpEvent>,
+    /// Sender for incoming events.
+    ///
+    /// Taken when the transport closes, so that subscribers observe the
+    /// channel closing.
+    event_tx: EventSender,
     /// Pending responses waiting for completion.
-    pending: Arc<Mutex<HashMap<u64, oneshot::Sender<CdpResponse>>>>,
+    pending: PendingCommands,
+    /// Sessions whose target crashed.
+    crashed_sessions: CrashedSessions,
+    /// Whether the transport has closed.
+    closed: watch::Receiver<bool>,
     /// Atomic counter for message IDs.
     message_id: AtomicU64,
     /// Handle to the background read task.
@@ -103,32 +135,79 @@ impl CdpConnection {
         info!(status = %response.status(), "WebSocket connection established");
 
         let (write, read) = ws_stream.split();
+        Ok(Self::from_transport(
+            websocket::incoming(read),
+            websocket::outgoing(write),
+        ))
+    }
+
+    /// Connect over a pair of pipes.
+    ///
+    /// This is the transport used by Chromium when launched with
+    /// `--remote-debugging-pipe`: messages are JSON documents terminated by a
+    /// NUL byte. `reader` receives messages from the browser (its fd 4) and
+    /// `writer` sends messages to it (its fd 3).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_cdp::CdpConnection;
+    ///
+    /// # async fn example() -> Result<(), viewpoint_cdp::CdpError> {
+    /// let (to_browser, from_browser) = tokio::io::duplex(1024);
+    /// let conn = CdpConnection::connect_pipe(from_browser, to_browser);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn connect_pipe<R, W>(reader: R, writer: W) -> Self
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+        W: AsyncWrite + Send + Unpin + 'static,
+    {
+        info!("Connecting to CDP over pipes");
+        Self::from_transport(pipe::incoming(reader), pipe::outgoing(writer))
+    }
 
+    /// Start the background tasks for a transport carrying JSON text messages.
+    fn from_transport<R, W>(incoming: R, outgoing: W) -> Self
+    where
+        R: Stream<Item = String> + Send + Unpin + 'static,
+        W: Sink<String> + Send + Unpin + 'static,
+        W::Error: std::fmt::Display,
+    {
         // Channels for internal communication
         let (tx, rx) = mpsc::channel::<CdpRequest>(64);
         let (event_tx, _) = broadcast::channel::<CdpEvent>(EVENT_CHANNEL_SIZE);
-        let pending: Arc<Mutex<HashMap<u64, oneshot::Sender<CdpResponse>>>> =
-            Arc::new(Mutex::new(HashMap::new()));
+        let event_tx = Arc::new(RwLock::new(Some(event_tx)));
+        let (closed_tx, closed_rx) = watch::channel(false);
+        let pending: PendingCommands = Arc::new(Mutex::new(HashMap::new()));
+        let crashed_sessions: CrashedSessions = Arc::new(Mutex::new(HashSet::new()));
 
         // Spawn the write task
-        let write_handle = tokio::spawn(Self::write_loop(rx, write));
+        let write_handle = tokio::spawn(Self::write_loop(rx, outgoing));
         debug!("Spawned CDP write loop");
 
-        // Spawn the read task
-        let read_pending = pending.clone();
-        let read_event_tx = event_tx.clone();
-        let read_handle = tokio::spawn(Self::read_loop(read, read_pending, read_event_tx));
+        // Spawn the read task, which also detects crashes and disconnects
+        let read_handle = tokio::spawn(read_loop::run(
+            incoming,
+            pending.clone(),
+            event_tx.clone(),
+            crashed_sessions.clone(),
+            closed_tx,
+        ));
         debug!("Spawned CDP read loop");
 
         info!("CDP connection ready");
-        Ok(Self {
+        Self {
             tx,
-            event_rx: event_tx,
+            event_tx,
             pending,
+            crashed_sessions,
+            closed: closed_rx,
             message_id: AtomicU64::new(1),
             _read_handle: read_handle,
             _write_handle: write_handle,
-        })
+        }
     }
 
     /// Connect to a browser via HTTP endpoint URL.
@@ -185,10 +264,11 @@ impl CdpConnection {
         Self::connect_with_options(&ws_url, &options).await
     }
 
-    /// Background task that writes CDP requests to the WebSocket.
+    /// Background task that writes CDP requests to the transport.
     async fn write_loop<S>(mut rx: mpsc::Receiver<CdpRequest>, mut sink: S)
     where
-        S: futures_util::Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+        S: Sink<String> + Unpin,
+        S::Error: std::fmt::Display,
     {
         debug!("CDP write loop started");
         while let Some(request) = rx.recv().await {
@@ -205,8 +285,8 @@ impl CdpConnection {
 
             trace!(id = id, method = %method, json_len = json.len(), "Sending CDP request");
 
-            if sink.send(Message::Text(json.into())).await.is_err() {
-                warn!("WebSocket sink closed, ending write loop");
+            if let Err(e) = sink.send(json).await {
+                warn!(error = %e, "CDP transport closed, ending write loop");
                 break;
             }
 
@@ -215,64 +295,6 @@ impl CdpConnection {
         debug!("CDP write loop ended");
     }
 
-    /// Background task that reads CDP messages from the WebSocket.
-    async fn read_loop<S>(
-        mut stream: S,
-        pending: Arc<Mutex<HashMap<u64, oneshot::Sender<CdpResponse>>>>,
-        event_tx: broadcast::Sender<CdpEvent>,
-    ) where
-        S: futures_util::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>>
-            + Unpin,
-    {
-        debug!("CDP read loop started");
-        while let Some(msg) = stream.next().await {
-            let msg = match msg {
-                Ok(Message::Text(text)) => text,
-                Ok(Message::Close(frame)) => {
-                    info!(?frame, "WebSocket closed by remote");
-                    break;
-                }
-                Err(e) => {
-                    warn!(error = %e, "WebSocket error, ending read loop");
-                    break;
-                }
-                Ok(_) => continue,
-            };
-
-            trace!(json_len = msg.len(), "Received CDP message");
-
-            // Parse the incoming message
-            let cdp_msg: CdpMessage = match serde_json::from_str(&msg) {
-                Ok(m) => m,
-                Err(e) => {
-                    error!(error = %e, "Failed to parse CDP message");
-                    continue;
-                }
-            };
-
-            match cdp_msg {
-                CdpMessage::Response(resp) => {
-                    let id = resp.id;
-                    let has_error = resp.error.is_some();
-                    debug!(id = id, has_error = has_error, "Received CDP response");
-
-                    let mut pending = pending.lock().await;
-                    if let Some(sender) = pending.remove(&id) {
-                        let _ = sender.send(resp);
-                    } else {
-                        warn!(id = id, "Received response for unknown request ID");
-                    }
-                }
-                CdpMessage::Event(ref event) => {
-                    trace!(method = %event.method, session_id = ?event.session_id, "Received CDP event");
-                    // Broadcast to all subscribers; ignore if no receivers.
-                    let _ = event_tx.send(event.clone());
-                }
-            }
-        }
-        debug!("CDP read loop ended");
-    }
-
     /// Send a CDP command and wait for the response.
     ///
     /// # Errors
@@ -337,7 +359,21 @@ impl CdpConnection {
         // Register the pending response
         {
             let mut pending = self.pending.lock().await;
-            pending.insert(id, resp_tx);
+            if self.is_closed() {
+                return Err(CdpError::ConnectionLost);
+            }
+            if let Some(session_id) = session_id {
+                if self.crashed_sessions.lock().await.contains(session_id) {
+                    return Err(CdpError::TargetCrashed(session_id.to_string()));
+                }
+            }
+            pending.insert(
+                id,
+                PendingCommand {
+                    session_id: session_id.map(ToString::to_string),
+                    tx: resp_tx,
+                },
+            );
             trace!(
                 id = id,
                 pending_count = pending.len(),
@@ -354,13 +390,15 @@ impl CdpConnection {
         trace!(id = id, "Request queued for sending");
 
         // Wait for the response with timeout
-        let response = timeout(timeout_duration, resp_rx)
-            .await
-            .map_err(|_| {
+        let response = match timeout(timeout_duration, resp_rx).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(_)) => return Err(self.dropped_command_error(session_id).await),
+            Err(_) => {
                 warn!(id = id, method = %method, "CDP command timed out");
-                CdpError::Timeout(timeout_duration)
-            })?
-            .map_err(|_| CdpError::ConnectionLost)?;
+                self.pending.lock().await.remove(&id);
+                return Err(CdpError::Timeout(timeout_duration));
+            }
+        };
 
         // Check for protocol errors
         if let Some(ref error) = response.error {
@@ -378,12 +416,48 @@ impl CdpConnection {
         serde_json::from_value(result).map_err(CdpError::from)
     }
 
+    /// The error for a command whose response sender was dropped.
+    async fn dropped_command_error(&self, session_id: Option<&str>) -> CdpError {
+        if let Some(session_id) = session_id {
+            if self.crashed_sessions.lock().await.contains(session_id) {
+                return CdpError::TargetCrashed(session_id.to_string());
+            }
+        }
+        CdpError::ConnectionLost
+    }
+
     /// Subscribe to CDP events.
     ///
     /// Returns a receiver that will receive all CDP events from the browser.
+    /// The receiver reports the channel as closed once the connection closes.
     pub fn subscribe_events(&self) -> broadcast::Receiver<CdpEvent> {
         debug!("New CDP event subscription created");
-        self.event_rx.subscribe()
+        match &*self
+            .event_tx
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+        {
+            Some(event_tx) => event_tx.subscribe(),
+            // Closed: the sender of the new channel is dropped right away
+            None => broadcast::channel(1).1,
+        }
+    }
+
+    /// Check whether the connection to the browser has closed.
+    ///
+    /// Once closed, every command fails with [`CdpError::ConnectionLost`].
+    pub fn is_closed(&self) -> bool {
+        *self.closed.borrow()
+    }
+
+    /// Wait until the connection to the browser closes.
+    ///
+    /// This resolves when the transport ends, for example because the browser
+    /// process exited or the WebSocket was closed by the remote end.
+    pub async fn closed(&self) {
+        let mut closed = self.closed.clone();
+        // An error means the read loop is gone, which also means closed
+        let _ = closed.wait_for(|closed| *closed).await;
     }
 }
 
@@ -0,0 +1,59 @@
+//! Pipe transport.
+//!
+//! Chromium launched with `--remote-debugging-pipe` reads commands from file
+//! descriptor 3 and writes responses and events to file descriptor 4. Each
+//! message is a JSON document terminated by a NUL byte.
+
+use futures_util::{Sink, Stream};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tracing::{info, warn};
+
+/// Message delimiter used by the pipe transport.
+const DELIMITER: u8 = 0;
+
+/// Split a byte stream into NUL-delimited messages.
+///
+/// The stream ends when the pipe is closed or errors.
+pub(super) fn incoming<R>(reader: R) -> impl Stream<Item = String> + Send + Unpin
+where
+    R: AsyncRead + Send + Unpin + 'static,
+{
+    Box::pin(futures_util::stream::unfold(
+        BufReader::new(reader),
+        |mut reader| async move {
+            let mut buf = Vec::new();
+            match reader.read_until(DELIMITER, &mut buf).await {
+                Ok(0) => {
+                    info!("CDP pipe closed by remote");
+                    None
+                }
+                Ok(_) => {
+                    if buf.last() == Some(&DELIMITER) {
+                        buf.pop();
+                    }
+                    Some((String::from_utf8_lossy(&buf).into_owned(), reader))
+                }
+                Err(e) => {
+                    warn!(error = %e, "CDP pipe error, ending read loop");
+                    None
+                }
+            }
+        },
+    ))
+}
+
+/// Write messages to a byte stream, each followed by a NUL byte.
+pub(super) fn outgoing<W>(writer: W) -> impl Sink<String, Error = std::io::Error> + Send + Unpin
+where
+    W: AsyncWrite + Send + Unpin + 'static,
+{
+    Box::pin(futures_util::sink::unfold(
+        writer,
+        |mut writer, message: String| async move {
+            writer.write_all(message.as_bytes()).await?;
+            writer.write_all(&[DELIMITER]).await?;
+            writer.flush().await?;
+            Ok::<_, std::io::Error>(writer)
+        },
+    ))
+}
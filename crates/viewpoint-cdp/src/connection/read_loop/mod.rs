@@ -0,0 +1,126 @@
+//! Background task reading CDP messages from the transport.
+//!
+//! Besides completing commands and broadcasting events, the read loop detects
+//! crashed targets and the transport closing. Both are handled here rather
+//! than by an event subscriber, which could lag behind and miss the event.
+
+use std::sync::PoisonError;
+
+use futures_util::{Stream, StreamExt};
+use tokio::sync::watch;
+use tracing::{debug, error, trace, warn};
+
+use super::{CrashedSessions, EventSender, PendingCommands};
+use crate::transport::CdpMessage;
+
+/// Event reporting that the target of a session crashed.
+const TARGET_CRASHED: &str = "Inspector.targetCrashed";
+
+/// Read CDP messages until the transport closes.
+///
+/// A crashed target has its pending commands failed before the crash event is
+/// broadcast. When the transport closes, the connection is marked closed,
+/// pending commands fail and the event sender is dropped so that event
+/// subscribers see the channel close.
+pub(super) async fn run<S>(
+    mut stream: S,
+    pending: PendingCommands,
+    event_tx: EventSender,
+    crashed_sessions: CrashedSessions,
+    closed_tx: watch::Sender<bool>,
+) where
+    S: Stream<Item = String> + Unpin,
+{
+    debug!("CDP read loop started");
+    let sender = event_tx
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    while let Some(msg) = stream.next().await {
+        trace!(json_len = msg.len(), "Received CDP message");
+
+        // Parse the incoming message
+        let cdp_msg: CdpMessage = match serde_json::from_str(&msg) {
+            Ok(m) => m,
+            Err(e) => {
+                error!(error = %e, "Failed to parse CDP message");
+                continue;
+            }
+        };
+
+        match cdp_msg {
+            CdpMessage::Response(resp) => {
+                let id = resp.id;
+                let has_error = resp.error.is_some();
+                debug!(id = id, has_error = has_error, "Received CDP response");
+
+                let mut pending = pending.lock().await;
+                if let Some(command) = pending.remove(&id) {
+                    let _ = command.tx.send(resp);
+                } else {
+                    warn!(id = id, "Received response for unknown request ID");
+                }
+            }
+            CdpMessage::Event(event) => {
+                trace!(method = %event.method, session_id = ?event.session_id, "Received CDP event");
+                if event.method == TARGET_CRASHED {
+                    if let Some(session_id) = &event.session_id {
+                        fail_crashed_session(&pending, &crashed_sessions, session_id).await;
+                    }
+                }
+                // Broadcast to all subscribers; ignore if no receivers.
+                if let Some(sender) = &sender {
+                    let _ = sender.send(event);
+                }
+            }
+        }
+    }
+    debug!("CDP read loop ended");
+
+    disconnect(&pending, &event_tx, &closed_tx).await;
+    drop(sender);
+}
+
+/// Fail the pending commands of a session whose target crashed.
+///
+/// Later commands to the session fail as soon as they are sent.
+async fn fail_crashed_session(
+    pending: &PendingCommands,
+    crashed_sessions: &CrashedSessions,
+    session_id: &str,
+) {
+    let mut pending = pending.lock().await;
+    crashed_sessions.lock().await.insert(session_id.to_string());
+
+    let before = pending.len();
+    pending.retain(|_, command| command.session_id.as_deref() != Some(session_id));
+    warn!(
+        session_id = %session_id,
+        failed_commands = before - pending.len(),
+        "Target crashed"
+    );
+}
+
+/// Mark the connection closed, fail all pending commands and stop event
+/// delivery.
+async fn disconnect(
+    pending: &PendingCommands,
+    event_tx: &EventSender,
+    closed_tx: &watch::Sender<bool>,
+) {
+    // Mark the connection closed before failing pending commands, so that
+    // commands registered from now on fail immediately.
+    closed_tx.send_replace(true);
+    let failed = {
+        let mut pending = pending.lock().await;
+        let count = pending.len();
+        pending.clear();
+        count
+    };
+    warn!(failed_commands = failed, "CDP transport closed");
+
+    event_tx
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+}
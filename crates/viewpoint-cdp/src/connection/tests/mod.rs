@@ -48,3 +48,180 @@ fn test_cdp_message_discrimination() {
     assert!(resp.into_response().is_some());
     assert!(event.into_event().is_some());
 }
+
+#[tokio::test]
+async fn test_pipe_transport_round_trip() {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    use crate::CdpConnection;
+
+    let (to_browser, browser_in) = tokio::io::duplex(4096);
+    let (mut browser_out, from_browser) = tokio::io::duplex(4096);
+
+    let browser = tokio::spawn(async move {
+        let mut reader = BufReader::new(browser_in);
+        let mut buf = Vec::new();
+        reader.read_until(0, &mut buf).await.unwrap();
+        assert_eq!(buf.pop(), Some(0));
+
+        let request: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(request["method"], "Browser.getVersion");
+
+        let event = r#"{"method": "Target.targetCreated", "params": {}}"#;
+        let response = serde_json::json!({
+            "id": request["id"],
+            "result": {"product": "HeadlessChrome/1.0"}
+        });
+        browser_out.write_all(event.as_bytes()).await.unwrap();
+        browser_out.write_all(&[0]).await.unwrap();
+        browser_out
+            .write_all(response.to_string().as_bytes())
+            .await
+            .unwrap();
+        browser_out.write_all(&[0]).await.unwrap();
+        browser_out
+    });
+
+    let conn = CdpConnection::connect_pipe(from_browser, to_browser);
+    let mut events = conn.subscribe_events();
+
+    let result: serde_json::Value = conn
+        .send_command("Browser.getVersion", None::<()>, None)
+        .await
+        .unwrap();
+    assert_eq!(result["product"], "HeadlessChrome/1.0");
+
+    let event = events.recv().await.unwrap();
+    assert_eq!(event.method, "Target.targetCreated");
+
+    browser.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_pipe_close_fails_pending_commands() {
+    use std::time::Duration;
+
+    use tokio::io::{AsyncBufReadExt, BufReader};
+
+    use crate::{CdpConnection, CdpError};
+
+    let (to_browser, browser_in) = tokio::io::duplex(4096);
+    let (browser_out, from_browser) = tokio::io::duplex(4096);
+
+    let browser = tokio::spawn(async move {
+        // Read the request, then exit without answering
+        let mut reader = BufReader::new(browser_in);
+        let mut buf = Vec::new();
+        reader.read_until(0, &mut buf).await.unwrap();
+        drop(browser_out);
+    });
+
+    let conn = CdpConnection::connect_pipe(from_browser, to_browser);
+    let mut events = conn.subscribe_events();
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        conn.send_command::<_, serde_json::Value>("Browser.getVersion", None::<()>, None),
+    )
+    .await
+    .expect("pending command should fail fast");
+    assert!(matches!(result, Err(CdpError::ConnectionLost)));
+
+    conn.closed().await;
+    assert!(conn.is_closed());
+    assert!(events.recv().await.is_err());
+    assert!(conn.subscribe_events().recv().await.is_err());
+
+    let result = conn
+        .send_command::<_, serde_json::Value>("Browser.getVersion", None::<()>, None)
+        .await;
+    assert!(matches!(result, Err(CdpError::ConnectionLost)));
+
+    browser.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_target_crash_fails_session_commands() {
+    use std::time::Duration;
+
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    use crate::{CdpConnection, CdpError};
+
+    let (to_browser, browser_in) = tokio::io::duplex(4096);
+    let (mut browser_out, from_browser) = tokio::io::duplex(4096);
+
+    let browser = tokio::spawn(async move {
+        let mut reader = BufReader::new(browser_in);
+        let mut buf = Vec::new();
+        reader.read_until(0, &mut buf).await.unwrap();
+
+        let crashed =
+            r#"{"method": "Inspector.targetCrashed", "params": {}, "sessionId": "page-1"}"#;
+        browser_out.write_all(crashed.as_bytes()).await.unwrap();
+        browser_out.write_all(&[0]).await.unwrap();
+        browser_out
+    });
+
+    let conn = CdpConnection::connect_pipe(from_browser, to_browser);
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        conn.send_command::<_, serde_json::Value>("Runtime.evaluate", None::<()>, Some("page-1")),
+    )
+    .await
+    .expect("pending command should fail fast");
+    assert!(matches!(result, Err(CdpError::TargetCrashed(ref id)) if id == "page-1"));
+
+    let result = conn
+        .send_command::<_, serde_json::Value>("Page.reload", None::<()>, Some("page-1"))
+        .await;
+    assert!(matches!(result, Err(CdpError::TargetCrashed(_))));
+    assert!(!conn.is_closed());
+
+    drop(browser.await.unwrap());
+}
+
+#[tokio::test]
+async fn test_target_crash_after_event_burst_fails_session_commands() {
+    use std::time::Duration;
+
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    use crate::{CdpConnection, CdpError};
+
+    let (to_browser, browser_in) = tokio::io::duplex(4096);
+    let (mut browser_out, from_browser) = tokio::io::duplex(64 * 1024);
+
+    let browser = tokio::spawn(async move {
+        let mut reader = BufReader::new(browser_in);
+        let mut buf = Vec::new();
+        reader.read_until(0, &mut buf).await.unwrap();
+
+        // Overflow the event channel before the crash arrives
+        for _ in 0..1024 {
+            let event = r#"{"method": "Page.frameNavigated", "params": {}}"#;
+            browser_out.write_all(event.as_bytes()).await.unwrap();
+            browser_out.write_all(&[0]).await.unwrap();
+        }
+        let crashed =
+            r#"{"method": "Inspector.targetCrashed", "params": {}, "sessionId": "page-1"}"#;
+        browser_out.write_all(crashed.as_bytes()).await.unwrap();
+        browser_out.write_all(&[0]).await.unwrap();
+        browser_out
+    });
+
+    let conn = CdpConnection::connect_pipe(from_browser, to_browser);
+    // A subscriber that never reads lags behind the burst
+    let _events = conn.subscribe_events();
+
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        conn.send_command::<_, serde_json::Value>("Runtime.evaluate", None::<()>, Some("page-1")),
+    )
+    .await
+    .expect("pending command should fail fast");
+    assert!(matches!(result, Err(CdpError::TargetCrashed(ref id)) if id == "page-1"));
+
+    drop(browser.await.unwrap());
+}
@@ -0,0 +1,42 @@
+//! WebSocket transport.
+
+use futures_util::future::ready;
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
+use tokio_tungstenite::tungstenite::{Error, Message};
+use tracing::{info, warn};
+
+/// Adapt a WebSocket stream into a stream of text messages.
+///
+/// The stream ends when the socket is closed or errors.
+pub(super) fn incoming<S>(stream: S) -> impl Stream<Item = String> + Send + Unpin
+where
+    S: Stream<Item = Result<Message, Error>> + Send + Unpin + 'static,
+{
+    Box::pin(futures_util::stream::unfold(
+        stream,
+        |mut stream| async move {
+            loop {
+                match stream.next().await? {
+                    Ok(Message::Text(text)) => return Some((text.to_string(), stream)),
+                    Ok(Message::Close(frame)) => {
+                        info!(?frame, "WebSocket closed by remote");
+                        return None;
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "WebSocket error, ending read loop");
+                        return None;
+                    }
+                    Ok(_) => {}
+                }
+            }
+        },
+    ))
+}
+
+/// Adapt a WebSocket sink into a sink of text messages.
+pub(super) fn outgoing<S>(sink: S) -> impl Sink<String, Error = Error> + Send + Unpin
+where
+    S: Sink<Message, Error = Error> + Send + Unpin + 'static,
+{
+    sink.with(|json: String| ready(Ok::<_, Error>(Message::Text(json.into()))))
+}
@@ -13,6 +13,10 @@ pub enum CdpError {
     #[error("WebSocket connection lost")]
     ConnectionLost,
 
+    /// The target attached to a session crashed.
+    #[error("target crashed (session {0})")]
+    TargetCrashed(String),
+
     /// Failed to send a CDP message.
     #[error("failed to send CDP message: {0}")]
     SendFailed(String),
@@ -16,6 +16,12 @@ fn test_connection_lost_error_display() {
     assert_eq!(err.to_string(), "WebSocket connection lost");
 }
 
+#[test]
+fn test_target_crashed_error_display() {
+    let err = CdpError::TargetCrashed("session-1".to_string());
+    assert_eq!(err.to_string(), "target crashed (session session-1)");
+}
+
 #[test]
 fn test_send_failed_error_display() {
     let err = CdpError::SendFailed("channel closed".to_string());
@@ -0,0 +1,177 @@
+//! Accessibility domain types.
+//!
+//! The Accessibility domain exposes the browser's computed accessibility tree.
+
+use serde::{Deserialize, Serialize};
+
+use super::dom::BackendNodeId;
+
+/// Unique accessibility node identifier.
+pub type AXNodeId = String;
+
+/// A single computed accessibility property or value.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AXValue {
+    /// The type of this value (e.g. "string", "role", "tristate", "idrefList").
+    #[serde(rename = "type")]
+    pub value_type: String,
+    /// The computed value.
+    pub value: Option<serde_json::Value>,
+    /// One or more related nodes, if applicable.
+    pub related_nodes: Option<Vec<AXRelatedNode>>,
+}
+
+impl AXValue {
+    /// Get the value as a string, if it is one.
+    pub fn as_str(&self) -> Option<&str> {
+        self.value.as_ref().and_then(serde_json::Value::as_str)
+    }
+}
+
+/// A node related to an accessibility value.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AXRelatedNode {
+    /// The backend node ID of the related DOM node.
+    #[serde(rename = "backendDOMNodeId")]
+    pub backend_dom_node_id: BackendNodeId,
+    /// The IDRef value provided, if any.
+    pub idref: Option<String>,
+    /// The text alternative of this node in the current context.
+    pub text: Option<String>,
+}
+
+/// A named accessibility property.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AXProperty {
+    /// The name of this property (e.g. "focusable", "checked", "level").
+    pub name: String,
+    /// The value of this property.
+    pub value: AXValue,
+}
+
+/// A node in the accessibility tree.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AXNode {
+    /// Unique identifier for this node.
+    pub node_id: AXNodeId,
+    /// Whether this node is ignored for accessibility.
+    pub ignored: bool,
+    /// Collection of reasons why this node is hidden.
+    pub ignored_reasons: Option<Vec<AXProperty>>,
+    /// This node's role, whether explicit or implicit.
+    pub role: Option<AXValue>,
+    /// This node's Chrome raw role.
+    pub chrome_role: Option<AXValue>,
+    /// The accessible name for this node.
+    pub name: Option<AXValue>,
+    /// The accessible description for this node.
+    pub description: Option<AXValue>,
+    /// The value for this node.
+    pub value: Option<AXValue>,
+    /// All other properties.
+    pub properties: Option<Vec<AXProperty>>,
+    /// ID of the parent node, if any.
+    pub parent_id: Option<AXNodeId>,
+    /// IDs of child nodes.
+    pub child_ids: Option<Vec<AXNodeId>>,
+    /// The backend ID for the associated DOM node, if any.
+    #[serde(rename = "backendDOMNodeId")]
+    pub backend_dom_node_id: Option<BackendNodeId>,
+    /// The frame ID for the frame associated with this node's document.
+    pub frame_id: Option<String>,
+}
+
+// =============================================================================
+// Accessibility.getFullAXTree
+// =============================================================================
+
+/// Parameters for Accessibility.getFullAXTree.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFullAXTreeParams {
+    /// The maximum depth at which descendants of the root node should be retrieved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<i32>,
+    /// The frame for whose document the tree should be retrieved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frame_id: Option<String>,
+}
+
+/// Result of Accessibility.getFullAXTree.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetFullAXTreeResult {
+    /// The accessibility tree nodes.
+    pub nodes: Vec<AXNode>,
+}
+
+// =============================================================================
+// Accessibility.getPartialAXTree
+// =============================================================================
+
+/// Parameters for Accessibility.getPartialAXTree.
+///
+/// Exactly one of the node identifiers should be set.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPartialAXTreeParams {
+    /// Identifier of the node to get the partial accessibility tree for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<i32>,
+    /// Identifier of the backend node to get the partial accessibility tree for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backend_node_id: Option<BackendNodeId>,
+    /// JavaScript object ID of the node wrapper to get the partial accessibility tree for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<String>,
+    /// Whether to fetch this node's ancestors, siblings and children. Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fetch_relatives: Option<bool>,
+}
+
+/// Result of Accessibility.getPartialAXTree.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetPartialAXTreeResult {
+    /// The accessibility tree nodes for the given node and, optionally, its relatives.
+    pub nodes: Vec<AXNode>,
+}
+
+// =============================================================================
+// Accessibility.queryAXTree
+// =============================================================================
+
+/// Parameters for Accessibility.queryAXTree.
+///
+/// Queries the subtree of the given root node for accessible name and role
+/// matches. Exactly one of the root identifiers should be set.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryAXTreeParams {
+    /// Identifier of the node for the root to query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<i32>,
+    /// Identifier of the backend node for the root to query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backend_node_id: Option<BackendNodeId>,
+    /// JavaScript object ID of the node wrapper for the root to query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<String>,
+    /// Find nodes with this computed name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessible_name: Option<String>,
+    /// Find nodes with this computed role.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
+
+/// Result of Accessibility.queryAXTree.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueryAXTreeResult {
+    /// A list of accessibility nodes matching the query.
+    pub nodes: Vec<AXNode>,
+}
+
+#[cfg(test)]
+mod tests;
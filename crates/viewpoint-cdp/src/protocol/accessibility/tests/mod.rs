@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn test_ax_node_deserialization() {
+    let json = r#"{
+        "nodeId": "12",
+        "ignored": false,
+        "role": {"type": "role", "value": "button"},
+        "name": {"type": "computedString", "value": "Submit"},
+        "properties": [
+            {"name": "focusable", "value": {"type": "booleanOrUndefined", "value": true}}
+        ],
+        "parentId": "3",
+        "childIds": ["13"],
+        "backendDOMNodeId": 42
+    }"#;
+
+    let node: AXNode = serde_json::from_str(json).unwrap();
+    assert_eq!(node.node_id, "12");
+    assert!(!node.ignored);
+    assert_eq!(node.role.as_ref().and_then(AXValue::as_str), Some("button"));
+    assert_eq!(node.name.as_ref().and_then(AXValue::as_str), Some("Submit"));
+    assert_eq!(node.backend_dom_node_id, Some(42));
+    assert_eq!(node.child_ids, Some(vec!["13".to_string()]));
+
+    let properties = node.properties.unwrap();
+    assert_eq!(properties[0].name, "focusable");
+    assert_eq!(properties[0].value.value, Some(serde_json::json!(true)));
+}
+
+#[test]
+fn test_query_ax_tree_params() {
+    let params = QueryAXTreeParams {
+        backend_node_id: Some(1),
+        role: Some("button".to_string()),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&params).unwrap();
+    assert_eq!(json, r#"{"backendNodeId":1,"role":"button"}"#);
+}
+
+#[test]
+fn test_get_partial_ax_tree_params() {
+    let params = GetPartialAXTreeParams {
+        object_id: Some("obj-1".to_string()),
+        fetch_relatives: Some(false),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_string(&params).unwrap();
+    assert_eq!(json, r#"{"objectId":"obj-1","fetchRelatives":false}"#);
+}
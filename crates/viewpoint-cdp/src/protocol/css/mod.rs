@@ -0,0 +1,88 @@
+//! CSS domain types.
+//!
+//! The CSS domain exposes stylesheets and tracks which CSS rules are used.
+
+use serde::{Deserialize, Serialize};
+
+/// Stylesheet origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StyleSheetOrigin {
+    /// Stylesheet injected via `Page.addStyleSheet` or similar.
+    Injected,
+    /// User-agent stylesheet.
+    UserAgent,
+    /// Stylesheet created by the inspector.
+    Inspector,
+    /// Regular stylesheet from the page.
+    Regular,
+}
+
+/// Information about a stylesheet.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CssStyleSheetHeader {
+    /// The stylesheet identifier.
+    pub style_sheet_id: String,
+    /// Owner frame identifier.
+    pub frame_id: String,
+    /// Stylesheet resource URL. Empty for inline stylesheets.
+    pub source_url: String,
+    /// Stylesheet origin.
+    pub origin: StyleSheetOrigin,
+    /// Whether the stylesheet is defined inline in the document.
+    #[serde(default)]
+    pub is_inline: bool,
+    /// Length of the stylesheet text.
+    #[serde(default)]
+    pub length: f64,
+}
+
+/// Event: CSS.styleSheetAdded
+///
+/// Fired whenever an active document stylesheet is added, including existing
+/// stylesheets when the CSS domain is enabled.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StyleSheetAddedEvent {
+    /// Added stylesheet metainfo.
+    pub header: CssStyleSheetHeader,
+}
+
+/// CSS coverage information for a single rule.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleUsage {
+    /// The stylesheet the rule belongs to.
+    pub style_sheet_id: String,
+    /// Offset of the start of the rule (including selector) from the start of the stylesheet.
+    pub start_offset: f64,
+    /// Offset of the end of the rule body from the start of the stylesheet.
+    pub end_offset: f64,
+    /// Whether the rule was used.
+    pub used: bool,
+}
+
+/// Result of CSS.stopRuleUsageTracking.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StopRuleUsageTrackingResult {
+    /// Rule usage recorded since tracking started.
+    pub rule_usage: Vec<RuleUsage>,
+}
+
+/// Parameters for CSS.getStyleSheetText.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStyleSheetTextParams {
+    /// The stylesheet identifier.
+    pub style_sheet_id: String,
+}
+
+/// Result of CSS.getStyleSheetText.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStyleSheetTextResult {
+    /// The stylesheet text.
+    pub text: String,
+}
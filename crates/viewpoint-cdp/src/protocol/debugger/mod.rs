@@ -0,0 +1,24 @@
+//! Debugger domain types.
+//!
+//! The Debugger domain exposes JavaScript debugging capabilities. Only the
+//! parts needed to read script sources are modelled here.
+
+use serde::{Deserialize, Serialize};
+
+/// Parameters for Debugger.getScriptSource.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetScriptSourceParams {
+    /// ID of the script to get the source for.
+    pub script_id: String,
+}
+
+/// Result of Debugger.getScriptSource.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetScriptSourceResult {
+    /// Script source (empty in case of Wasm bytecode).
+    pub script_source: String,
+    /// Wasm bytecode, base64-encoded.
+    pub bytecode: Option<String>,
+}
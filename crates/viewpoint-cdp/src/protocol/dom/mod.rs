@@ -195,3 +195,23 @@ pub struct NodeDescription {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub frame_id: Option<String>,
 }
+
+/// Parameters for DOM.getFrameOwner.
+///
+/// Must be sent to the session of the frame's parent document.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFrameOwnerParams {
+    /// Identifier of the frame.
+    pub frame_id: String,
+}
+
+/// Result of DOM.getFrameOwner.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFrameOwnerResult {
+    /// Resulting node.
+    pub backend_node_id: BackendNodeId,
+    /// Id of the node at given coordinates, only when enabled and requested document.
+    pub node_id: Option<NodeId>,
+}
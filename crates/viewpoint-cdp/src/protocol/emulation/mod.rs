@@ -191,6 +191,13 @@ pub struct SetTouchEmulationEnabledParams {
     pub max_touch_points: Option<i32>,
 }
 
+/// Parameters for Emulation.setScriptExecutionDisabled.
+#[derive(Debug, Clone, Serialize)]
+pub struct SetScriptExecutionDisabledParams {
+    /// Whether script execution should be disabled in the page.
+    pub value: bool,
+}
+
 // =============================================================================
 // Geolocation Emulation
 // =============================================================================
@@ -406,3 +413,103 @@ impl SetEmulatedVisionDeficiencyParams {
         }
     }
 }
+
+// =============================================================================
+// CPU Throttling
+// =============================================================================
+
+/// Parameters for Emulation.setCPUThrottlingRate.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCpuThrottlingRateParams {
+    /// Throttling rate as a slowdown factor (1 is no throttle, 2 is 2x slowdown, etc).
+    pub rate: f64,
+}
+
+// =============================================================================
+// Virtual Time
+// =============================================================================
+
+/// Virtual time policy.
+///
+/// Virtual time decouples the page's notion of time from wall-clock time, so
+/// timers, animations and network-dependent rendering become deterministic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VirtualTimePolicy {
+    /// Advance virtual time whenever the page is idle.
+    Advance,
+    /// Pause virtual time.
+    Pause,
+    /// Advance virtual time, but pause while network fetches are pending.
+    PauseIfNetworkFetchesPending,
+}
+
+/// Parameters for Emulation.setVirtualTimePolicy.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetVirtualTimePolicyParams {
+    /// The virtual time policy.
+    pub policy: VirtualTimePolicy,
+    /// Virtual milliseconds after which virtual time is paused and a
+    /// `virtualTimeBudgetExpired` event is sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<f64>,
+    /// Maximum number of tasks that may run before virtual time is forced
+    /// forwards, to prevent deadlock.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_virtual_time_task_starvation_count: Option<u32>,
+    /// Initial virtual time, in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_virtual_time: Option<f64>,
+}
+
+impl SetVirtualTimePolicyParams {
+    /// Create virtual time policy params.
+    pub fn new(policy: VirtualTimePolicy) -> Self {
+        Self {
+            policy,
+            budget: None,
+            max_virtual_time_task_starvation_count: None,
+            initial_virtual_time: None,
+        }
+    }
+
+    /// Set the budget in virtual milliseconds.
+    #[must_use]
+    pub fn budget(mut self, budget: f64) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Set the maximum task starvation count.
+    #[must_use]
+    pub fn max_virtual_time_task_starvation_count(mut self, count: u32) -> Self {
+        self.max_virtual_time_task_starvation_count = Some(count);
+        self
+    }
+
+    /// Set the initial virtual time in seconds since the Unix epoch.
+    #[must_use]
+    pub fn initial_virtual_time(mut self, time: f64) -> Self {
+        self.initial_virtual_time = Some(time);
+        self
+    }
+}
+
+/// Result of Emulation.setVirtualTimePolicy.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetVirtualTimePolicyResult {
+    /// Absolute timer value of the virtual time base, in milliseconds.
+    pub virtual_time_ticks_base: f64,
+}
+
+/// Event: Emulation.virtualTimeBudgetExpired
+///
+/// Sent when the virtual time budget of the current policy has run out.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct VirtualTimeBudgetExpiredEvent {}
+
+#[cfg(test)]
+mod tests;
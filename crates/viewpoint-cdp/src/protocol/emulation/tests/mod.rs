@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn test_set_virtual_time_policy_params() {
+    let params = SetVirtualTimePolicyParams::new(VirtualTimePolicy::PauseIfNetworkFetchesPending)
+        .budget(1000.0)
+        .max_virtual_time_task_starvation_count(50);
+
+    let json = serde_json::to_string(&params).unwrap();
+    assert!(json.contains("\"policy\":\"pauseIfNetworkFetchesPending\""));
+    assert!(json.contains("\"budget\":1000.0"));
+    assert!(json.contains("\"maxVirtualTimeTaskStarvationCount\":50"));
+    assert!(!json.contains("initialVirtualTime"));
+}
+
+#[test]
+fn test_set_virtual_time_policy_pause() {
+    let params = SetVirtualTimePolicyParams::new(VirtualTimePolicy::Pause);
+
+    let json = serde_json::to_string(&params).unwrap();
+    assert_eq!(json, r#"{"policy":"pause"}"#);
+}
+
+#[test]
+fn test_set_virtual_time_policy_result_deserialization() {
+    let json = r#"{"virtualTimeTicksBase": 12345.5}"#;
+    let result: SetVirtualTimePolicyResult = serde_json::from_str(json).unwrap();
+    assert!((result.virtual_time_ticks_base - 12345.5).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_set_cpu_throttling_rate_params() {
+    let params = SetCpuThrottlingRateParams { rate: 4.0 };
+
+    let json = serde_json::to_string(&params).unwrap();
+    assert_eq!(json, r#"{"rate":4.0}"#);
+}
+
+#[test]
+fn test_set_script_execution_disabled_params() {
+    let params = SetScriptExecutionDisabledParams { value: true };
+
+    let json = serde_json::to_string(&params).unwrap();
+    assert_eq!(json, r#"{"value":true}"#);
+}
@@ -1,6 +1,9 @@
 //! CDP protocol domain types.
 
+pub mod accessibility;
 pub mod browser;
+pub mod css;
+pub mod debugger;
 pub mod dom;
 pub mod dom_snapshot;
 pub mod emulation;
@@ -13,7 +16,10 @@ pub mod page;
 mod page_dialog;
 mod page_download;
 mod page_screencast;
+pub mod performance;
+pub mod profiler;
 pub mod runtime;
+pub mod security;
 pub mod storage;
 pub mod target_domain;
 pub mod tracing;
@@ -40,6 +40,13 @@ pub struct SetLifecycleEventsEnabledParams {
     pub enabled: bool,
 }
 
+/// Parameters for Page.setBypassCSP.
+#[derive(Debug, Clone, Serialize)]
+pub struct SetBypassCspParams {
+    /// Whether to bypass page CSP.
+    pub enabled: bool,
+}
+
 /// Parameters for Page.captureScreenshot.
 #[derive(Debug, Clone, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,55 @@
+//! Performance domain types.
+//!
+//! The Performance domain exposes run-time performance counters such as
+//! layout counts, script duration and JavaScript heap size.
+
+use serde::{Deserialize, Serialize};
+
+/// Time domain used for timestamps reported by the Performance domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimeDomain {
+    /// Use monotonically increasing abstract time (default).
+    TimeTicks,
+    /// Use thread running time.
+    ThreadTicks,
+}
+
+/// Parameters for Performance.enable.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableParams {
+    /// Time domain to use for collecting and reporting duration metrics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_domain: Option<TimeDomain>,
+}
+
+/// A run-time execution metric.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Metric {
+    /// Metric name, e.g. `"JSHeapUsedSize"` or `"LayoutCount"`.
+    pub name: String,
+    /// Metric value.
+    pub value: f64,
+}
+
+/// Result of Performance.getMetrics.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetMetricsResult {
+    /// Current values of run-time metrics.
+    pub metrics: Vec<Metric>,
+}
+
+/// Event: Performance.metrics
+///
+/// Current values of the metrics.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetricsEvent {
+    /// Current values of the metrics.
+    pub metrics: Vec<Metric>,
+    /// Timestamp title.
+    pub title: String,
+}
+
+#[cfg(test)]
+mod tests;
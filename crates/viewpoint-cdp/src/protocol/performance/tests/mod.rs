@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn test_enable_params_serialization() {
+    let json = serde_json::to_string(&EnableParams::default()).unwrap();
+    assert_eq!(json, "{}");
+
+    let params = EnableParams {
+        time_domain: Some(TimeDomain::ThreadTicks),
+    };
+    let json = serde_json::to_string(&params).unwrap();
+    assert_eq!(json, r#"{"timeDomain":"threadTicks"}"#);
+}
+
+#[test]
+fn test_get_metrics_result_deserialization() {
+    let json = r#"{
+        "metrics": [
+            {"name": "Timestamp", "value": 12345.5},
+            {"name": "LayoutCount", "value": 3}
+        ]
+    }"#;
+
+    let result: GetMetricsResult = serde_json::from_str(json).unwrap();
+    assert_eq!(result.metrics.len(), 2);
+    assert_eq!(result.metrics[1].name, "LayoutCount");
+    assert!((result.metrics[1].value - 3.0).abs() < f64::EPSILON);
+}
@@ -0,0 +1,102 @@
+//! Profiler domain types.
+//!
+//! The Profiler domain collects CPU profiles and precise JavaScript code
+//! coverage.
+
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Profiler.startPreciseCoverage
+// ============================================================================
+
+/// Parameters for Profiler.startPreciseCoverage.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StartPreciseCoverageParams {
+    /// Collect accurate call counts beyond simple "covered" or "not covered".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_count: Option<bool>,
+    /// Collect block-based coverage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detailed: Option<bool>,
+    /// Allow the backend to send coverage updates on its own initiative.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_triggered_updates: Option<bool>,
+}
+
+impl StartPreciseCoverageParams {
+    /// Create new start precise coverage parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect accurate call counts.
+    #[must_use]
+    pub fn call_count(mut self, call_count: bool) -> Self {
+        self.call_count = Some(call_count);
+        self
+    }
+
+    /// Collect block-based coverage.
+    #[must_use]
+    pub fn detailed(mut self, detailed: bool) -> Self {
+        self.detailed = Some(detailed);
+        self
+    }
+}
+
+// ============================================================================
+// Profiler.takePreciseCoverage
+// ============================================================================
+
+/// Coverage data for a source range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoverageRange {
+    /// JavaScript script source offset for the range start.
+    pub start_offset: u32,
+    /// JavaScript script source offset for the range end.
+    pub end_offset: u32,
+    /// Collected execution count of the source range.
+    pub count: u32,
+}
+
+/// Coverage data for a JavaScript function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionCoverage {
+    /// JavaScript function name.
+    pub function_name: String,
+    /// Source ranges inside the function with coverage data.
+    ///
+    /// The first range covers the whole function; later ranges are nested
+    /// blocks when block coverage is enabled.
+    pub ranges: Vec<CoverageRange>,
+    /// Whether coverage data for this function has block granularity.
+    pub is_block_coverage: bool,
+}
+
+/// Coverage data for a JavaScript script.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptCoverage {
+    /// JavaScript script ID.
+    pub script_id: String,
+    /// JavaScript script name or URL.
+    pub url: String,
+    /// Functions contained in the script that have coverage data.
+    pub functions: Vec<FunctionCoverage>,
+}
+
+/// Result of Profiler.takePreciseCoverage.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakePreciseCoverageResult {
+    /// Coverage data for the current isolate.
+    pub result: Vec<ScriptCoverage>,
+    /// Monotonically increasing time (in seconds) when the coverage update was taken.
+    pub timestamp: f64,
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+fn test_start_precise_coverage_params() {
+    let params = StartPreciseCoverageParams::new()
+        .call_count(true)
+        .detailed(true);
+
+    let json = serde_json::to_string(&params).unwrap();
+    assert!(json.contains("\"callCount\":true"));
+    assert!(json.contains("\"detailed\":true"));
+    assert!(!json.contains("allowTriggeredUpdates"));
+}
+
+#[test]
+fn test_take_precise_coverage_result_deserialization() {
+    let json = r#"{
+        "result": [{
+            "scriptId": "42",
+            "url": "https://example.com/app.js",
+            "functions": [{
+                "functionName": "",
+                "ranges": [{"startOffset": 0, "endOffset": 120, "count": 1}],
+                "isBlockCoverage": true
+            }]
+        }],
+        "timestamp": 1.5
+    }"#;
+
+    let result: TakePreciseCoverageResult = serde_json::from_str(json).unwrap();
+    assert_eq!(result.result.len(), 1);
+
+    let script = &result.result[0];
+    assert_eq!(script.script_id, "42");
+    assert_eq!(script.url, "https://example.com/app.js");
+    assert_eq!(
+        script.functions[0].ranges[0],
+        CoverageRange {
+            start_offset: 0,
+            end_offset: 120,
+            count: 1
+        }
+    );
+}
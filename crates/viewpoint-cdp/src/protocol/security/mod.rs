@@ -0,0 +1,13 @@
+//! Security domain types.
+//!
+//! The Security domain handles certificate errors and reports the security
+//! state of the page.
+
+use serde::Serialize;
+
+/// Parameters for Security.setIgnoreCertificateErrors.
+#[derive(Debug, Clone, Serialize)]
+pub struct SetIgnoreCertificateErrorsParams {
+    /// If true, all certificate errors will be ignored.
+    pub ignore: bool,
+}
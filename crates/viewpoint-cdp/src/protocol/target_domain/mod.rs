@@ -194,6 +194,19 @@ pub struct AttachedToTargetEvent {
     pub waiting_for_debugger: bool,
 }
 
+/// Event: Target.detachedFromTarget
+///
+/// Issued when a session is detached, for example because its target was
+/// destroyed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetachedFromTargetEvent {
+    /// Detached session identifier.
+    pub session_id: String,
+    /// Target ID of the detached session.
+    pub target_id: Option<String>,
+}
+
 /// Event: Target.targetInfoChanged
 ///
 /// Issued when target info (e.g., title, URL, or visibility) changes.
@@ -205,6 +218,20 @@ pub struct TargetInfoChangedEvent {
     pub target_info: TargetInfo,
 }
 
+/// Event: Target.targetCrashed
+///
+/// Issued when a target's renderer process has crashed or was killed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TargetCrashedEvent {
+    /// ID of the crashed target.
+    pub target_id: String,
+    /// Termination status type (e.g. `crashed`, `killed`, `oom`).
+    pub status: String,
+    /// Termination error code.
+    pub error_code: i32,
+}
+
 /// Parameters for Target.setDiscoverTargets.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -213,5 +240,18 @@ pub struct SetDiscoverTargetsParams {
     pub discover: bool,
 }
 
+/// Parameters for Target.setAutoAttach.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetAutoAttachParams {
+    /// Whether to auto-attach to related targets.
+    pub auto_attach: bool,
+    /// Whether to pause new targets until `Runtime.runIfWaitingForDebugger` is sent.
+    pub wait_for_debugger_on_start: bool,
+    /// Enables "flat" access to the session via specifying sessionId.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flatten: Option<bool>,
+}
+
 #[cfg(test)]
 mod tests;
@@ -61,3 +61,36 @@ fn test_target_created_event_deserialization() {
     assert_eq!(event.target_info.target_id, "target-123");
     assert_eq!(event.target_info.opener_id, Some("opener-456".to_string()));
 }
+
+#[test]
+fn test_set_auto_attach_params_serialization() {
+    let params = SetAutoAttachParams {
+        auto_attach: true,
+        wait_for_debugger_on_start: true,
+        flatten: Some(true),
+    };
+
+    let json = serde_json::to_string(&params).unwrap();
+    assert!(json.contains("\"autoAttach\":true"));
+    assert!(json.contains("\"waitForDebuggerOnStart\":true"));
+    assert!(json.contains("\"flatten\":true"));
+}
+
+#[test]
+fn test_detached_from_target_event_deserialization() {
+    let json = r#"{"sessionId": "session-1", "targetId": "worker-1"}"#;
+
+    let event: DetachedFromTargetEvent = serde_json::from_str(json).unwrap();
+    assert_eq!(event.session_id, "session-1");
+    assert_eq!(event.target_id.as_deref(), Some("worker-1"));
+}
+
+#[test]
+fn test_target_crashed_event_deserialization() {
+    let json = r#"{"targetId": "ABC123", "status": "oom", "errorCode": 9}"#;
+
+    let event: TargetCrashedEvent = serde_json::from_str(json).unwrap();
+    assert_eq!(event.target_id, "ABC123");
+    assert_eq!(event.status, "oom");
+    assert_eq!(event.error_code, 9);
+}
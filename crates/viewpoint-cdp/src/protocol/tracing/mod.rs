@@ -5,9 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Transfer mode for tracing data.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[derive(Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum TransferMode {
     /// Report trace events via `Tracing.dataCollected` events.
     #[default]
@@ -306,3 +304,6 @@ pub struct BufferUsageEvent {
     /// A number in range [0..1] that indicates the used size of event buffer.
     pub value: Option<f64>,
 }
+
+#[cfg(test)]
+mod tests;
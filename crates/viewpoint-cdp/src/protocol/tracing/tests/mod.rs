@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+fn test_start_params_serialization() {
+    let params = StartParams::new()
+        .transfer_mode(TransferMode::ReportEvents)
+        .trace_config(TraceConfig::new().include_category("devtools.timeline"));
+
+    let json = serde_json::to_value(&params).unwrap();
+    assert_eq!(json["transferMode"], "ReportEvents");
+    assert_eq!(
+        json["traceConfig"]["includedCategories"],
+        serde_json::json!(["devtools.timeline"])
+    );
+    assert!(json.get("categories").is_none());
+}
+
+#[test]
+fn test_transfer_mode_serialization() {
+    assert_eq!(
+        serde_json::to_string(&TransferMode::ReturnAsStream).unwrap(),
+        "\"ReturnAsStream\""
+    );
+}
+
+#[test]
+fn test_data_collected_event_deserialization() {
+    let json = r#"{"value": [{"name": "RunTask", "ph": "X"}, {"name": "Layout", "ph": "X"}]}"#;
+
+    let event: DataCollectedEvent = serde_json::from_str(json).unwrap();
+    assert_eq!(event.value.len(), 2);
+}
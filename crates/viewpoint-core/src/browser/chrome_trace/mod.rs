@@ -0,0 +1,239 @@
+//! Browser-wide Chrome trace recording.
+//!
+//! Unlike [`context.tracing()`](crate::BrowserContext::tracing), which records
+//! Playwright-style action traces, this records raw Chromium trace events for
+//! the whole browser. The result can be loaded in `chrome://tracing`, Perfetto
+//! or the DevTools Performance panel.
+
+use std::path::Path;
+
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, instrument, warn};
+use viewpoint_cdp::protocol::tracing::{
+    DataCollectedEvent, EndParams, StartParams, TraceConfig, TransferMode,
+};
+use viewpoint_cdp::{CdpConnection, CdpEvent};
+
+use super::{Browser, DEFAULT_TIMEOUT};
+use crate::error::BrowserError;
+
+/// Categories recorded when [`Browser::start_tracing`] is given none.
+///
+/// These match the categories recorded by the DevTools Performance panel.
+pub const DEFAULT_TRACE_CATEGORIES: &[&str] = &[
+    "-*",
+    "devtools.timeline",
+    "v8.execute",
+    "disabled-by-default-devtools.timeline",
+    "disabled-by-default-devtools.timeline.frame",
+    "toplevel",
+    "blink.console",
+    "blink.user_timing",
+    "latencyInfo",
+    "disabled-by-default-devtools.timeline.stack",
+    "disabled-by-default-v8.cpu_profiler",
+];
+
+/// A recorded Chrome trace.
+///
+/// Returned by [`Browser::stop_tracing`].
+#[derive(Debug, Clone, Default)]
+pub struct ChromeTrace {
+    events: Vec<serde_json::Value>,
+}
+
+impl ChromeTrace {
+    /// The raw trace events.
+    pub fn events(&self) -> &[serde_json::Value] {
+        &self.events
+    }
+
+    /// Serialize the trace in the JSON object format understood by
+    /// `chrome://tracing`, i.e. `{"traceEvents": [...]}`.
+    pub fn to_json(&self) -> String {
+        serde_json::json!({ "traceEvents": self.events }).to_string()
+    }
+
+    /// Save the trace as a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        tokio::fs::write(path, self.to_json()).await
+    }
+}
+
+/// Build the `Tracing.start` parameters for a set of categories.
+///
+/// Categories prefixed with `-` are excluded.
+pub(super) fn start_params(categories: &[String]) -> StartParams {
+    let (excluded, included): (Vec<&String>, Vec<&String>) =
+        categories.iter().partition(|c| c.starts_with('-'));
+
+    let mut config = TraceConfig::new()
+        .record_mode("recordAsMuchAsPossible")
+        .included_categories(included.into_iter().cloned().collect());
+    if !excluded.is_empty() {
+        config = config.excluded_categories(
+            excluded
+                .into_iter()
+                .map(|c| c.trim_start_matches('-').to_string())
+                .collect(),
+        );
+    }
+
+    StartParams::new()
+        .transfer_mode(TransferMode::ReportEvents)
+        .trace_config(config)
+}
+
+/// Task collecting the events of an active Chrome trace.
+pub(super) type TraceCollector = JoinHandle<Result<Vec<serde_json::Value>, BrowserError>>;
+
+/// Collect browser-level trace events until tracing completes.
+///
+/// Fails if the receiver lagged, since the trace would be missing events.
+fn spawn_collector(mut events: broadcast::Receiver<CdpEvent>) -> TraceCollector {
+    tokio::spawn(async move {
+        let mut collected = Vec::new();
+        loop {
+            match events.recv().await {
+                Ok(event) if event.session_id.is_some() => {}
+                Ok(event) if event.method == "Tracing.dataCollected" => {
+                    if let Some(data) = event
+                        .params
+                        .and_then(|p| serde_json::from_value::<DataCollectedEvent>(p).ok())
+                    {
+                        collected.extend(data.value);
+                    }
+                }
+                Ok(event) if event.method == "Tracing.tracingComplete" => break,
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    warn!(skipped = n, "Trace collector lagged, events were dropped");
+                    return Err(BrowserError::Tracing(format!(
+                        "trace is incomplete: {n} events were dropped"
+                    )));
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+        Ok(collected)
+    })
+}
+
+impl Browser {
+    /// Start recording a Chrome trace of the whole browser.
+    ///
+    /// If `categories` is empty, [`DEFAULT_TRACE_CATEGORIES`] are recorded.
+    /// Categories prefixed with `-` are excluded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Browser;
+    ///
+    /// # async fn example() -> Result<(), viewpoint_core::CoreError> {
+    /// let browser = Browser::launch().headless(true).launch().await?;
+    /// let context = browser.new_context().await?;
+    /// let page = context.new_page().await?;
+    ///
+    /// browser.start_tracing(["devtools.timeline", "v8.execute"]).await?;
+    /// page.goto("https://example.com").goto().await?;
+    /// let trace = browser.stop_tracing().await?;
+    ///
+    /// // Open in chrome://tracing or the DevTools Performance panel
+    /// trace.save("trace.json").await.expect("failed to save trace");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if tracing is already active or the CDP command fails.
+    #[instrument(level = "info", skip(self, categories))]
+    pub async fn start_tracing<I, S>(&self, categories: I) -> Result<(), BrowserError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut tracing = self.tracing.lock().await;
+        if tracing.is_some() {
+            return Err(BrowserError::Tracing(
+                "tracing is already active".to_string(),
+            ));
+        }
+
+        let mut categories: Vec<String> = categories.into_iter().map(Into::into).collect();
+        if categories.is_empty() {
+            categories = DEFAULT_TRACE_CATEGORIES
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+        }
+
+        // Subscribe before starting so no early data is missed
+        let collector = spawn_collector(self.connection.subscribe_events());
+
+        if let Err(e) = start(&self.connection, &categories).await {
+            collector.abort();
+            return Err(e);
+        }
+
+        info!(categories = categories.len(), "Chrome tracing started");
+        *tracing = Some(collector);
+        Ok(())
+    }
+
+    /// Stop recording and return the Chrome trace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if tracing is not active, the CDP command fails, the
+    /// browser does not finish flushing trace data in time, or trace events
+    /// were dropped because they arrived faster than they were collected.
+    #[instrument(level = "info", skip(self))]
+    pub async fn stop_tracing(&self) -> Result<ChromeTrace, BrowserError> {
+        let mut collector = self
+            .tracing
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| BrowserError::Tracing("tracing is not active".to_string()))?;
+
+        if let Err(e) = self
+            .connection
+            .send_command::<_, serde_json::Value>("Tracing.end", Some(EndParams {}), None)
+            .await
+        {
+            collector.abort();
+            return Err(e.into());
+        }
+
+        let Ok(collected) = tokio::time::timeout(DEFAULT_TIMEOUT, &mut collector).await else {
+            collector.abort();
+            return Err(BrowserError::Tracing(
+                "timed out waiting for trace data".to_string(),
+            ));
+        };
+        let events = collected
+            .map_err(|e| BrowserError::Tracing(format!("trace collector failed: {e}")))??;
+
+        info!(events = events.len(), "Chrome tracing stopped");
+        Ok(ChromeTrace { events })
+    }
+}
+
+/// Send `Tracing.start` on the browser target.
+async fn start(connection: &CdpConnection, categories: &[String]) -> Result<(), BrowserError> {
+    debug!(?categories, "Starting Chrome tracing");
+    connection
+        .send_command::<_, serde_json::Value>("Tracing.start", Some(start_params(categories)), None)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,72 @@
+use super::*;
+
+#[test]
+fn test_start_params_split_categories() {
+    let categories = vec![
+        "-*".to_string(),
+        "devtools.timeline".to_string(),
+        "v8.execute".to_string(),
+    ];
+
+    let json = serde_json::to_value(start_params(&categories)).unwrap();
+    assert_eq!(json["transferMode"], "ReportEvents");
+    assert_eq!(
+        json["traceConfig"]["includedCategories"],
+        serde_json::json!(["devtools.timeline", "v8.execute"])
+    );
+    assert_eq!(
+        json["traceConfig"]["excludedCategories"],
+        serde_json::json!(["*"])
+    );
+}
+
+#[test]
+fn test_start_params_without_exclusions() {
+    let json = serde_json::to_value(start_params(&["toplevel".to_string()])).unwrap();
+    assert!(json["traceConfig"].get("excludedCategories").is_none());
+}
+
+#[test]
+fn test_chrome_trace_json_format() {
+    let trace = ChromeTrace {
+        events: vec![serde_json::json!({"name": "RunTask", "ph": "X", "ts": 1})],
+    };
+
+    let parsed: serde_json::Value = serde_json::from_str(&trace.to_json()).unwrap();
+    assert_eq!(parsed["traceEvents"].as_array().unwrap().len(), 1);
+    assert_eq!(parsed["traceEvents"][0]["name"], "RunTask");
+    assert!(ChromeTrace::default().events().is_empty());
+}
+
+fn trace_event(method: &str) -> CdpEvent {
+    CdpEvent {
+        method: method.to_string(),
+        params: Some(serde_json::json!({ "value": [{ "name": "RunTask" }] })),
+        session_id: None,
+    }
+}
+
+#[tokio::test]
+async fn test_collector_gathers_events_until_complete() {
+    let (tx, rx) = broadcast::channel(16);
+    let collector = spawn_collector(rx);
+    tx.send(trace_event("Tracing.dataCollected")).unwrap();
+    tx.send(trace_event("Tracing.dataCollected")).unwrap();
+    tx.send(trace_event("Tracing.tracingComplete")).unwrap();
+
+    let events = collector.await.unwrap().unwrap();
+    assert_eq!(events.len(), 2);
+}
+
+#[tokio::test]
+async fn test_collector_fails_when_events_were_dropped() {
+    let (tx, rx) = broadcast::channel(1);
+    // Queue more events than the channel holds before the collector runs
+    for _ in 0..3 {
+        tx.send(trace_event("Tracing.dataCollected")).unwrap();
+    }
+    let collector = spawn_collector(rx);
+
+    let err = collector.await.unwrap().unwrap_err();
+    assert!(err.to_string().contains("incomplete"));
+}
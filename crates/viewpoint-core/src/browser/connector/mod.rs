@@ -4,7 +4,6 @@
 //! via HTTP or WebSocket endpoints.
 
 use std::collections::HashMap;
-use std::sync::Arc;
 use std::time::Duration;
 
 use tracing::{info, instrument};
@@ -143,11 +142,6 @@ impl ConnectOverCdpBuilder {
 
         info!("Successfully connected to browser");
 
-        Ok(Browser {
-            connection: Arc::new(connection),
-            process: None,
-            owned: false,
-            _temp_user_data_dir: None,
-        })
+        Ok(Browser::from_connection(connection))
     }
 }
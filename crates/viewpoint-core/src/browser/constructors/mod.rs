@@ -0,0 +1,63 @@
+//! Browser constructor methods.
+//!
+//! Both constructors start the task that watches the connection, and the
+//! process for launched browsers, for a disconnect.
+
+use std::process::Child;
+use std::sync::Arc;
+
+use tempfile::TempDir;
+use tokio::sync::Mutex;
+use viewpoint_cdp::CdpConnection;
+
+use super::{Browser, DisconnectState, Selectors, launcher};
+
+impl Browser {
+    /// Create a browser from a connection to a browser we did not launch.
+    pub(crate) fn from_connection(connection: CdpConnection) -> Self {
+        let connection = Arc::new(connection);
+        let disconnect = Arc::new(DisconnectState::default());
+        let disconnect_monitor =
+            launcher::spawn_monitor(connection.clone(), None, None, disconnect.clone());
+
+        Self {
+            connection,
+            process: None,
+            owned: false,
+            _temp_user_data_dir: None,
+            tracing: Mutex::new(None),
+            selectors: Selectors::default(),
+            disconnect,
+            disconnect_monitor,
+        }
+    }
+
+    /// Create a browser from a launch operation with optional temp directory.
+    pub(crate) fn from_launch(
+        connection: CdpConnection,
+        process: Child,
+        stderr_tail: launcher::StderrTail,
+        temp_user_data_dir: Option<TempDir>,
+    ) -> Self {
+        let connection = Arc::new(connection);
+        let process = Arc::new(Mutex::new(process));
+        let disconnect = Arc::new(DisconnectState::default());
+        let disconnect_monitor = launcher::spawn_monitor(
+            connection.clone(),
+            Some(process.clone()),
+            Some(stderr_tail),
+            disconnect.clone(),
+        );
+
+        Self {
+            connection,
+            process: Some(process),
+            owned: true,
+            _temp_user_data_dir: temp_user_data_dir,
+            tracing: Mutex::new(None),
+            selectors: Selectors::default(),
+            disconnect,
+            disconnect_monitor,
+        }
+    }
+}
@@ -273,6 +273,77 @@ impl<'a> NewContextBuilder<'a> {
         self
     }
 
+    /// Ignore HTTPS certificate errors.
+    ///
+    /// Useful for staging hosts with self-signed certificates.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Browser;
+    ///
+    /// # async fn example() -> Result<(), viewpoint_core::CoreError> {
+    /// let browser = Browser::launch().headless(true).launch().await?;
+    /// let context = browser.new_context_builder()
+    ///     .ignore_https_errors(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn ignore_https_errors(mut self, ignore: bool) -> Self {
+        self.builder = self.builder.ignore_https_errors(ignore);
+        self
+    }
+
+    /// Bypass the page's Content-Security-Policy.
+    ///
+    /// Lets `add_script_tag` and `evaluate` work on pages with a strict CSP.
+    #[must_use]
+    pub fn bypass_csp(mut self, bypass: bool) -> Self {
+        self.builder = self.builder.bypass_csp(bypass);
+        self
+    }
+
+    /// Enable or disable JavaScript in pages.
+    ///
+    /// Scripts evaluated through the API still run when JavaScript is
+    /// disabled; only the page's own scripts are blocked.
+    #[must_use]
+    pub fn java_script_enabled(mut self, enabled: bool) -> Self {
+        self.builder = self.builder.java_script_enabled(enabled);
+        self
+    }
+
+    /// Set the base URL for relative URLs.
+    ///
+    /// `page.goto()`, `route()`, `wait_for_request()` and `wait_for_response()`
+    /// resolve relative URLs and glob patterns like `"/api/**"` against it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Browser;
+    ///
+    /// # async fn example() -> Result<(), viewpoint_core::CoreError> {
+    /// let browser = Browser::launch().headless(true).launch().await?;
+    /// let context = browser.new_context_builder()
+    ///     .base_url("https://staging.example.com")
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let page = context.new_page().await?;
+    /// page.goto("/login").goto().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.builder = self.builder.base_url(base_url);
+        self
+    }
+
     /// Build and create the browser context.
     ///
     /// # Errors
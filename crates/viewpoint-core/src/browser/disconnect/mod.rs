@@ -0,0 +1,197 @@
+//! Browser disconnection tracking.
+//!
+//! A browser disconnects when its CDP connection closes, either because the
+//! browser process exited (crashed, was killed, or was closed) or because the
+//! remote end dropped the connection.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::process::ExitStatus;
+use std::sync::OnceLock;
+
+use tokio::sync::RwLock;
+use tracing::warn;
+
+use super::Browser;
+use crate::context::HandlerId;
+use crate::error::BrowserError;
+
+/// Type alias for the disconnected event handler function.
+pub type DisconnectedHandler =
+    Box<dyn Fn(DisconnectReason) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
+/// Why a browser disconnected.
+///
+/// For launched browsers this carries the exit status of the browser process
+/// and the last lines it wrote to stderr, which usually explain a crash.
+#[derive(Debug, Clone, Default)]
+pub struct DisconnectReason {
+    /// Exit status of the browser process, if it exited.
+    exit_status: Option<ExitStatus>,
+    /// The last lines the browser process wrote to stderr.
+    stderr_tail: Vec<String>,
+}
+
+impl DisconnectReason {
+    /// Create a disconnect reason.
+    pub(crate) fn new(exit_status: Option<ExitStatus>, stderr_tail: Vec<String>) -> Self {
+        Self {
+            exit_status,
+            stderr_tail,
+        }
+    }
+
+    /// Get the exit status of the browser process.
+    ///
+    /// Returns `None` for connected browsers, or if the connection closed
+    /// while the process kept running.
+    pub fn exit_status(&self) -> Option<ExitStatus> {
+        self.exit_status
+    }
+
+    /// Get the last lines the browser process wrote to stderr.
+    ///
+    /// Empty for connected browsers.
+    pub fn stderr_tail(&self) -> &[String] {
+        &self.stderr_tail
+    }
+}
+
+impl fmt::Display for DisconnectReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.exit_status {
+            Some(status) => write!(f, "browser process exited ({status})")?,
+            None => write!(f, "connection to the browser closed")?,
+        }
+
+        if !self.stderr_tail.is_empty() {
+            write!(f, "; last stderr output:")?;
+            for line in &self.stderr_tail {
+                write!(f, "\n  {line}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Shared disconnection state of a browser.
+#[derive(Default)]
+pub(crate) struct DisconnectState {
+    /// The reason, once the browser has disconnected.
+    reason: OnceLock<DisconnectReason>,
+    /// Handlers for 'disconnected' events.
+    handlers: RwLock<HashMap<HandlerId, DisconnectedHandler>>,
+}
+
+impl fmt::Debug for DisconnectState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisconnectState")
+            .field("reason", &self.reason.get())
+            .finish_non_exhaustive()
+    }
+}
+
+impl DisconnectState {
+    /// Get the disconnect reason, if the browser has disconnected.
+    pub(crate) fn reason(&self) -> Option<DisconnectReason> {
+        self.reason.get().cloned()
+    }
+
+    /// Register a handler and return its ID.
+    pub(crate) async fn add_handler(&self, handler: DisconnectedHandler) -> HandlerId {
+        let id = HandlerId::new();
+        self.handlers.write().await.insert(id, handler);
+        id
+    }
+
+    /// Remove a handler by its ID.
+    pub(crate) async fn remove_handler(&self, id: HandlerId) -> bool {
+        self.handlers.write().await.remove(&id).is_some()
+    }
+
+    /// Record the disconnect and notify all handlers.
+    ///
+    /// Only the first call has an effect.
+    pub(crate) async fn disconnected(&self, reason: DisconnectReason) {
+        if self.reason.set(reason.clone()).is_err() {
+            return;
+        }
+        warn!(%reason, "Browser disconnected");
+
+        let handlers = self.handlers.read().await;
+        for handler in handlers.values() {
+            handler(reason.clone()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
+
+impl Browser {
+    /// Check if the browser is still connected.
+    ///
+    /// Returns `false` once the browser process has exited or the CDP
+    /// connection has closed.
+    pub fn is_connected(&self) -> bool {
+        !self.connection.is_closed()
+    }
+
+    /// Get the reason the browser disconnected.
+    ///
+    /// Returns `None` while the browser is connected.
+    pub fn disconnect_reason(&self) -> Option<DisconnectReason> {
+        self.disconnect.reason()
+    }
+
+    /// Register a handler for the browser disconnecting.
+    ///
+    /// The handler is called once, when the browser process exits (including
+    /// after [`close()`](Self::close)) or the connection to the browser is
+    /// lost. For launched browsers the [`DisconnectReason`] carries the exit
+    /// status and the last lines of the browser's stderr.
+    /// Returns a handler ID that can be used to remove the handler.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Browser;
+    ///
+    /// # async fn example() -> Result<(), viewpoint_core::CoreError> {
+    /// let browser = Browser::launch().headless(true).launch().await?;
+    ///
+    /// browser.on_disconnected(|reason| async move {
+    ///     eprintln!("Browser went away: {reason}");
+    /// }).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_disconnected<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(DisconnectReason) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.disconnect
+            .add_handler(Box::new(move |reason| Box::pin(handler(reason))))
+            .await
+    }
+
+    /// Remove a disconnected handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed.
+    pub async fn off_disconnected(&self, id: HandlerId) -> bool {
+        self.disconnect.remove_handler(id).await
+    }
+
+    /// Fail with the disconnect reason if the browser is gone.
+    pub(super) fn ensure_connected(&self) -> Result<(), BrowserError> {
+        if self.connection.is_closed() {
+            return Err(BrowserError::Disconnected(
+                self.disconnect.reason().unwrap_or_default(),
+            ));
+        }
+        Ok(())
+    }
+}
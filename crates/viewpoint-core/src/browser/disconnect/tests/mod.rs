@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::*;
+
+#[test]
+fn test_disconnect_reason_display_without_process() {
+    let reason = DisconnectReason::default();
+    assert_eq!(reason.to_string(), "connection to the browser closed");
+    assert!(reason.exit_status().is_none());
+    assert!(reason.stderr_tail().is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_disconnect_reason_display_with_stderr() {
+    use std::os::unix::process::ExitStatusExt;
+
+    let reason = DisconnectReason::new(
+        Some(ExitStatus::from_raw(1 << 8)),
+        vec![
+            "[ERROR] Failed to launch".to_string(),
+            "Out of memory".to_string(),
+        ],
+    );
+
+    assert_eq!(reason.exit_status().and_then(|s| s.code()), Some(1));
+    assert_eq!(
+        reason.to_string(),
+        "browser process exited (exit status: 1); last stderr output:\n  [ERROR] Failed to launch\n  Out of memory"
+    );
+}
+
+#[tokio::test]
+async fn test_disconnect_state_notifies_handlers_once() {
+    let state = DisconnectState::default();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let handler_calls = calls.clone();
+    let id = state
+        .add_handler(Box::new(move |_reason| {
+            let calls = handler_calls.clone();
+            Box::pin(async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+            })
+        }))
+        .await;
+
+    assert!(state.reason().is_none());
+    state.disconnected(DisconnectReason::default()).await;
+    state.disconnected(DisconnectReason::default()).await;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert!(state.reason().is_some());
+    assert!(state.remove_handler(id).await);
+    assert!(!state.remove_handler(id).await);
+}
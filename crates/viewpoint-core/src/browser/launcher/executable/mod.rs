@@ -0,0 +1,64 @@
+//! Lookup of the Chromium executable.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use tracing::{debug, info, instrument, warn};
+
+use super::chromium_args::CHROMIUM_PATHS;
+use crate::error::BrowserError;
+
+/// Find the Chromium executable.
+///
+/// An explicit path wins, then the `CHROMIUM_PATH` environment variable,
+/// then the common install locations.
+#[instrument(level = "debug")]
+pub(super) fn find(explicit: Option<&Path>) -> Result<PathBuf, BrowserError> {
+    // Check if explicitly set
+    if let Some(path) = explicit {
+        debug!(path = %path.display(), "Checking explicit executable path");
+        if path.exists() {
+            info!(path = %path.display(), "Using explicit executable path");
+            return Ok(path.to_path_buf());
+        }
+        warn!(path = %path.display(), "Explicit executable path does not exist");
+        return Err(BrowserError::ChromiumNotFound);
+    }
+
+    // Check environment variable
+    if let Ok(path_str) = env::var("CHROMIUM_PATH") {
+        let path = PathBuf::from(&path_str);
+        debug!(path = %path.display(), "Checking CHROMIUM_PATH environment variable");
+        if path.exists() {
+            info!(path = %path.display(), "Using CHROMIUM_PATH");
+            return Ok(path);
+        }
+        warn!(path = %path.display(), "CHROMIUM_PATH does not exist");
+    }
+
+    // Search common paths
+    debug!("Searching common Chromium paths");
+    for path_str in CHROMIUM_PATHS {
+        let path = PathBuf::from(path_str);
+        if path.exists() {
+            info!(path = %path.display(), "Found Chromium at common path");
+            return Ok(path);
+        }
+
+        // Also try which/where
+        if let Ok(output) = Command::new("which").arg(path_str).output() {
+            if output.status.success() {
+                let found = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                if !found.is_empty() {
+                    let found_path = PathBuf::from(&found);
+                    info!(path = %found_path.display(), "Found Chromium via 'which'");
+                    return Ok(found_path);
+                }
+            }
+        }
+    }
+
+    warn!("Chromium not found in any expected location");
+    Err(BrowserError::ChromiumNotFound)
+}
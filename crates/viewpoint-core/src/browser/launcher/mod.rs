@@ -1,11 +1,13 @@
 //! Browser launching functionality.
 
 mod chromium_args;
+mod executable;
 mod fs_utils;
+mod monitor;
+mod pipe;
+mod stderr;
 mod user_data;
 
-use std::env;
-use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 use std::process::{Child, Command, Stdio};
 use std::time::Duration;
@@ -15,12 +17,14 @@ use tokio::time::timeout;
 use tracing::{debug, info, instrument, trace, warn};
 use viewpoint_cdp::CdpConnection;
 
-use super::Browser;
+use super::{Browser, DisconnectReason};
 use crate::error::BrowserError;
 
+pub(super) use monitor::spawn as spawn_monitor;
+pub(super) use stderr::StderrTail;
 pub use user_data::UserDataDir;
 
-use chromium_args::{CHROMIUM_PATHS, STABILITY_ARGS};
+use chromium_args::STABILITY_ARGS;
 use fs_utils::copy_dir_recursive;
 
 /// Default timeout for browser launch.
@@ -39,6 +43,8 @@ pub struct BrowserBuilder {
     timeout: Duration,
     /// User data directory configuration.
     user_data_dir: UserDataDir,
+    /// Whether to talk CDP over pipes instead of a WebSocket.
+    pipe: bool,
 }
 
 impl Default for BrowserBuilder {
@@ -60,6 +66,7 @@ impl BrowserBuilder {
             args: Vec::new(),
             timeout: DEFAULT_LAUNCH_TIMEOUT,
             user_data_dir: UserDataDir::Temp,
+            pipe: false,
         }
     }
 
@@ -102,6 +109,33 @@ impl BrowserBuilder {
         self
     }
 
+    /// Connect to the browser over pipes instead of a WebSocket.
+    ///
+    /// The browser is launched with `--remote-debugging-pipe` and CDP messages
+    /// are exchanged over file descriptors 3 and 4, so no debugging port is
+    /// opened. Only supported on Unix.
+    ///
+    /// Default is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Browser;
+    ///
+    /// # async fn example() -> Result<(), viewpoint_core::CoreError> {
+    /// let browser = Browser::launch()
+    ///     .pipe(true)
+    ///     .launch()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn pipe(mut self, pipe: bool) -> Self {
+        self.pipe = pipe;
+        self
+    }
+
     /// Set a persistent user data directory for browser profile.
     ///
     /// When set, browser state (cookies, localStorage, settings) persists
@@ -211,21 +245,33 @@ impl BrowserBuilder {
     /// - Chromium is not found
     /// - The process fails to spawn
     /// - The browser doesn't start within the timeout
+    /// - The browser exits during startup; the error carries its exit status
+    ///   and the last lines of its stderr
     /// - Template directory doesn't exist or can't be copied
-    #[instrument(level = "info", skip(self), fields(headless = self.headless, timeout_ms = self.timeout.as_millis()))]
+    /// - Pipe transport is requested on a non-Unix platform
+    #[instrument(level = "info", skip(self), fields(headless = self.headless, pipe = self.pipe, timeout_ms = self.timeout.as_millis()))]
     pub async fn launch(self) -> Result<Browser, BrowserError> {
         info!("Launching browser");
 
-        let executable = self.find_executable()?;
+        let executable = executable::find(self.executable_path.as_deref())?;
         info!(executable = %executable.display(), "Found Chromium executable");
 
         // Handle user data directory configuration
         let (user_data_path, temp_dir) = self.prepare_user_data_dir()?;
 
-        let mut cmd = Command::new(&executable);
-
-        // Add default arguments
-        cmd.arg("--remote-debugging-port=0");
+        let mut cmd = if self.pipe {
+            let mut cmd = pipe::command(&executable)?;
+            cmd.arg("--remote-debugging-pipe");
+            debug!("Using pipe transport");
+            cmd
+        } else {
+            let mut cmd = Command::new(&executable);
+            cmd.arg("--remote-debugging-port=0");
+            cmd.stdout(Stdio::null());
+            cmd
+        };
+        // Capture stderr for the WebSocket URL and crash reports
+        cmd.stderr(Stdio::piped());
 
         if self.headless {
             cmd.arg("--headless=new");
@@ -252,10 +298,6 @@ impl BrowserBuilder {
             debug!(user_args = ?self.args, "Added user arguments");
         }
 
-        // Capture stderr for the WebSocket URL
-        cmd.stderr(Stdio::piped());
-        cmd.stdout(Stdio::null());
-
         info!("Spawning Chromium process");
         let mut child = cmd.spawn().map_err(|e| {
             warn!(error = %e, "Failed to spawn Chromium process");
@@ -265,44 +307,89 @@ impl BrowserBuilder {
         let pid = child.id();
         info!(pid = pid, "Chromium process spawned");
 
-        // Read the WebSocket URL from stderr
-        debug!("Waiting for DevTools WebSocket URL");
-        let ws_url = timeout(self.timeout, Self::read_ws_url(&mut child))
-            .await
-            .map_err(|_| {
-                warn!(
-                    timeout_ms = self.timeout.as_millis(),
-                    "Browser launch timed out"
-                );
-                BrowserError::LaunchTimeout(self.timeout)
-            })??;
+        let stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| BrowserError::LaunchFailed("failed to capture stderr".into()))?;
+        let (stderr_tail, ws_url) = stderr::capture(stderr)?;
 
-        info!(ws_url = %ws_url, "Got DevTools WebSocket URL");
+        let connection = if self.pipe {
+            debug!("Connecting to browser via CDP pipes");
+            pipe::connect(&mut child)?
+        } else {
+            // Read the WebSocket URL from stderr
+            debug!("Waiting for DevTools WebSocket URL");
+            let ws_url = match timeout(self.timeout, ws_url).await {
+                Ok(Ok(ws_url)) => ws_url,
+                Ok(Err(_)) => {
+                    return Err(Self::exited_during_launch(&mut child, &stderr_tail).await);
+                }
+                Err(_) => {
+                    warn!(
+                        timeout_ms = self.timeout.as_millis(),
+                        "Browser launch timed out"
+                    );
+                    return Err(BrowserError::LaunchTimeout(self.timeout));
+                }
+            };
 
-        // Connect to the browser
-        debug!("Connecting to browser via CDP");
-        let connection = CdpConnection::connect(&ws_url).await?;
+            info!(ws_url = %ws_url, "Got DevTools WebSocket URL");
+
+            // Connect to the browser
+            debug!("Connecting to browser via CDP");
+            CdpConnection::connect(&ws_url).await?
+        };
 
         // Enable target discovery to receive Target.targetCreated events
         // This is required for automatic page tracking (popups, target="_blank" links)
         debug!("Enabling target discovery");
-        connection
-            .send_command::<_, serde_json::Value>(
-                "Target.setDiscoverTargets",
-                Some(
-                    viewpoint_cdp::protocol::target_domain::SetDiscoverTargetsParams {
-                        discover: true,
-                    },
-                ),
-                None,
-            )
-            .await
-            .map_err(|e| {
-                BrowserError::LaunchFailed(format!("Failed to enable target discovery: {e}"))
-            })?;
+        let discovery = connection.send_command::<_, serde_json::Value>(
+            "Target.setDiscoverTargets",
+            Some(
+                viewpoint_cdp::protocol::target_domain::SetDiscoverTargetsParams { discover: true },
+            ),
+            None,
+        );
+        // Over pipes this is the first command, so the launch timeout covers
+        // the browser becoming ready to answer it
+        let discovery = if self.pipe {
+            if let Ok(result) = timeout(self.timeout, discovery).await {
+                result
+            } else {
+                warn!(
+                    timeout_ms = self.timeout.as_millis(),
+                    "Browser launch timed out"
+                );
+                let _ = child.kill();
+                return Err(BrowserError::LaunchTimeout(self.timeout));
+            }
+        } else {
+            discovery.await
+        };
+        if let Err(e) = discovery {
+            if connection.is_closed() {
+                return Err(Self::exited_during_launch(&mut child, &stderr_tail).await);
+            }
+            return Err(BrowserError::LaunchFailed(format!(
+                "Failed to enable target discovery: {e}"
+            )));
+        }
 
         info!(pid = pid, "Browser launched and connected successfully");
-        Ok(Browser::from_launch(connection, child, temp_dir))
+        Ok(Browser::from_launch(
+            connection,
+            child,
+            stderr_tail,
+            temp_dir,
+        ))
+    }
+
+    /// Build the error for a browser that exited before it was ready.
+    async fn exited_during_launch(child: &mut Child, stderr_tail: &StderrTail) -> BrowserError {
+        let exit_status = monitor::wait_for_exit(child).await;
+        let reason = DisconnectReason::new(exit_status, stderr_tail.lines());
+        warn!(%reason, "Browser exited during launch");
+        BrowserError::LaunchFailed(reason.to_string())
     }
 
     /// Prepare the user data directory based on configuration.
@@ -375,89 +462,4 @@ impl BrowserBuilder {
             }
         }
     }
-
-    /// Find the Chromium executable.
-    #[instrument(level = "debug", skip(self))]
-    fn find_executable(&self) -> Result<PathBuf, BrowserError> {
-        // Check if explicitly set
-        if let Some(ref path) = self.executable_path {
-            debug!(path = %path.display(), "Checking explicit executable path");
-            if path.exists() {
-                info!(path = %path.display(), "Using explicit executable path");
-                return Ok(path.clone());
-            }
-            warn!(path = %path.display(), "Explicit executable path does not exist");
-            return Err(BrowserError::ChromiumNotFound);
-        }
-
-        // Check environment variable
-        if let Ok(path_str) = env::var("CHROMIUM_PATH") {
-            let path = PathBuf::from(&path_str);
-            debug!(path = %path.display(), "Checking CHROMIUM_PATH environment variable");
-            if path.exists() {
-                info!(path = %path.display(), "Using CHROMIUM_PATH");
-                return Ok(path);
-            }
-            warn!(path = %path.display(), "CHROMIUM_PATH does not exist");
-        }
-
-        // Search common paths
-        debug!("Searching common Chromium paths");
-        for path_str in CHROMIUM_PATHS {
-            let path = PathBuf::from(path_str);
-            if path.exists() {
-                info!(path = %path.display(), "Found Chromium at common path");
-                return Ok(path);
-            }
-
-            // Also try which/where
-            if let Ok(output) = Command::new("which").arg(path_str).output() {
-                if output.status.success() {
-                    let found = String::from_utf8_lossy(&output.stdout).trim().to_string();
-                    if !found.is_empty() {
-                        let found_path = PathBuf::from(&found);
-                        info!(path = %found_path.display(), "Found Chromium via 'which'");
-                        return Ok(found_path);
-                    }
-                }
-            }
-        }
-
-        warn!("Chromium not found in any expected location");
-        Err(BrowserError::ChromiumNotFound)
-    }
-
-    /// Read the WebSocket URL from the browser's stderr.
-    async fn read_ws_url(child: &mut Child) -> Result<String, BrowserError> {
-        let stderr = child
-            .stderr
-            .take()
-            .ok_or_else(|| BrowserError::LaunchFailed("failed to capture stderr".into()))?;
-
-        // Spawn blocking read in a separate task
-        let handle = tokio::task::spawn_blocking(move || {
-            let reader = BufReader::new(stderr);
-
-            for line in reader.lines() {
-                let Ok(line) = line else { continue };
-
-                trace!(line = %line, "Read line from Chromium stderr");
-
-                // Look for "DevTools listening on ws://..."
-                if let Some(pos) = line.find("DevTools listening on ") {
-                    let url = &line[pos + 22..];
-                    return Some(url.trim().to_string());
-                }
-            }
-
-            None
-        });
-
-        handle
-            .await
-            .map_err(|e| BrowserError::LaunchFailed(e.to_string()))?
-            .ok_or(BrowserError::LaunchFailed(
-                "failed to find WebSocket URL in browser output".into(),
-            ))
-    }
 }
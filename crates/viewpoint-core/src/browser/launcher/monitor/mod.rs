@@ -0,0 +1,99 @@
+//! Monitoring of the browser process and its connection.
+//!
+//! The monitor notices when the browser goes away, either because the
+//! process exited or because the CDP connection closed, and reports the exit
+//! status together with the tail of the browser's stderr.
+
+use std::process::{Child, ExitStatus};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+use tracing::debug;
+use viewpoint_cdp::CdpConnection;
+
+use super::stderr::StderrTail;
+use crate::browser::{DisconnectReason, DisconnectState};
+
+/// Interval between checks of the browser process.
+const EXIT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long to wait for the process to exit once the connection closed.
+const EXIT_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// Interval between checks during the grace period.
+const EXIT_GRACE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Spawn a task that records the disconnect of a browser.
+///
+/// For connected browsers (`process` is `None`) only the connection is
+/// watched.
+pub(crate) fn spawn(
+    connection: Arc<CdpConnection>,
+    process: Option<Arc<Mutex<Child>>>,
+    stderr: Option<StderrTail>,
+    state: Arc<DisconnectState>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let exit_status = if let Some(process) = &process {
+            watch_process(&connection, process).await
+        } else {
+            connection.closed().await;
+            None
+        };
+
+        let stderr_tail = stderr.map(|tail| tail.lines()).unwrap_or_default();
+        state
+            .disconnected(DisconnectReason::new(exit_status, stderr_tail))
+            .await;
+    })
+}
+
+/// Wait until the process exits or the connection closes.
+///
+/// Returns the exit status if the process exited. The process lock is only
+/// held while polling, so closing the browser is never blocked.
+async fn watch_process(connection: &CdpConnection, process: &Mutex<Child>) -> Option<ExitStatus> {
+    loop {
+        tokio::select! {
+            () = connection.closed() => break,
+            () = sleep(EXIT_POLL_INTERVAL) => {}
+        }
+
+        if let Some(status) = exit_status(&mut *process.lock().await) {
+            return Some(status);
+        }
+    }
+
+    debug!("Browser connection closed, waiting for process exit");
+    for _ in 0..grace_attempts() {
+        if let Some(status) = exit_status(&mut *process.lock().await) {
+            return Some(status);
+        }
+        sleep(EXIT_GRACE_INTERVAL).await;
+    }
+    exit_status(&mut *process.lock().await)
+}
+
+/// Wait briefly for a process to exit and return its status.
+pub(super) async fn wait_for_exit(child: &mut Child) -> Option<ExitStatus> {
+    for _ in 0..grace_attempts() {
+        if let Some(status) = exit_status(child) {
+            return Some(status);
+        }
+        sleep(EXIT_GRACE_INTERVAL).await;
+    }
+    exit_status(child)
+}
+
+/// Number of exit checks during the grace period.
+fn grace_attempts() -> u128 {
+    EXIT_GRACE_PERIOD.as_millis() / EXIT_GRACE_INTERVAL.as_millis()
+}
+
+/// Get the exit status of a process if it has exited.
+fn exit_status(child: &mut Child) -> Option<ExitStatus> {
+    child.try_wait().ok().flatten()
+}
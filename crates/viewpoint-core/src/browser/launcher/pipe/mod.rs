@@ -0,0 +1,72 @@
+//! Pipe transport for launched browsers.
+//!
+//! With `--remote-debugging-pipe`, Chromium reads CDP commands from file
+//! descriptor 3 and writes responses and events to file descriptor 4.
+//! `std::process::Command` can only wire up stdio, so the browser is started
+//! through `sh`, which moves our stdin/stdout pipes onto fds 3 and 4 before
+//! exec'ing Chromium. Stderr is left alone, so the launcher captures it for
+//! launch and crash diagnostics just as in WebSocket mode.
+
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+
+use viewpoint_cdp::CdpConnection;
+
+use crate::error::BrowserError;
+
+/// Shell script that moves stdin/stdout onto fds 3/4 and execs the browser.
+///
+/// `$0` is the executable and `$@` its arguments.
+#[cfg(unix)]
+const EXEC_WITH_PIPES: &str = r#"exec "$0" "$@" 3<&0 4>&1 </dev/null >/dev/null"#;
+
+/// Build a command that launches `executable` with CDP pipes on fds 3 and 4.
+#[cfg(unix)]
+#[allow(clippy::unnecessary_wraps)] // Fallible on non-Unix platforms
+pub(super) fn command(executable: &Path) -> Result<Command, BrowserError> {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(EXEC_WITH_PIPES).arg(executable);
+    cmd.stdin(Stdio::piped());
+    cmd.stdout(Stdio::piped());
+    Ok(cmd)
+}
+
+/// Build a command that launches `executable` with CDP pipes on fds 3 and 4.
+#[cfg(not(unix))]
+pub(super) fn command(_executable: &Path) -> Result<Command, BrowserError> {
+    Err(BrowserError::LaunchFailed(
+        "pipe transport is only supported on Unix".into(),
+    ))
+}
+
+/// Connect to a browser spawned by [`command`].
+#[cfg(unix)]
+pub(super) fn connect(child: &mut Child) -> Result<CdpConnection, BrowserError> {
+    use std::os::fd::OwnedFd;
+
+    use tokio::net::unix::pipe;
+
+    let stdin = child
+        .stdin
+        .take()
+        .ok_or_else(|| BrowserError::LaunchFailed("failed to capture stdin".into()))?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| BrowserError::LaunchFailed("failed to capture stdout".into()))?;
+
+    let writer = pipe::Sender::from_owned_fd(OwnedFd::from(stdin))
+        .map_err(|e| BrowserError::LaunchFailed(format!("failed to open CDP pipe: {e}")))?;
+    let reader = pipe::Receiver::from_owned_fd(OwnedFd::from(stdout))
+        .map_err(|e| BrowserError::LaunchFailed(format!("failed to open CDP pipe: {e}")))?;
+
+    Ok(CdpConnection::connect_pipe(reader, writer))
+}
+
+/// Connect to a browser spawned by [`command`].
+#[cfg(not(unix))]
+pub(super) fn connect(_child: &mut Child) -> Result<CdpConnection, BrowserError> {
+    Err(BrowserError::LaunchFailed(
+        "pipe transport is only supported on Unix".into(),
+    ))
+}
@@ -0,0 +1,77 @@
+//! Capture of the browser's stderr.
+//!
+//! Chromium reports startup failures and crash reasons on stderr. The stream
+//! is drained on a dedicated thread for the lifetime of the process, keeping
+//! the last lines for error reports, and the DevTools WebSocket URL is
+//! extracted when the browser announces it.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
+use std::process::ChildStderr;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::oneshot;
+use tracing::trace;
+
+use crate::error::BrowserError;
+
+/// Number of stderr lines kept for error reports.
+const STDERR_TAIL_LINES: usize = 30;
+
+/// Prefix of the line announcing the DevTools WebSocket URL.
+const DEVTOOLS_LISTENING: &str = "DevTools listening on ";
+
+/// The last lines written to the browser's stderr.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StderrTail(Arc<Mutex<VecDeque<String>>>);
+
+impl StderrTail {
+    /// Get the captured lines, oldest first.
+    pub(crate) fn lines(&self) -> Vec<String> {
+        self.0.lock().iter().cloned().collect()
+    }
+
+    /// Record a line, dropping the oldest one if the tail is full.
+    fn push(&self, line: String) {
+        let mut lines = self.0.lock();
+        if lines.len() == STDERR_TAIL_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+}
+
+/// Start draining the browser's stderr.
+///
+/// Returns the captured tail and a receiver for the DevTools WebSocket URL.
+/// The receiver fails if stderr closes before the URL is announced, which
+/// means the browser exited during startup.
+pub(super) fn capture(
+    stderr: ChildStderr,
+) -> Result<(StderrTail, oneshot::Receiver<String>), BrowserError> {
+    let tail = StderrTail::default();
+    let (url_tx, url_rx) = oneshot::channel();
+
+    let thread_tail = tail.clone();
+    std::thread::Builder::new()
+        .name("viewpoint-browser-stderr".into())
+        .spawn(move || {
+            let mut url_tx = Some(url_tx);
+            for line in BufReader::new(stderr).lines() {
+                let Ok(line) = line else { continue };
+                trace!(line = %line, "Read line from Chromium stderr");
+
+                if let Some(pos) = line.find(DEVTOOLS_LISTENING) {
+                    if let Some(tx) = url_tx.take() {
+                        let url = line[pos + DEVTOOLS_LISTENING.len()..].trim().to_string();
+                        let _ = tx.send(url);
+                    }
+                }
+                thread_tail.push(line);
+            }
+        })
+        .map_err(|e| BrowserError::LaunchFailed(format!("failed to read browser stderr: {e}")))?;
+
+    Ok((tail, url_rx))
+}
@@ -72,10 +72,14 @@
 //! - **Created contexts** (`new_context()`) are owned - closing disposes them
 //! - **Discovered contexts** (`contexts()`) are not owned - closing only disconnects
 
+mod chrome_trace;
 mod connector;
+mod constructors;
 mod context_builder;
+mod disconnect;
 mod launcher;
 mod process;
+pub(crate) mod selectors;
 
 use std::process::Child;
 use std::sync::Arc;
@@ -83,6 +87,7 @@ use std::time::Duration;
 
 use tempfile::TempDir;
 use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
 use tracing::info;
 use viewpoint_cdp::CdpConnection;
 use viewpoint_cdp::protocol::target_domain::{
@@ -92,9 +97,13 @@ use viewpoint_cdp::protocol::target_domain::{
 use crate::context::{BrowserContext, ContextOptions, StorageState, StorageStateSource};
 use crate::error::BrowserError;
 
+pub use chrome_trace::{ChromeTrace, DEFAULT_TRACE_CATEGORIES};
 pub use connector::ConnectOverCdpBuilder;
 pub use context_builder::NewContextBuilder;
+pub(crate) use disconnect::DisconnectState;
+pub use disconnect::{DisconnectReason, DisconnectedHandler};
 pub use launcher::{BrowserBuilder, UserDataDir};
+pub use selectors::Selectors;
 
 /// Default timeout for browser operations.
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
@@ -132,12 +141,20 @@ pub struct Browser {
     /// CDP connection to the browser.
     connection: Arc<CdpConnection>,
     /// Browser process (only present if we launched it).
-    process: Option<Mutex<Child>>,
+    process: Option<Arc<Mutex<Child>>>,
     /// Whether the browser was launched by us (vs connected to).
     owned: bool,
     /// Temporary user data directory (if using Temp or TempFromTemplate mode).
     /// Stored here to ensure cleanup on drop.
     _temp_user_data_dir: Option<TempDir>,
+    /// Collector for an in-progress Chrome trace.
+    tracing: Mutex<Option<chrome_trace::TraceCollector>>,
+    /// Custom selector engines installed in new contexts.
+    selectors: Selectors,
+    /// Disconnect reason and handlers.
+    disconnect: Arc<DisconnectState>,
+    /// Task watching the process and connection for a disconnect.
+    disconnect_monitor: JoinHandle<()>,
 }
 
 impl Browser {
@@ -196,12 +213,7 @@ impl Browser {
                 BrowserError::ConnectionFailed(format!("Failed to enable target discovery: {e}"))
             })?;
 
-        Ok(Self {
-            connection: Arc::new(connection),
-            process: None,
-            owned: false,
-            _temp_user_data_dir: None,
-        })
+        Ok(Self::from_connection(connection))
     }
 
     /// Connect to an already-running browser via HTTP endpoint or WebSocket URL.
@@ -282,6 +294,7 @@ impl Browser {
     /// Returns an error if querying contexts fails.
     pub async fn contexts(&self) -> Result<Vec<BrowserContext>, BrowserError> {
         info!("Getting browser contexts");
+        self.ensure_connected()?;
 
         let result: GetBrowserContextsResult = self
             .connection
@@ -312,30 +325,6 @@ impl Browser {
         Ok(contexts)
     }
 
-    /// Create a browser from an existing connection and process (legacy, no temp dir).
-    pub(crate) fn from_connection_and_process(connection: CdpConnection, process: Child) -> Self {
-        Self {
-            connection: Arc::new(connection),
-            process: Some(Mutex::new(process)),
-            owned: true,
-            _temp_user_data_dir: None,
-        }
-    }
-
-    /// Create a browser from a launch operation with optional temp directory.
-    pub(crate) fn from_launch(
-        connection: CdpConnection,
-        process: Child,
-        temp_user_data_dir: Option<TempDir>,
-    ) -> Self {
-        Self {
-            connection: Arc::new(connection),
-            process: Some(Mutex::new(process)),
-            owned: true,
-            _temp_user_data_dir: temp_user_data_dir,
-        }
-    }
-
     /// Create a new isolated browser context.
     ///
     /// Browser contexts are isolated environments within the browser,
@@ -346,6 +335,8 @@ impl Browser {
     ///
     /// Returns an error if context creation fails.
     pub async fn new_context(&self) -> Result<BrowserContext, BrowserError> {
+        self.ensure_connected()?;
+
         let result: CreateBrowserContextResult = self
             .connection
             .send_command(
@@ -355,10 +346,10 @@ impl Browser {
             )
             .await?;
 
-        Ok(BrowserContext::new(
-            self.connection.clone(),
-            result.browser_context_id,
-        ))
+        let context = BrowserContext::new(self.connection.clone(), result.browser_context_id);
+        self.install_selector_engines(&context).await?;
+
+        Ok(context)
     }
 
     /// Create a new context options builder.
@@ -395,6 +386,8 @@ impl Browser {
         &self,
         options: ContextOptions,
     ) -> Result<BrowserContext, BrowserError> {
+        self.ensure_connected()?;
+
         // Load storage state if specified
         let storage_state = match &options.storage_state {
             Some(StorageStateSource::Path(path)) => {
@@ -429,6 +422,7 @@ impl Browser {
 
         // Apply options
         context.apply_options().await?;
+        self.install_selector_engines(&context).await?;
 
         // Restore storage state if any
         if let Some(state) = storage_state {
@@ -441,6 +435,12 @@ impl Browser {
                 context.add_init_script(&local_storage_script).await?;
             }
 
+            // Restore sessionStorage via init script
+            let session_storage_script = state.to_session_storage_init_script();
+            if !session_storage_script.is_empty() {
+                context.add_init_script(&session_storage_script).await?;
+            }
+
             // Restore IndexedDB via init script
             let indexed_db_script = state.to_indexed_db_init_script();
             if !indexed_db_script.is_empty() {
@@ -483,6 +483,8 @@ impl Browser {
 
 impl Drop for Browser {
     fn drop(&mut self) {
+        self.disconnect_monitor.abort();
+
         // Try to kill and reap the process if we own it
         if self.owned {
             if let Some(ref process_mutex) = self.process {
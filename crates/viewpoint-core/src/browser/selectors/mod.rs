@@ -0,0 +1,151 @@
+//! Custom selector engine registration.
+//!
+//! A selector engine is a JavaScript object with `queryAll(root, body)`
+//! (or `query(root, body)`) that finds elements for a selector body. Once
+//! registered under a name, it is used by selectors prefixed with that name,
+//! e.g. `page.locator("data-qa=checkout >> button")`. `root` is the document,
+//! or each element matched by the previous part of a `>>` chain.
+
+use std::sync::RwLock;
+
+use tracing::{debug, instrument};
+
+use super::Browser;
+use crate::context::BrowserContext;
+use crate::error::BrowserError;
+use crate::page::locator::selector::{
+    BUILTIN_ENGINES, SELECTOR_ENGINES, is_engine_name, js_string_literal,
+};
+
+/// Browser-wide registry of custom selector engines.
+///
+/// Obtain it with [`Browser::selectors`](super::Browser::selectors). Engines
+/// are installed in every context created by the browser after they are
+/// registered, so register them before creating contexts. Use
+/// [`BrowserContext::register_selector_engine`](crate::BrowserContext::register_selector_engine)
+/// to add an engine to a single context.
+///
+/// # Example
+///
+/// ```no_run
+/// use viewpoint_core::Browser;
+///
+/// # async fn example() -> Result<(), viewpoint_core::CoreError> {
+/// let browser = Browser::launch().headless(true).launch().await?;
+///
+/// browser.selectors().register(
+///     "data-qa",
+///     r#"({
+///         queryAll(root, body) {
+///             return root.querySelectorAll(`[data-qa="${body}"]`);
+///         }
+///     })"#,
+/// )?;
+///
+/// let context = browser.new_context().await?;
+/// let page = context.new_page().await?;
+/// page.locator("data-qa=checkout >> button").click().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Selectors {
+    /// Registered engine names and their init scripts.
+    engines: RwLock<Vec<(String, String)>>,
+}
+
+impl Selectors {
+    /// Register a selector engine.
+    ///
+    /// `script` is a JavaScript expression evaluating to an object with a
+    /// `queryAll(root, body)` method returning the matching elements, or a
+    /// `query(root, body)` method returning the first match.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is not a valid engine name, is used by a
+    /// built-in engine, or is already registered.
+    #[instrument(level = "debug", skip(self, script))]
+    pub fn register(&self, name: &str, script: &str) -> Result<(), BrowserError> {
+        let init_script =
+            engine_init_script(name, script).map_err(BrowserError::InvalidSelectorEngine)?;
+
+        let mut engines = self
+            .engines
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if engines.iter().any(|(registered, _)| registered == name) {
+            return Err(BrowserError::InvalidSelectorEngine(format!(
+                "selector engine \"{name}\" is already registered"
+            )));
+        }
+        engines.push((name.to_string(), init_script));
+        debug!("Selector engine registered");
+        Ok(())
+    }
+
+    /// Names of the registered engines.
+    pub fn names(&self) -> Vec<String> {
+        self.engines
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Init scripts installing the registered engines in a page.
+    pub(super) fn init_scripts(&self) -> Vec<String> {
+        self.engines
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .map(|(_, script)| script.clone())
+            .collect()
+    }
+}
+
+/// Build the script installing a selector engine in a page.
+///
+/// Returns a description of the problem if `name` cannot be used.
+pub(crate) fn engine_init_script(name: &str, script: &str) -> Result<String, String> {
+    if !is_engine_name(name) {
+        return Err(format!(
+            "invalid selector engine name \"{name}\": use letters, digits, '_' and '-'"
+        ));
+    }
+    if BUILTIN_ENGINES.contains(&name) {
+        return Err(format!(
+            "selector engine name \"{name}\" is reserved for a built-in engine"
+        ));
+    }
+
+    Ok(format!(
+        "(globalThis.{SELECTOR_ENGINES} ??= {{}})[{}] = ({});",
+        js_string_literal(name),
+        script.trim().trim_end_matches(';')
+    ))
+}
+
+#[cfg(test)]
+mod tests;
+
+impl Browser {
+    /// Get the registry of custom selector engines.
+    ///
+    /// Engines registered here are installed in contexts created afterwards.
+    pub fn selectors(&self) -> &Selectors {
+        &self.selectors
+    }
+
+    /// Install the registered selector engines in a new context.
+    pub(super) async fn install_selector_engines(
+        &self,
+        context: &BrowserContext,
+    ) -> Result<(), BrowserError> {
+        for script in self.selectors.init_scripts() {
+            context.add_init_script(&script).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use super::*;
+
+const ENGINE: &str = "({ queryAll: (root, body) => root.querySelectorAll(body) })";
+
+#[test]
+fn test_engine_init_script() {
+    let script = engine_init_script("data-qa", &format!("{ENGINE};\n")).unwrap();
+    assert_eq!(
+        script,
+        format!("(globalThis.__viewpointSelectorEngines ??= {{}})['data-qa'] = ({ENGINE});")
+    );
+}
+
+#[test]
+fn test_engine_init_script_rejects_invalid_names() {
+    assert!(engine_init_script("", ENGINE).is_err());
+    assert!(engine_init_script("1qa", ENGINE).is_err());
+    assert!(engine_init_script("data qa", ENGINE).is_err());
+    assert!(engine_init_script("xpath", ENGINE).is_err());
+}
+
+#[test]
+fn test_register_rejects_duplicates() {
+    let selectors = Selectors::default();
+    selectors.register("component", ENGINE).unwrap();
+    selectors.register("data-qa", ENGINE).unwrap();
+    assert!(matches!(
+        selectors.register("component", ENGINE),
+        Err(BrowserError::InvalidSelectorEngine(_))
+    ));
+    assert_eq!(selectors.names(), vec!["component", "data-qa"]);
+    assert_eq!(selectors.init_scripts().len(), 2);
+}
@@ -62,6 +62,11 @@ impl BrowserContext {
             ));
         }
 
+        // Copy base URL if set
+        if let Some(ref base_url) = self.options.base_url {
+            options = options.base_url(base_url);
+        }
+
         // Create API context
         let api = APIRequestContext::new(options)
             .await
@@ -0,0 +1,348 @@
+//! Context-wide clock mocking.
+//!
+//! [`ContextClock`] controls time in every page, frame and worker of a
+//! [`BrowserContext`], including pages and popups opened later. All of them
+//! share one virtual timeline: the timeline is tracked here and pushed to each
+//! target after every operation, and is embedded in an init script so that it
+//! survives navigation.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "integration")]
+//! # tokio_test::block_on(async {
+//! # use viewpoint_core::Browser;
+//! use std::time::Duration;
+//! # let browser = Browser::launch().headless(true).launch().await.unwrap();
+//! # let context = browser.new_context().await.unwrap();
+//!
+//! context.clock().install().await.unwrap();
+//! context.clock().set_fixed_time("2024-01-01T00:00:00Z").await.unwrap();
+//!
+//! // Pages created after installing share the same timeline
+//! let page = context.new_page().await.unwrap();
+//!
+//! // Advance time in every page, firing scheduled timers
+//! context.clock().run_for(Duration::from_secs(30 * 60)).await.unwrap();
+//! # });
+//! ```
+
+mod operations;
+mod timeline;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use tracing::{debug, instrument, warn};
+use viewpoint_cdp::protocol::page::{
+    AddScriptToEvaluateOnNewDocumentParams, AddScriptToEvaluateOnNewDocumentResult,
+    RemoveScriptToEvaluateOnNewDocumentParams,
+};
+use viewpoint_cdp::protocol::runtime::{EvaluateParams, EvaluateResult};
+
+use super::BrowserContext;
+use crate::error::ContextError;
+use crate::page::{Page, Worker};
+
+use timeline::{Timeline, real_now};
+
+/// Shared state of the context clock.
+#[derive(Debug, Default)]
+pub(crate) struct ClockState {
+    /// The timeline, or `None` if the clock is not installed.
+    timeline: Option<Timeline>,
+    /// Init script identifiers, by page session ID.
+    script_ids: HashMap<String, String>,
+}
+
+impl ClockState {
+    /// Script to run in new workers, if the clock is installed.
+    pub(crate) fn init_script(&self) -> Option<String> {
+        self.timeline.map(Timeline::script)
+    }
+}
+
+/// Install the context clock into a newly created page, if it is installed.
+pub(crate) async fn apply_to_page(state: &Arc<RwLock<ClockState>>, page: &Page) {
+    let mut state = state.write().await;
+    if let Some(timeline) = state.timeline {
+        if let Err(e) = sync_page(&mut state, page, &timeline.script()).await {
+            warn!(target_id = %page.target_id(), error = %e, "Failed to install context clock in new page");
+        }
+    }
+}
+
+/// Register the clock script on a page and run it in its frames and workers.
+async fn sync_page(state: &mut ClockState, page: &Page, script: &str) -> Result<(), ContextError> {
+    let connection = page.connection();
+    let session_id = page.session_id();
+
+    if let Some(identifier) = state.script_ids.remove(session_id) {
+        remove_init_script(page, identifier).await?;
+    }
+
+    let result = connection
+        .send_command::<_, AddScriptToEvaluateOnNewDocumentResult>(
+            "Page.addScriptToEvaluateOnNewDocument",
+            Some(AddScriptToEvaluateOnNewDocumentParams {
+                source: script.to_string(),
+                world_name: None,
+                include_command_line_api: None,
+                run_immediately: None,
+            }),
+            Some(session_id),
+        )
+        .await?;
+    state
+        .script_ids
+        .insert(session_id.to_string(), result.identifier);
+
+    page.set_worker_init_script(Some(script.to_string())).await;
+    evaluate_in_page(page, script).await;
+    Ok(())
+}
+
+/// Remove a previously registered clock init script from a page.
+async fn remove_init_script(page: &Page, identifier: String) -> Result<(), ContextError> {
+    page.connection()
+        .send_command::<_, serde_json::Value>(
+            "Page.removeScriptToEvaluateOnNewDocument",
+            Some(RemoveScriptToEvaluateOnNewDocumentParams { identifier }),
+            Some(page.session_id()),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Evaluate an expression in every frame and worker of a page.
+///
+/// Failures (for example a frame navigating away) are logged and skipped,
+/// since the init script brings new documents onto the timeline.
+async fn evaluate_in_page(page: &Page, expression: &str) -> Vec<serde_json::Value> {
+    let mut context_ids: Vec<Option<_>> = page
+        .context_registry()
+        .main_world_contexts()
+        .into_iter()
+        .map(Some)
+        .collect();
+    if context_ids.is_empty() {
+        context_ids.push(None);
+    }
+
+    let mut values = Vec::new();
+    for context_id in context_ids {
+        let result = page
+            .connection()
+            .send_command::<_, EvaluateResult>(
+                "Runtime.evaluate",
+                Some(EvaluateParams {
+                    expression: expression.to_string(),
+                    object_group: None,
+                    include_command_line_api: None,
+                    silent: Some(true),
+                    context_id,
+                    return_by_value: Some(true),
+                    await_promise: Some(false),
+                }),
+                Some(page.session_id()),
+            )
+            .await;
+        match result {
+            Ok(result) if result.exception_details.is_none() => {
+                values.push(result.result.value.unwrap_or(serde_json::Value::Null));
+            }
+            Ok(_) => warn!("Clock evaluation threw in frame"),
+            Err(e) => warn!(error = %e, "Clock evaluation failed in frame"),
+        }
+    }
+
+    for worker in page.workers().await {
+        values.extend(evaluate_in_worker(&worker, expression).await);
+    }
+    values
+}
+
+/// Evaluate an expression in a worker, logging and skipping failures.
+async fn evaluate_in_worker(worker: &Worker, expression: &str) -> Option<serde_json::Value> {
+    worker
+        .evaluate::<serde_json::Value>(expression)
+        .await
+        .map_err(|e| warn!(url = %worker.url(), error = %e, "Clock evaluation failed in worker"))
+        .ok()
+}
+
+/// Clock controller for mocking time across a browser context.
+///
+/// Obtain it with [`BrowserContext::clock`]. Unlike [`Page::clock`], the
+/// clock is installed into every current and future page, frame and worker
+/// of the context and keeps them on a single virtual timeline.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use viewpoint_core::BrowserContext;
+///
+/// # async fn example(context: &BrowserContext) -> Result<(), viewpoint_core::CoreError> {
+/// context.clock().install().await?;
+/// context.clock().pause_at("2024-01-01T09:00:00Z").await?;
+///
+/// let first = context.new_page().await?;
+/// let second = context.new_page().await?;
+///
+/// // Both tabs see the session expire together
+/// context.clock().fast_forward(Duration::from_secs(3600)).await?;
+/// context.clock().run_all_timers().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ContextClock<'a> {
+    pages: &'a Arc<RwLock<Vec<Page>>>,
+    service_workers: &'a Arc<RwLock<Vec<Worker>>>,
+    shared_workers: &'a Arc<RwLock<Vec<Worker>>>,
+    state: &'a Arc<RwLock<ClockState>>,
+}
+
+impl ContextClock<'_> {
+    /// Install clock mocking into every page, frame and worker of the context.
+    ///
+    /// Time starts flowing from the current real time. Pages, popups and
+    /// workers created later are installed automatically, and the clock
+    /// survives navigation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock script cannot be registered on a page.
+    /// Frames and workers that fail to evaluate the script (for example
+    /// because they are navigating away) are logged and skipped.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn install(&self) -> Result<(), ContextError> {
+        let now = real_now();
+        let mut state = self.state.write().await;
+        let timeline = state.timeline.unwrap_or(Timeline::Flowing {
+            mocked_start: now,
+            real_start: now,
+        });
+        self.apply(&mut state, timeline).await?;
+
+        debug!("Context clock installed");
+        Ok(())
+    }
+
+    /// Uninstall clock mocking and restore the original time functions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock script cannot be removed from a page.
+    /// Frames and workers that fail to evaluate the uninstall are logged and
+    /// skipped.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn uninstall(&self) -> Result<(), ContextError> {
+        let mut state = self.state.write().await;
+        state.timeline = None;
+
+        let expression = "globalThis.__viewpointClock && globalThis.__viewpointClock.uninstall()";
+        for page in self.pages.read().await.iter() {
+            if let Some(identifier) = state.script_ids.remove(page.session_id()) {
+                remove_init_script(page, identifier).await?;
+            }
+            page.set_worker_init_script(None).await;
+            evaluate_in_page(page, expression).await;
+        }
+        for worker in self.context_workers().await {
+            evaluate_in_worker(&worker, expression).await;
+        }
+        state.script_ids.clear();
+
+        debug!("Context clock uninstalled");
+        Ok(())
+    }
+
+    /// Check if the context clock is installed.
+    pub async fn is_installed(&self) -> bool {
+        self.state.read().await.timeline.is_some()
+    }
+
+    /// Get the timeline, or an error if the clock is not installed.
+    fn installed(state: &ClockState) -> Result<Timeline, ContextError> {
+        state.timeline.ok_or_else(|| {
+            ContextError::Internal("Clock is not installed; call install() first".to_string())
+        })
+    }
+
+    /// Fire timers up to `target` everywhere, then move the timeline there.
+    async fn run_to(
+        &self,
+        state: &mut ClockState,
+        timeline: Timeline,
+        target: f64,
+    ) -> Result<u32, ContextError> {
+        let now = real_now();
+        let ms = (target - timeline.now(now)).max(0.0);
+        let fired = Self::sum(
+            self.evaluate_everywhere(&format!("globalThis.__viewpointClock.runFor({ms})"))
+                .await,
+        );
+        self.apply(state, timeline.moved_to(target, real_now()))
+            .await?;
+        Ok(fired)
+    }
+
+    /// Adopt a new timeline and push it to every page and worker.
+    async fn apply(&self, state: &mut ClockState, timeline: Timeline) -> Result<(), ContextError> {
+        state.timeline = Some(timeline);
+        let script = timeline.script();
+
+        for page in self.pages.read().await.iter() {
+            sync_page(state, page, &script).await?;
+        }
+        for worker in self.context_workers().await {
+            evaluate_in_worker(&worker, &script).await;
+        }
+        Ok(())
+    }
+
+    /// Evaluate an expression in every page, frame and worker.
+    async fn evaluate_everywhere(&self, expression: &str) -> Vec<serde_json::Value> {
+        let mut values = Vec::new();
+        for page in self.pages.read().await.iter() {
+            values.extend(evaluate_in_page(page, expression).await);
+        }
+        for worker in self.context_workers().await {
+            values.extend(evaluate_in_worker(&worker, expression).await);
+        }
+        values
+    }
+
+    /// Service and shared workers of the context.
+    async fn context_workers(&self) -> Vec<Worker> {
+        let mut workers = self.service_workers.read().await.clone();
+        workers.extend(self.shared_workers.read().await.iter().cloned());
+        workers
+    }
+
+    /// Sum numeric results.
+    fn sum(values: Vec<serde_json::Value>) -> u32 {
+        values
+            .iter()
+            .filter_map(serde_json::Value::as_f64)
+            .map(|v| v as u32)
+            .sum()
+    }
+}
+
+impl BrowserContext {
+    /// Get the context-wide clock controller.
+    ///
+    /// See [`ContextClock`] for details.
+    pub fn clock(&self) -> ContextClock<'_> {
+        ContextClock {
+            pages: &self.pages,
+            service_workers: &self.service_workers,
+            shared_workers: &self.shared_workers,
+            state: &self.clock_state,
+        }
+    }
+}
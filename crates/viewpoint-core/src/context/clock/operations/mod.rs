@@ -0,0 +1,192 @@
+//! Context clock operations for time manipulation.
+
+use std::time::Duration;
+
+use tracing::{debug, instrument};
+
+use super::ContextClock;
+use super::timeline::{Timeline, real_now, to_millis};
+use crate::error::ContextError;
+use crate::page::TimeValue;
+
+impl ContextClock<'_> {
+    /// Set a fixed time that doesn't advance.
+    ///
+    /// Time remains frozen until you call `run_for`, `fast_forward`,
+    /// `set_system_time`, or `resume`.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time to set, either as an ISO 8601 string (e.g., "2024-01-01T00:00:00Z")
+    ///   or a Unix timestamp in milliseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock is not installed, the time is invalid, or
+    /// the clock script cannot be registered on a page.
+    #[instrument(level = "debug", skip(self, time))]
+    pub async fn set_fixed_time(&self, time: impl Into<TimeValue>) -> Result<(), ContextError> {
+        let time = to_millis(&time.into())?;
+        let mut state = self.state.write().await;
+        Self::installed(&state)?;
+        self.apply(&mut state, Timeline::Fixed(time)).await?;
+        debug!(time, "Fixed time set");
+        Ok(())
+    }
+
+    /// Set the system time that flows normally.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The starting time, either as an ISO 8601 string or Unix timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock is not installed, the time is invalid, or
+    /// the clock script cannot be registered on a page.
+    #[instrument(level = "debug", skip(self, time))]
+    pub async fn set_system_time(&self, time: impl Into<TimeValue>) -> Result<(), ContextError> {
+        let time = to_millis(&time.into())?;
+        let mut state = self.state.write().await;
+        Self::installed(&state)?;
+        let timeline = Timeline::Flowing {
+            mocked_start: time,
+            real_start: real_now(),
+        };
+        self.apply(&mut state, timeline).await?;
+        debug!(time, "System time set");
+        Ok(())
+    }
+
+    /// Advance time by a duration, firing any scheduled timers.
+    ///
+    /// Timers fire in every page, frame and worker of the context.
+    ///
+    /// # Returns
+    ///
+    /// The total number of timers that were fired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock is not installed or the clock script
+    /// cannot be registered on a page.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn run_for(&self, duration: Duration) -> Result<u32, ContextError> {
+        let mut state = self.state.write().await;
+        let timeline = Self::installed(&state)?;
+        let now = real_now();
+        let target = timeline.now(now) + duration.as_millis() as f64;
+
+        let fired = self.run_to(&mut state, timeline, target).await?;
+        debug!(
+            duration_ms = duration.as_millis(),
+            timers_fired = fired,
+            "Time advanced"
+        );
+        Ok(fired)
+    }
+
+    /// Fast-forward time without firing timers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock is not installed or the clock script
+    /// cannot be registered on a page.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn fast_forward(&self, duration: Duration) -> Result<(), ContextError> {
+        let mut state = self.state.write().await;
+        let timeline = Self::installed(&state)?;
+        let now = real_now();
+        let target = timeline.now(now) + duration.as_millis() as f64;
+
+        self.apply(&mut state, timeline.moved_to(target, now))
+            .await?;
+        debug!(duration_ms = duration.as_millis(), "Time fast-forwarded");
+        Ok(())
+    }
+
+    /// Pause at a specific time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time to pause at, as an ISO string or timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock is not installed, the time is invalid, or
+    /// the clock script cannot be registered on a page.
+    #[instrument(level = "debug", skip(self, time))]
+    pub async fn pause_at(&self, time: impl Into<TimeValue>) -> Result<(), ContextError> {
+        let time = to_millis(&time.into())?;
+        let mut state = self.state.write().await;
+        Self::installed(&state)?;
+        self.apply(&mut state, Timeline::Fixed(time)).await?;
+        debug!(time, "Clock paused");
+        Ok(())
+    }
+
+    /// Resume normal time flow from the current mocked time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock is not installed or the clock script
+    /// cannot be registered on a page.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn resume(&self) -> Result<(), ContextError> {
+        let mut state = self.state.write().await;
+        if let Timeline::Fixed(time) = Self::installed(&state)? {
+            let timeline = Timeline::Flowing {
+                mocked_start: time,
+                real_start: real_now(),
+            };
+            self.apply(&mut state, timeline).await?;
+        }
+        debug!("Clock resumed");
+        Ok(())
+    }
+
+    /// Run all pending timers in every page, frame and worker.
+    ///
+    /// Time advances to the last scheduled timer across the context.
+    ///
+    /// # Returns
+    ///
+    /// The total number of timers that were fired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock is not installed or the clock script
+    /// cannot be registered on a page.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn run_all_timers(&self) -> Result<u32, ContextError> {
+        let mut state = self.state.write().await;
+        let timeline = Self::installed(&state)?;
+        let now = timeline.now(real_now());
+
+        let last_timer = self
+            .evaluate_everywhere("globalThis.__viewpointClock.lastTimerTime()")
+            .await
+            .into_iter()
+            .filter_map(|v| v.as_f64())
+            .fold(now, f64::max);
+
+        let fired = self.run_to(&mut state, timeline, last_timer).await?;
+        debug!(timers_fired = fired, "All timers executed");
+        Ok(fired)
+    }
+
+    /// Get the number of pending timers across the context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clock is not installed.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn pending_timer_count(&self) -> Result<u32, ContextError> {
+        let state = self.state.read().await;
+        Self::installed(&state)?;
+        Ok(Self::sum(
+            self.evaluate_everywhere("globalThis.__viewpointClock.pendingTimerCount()")
+                .await,
+        ))
+    }
+}
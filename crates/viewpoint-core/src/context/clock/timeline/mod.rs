@@ -0,0 +1,82 @@
+//! The virtual timeline shared by every target of a context, and the script
+//! that puts a target on it.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::ContextError;
+use crate::page::TimeValue;
+use crate::page::clock_script::CLOCK_MOCK_SCRIPT;
+
+/// The shared virtual timeline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Timeline {
+    /// Time is frozen at a timestamp.
+    Fixed(f64),
+    /// Time flows in real time from `mocked_start`, which corresponds to the
+    /// real time `real_start`.
+    Flowing {
+        /// Mocked time at `real_start`, in milliseconds.
+        mocked_start: f64,
+        /// Real time the timeline was anchored at, in milliseconds.
+        real_start: f64,
+    },
+}
+
+impl Timeline {
+    /// Current mocked time, given the current real time.
+    pub(super) fn now(self, real_now: f64) -> f64 {
+        match self {
+            Timeline::Fixed(time) => time,
+            Timeline::Flowing {
+                mocked_start,
+                real_start,
+            } => mocked_start + (real_now - real_start),
+        }
+    }
+
+    /// Move the timeline to `time`, keeping whether it is frozen or flowing.
+    pub(super) fn moved_to(self, time: f64, real_now: f64) -> Self {
+        match self {
+            Timeline::Fixed(_) => Timeline::Fixed(time),
+            Timeline::Flowing { .. } => Timeline::Flowing {
+                mocked_start: time,
+                real_start: real_now,
+            },
+        }
+    }
+
+    /// Script that installs the clock and adopts this timeline.
+    pub(super) fn script(self) -> String {
+        let timeline = match self {
+            Timeline::Fixed(time) => serde_json::json!({ "fixedTime": time }),
+            Timeline::Flowing {
+                mocked_start,
+                real_start,
+            } => serde_json::json!({
+                "fixedTime": null,
+                "startMockedTime": mocked_start,
+                "startRealTime": real_start,
+            }),
+        };
+        format!(
+            "{CLOCK_MOCK_SCRIPT}\nglobalThis.__viewpointClock.install();\nglobalThis.__viewpointClock.setTimeline({timeline});"
+        )
+    }
+}
+
+/// Current real time in milliseconds since the Unix epoch.
+pub(super) fn real_now() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |d| d.as_secs_f64() * 1000.0)
+}
+
+/// Convert a time value to milliseconds.
+pub(super) fn to_millis(time: &TimeValue) -> Result<f64, ContextError> {
+    time.to_millis()
+        .map(|ms| ms as f64)
+        .ok_or_else(|| ContextError::Internal(format!("Invalid time value: {time:?}")))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn test_fixed_timeline_does_not_advance() {
+    let timeline = Timeline::Fixed(1_000.0);
+    assert!((timeline.now(5_000.0) - 1_000.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_flowing_timeline_advances_with_real_time() {
+    let timeline = Timeline::Flowing {
+        mocked_start: 1_000.0,
+        real_start: 10_000.0,
+    };
+    assert!((timeline.now(10_500.0) - 1_500.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_moved_to_keeps_mode() {
+    assert_eq!(
+        Timeline::Fixed(1_000.0).moved_to(2_000.0, 50.0),
+        Timeline::Fixed(2_000.0)
+    );
+    assert_eq!(
+        Timeline::Flowing {
+            mocked_start: 1_000.0,
+            real_start: 0.0,
+        }
+        .moved_to(2_000.0, 50.0),
+        Timeline::Flowing {
+            mocked_start: 2_000.0,
+            real_start: 50.0,
+        }
+    );
+}
+
+#[test]
+fn test_script_embeds_timeline() {
+    let script = Timeline::Fixed(1_704_067_200_000.0).script();
+    assert!(script.starts_with(CLOCK_MOCK_SCRIPT));
+    assert!(script.contains("setTimeline({\"fixedTime\":1704067200000.0})"));
+}
+
+#[test]
+fn test_time_value_to_millis() {
+    assert_eq!(
+        TimeValue::from("2024-01-01T00:00:00Z").to_millis(),
+        Some(1_704_067_200_000)
+    );
+    assert_eq!(
+        TimeValue::from("2024-01-01").to_millis(),
+        Some(1_704_067_200_000)
+    );
+    assert_eq!(TimeValue::from(42_i64).to_millis(), Some(42));
+    assert!(to_millis(&TimeValue::from("not a date")).is_err());
+}
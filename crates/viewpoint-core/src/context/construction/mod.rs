@@ -8,10 +8,13 @@ use tracing::debug;
 
 use viewpoint_cdp::CdpConnection;
 
+use crate::context::clock::ClockState;
 use crate::context::target_events;
 use crate::context::trace::TracingState;
 use crate::context::{ContextOptions, DEFAULT_TEST_ID_ATTRIBUTE, binding, routing};
 use crate::error::ContextError;
+use crate::network::conditions::NetworkEmulation;
+use crate::network::{NetworkEventListener, WebSocketRouteRegistry};
 
 use super::{BrowserContext, ContextEventManager};
 
@@ -32,9 +35,19 @@ impl BrowserContext {
         ));
         let binding_registry = Arc::new(binding::ContextBindingRegistry::new());
         let pages = Arc::new(RwLock::new(Vec::new()));
+        let service_workers = Arc::new(RwLock::new(Vec::new()));
+        let shared_workers = Arc::new(RwLock::new(Vec::new()));
+        let clock_state = Arc::new(RwLock::new(ClockState::default()));
+        let network_emulation = Arc::new(RwLock::new(NetworkEmulation::default()));
         let page_index_counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
         let event_manager = Arc::new(ContextEventManager::new());
         let test_id_attribute = Arc::new(RwLock::new(DEFAULT_TEST_ID_ATTRIBUTE.to_string()));
+        let tracing_state = Arc::new(RwLock::new(TracingState::default()));
+        let websocket_route_registry = Arc::new(WebSocketRouteRegistry::new());
+        let network_events = Arc::new(NetworkEventListener::new(
+            connection.clone(),
+            context_id.clone(),
+        ));
         let options = ContextOptions::default();
 
         // Start the target event listener for automatic page tracking
@@ -42,12 +55,19 @@ impl BrowserContext {
             connection.clone(),
             context_id.clone(),
             pages.clone(),
+            service_workers.clone(),
+            shared_workers.clone(),
+            clock_state.clone(),
+            network_emulation.clone(),
             event_manager.clone(),
             route_registry.clone(),
             options.clone(),
             context_index,
             page_index_counter.clone(),
             test_id_attribute.clone(),
+            tracing_state.clone(),
+            websocket_route_registry.clone(),
+            network_events.clone(),
         );
 
         let ctx = Self {
@@ -57,6 +77,10 @@ impl BrowserContext {
             closed: false,
             owned: true, // We created this context
             pages,
+            service_workers,
+            shared_workers,
+            clock_state,
+            network_emulation,
             page_index_counter,
             default_timeout: Duration::from_secs(30),
             default_navigation_timeout: Duration::from_secs(30),
@@ -68,7 +92,9 @@ impl BrowserContext {
             init_scripts: Arc::new(RwLock::new(Vec::new())),
             test_id_attribute,
             har_recorder: Arc::new(RwLock::new(None)),
-            tracing_state: Arc::new(RwLock::new(TracingState::default())),
+            tracing_state,
+            websocket_route_registry,
+            network_events,
         };
         ctx.start_weberror_listener();
         ctx
@@ -88,21 +114,38 @@ impl BrowserContext {
         ));
         let binding_registry = Arc::new(binding::ContextBindingRegistry::new());
         let pages = Arc::new(RwLock::new(Vec::new()));
+        let service_workers = Arc::new(RwLock::new(Vec::new()));
+        let shared_workers = Arc::new(RwLock::new(Vec::new()));
+        let clock_state = Arc::new(RwLock::new(ClockState::default()));
+        let network_emulation = Arc::new(RwLock::new(NetworkEmulation::default()));
         let page_index_counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
         let event_manager = Arc::new(ContextEventManager::new());
         let test_id_attribute = Arc::new(RwLock::new(DEFAULT_TEST_ID_ATTRIBUTE.to_string()));
+        let tracing_state = Arc::new(RwLock::new(TracingState::default()));
+        let websocket_route_registry = Arc::new(WebSocketRouteRegistry::new());
+        let network_events = Arc::new(NetworkEventListener::new(
+            connection.clone(),
+            context_id.clone(),
+        ));
 
         // Start the target event listener for automatic page tracking
         target_events::start_target_event_listener(
             connection.clone(),
             context_id.clone(),
             pages.clone(),
+            service_workers.clone(),
+            shared_workers.clone(),
+            clock_state.clone(),
+            network_emulation.clone(),
             event_manager.clone(),
             route_registry.clone(),
             options.clone(),
             context_index,
             page_index_counter.clone(),
             test_id_attribute.clone(),
+            tracing_state.clone(),
+            websocket_route_registry.clone(),
+            network_events.clone(),
         );
 
         let ctx = Self {
@@ -112,6 +155,10 @@ impl BrowserContext {
             closed: false,
             owned: true, // We created this context
             pages,
+            service_workers,
+            shared_workers,
+            clock_state,
+            network_emulation,
             page_index_counter,
             default_timeout: options.default_timeout.unwrap_or(Duration::from_secs(30)),
             default_navigation_timeout: options
@@ -125,7 +172,9 @@ impl BrowserContext {
             init_scripts: Arc::new(RwLock::new(Vec::new())),
             test_id_attribute,
             har_recorder: Arc::new(RwLock::new(None)),
-            tracing_state: Arc::new(RwLock::new(TracingState::default())),
+            tracing_state,
+            websocket_route_registry,
+            network_events,
         };
         ctx.start_weberror_listener();
         ctx
@@ -146,9 +195,19 @@ impl BrowserContext {
         ));
         let binding_registry = Arc::new(binding::ContextBindingRegistry::new());
         let pages = Arc::new(RwLock::new(Vec::new()));
+        let service_workers = Arc::new(RwLock::new(Vec::new()));
+        let shared_workers = Arc::new(RwLock::new(Vec::new()));
+        let clock_state = Arc::new(RwLock::new(ClockState::default()));
+        let network_emulation = Arc::new(RwLock::new(NetworkEmulation::default()));
         let page_index_counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
         let event_manager = Arc::new(ContextEventManager::new());
         let test_id_attribute = Arc::new(RwLock::new(DEFAULT_TEST_ID_ATTRIBUTE.to_string()));
+        let tracing_state = Arc::new(RwLock::new(TracingState::default()));
+        let websocket_route_registry = Arc::new(WebSocketRouteRegistry::new());
+        let network_events = Arc::new(NetworkEventListener::new(
+            connection.clone(),
+            context_id.clone(),
+        ));
         let options = ContextOptions::default();
 
         // Start the target event listener for automatic page tracking
@@ -156,12 +215,19 @@ impl BrowserContext {
             connection.clone(),
             context_id.clone(),
             pages.clone(),
+            service_workers.clone(),
+            shared_workers.clone(),
+            clock_state.clone(),
+            network_emulation.clone(),
             event_manager.clone(),
             route_registry.clone(),
             options.clone(),
             context_index,
             page_index_counter.clone(),
             test_id_attribute.clone(),
+            tracing_state.clone(),
+            websocket_route_registry.clone(),
+            network_events.clone(),
         );
 
         let ctx = Self {
@@ -171,6 +237,10 @@ impl BrowserContext {
             closed: false,
             owned: false, // We didn't create this context
             pages,
+            service_workers,
+            shared_workers,
+            clock_state,
+            network_emulation,
             page_index_counter,
             default_timeout: Duration::from_secs(30),
             default_navigation_timeout: Duration::from_secs(30),
@@ -182,7 +252,9 @@ impl BrowserContext {
             init_scripts: Arc::new(RwLock::new(Vec::new())),
             test_id_attribute,
             har_recorder: Arc::new(RwLock::new(None)),
-            tracing_state: Arc::new(RwLock::new(TracingState::default())),
+            tracing_state,
+            websocket_route_registry,
+            network_events,
         };
         ctx.start_weberror_listener();
         ctx
@@ -1,6 +1,7 @@
 //! Emulation methods for BrowserContext.
 //!
-//! This module provides methods for geolocation, offline mode, and HTTP headers.
+//! This module provides methods for geolocation, offline mode, network
+//! throttling, and HTTP headers.
 
 use std::collections::HashMap;
 
@@ -9,10 +10,11 @@ use tracing::{debug, instrument};
 use viewpoint_cdp::protocol::emulation::{
     ClearGeolocationOverrideParams, SetGeolocationOverrideParams,
 };
-use viewpoint_cdp::protocol::network::{EmulateNetworkConditionsParams, SetExtraHTTPHeadersParams};
+use viewpoint_cdp::protocol::network::SetExtraHTTPHeadersParams;
 
 use super::BrowserContext;
-use crate::error::ContextError;
+use crate::error::{ContextError, PageError};
+use crate::network::NetworkConditions;
 
 impl BrowserContext {
     /// Clear the geolocation override.
@@ -94,6 +96,9 @@ impl BrowserContext {
 
     /// Set offline mode.
     ///
+    /// Offline mode applies to all pages in the context, including pages
+    /// opened later, and takes precedence over any network conditions.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -120,23 +125,77 @@ impl BrowserContext {
 
         debug!(offline = offline, "Setting offline mode");
 
-        let params = if offline {
-            EmulateNetworkConditionsParams::offline()
-        } else {
-            EmulateNetworkConditionsParams::online()
-        };
+        self.network_emulation.write().await.offline = offline;
+        self.apply_network_emulation().await
+    }
 
-        // Set on all pages
+    /// Emulate network conditions for all pages in the context.
+    ///
+    /// The conditions apply to existing pages and to pages opened later,
+    /// including popups. A page can override them with
+    /// [`Page::emulate_network`](crate::page::Page::emulate_network).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use viewpoint_core::{BrowserContext, ConnectionType, NetworkConditions};
+    ///
+    /// # async fn example(context: &BrowserContext) -> Result<(), viewpoint_core::CoreError> {
+    /// // Use a preset
+    /// context.emulate_network(NetworkConditions::SLOW_3G).await?;
+    ///
+    /// // Or describe the connection
+    /// context.emulate_network(NetworkConditions {
+    ///     latency: Duration::from_millis(300),
+    ///     download_bps: Some(500_000),
+    ///     upload_bps: Some(250_000),
+    ///     connection_type: Some(ConnectionType::Wifi),
+    /// }).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context is closed or applying the conditions
+    /// to a page fails.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn emulate_network(&self, conditions: NetworkConditions) -> Result<(), ContextError> {
+        if self.is_closed() {
+            return Err(ContextError::Closed);
+        }
+
+        self.network_emulation.write().await.conditions = Some(conditions);
+        self.apply_network_emulation().await
+    }
+
+    /// Stop emulating network conditions for the context.
+    ///
+    /// Offline mode and per-page overrides are not affected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context is closed or updating a page fails.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn clear_network_emulation(&self) -> Result<(), ContextError> {
+        if self.is_closed() {
+            return Err(ContextError::Closed);
+        }
+
+        self.network_emulation.write().await.conditions = None;
+        self.apply_network_emulation().await
+    }
+
+    /// Apply the current network emulation state to all pages.
+    async fn apply_network_emulation(&self) -> Result<(), ContextError> {
         let pages = self.pages.read().await;
         for page in pages.iter() {
             if !page.session_id().is_empty() {
-                self.connection()
-                    .send_command::<_, serde_json::Value>(
-                        "Network.emulateNetworkConditions",
-                        Some(params.clone()),
-                        Some(page.session_id()),
-                    )
-                    .await?;
+                page.apply_network_emulation().await.map_err(|e| match e {
+                    PageError::Cdp(e) => ContextError::Cdp(e),
+                    e => ContextError::Internal(e.to_string()),
+                })?;
             }
         }
 
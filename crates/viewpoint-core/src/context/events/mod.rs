@@ -11,7 +11,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 use tokio::sync::RwLock;
 
-use crate::page::Page;
+use crate::page::{Page, Worker};
 
 /// A unique identifier for an event handler.
 ///
@@ -39,6 +39,10 @@ pub type PageEventHandler =
 pub type PageActivatedEventHandler =
     Box<dyn Fn(Page) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
 
+/// Type alias for the service and shared worker event handler function.
+pub type ServiceWorkerEventHandler =
+    Box<dyn Fn(Worker) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
 /// Type alias for the close event handler function.
 pub type CloseEventHandler =
     Box<dyn Fn() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
@@ -127,6 +131,16 @@ impl EventEmitter<PageActivatedEventHandler> {
     }
 }
 
+impl EventEmitter<ServiceWorkerEventHandler> {
+    /// Emit a service or shared worker event to all handlers.
+    pub async fn emit_service_worker(&self, worker: Worker) {
+        let handlers = self.handlers.read().await;
+        for handler in handlers.values() {
+            handler(worker.clone()).await;
+        }
+    }
+}
+
 impl EventEmitter<CloseEventHandler> {
     /// Emit a close event to all handlers.
     pub async fn emit(&self) {
@@ -144,6 +158,10 @@ pub struct ContextEventManager {
     page_handlers: EventEmitter<PageEventHandler>,
     /// Handlers for 'page_activated' events (page became active/foreground).
     page_activated_handlers: EventEmitter<PageActivatedEventHandler>,
+    /// Handlers for 'service_worker' events (new service worker attached).
+    service_worker_handlers: EventEmitter<ServiceWorkerEventHandler>,
+    /// Handlers for 'shared_worker' events (new shared worker attached).
+    shared_worker_handlers: EventEmitter<ServiceWorkerEventHandler>,
     /// Handlers for 'close' events (context closing).
     close_handlers: EventEmitter<CloseEventHandler>,
 }
@@ -154,6 +172,8 @@ impl ContextEventManager {
         Self {
             page_handlers: EventEmitter::new(),
             page_activated_handlers: EventEmitter::new(),
+            service_worker_handlers: EventEmitter::new(),
+            shared_worker_handlers: EventEmitter::new(),
             close_handlers: EventEmitter::new(),
         }
     }
@@ -210,6 +230,62 @@ impl ContextEventManager {
         self.page_activated_handlers.emit_page_activated(page).await;
     }
 
+    /// Register a handler for service worker events.
+    ///
+    /// The handler will be called whenever a service worker starts in the context.
+    /// Returns a handler ID that can be used to remove the handler.
+    pub async fn on_service_worker<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Worker) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let boxed_handler: ServiceWorkerEventHandler =
+            Box::new(move |worker| Box::pin(handler(worker)));
+        self.service_worker_handlers.add(boxed_handler).await
+    }
+
+    /// Remove a service worker event handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed.
+    pub async fn off_service_worker(&self, id: HandlerId) -> bool {
+        self.service_worker_handlers.remove(id).await
+    }
+
+    /// Emit a service worker event to all registered handlers.
+    pub async fn emit_service_worker(&self, worker: Worker) {
+        self.service_worker_handlers
+            .emit_service_worker(worker)
+            .await;
+    }
+
+    /// Register a handler for shared worker events.
+    ///
+    /// The handler will be called whenever a shared worker starts in the context.
+    /// Returns a handler ID that can be used to remove the handler.
+    pub async fn on_shared_worker<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Worker) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let boxed_handler: ServiceWorkerEventHandler =
+            Box::new(move |worker| Box::pin(handler(worker)));
+        self.shared_worker_handlers.add(boxed_handler).await
+    }
+
+    /// Remove a shared worker event handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed.
+    pub async fn off_shared_worker(&self, id: HandlerId) -> bool {
+        self.shared_worker_handlers.remove(id).await
+    }
+
+    /// Emit a shared worker event to all registered handlers.
+    pub async fn emit_shared_worker(&self, worker: Worker) {
+        self.shared_worker_handlers
+            .emit_service_worker(worker)
+            .await;
+    }
+
     /// Register a handler for context close events.
     ///
     /// The handler will be called when the context is about to close.
@@ -239,6 +315,8 @@ impl ContextEventManager {
     pub async fn clear(&self) {
         self.page_handlers.clear().await;
         self.page_activated_handlers.clear().await;
+        self.service_worker_handlers.clear().await;
+        self.shared_worker_handlers.clear().await;
         self.close_handlers.clear().await;
     }
 }
@@ -158,11 +158,13 @@
 
 mod api;
 pub mod binding;
+pub mod clock;
 mod construction;
 mod cookies;
 mod emulation;
 pub mod events;
 mod har;
+mod network_events;
 mod page_events;
 mod page_factory;
 mod page_management;
@@ -170,6 +172,8 @@ mod permissions;
 pub mod routing;
 mod routing_impl;
 mod scripts;
+mod selectors;
+mod service_workers;
 pub mod storage;
 mod storage_restore;
 mod target_events;
@@ -180,6 +184,7 @@ mod tracing_access;
 pub mod types;
 mod weberror;
 
+pub use clock::ContextClock;
 pub use cookies::ClearCookiesBuilder;
 pub use emulation::SetGeolocationBuilder;
 
@@ -198,7 +203,7 @@ use crate::error::ContextError;
 pub use events::{ContextEventManager, HandlerId};
 pub use storage::{StorageStateBuilder, StorageStateOptions};
 use trace::TracingState;
-pub use trace::{Tracing, TracingOptions};
+pub use trace::{TraceArchive, TraceFormat, TraceScreenshot, TraceSource, Tracing, TracingOptions};
 pub use types::{
     ColorScheme, ContextOptions, ContextOptionsBuilder, Cookie, ForcedColors, Geolocation,
     HttpCredentials, IndexedDbDatabase, IndexedDbEntry, IndexedDbIndex, IndexedDbObjectStore,
@@ -252,6 +257,14 @@ pub struct BrowserContext {
     /// Created pages for `pages()` method.
     /// Stores actual Page objects to allow returning fully functional pages.
     pages: Arc<RwLock<Vec<crate::page::Page>>>,
+    /// Service workers running in this context.
+    service_workers: Arc<RwLock<Vec<crate::page::Worker>>>,
+    /// Shared workers running in this context.
+    shared_workers: Arc<RwLock<Vec<crate::page::Worker>>>,
+    /// Shared state of the context-wide clock.
+    clock_state: Arc<RwLock<clock::ClockState>>,
+    /// Network conditions shared by all pages in this context.
+    network_emulation: Arc<RwLock<crate::network::conditions::NetworkEmulation>>,
     /// Counter for assigning page indices within this context.
     /// Wrapped in Arc to share with the target event listener.
     page_index_counter: Arc<std::sync::atomic::AtomicUsize>,
@@ -277,6 +290,10 @@ pub struct BrowserContext {
     har_recorder: Arc<RwLock<Option<crate::network::HarRecorder>>>,
     /// Shared tracing state for persistent tracing across `tracing()` calls.
     tracing_state: Arc<RwLock<TracingState>>,
+    /// Context-level WebSocket route registry.
+    websocket_route_registry: Arc<crate::network::WebSocketRouteRegistry>,
+    /// Network events relayed from all pages in this context.
+    network_events: Arc<crate::network::NetworkEventListener>,
 }
 
 // Manual Debug implementation since WebErrorHandler doesn't implement Debug
@@ -397,6 +414,13 @@ impl BrowserContext {
             }
         }
 
+        // Finalize videos of pages that are still open
+        for page in self.pages.read().await.iter() {
+            if let Err(e) = page.stop_video_recording().await {
+                debug!("Failed to finalize video on close: {}", e);
+            }
+        }
+
         // Emit close event before cleanup
         self.event_manager.emit_close().await;
 
@@ -453,6 +477,11 @@ impl BrowserContext {
         self.closed
     }
 
+    /// Get the base URL used to resolve relative URLs, if set.
+    pub fn base_url(&self) -> Option<&str> {
+        self.options.base_url.as_deref()
+    }
+
     /// Get a reference to the CDP connection.
     pub fn connection(&self) -> &Arc<CdpConnection> {
         &self.connection
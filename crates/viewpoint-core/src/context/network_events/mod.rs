@@ -0,0 +1,166 @@
+//! Network event listeners for BrowserContext.
+//!
+//! This module provides handlers and streams observing the requests of every
+//! page in the context, including pages opened after the handler is added.
+
+use std::future::Future;
+
+use futures::Stream;
+
+use super::BrowserContext;
+use super::events::HandlerId;
+use crate::network::{NetworkEvent, NetworkEventKind, Request, Response};
+
+impl BrowserContext {
+    /// Register a handler for requests issued by any page in this context.
+    ///
+    /// Returns a handler ID that can be used to remove the handler with `off_request`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::{Browser, Request};
+    ///
+    /// # async fn example() -> Result<(), viewpoint_core::CoreError> {
+    /// let browser = Browser::launch().headless(true).launch().await?;
+    /// let context = browser.new_context().await?;
+    ///
+    /// let handler_id = context.on_request(|request: Request| async move {
+    ///     println!("{} {}", request.method(), request.url());
+    /// }).await;
+    ///
+    /// // Later, remove the handler
+    /// context.off_request(handler_id).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_request<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Request) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.network_events
+            .add_handler(
+                NetworkEventKind::Request,
+                NetworkEvent::into_request,
+                handler,
+            )
+            .await
+    }
+
+    /// Remove a request handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed, `false` if the ID was not found
+    /// or belongs to a handler of another kind.
+    pub async fn off_request(&self, handler_id: HandlerId) -> bool {
+        self.network_events
+            .remove_handler(NetworkEventKind::Request, handler_id)
+            .await
+    }
+
+    /// Register a handler for responses received by any page in this context.
+    ///
+    /// Returns a handler ID that can be used to remove the handler with `off_response`.
+    pub async fn on_response<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Response) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.network_events
+            .add_handler(
+                NetworkEventKind::Response,
+                NetworkEvent::into_response,
+                handler,
+            )
+            .await
+    }
+
+    /// Remove a response handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed, `false` if the ID was not found
+    /// or belongs to a handler of another kind.
+    pub async fn off_response(&self, handler_id: HandlerId) -> bool {
+        self.network_events
+            .remove_handler(NetworkEventKind::Response, handler_id)
+            .await
+    }
+
+    /// Register a handler for requests that finished loading in any page.
+    ///
+    /// Returns a handler ID that can be used to remove the handler with
+    /// `off_request_finished`.
+    pub async fn on_request_finished<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Request) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.network_events
+            .add_handler(
+                NetworkEventKind::RequestFinished,
+                NetworkEvent::into_finished_request,
+                handler,
+            )
+            .await
+    }
+
+    /// Remove a request finished handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed, `false` if the ID was not found
+    /// or belongs to a handler of another kind.
+    pub async fn off_request_finished(&self, handler_id: HandlerId) -> bool {
+        self.network_events
+            .remove_handler(NetworkEventKind::RequestFinished, handler_id)
+            .await
+    }
+
+    /// Register a handler for requests that failed in any page.
+    ///
+    /// The error text is available from [`Request::failure`].
+    /// Returns a handler ID that can be used to remove the handler with
+    /// `off_request_failed`.
+    pub async fn on_request_failed<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Request) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.network_events
+            .add_handler(
+                NetworkEventKind::RequestFailed,
+                NetworkEvent::into_failed_request,
+                handler,
+            )
+            .await
+    }
+
+    /// Remove a request failed handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed, `false` if the ID was not found
+    /// or belongs to a handler of another kind.
+    pub async fn off_request_failed(&self, handler_id: HandlerId) -> bool {
+        self.network_events
+            .remove_handler(NetworkEventKind::RequestFailed, handler_id)
+            .await
+    }
+
+    /// Stream the requests issued by pages in this context from now on.
+    pub fn request_stream(&self) -> impl Stream<Item = Request> + Send + use<> {
+        self.network_events.stream(NetworkEvent::into_request)
+    }
+
+    /// Stream the responses received by pages in this context from now on.
+    pub fn response_stream(&self) -> impl Stream<Item = Response> + Send + use<> {
+        self.network_events.stream(NetworkEvent::into_response)
+    }
+
+    /// Stream the requests that finish loading from now on.
+    pub fn request_finished_stream(&self) -> impl Stream<Item = Request> + Send + use<> {
+        self.network_events
+            .stream(NetworkEvent::into_finished_request)
+    }
+
+    /// Stream the requests that fail from now on.
+    pub fn request_failed_stream(&self) -> impl Stream<Item = Request> + Send + use<> {
+        self.network_events
+            .stream(NetworkEvent::into_failed_request)
+    }
+}
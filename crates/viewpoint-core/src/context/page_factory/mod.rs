@@ -10,8 +10,11 @@ use tracing::{debug, trace as trace_log};
 use viewpoint_cdp::CdpConnection;
 use viewpoint_cdp::protocol::emulation::{
     MediaFeature, SetDeviceMetricsOverrideParams, SetEmulatedMediaParams, SetLocaleOverrideParams,
-    SetTimezoneOverrideParams, SetTouchEmulationEnabledParams, SetUserAgentOverrideParams,
+    SetScriptExecutionDisabledParams, SetTimezoneOverrideParams, SetTouchEmulationEnabledParams,
+    SetUserAgentOverrideParams,
 };
+use viewpoint_cdp::protocol::page::SetBypassCspParams;
+use viewpoint_cdp::protocol::security::SetIgnoreCertificateErrorsParams;
 
 use super::types::{ColorScheme, ContextOptions, ForcedColors, ReducedMotion, ViewportSize};
 use crate::error::ContextError;
@@ -36,6 +39,11 @@ pub(crate) async fn enable_page_domains(
         .send_command::<(), serde_json::Value>("Runtime.enable", None, Some(session_id))
         .await?;
 
+    trace_log!("Enabling Inspector domain for crash notifications");
+    connection
+        .send_command::<(), serde_json::Value>("Inspector.enable", None, Some(session_id))
+        .await?;
+
     trace_log!("Enabling lifecycle events");
     connection
         .send_command::<_, serde_json::Value>(
@@ -138,6 +146,51 @@ pub(crate) async fn apply_emulation_settings(
     // Apply media emulation settings (color scheme, reduced motion, forced colors)
     apply_media_features(connection, session_id, options).await?;
 
+    // Apply security settings (certificate errors, CSP, JavaScript)
+    apply_security_settings(connection, session_id, options).await?;
+
+    Ok(())
+}
+
+/// Apply security-related settings.
+async fn apply_security_settings(
+    connection: &CdpConnection,
+    session_id: &str,
+    options: &ContextOptions,
+) -> Result<(), ContextError> {
+    if options.ignore_https_errors {
+        trace_log!("Ignoring certificate errors");
+        connection
+            .send_command::<_, serde_json::Value>(
+                "Security.setIgnoreCertificateErrors",
+                Some(SetIgnoreCertificateErrorsParams { ignore: true }),
+                Some(session_id),
+            )
+            .await?;
+    }
+
+    if options.bypass_csp {
+        trace_log!("Bypassing CSP");
+        connection
+            .send_command::<_, serde_json::Value>(
+                "Page.setBypassCSP",
+                Some(SetBypassCspParams { enabled: true }),
+                Some(session_id),
+            )
+            .await?;
+    }
+
+    if options.java_script_enabled == Some(false) {
+        trace_log!("Disabling script execution");
+        connection
+            .send_command::<_, serde_json::Value>(
+                "Emulation.setScriptExecutionDisabled",
+                Some(SetScriptExecutionDisabledParams { value: true }),
+                Some(session_id),
+            )
+            .await?;
+    }
+
     Ok(())
 }
 
@@ -4,7 +4,7 @@ use std::future::Future;
 use std::sync::Arc;
 
 use crate::error::NetworkError;
-use crate::network::{HarReplayOptions, Route, UrlPattern};
+use crate::network::{HarReplayOptions, Route, UrlPattern, WebSocketRoute, resolve_glob};
 
 use super::BrowserContext;
 
@@ -53,6 +53,9 @@ impl BrowserContext {
         if self.is_closed() {
             return Err(NetworkError::Aborted);
         }
+        let pattern = pattern
+            .into()
+            .with_base_url(self.options.base_url.as_deref());
         self.route_registry.route(pattern, handler).await
     }
 
@@ -107,7 +110,8 @@ impl BrowserContext {
     ///
     /// This removes handlers registered with `route()` that match the pattern.
     pub async fn unroute(&self, pattern: &str) {
-        self.route_registry.unroute(pattern).await;
+        let pattern = resolve_glob(self.options.base_url.as_deref(), pattern);
+        self.route_registry.unroute(&pattern).await;
     }
 
     /// Unregister all route handlers.
@@ -117,6 +121,60 @@ impl BrowserContext {
         self.route_registry.unroute_all().await;
     }
 
+    /// Register a route handler for WebSocket connections from all pages in this context.
+    ///
+    /// The route applies to pages that are already open and to pages created
+    /// later. Page-level WebSocket routes take precedence over context routes.
+    /// See [`Page::route_web_socket`](crate::page::Page::route_web_socket) for
+    /// how routed connections behave.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Browser;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let browser = Browser::launch().headless(true).launch().await?;
+    /// let context = browser.new_context().await?;
+    ///
+    /// // Reject every WebSocket connection to the chat service
+    /// context.route_web_socket("**/chat/**", |ws| async move {
+    ///     ws.close_with(4003, "chat disabled in tests").await
+    /// }).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context is closed or routing cannot be
+    /// installed in an open page.
+    pub async fn route_web_socket<M, H, Fut>(
+        &self,
+        pattern: M,
+        handler: H,
+    ) -> Result<(), NetworkError>
+    where
+        M: Into<UrlPattern>,
+        H: Fn(WebSocketRoute) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), NetworkError>> + Send + 'static,
+    {
+        if self.is_closed() {
+            return Err(NetworkError::Aborted);
+        }
+        self.websocket_route_registry.route(pattern, handler).await
+    }
+
+    /// Unregister WebSocket route handlers matching the given pattern.
+    pub async fn unroute_web_socket(&self, pattern: &str) {
+        self.websocket_route_registry.unroute(pattern).await;
+    }
+
+    /// Unregister all WebSocket route handlers.
+    pub async fn unroute_all_web_sockets(&self) {
+        self.websocket_route_registry.unroute_all().await;
+    }
+
     /// Route requests from a HAR file for all pages in this context.
     ///
     /// Requests that match entries in the HAR file will be fulfilled with the
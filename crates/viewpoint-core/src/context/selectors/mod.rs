@@ -0,0 +1,81 @@
+//! Context-level custom selector engines.
+
+use tracing::{debug, instrument};
+
+use crate::browser::selectors::engine_init_script;
+use crate::error::{ContextError, PageError};
+
+use super::BrowserContext;
+
+impl BrowserContext {
+    /// Register a custom selector engine for all pages in this context.
+    ///
+    /// `script` is a JavaScript expression evaluating to an object with a
+    /// `queryAll(root, body)` method returning the matching elements, or a
+    /// `query(root, body)` method returning the first match. Selectors
+    /// prefixed with `name=` are then resolved by the engine.
+    ///
+    /// The engine is installed in existing pages immediately and in every
+    /// page and document loaded afterwards. It replaces an engine with the
+    /// same name registered with [`Browser::selectors`](crate::Browser::selectors).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Browser;
+    ///
+    /// # async fn example() -> Result<(), viewpoint_core::CoreError> {
+    /// let browser = Browser::launch().headless(true).launch().await?;
+    /// let context = browser.new_context().await?;
+    ///
+    /// // Find React components by their display name
+    /// context.register_selector_engine(
+    ///     "component",
+    ///     r#"({
+    ///         queryAll(root, name) {
+    ///             return [...root.querySelectorAll('*')].filter(el => {
+    ///                 const key = Object.keys(el).find(k => k.startsWith('__reactFiber'));
+    ///                 return key && el[key].return?.type?.name === name;
+    ///             });
+    ///         }
+    ///     })"#,
+    /// ).await?;
+    ///
+    /// let page = context.new_page().await?;
+    /// page.locator("component=CheckoutForm >> button").click().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is not a valid engine name or is used by
+    /// a built-in engine, if the context is closed, or if the engine cannot
+    /// be installed in an existing page.
+    #[instrument(level = "debug", skip(self, script))]
+    pub async fn register_selector_engine(
+        &self,
+        name: &str,
+        script: &str,
+    ) -> Result<(), ContextError> {
+        let init_script =
+            engine_init_script(name, script).map_err(ContextError::InvalidSelectorEngine)?;
+        self.add_init_script(&init_script).await?;
+
+        // Install in the documents already loaded
+        let pages = self.pages.read().await;
+        for page in pages.iter() {
+            if !page.session_id().is_empty() {
+                page.evaluate::<serde_json::Value>(&format!("{init_script} null"))
+                    .await
+                    .map_err(|e| match e {
+                        PageError::Cdp(e) => ContextError::Cdp(e),
+                        e => ContextError::Internal(e.to_string()),
+                    })?;
+            }
+        }
+
+        debug!("Selector engine registered");
+        Ok(())
+    }
+}
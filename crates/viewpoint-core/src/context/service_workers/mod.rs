@@ -0,0 +1,116 @@
+//! Service worker and shared worker access for BrowserContext.
+//!
+//! Service workers and shared workers are attached to automatically when
+//! they start and are tracked until their target is destroyed.
+
+use std::future::Future;
+
+use super::BrowserContext;
+use super::events::HandlerId;
+use crate::error::ContextError;
+use crate::page::Worker;
+
+impl BrowserContext {
+    /// Get the service workers running in this context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context is closed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::BrowserContext;
+    ///
+    /// # async fn example(context: &BrowserContext) -> Result<(), viewpoint_core::CoreError> {
+    /// for worker in context.service_workers().await? {
+    ///     println!("Service worker: {}", worker.url());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn service_workers(&self) -> Result<Vec<Worker>, ContextError> {
+        if self.closed {
+            return Err(ContextError::Closed);
+        }
+
+        let mut workers = self.service_workers.write().await;
+        workers.retain(|w| !w.is_closed());
+        Ok(workers.clone())
+    }
+
+    /// Register a handler for service worker events.
+    ///
+    /// The handler will be called whenever a service worker starts in this context.
+    /// Returns a handler ID that can be used to remove the handler with
+    /// `off_service_worker`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::{BrowserContext, Worker};
+    ///
+    /// # async fn example(context: &BrowserContext) -> Result<(), viewpoint_core::CoreError> {
+    /// let handler_id = context.on_service_worker(|worker: Worker| async move {
+    ///     println!("Service worker started: {}", worker.url());
+    /// }).await;
+    ///
+    /// // Later, remove the handler
+    /// context.off_service_worker(handler_id).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_service_worker<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Worker) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.event_manager.on_service_worker(handler).await
+    }
+
+    /// Remove a service worker event handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed, `false` if the ID was not found.
+    pub async fn off_service_worker(&self, handler_id: HandlerId) -> bool {
+        self.event_manager.off_service_worker(handler_id).await
+    }
+
+    /// Get the shared workers running in this context.
+    ///
+    /// Shared workers are started with `new SharedWorker(...)` and can be
+    /// used by several pages of the same origin, so they belong to the
+    /// context rather than to a page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context is closed.
+    pub async fn shared_workers(&self) -> Result<Vec<Worker>, ContextError> {
+        if self.closed {
+            return Err(ContextError::Closed);
+        }
+
+        let mut workers = self.shared_workers.write().await;
+        workers.retain(|w| !w.is_closed());
+        Ok(workers.clone())
+    }
+
+    /// Register a handler for shared worker events.
+    ///
+    /// The handler will be called whenever a shared worker starts in this context.
+    /// Returns a handler ID that can be used to remove the handler with
+    /// `off_shared_worker`.
+    pub async fn on_shared_worker<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Worker) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.event_manager.on_shared_worker(handler).await
+    }
+
+    /// Remove a shared worker event handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed, `false` if the ID was not found.
+    pub async fn off_shared_worker(&self, handler_id: HandlerId) -> bool {
+        self.event_manager.off_shared_worker(handler_id).await
+    }
+}
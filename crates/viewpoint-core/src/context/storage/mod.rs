@@ -1,7 +1,7 @@
 //! Storage state collection and restoration.
 //!
 //! This module provides functionality for collecting and restoring browser
-//! storage state including cookies, localStorage, and `IndexedDB`.
+//! storage state including cookies, localStorage, sessionStorage and `IndexedDB`.
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -18,7 +18,9 @@ use super::types::{Cookie, IndexedDbDatabase, LocalStorageEntry, StorageOrigin,
 use crate::error::ContextError;
 
 // Re-export restore functions for external use
-pub use super::storage_restore::{restore_indexed_db, restore_local_storage};
+pub use super::storage_restore::{
+    restore_indexed_db, restore_local_storage, restore_session_storage,
+};
 
 impl BrowserContext {
     /// Get the storage state (cookies and localStorage).
@@ -48,8 +50,8 @@ impl BrowserContext {
 
     /// Create a builder for collecting storage state with options.
     ///
-    /// Use this method when you need to include `IndexedDB` data or configure
-    /// other collection options.
+    /// Use this method when you need to include sessionStorage or `IndexedDB`
+    /// data or configure other collection options.
     ///
     /// # Example
     ///
@@ -75,6 +77,8 @@ impl BrowserContext {
 /// Options for collecting storage state.
 #[derive(Debug, Clone, Default)]
 pub struct StorageStateOptions {
+    /// Include sessionStorage data in the snapshot.
+    pub session_storage: bool,
     /// Include `IndexedDB` data in the snapshot.
     pub indexed_db: bool,
     /// Maximum entries per `IndexedDB` object store.
@@ -86,11 +90,19 @@ impl StorageStateOptions {
     /// Create new default options.
     pub fn new() -> Self {
         Self {
+            session_storage: false,
             indexed_db: false,
             indexed_db_max_entries: 1000,
         }
     }
 
+    /// Include sessionStorage data in the snapshot.
+    #[must_use]
+    pub fn session_storage(mut self, include: bool) -> Self {
+        self.session_storage = include;
+        self
+    }
+
     /// Include `IndexedDB` data in the snapshot.
     #[must_use]
     pub fn indexed_db(mut self, include: bool) -> Self {
@@ -128,6 +140,16 @@ impl<'a> StorageStateBuilder<'a> {
         }
     }
 
+    /// Include sessionStorage data in the storage state.
+    ///
+    /// sessionStorage is collected from every open page, so entries written
+    /// by different tabs of the same origin are merged.
+    #[must_use]
+    pub fn session_storage(mut self, include: bool) -> Self {
+        self.options.session_storage = include;
+        self
+    }
+
     /// Include `IndexedDB` data in the storage state.
     #[must_use]
     pub fn indexed_db(mut self, include: bool) -> Self {
@@ -168,7 +190,17 @@ impl<'a> StorageStateBuilder<'a> {
             }
 
             // Get localStorage for this page
-            let local_storage = self.collect_local_storage(page.session_id()).await?;
+            let local_storage = self
+                .collect_web_storage(page.session_id(), "localStorage")
+                .await?;
+
+            // Get sessionStorage if requested
+            let session_storage = if self.options.session_storage {
+                self.collect_web_storage(page.session_id(), "sessionStorage")
+                    .await?
+            } else {
+                Vec::new()
+            };
 
             // Get IndexedDB if requested
             let indexed_db = if self.options.indexed_db {
@@ -182,6 +214,7 @@ impl<'a> StorageStateBuilder<'a> {
                 .entry(origin.clone())
                 .or_insert_with(|| StorageOrigin::new(origin));
             storage_origin.local_storage.extend(local_storage);
+            storage_origin.session_storage.extend(session_storage);
             storage_origin.indexed_db.extend(indexed_db);
         }
 
@@ -258,30 +291,32 @@ impl<'a> StorageStateBuilder<'a> {
             .unwrap_or_default())
     }
 
-    /// Collect localStorage entries from a page.
-    async fn collect_local_storage(
+    /// Collect `localStorage` or `sessionStorage` entries from a page.
+    async fn collect_web_storage(
         &self,
         session_id: &str,
+        storage: &str,
     ) -> Result<Vec<LocalStorageEntry>, ContextError> {
-        let js = r"
+        let js_code = js! {
             (function() {
+                const storage = window[#{storage}];
                 const entries = [];
-                for (let i = 0; i < localStorage.length; i++) {
-                    const key = localStorage.key(i);
+                for (let i = 0; i < storage.length; i++) {
+                    const key = storage.key(i);
                     if (key !== null) {
-                        entries.push({ name: key, value: localStorage.getItem(key) || '' });
+                        entries.push({ name: key, value: storage.getItem(key) || "" });
                     }
                 }
                 return entries;
             })()
-        ";
+        };
 
         let result: viewpoint_cdp::protocol::runtime::EvaluateResult = self
             .connection
             .send_command(
                 "Runtime.evaluate",
                 Some(viewpoint_cdp::protocol::runtime::EvaluateParams {
-                    expression: js.to_string(),
+                    expression: js_code,
                     object_group: None,
                     include_command_line_api: None,
                     silent: Some(true),
@@ -295,7 +330,7 @@ impl<'a> StorageStateBuilder<'a> {
 
         if let Some(value) = result.result.value {
             let entries: Vec<LocalStorageEntry> = serde_json::from_value(value).unwrap_or_default();
-            debug!("Collected {} localStorage entries", entries.len());
+            debug!("Collected {} {storage} entries", entries.len());
             Ok(entries)
         } else {
             Ok(Vec::new())
@@ -9,3 +9,11 @@ fn test_storage_state_options() {
     assert!(options.indexed_db);
     assert_eq!(options.indexed_db_max_entries, 500);
 }
+
+#[test]
+fn test_storage_state_options_session_storage() {
+    assert!(!StorageStateOptions::new().session_storage);
+
+    let options = StorageStateOptions::new().session_storage(true);
+    assert!(options.session_storage);
+}
@@ -1,7 +1,7 @@
 //! Storage state restoration functions.
 //!
 //! This module contains functions for restoring browser storage state
-//! including localStorage and IndexedDB.
+//! including localStorage, sessionStorage and IndexedDB.
 
 use std::sync::Arc;
 
@@ -58,6 +58,54 @@ pub async fn restore_local_storage(
     Ok(())
 }
 
+/// Restore sessionStorage entries to a page.
+///
+/// Entries are written to the sessionStorage of the page's current origin.
+///
+/// # Errors
+///
+/// Returns an error if the storage state cannot be restored.
+pub async fn restore_session_storage(
+    connection: &Arc<CdpConnection>,
+    session_id: &str,
+    entries: &[LocalStorageEntry],
+) -> Result<(), ContextError> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let entries_json = serde_json::to_string(entries)
+        .map_err(|e| ContextError::Internal(format!("Failed to serialize sessionStorage: {e}")))?;
+
+    let js_code = js! {
+        (function() {
+            const entries = @{entries_json};
+            for (const entry of entries) {
+                sessionStorage.setItem(entry.name, entry.value);
+            }
+        })()
+    };
+
+    connection
+        .send_command::<_, serde_json::Value>(
+            "Runtime.evaluate",
+            Some(viewpoint_cdp::protocol::runtime::EvaluateParams {
+                expression: js_code,
+                object_group: None,
+                include_command_line_api: None,
+                silent: Some(true),
+                context_id: None,
+                return_by_value: Some(true),
+                await_promise: Some(false),
+            }),
+            Some(session_id),
+        )
+        .await?;
+
+    debug!("Restored {} sessionStorage entries", entries.len());
+    Ok(())
+}
+
 /// Restore IndexedDB databases to a page.
 ///
 /// # Errors
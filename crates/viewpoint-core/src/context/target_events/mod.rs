@@ -12,6 +12,11 @@
 //! - `new_page()` uses `wait_for_page()` internally to receive the Page
 //! - External pages trigger `on_page` event handlers
 //! - No special cases, no deduplication needed
+//!
+//! Service worker and shared worker targets of the context are attached to as
+//! well and tracked as [`Worker`]s.
+
+mod workers;
 
 use std::sync::Arc;
 
@@ -21,20 +26,27 @@ use tracing::{debug, trace};
 use viewpoint_cdp::CdpConnection;
 use viewpoint_cdp::protocol::target_domain::{
     AttachToTargetParams, AttachToTargetResult, TargetCreatedEvent, TargetDestroyedEvent,
-    TargetInfoChangedEvent,
+    TargetInfo, TargetInfoChangedEvent,
 };
 
+use super::clock::{self, ClockState};
 use super::events::ContextEventManager;
 use super::page_factory;
 use super::routing::ContextRouteRegistry;
+use super::trace::TracingState;
 use super::types::ContextOptions;
-use crate::page::Page;
+use crate::network::conditions::NetworkEmulation;
+use crate::network::{NetworkEventListener, WebSocketRouteRegistry};
+use crate::page::{Page, Worker};
+
+use workers::{WorkerKind, handle_worker_created};
 
 /// Start listening for target events on a browser context.
 ///
 /// This spawns a background task that listens for:
 /// - `Target.targetCreated` - creates, tracks, and emits events for ALL new pages
-/// - `Target.targetDestroyed` - removes pages from tracking
+///   and service and shared workers
+/// - `Target.targetDestroyed` - removes pages and workers from tracking
 ///
 /// The listener automatically:
 /// - Filters events by context ID (only tracks pages belonging to this context)
@@ -45,12 +57,19 @@ pub(crate) fn start_target_event_listener(
     connection: Arc<CdpConnection>,
     context_id: String,
     pages: Arc<RwLock<Vec<Page>>>,
+    service_workers: Arc<RwLock<Vec<Worker>>>,
+    shared_workers: Arc<RwLock<Vec<Worker>>>,
+    clock_state: Arc<RwLock<ClockState>>,
+    network_emulation: Arc<RwLock<NetworkEmulation>>,
     event_manager: Arc<ContextEventManager>,
     route_registry: Arc<ContextRouteRegistry>,
     options: ContextOptions,
     context_index: usize,
     page_index_counter: Arc<std::sync::atomic::AtomicUsize>,
     test_id_attribute: Arc<RwLock<String>>,
+    tracing_state: Arc<RwLock<TracingState>>,
+    websocket_route_registry: Arc<WebSocketRouteRegistry>,
+    network_events: Arc<NetworkEventListener>,
 ) {
     let mut events = connection.subscribe_events();
 
@@ -62,16 +81,39 @@ pub(crate) fn start_target_event_listener(
                         if let Ok(created_event) =
                             serde_json::from_value::<TargetCreatedEvent>(params.clone())
                         {
+                            let worker_kind = match created_event.target_info.target_type.as_str() {
+                                "service_worker" => Some((WorkerKind::Service, &service_workers)),
+                                "shared_worker" => Some((WorkerKind::Shared, &shared_workers)),
+                                _ => None,
+                            };
+                            if let Some((kind, workers)) = worker_kind {
+                                handle_worker_created(
+                                    &connection,
+                                    &context_id,
+                                    workers,
+                                    &clock_state,
+                                    &event_manager,
+                                    kind,
+                                    created_event,
+                                )
+                                .await;
+                                continue;
+                            }
                             handle_target_created(
                                 &connection,
                                 &context_id,
                                 &pages,
+                                &clock_state,
+                                &network_emulation,
                                 &event_manager,
                                 &route_registry,
                                 &options,
                                 context_index,
                                 &page_index_counter,
                                 &test_id_attribute,
+                                &tracing_state,
+                                &websocket_route_registry,
+                                &network_events,
                                 created_event,
                             )
                             .await;
@@ -83,7 +125,12 @@ pub(crate) fn start_target_event_listener(
                         if let Ok(destroyed_event) =
                             serde_json::from_value::<TargetDestroyedEvent>(params.clone())
                         {
-                            handle_target_destroyed(&pages, destroyed_event).await;
+                            handle_target_destroyed(
+                                &pages,
+                                &[&service_workers, &shared_workers],
+                                destroyed_event,
+                            )
+                            .await;
                         }
                     }
                 }
@@ -122,12 +169,17 @@ async fn handle_target_created(
     connection: &Arc<CdpConnection>,
     context_id: &str,
     pages: &Arc<RwLock<Vec<Page>>>,
+    clock_state: &Arc<RwLock<ClockState>>,
+    network_emulation: &Arc<RwLock<NetworkEmulation>>,
     event_manager: &Arc<ContextEventManager>,
     route_registry: &Arc<ContextRouteRegistry>,
     options: &ContextOptions,
     context_index: usize,
     page_index_counter: &Arc<std::sync::atomic::AtomicUsize>,
     test_id_attribute: &Arc<RwLock<String>>,
+    tracing_state: &Arc<RwLock<TracingState>>,
+    websocket_route_registry: &Arc<WebSocketRouteRegistry>,
+    network_events: &Arc<NetworkEventListener>,
     event: TargetCreatedEvent,
 ) {
     let info = &event.target_info;
@@ -142,16 +194,7 @@ async fn handle_target_created(
         return;
     }
 
-    // Filter by context ID
-    // For default context (empty string), match targets without a context ID
-    // For named contexts, require exact match
-    let matches_context = if context_id.is_empty() {
-        info.browser_context_id.is_none() || info.browser_context_id.as_deref() == Some("")
-    } else {
-        info.browser_context_id.as_deref() == Some(context_id)
-    };
-
-    if !matches_context {
+    if !matches_context(info, context_id) {
         trace!(
             target_context = ?info.browser_context_id,
             our_context = %context_id,
@@ -260,12 +303,18 @@ async fn handle_target_created(
         )
         .with_test_id_attribute(test_id_attr)
         .with_context_pages(pages.clone())
+        .with_tracing_state(tracing_state.clone())
+        .with_network_emulation(network_emulation.clone())
+        .with_context_network_events(network_events)
+        .with_base_url(options.base_url.clone())
         .with_context_routes_and_proxy(
             route_registry.clone(),
             http_credentials.clone(),
             proxy_credentials,
         )
         .await
+        .with_context_websocket_routes(websocket_route_registry.clone())
+        .await
     } else {
         Page::new_with_indices(
             connection.clone(),
@@ -277,10 +326,25 @@ async fn handle_target_created(
         )
         .with_test_id_attribute(test_id_attr)
         .with_context_pages(pages.clone())
+        .with_tracing_state(tracing_state.clone())
+        .with_network_emulation(network_emulation.clone())
+        .with_context_network_events(network_events)
+        .with_base_url(options.base_url.clone())
         .with_context_routes_and_proxy(route_registry.clone(), http_credentials, proxy_credentials)
         .await
+        .with_context_websocket_routes(websocket_route_registry.clone())
+        .await
     };
 
+    // Start video recording if enabled for the context
+    if let Err(e) = page.start_video_recording().await {
+        debug!(
+            target_id = %info.target_id,
+            error = %e,
+            "Failed to start video recording"
+        );
+    }
+
     // Enable Fetch for context routes
     if let Err(e) = page.enable_fetch_for_context_routes().await {
         debug!(
@@ -291,6 +355,29 @@ async fn handle_target_created(
         // Continue anyway
     }
 
+    // Install WebSocket routing for context WebSocket routes
+    if let Err(e) = page.enable_context_websocket_routes().await {
+        debug!(
+            target_id = %info.target_id,
+            error = %e,
+            "Failed to install context WebSocket routes"
+        );
+    }
+
+    // Apply the context's network conditions
+    if network_emulation.read().await.params_for(None).is_some() {
+        if let Err(e) = page.apply_network_emulation().await {
+            debug!(
+                target_id = %info.target_id,
+                error = %e,
+                "Failed to apply network emulation"
+            );
+        }
+    }
+
+    // Bring the page onto the context clock's timeline
+    clock::apply_to_page(clock_state, &page).await;
+
     // Track the page by storing a clone in the pages list
     {
         let mut pages_guard = pages.write().await;
@@ -308,11 +395,34 @@ async fn handle_target_created(
     event_manager.emit_page(page).await;
 }
 
+/// Check whether a target belongs to the given context.
+///
+/// For the default context (empty string), targets without a context ID match.
+/// For named contexts, an exact match is required.
+fn matches_context(info: &TargetInfo, context_id: &str) -> bool {
+    if context_id.is_empty() {
+        info.browser_context_id.is_none() || info.browser_context_id.as_deref() == Some("")
+    } else {
+        info.browser_context_id.as_deref() == Some(context_id)
+    }
+}
+
 /// Handle a Target.targetDestroyed event.
 ///
 /// This is the single entry point for ALL page destruction.
-/// It removes the page from tracking.
-async fn handle_target_destroyed(pages: &Arc<RwLock<Vec<Page>>>, event: TargetDestroyedEvent) {
+/// It removes the page or worker from tracking.
+async fn handle_target_destroyed(
+    pages: &Arc<RwLock<Vec<Page>>>,
+    workers: &[&Arc<RwLock<Vec<Worker>>>],
+    event: TargetDestroyedEvent,
+) {
+    for workers in workers {
+        workers
+            .write()
+            .await
+            .retain(|w| w.target_id() != event.target_id);
+    }
+
     let mut pages_guard = pages.write().await;
     let initial_len = pages_guard.len();
     pages_guard.retain(|p| p.target_id() != event.target_id);
@@ -349,16 +459,7 @@ async fn handle_target_info_changed(
         return;
     }
 
-    // Filter by context ID
-    // For default context (empty string), match targets without a context ID
-    // For named contexts, require exact match
-    let matches_context = if context_id.is_empty() {
-        info.browser_context_id.is_none() || info.browser_context_id.as_deref() == Some("")
-    } else {
-        info.browser_context_id.as_deref() == Some(context_id)
-    };
-
-    if !matches_context {
+    if !matches_context(info, context_id) {
         trace!(
             target_context = ?info.browser_context_id,
             our_context = %context_id,
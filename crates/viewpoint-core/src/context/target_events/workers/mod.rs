@@ -0,0 +1,101 @@
+//! Tracking of the service and shared workers of a context.
+
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use tracing::debug;
+use viewpoint_cdp::CdpConnection;
+use viewpoint_cdp::protocol::target_domain::{
+    AttachToTargetParams, AttachToTargetResult, TargetCreatedEvent,
+};
+
+use super::matches_context;
+use crate::context::clock::ClockState;
+use crate::context::events::ContextEventManager;
+use crate::page::Worker;
+
+/// Kind of context-level worker target.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum WorkerKind {
+    /// A `service_worker` target.
+    Service,
+    /// A `shared_worker` target.
+    Shared,
+}
+
+/// Handle a Target.targetCreated event for a service or shared worker.
+///
+/// Attaches to the worker, tracks it in `workers`, and emits the
+/// `service_worker` or `shared_worker` event.
+pub(super) async fn handle_worker_created(
+    connection: &Arc<CdpConnection>,
+    context_id: &str,
+    workers: &Arc<RwLock<Vec<Worker>>>,
+    clock_state: &Arc<RwLock<ClockState>>,
+    event_manager: &Arc<ContextEventManager>,
+    kind: WorkerKind,
+    event: TargetCreatedEvent,
+) {
+    let info = &event.target_info;
+
+    if !matches_context(info, context_id) || info.attached {
+        return;
+    }
+
+    debug!(
+        target_id = %info.target_id,
+        url = %info.url,
+        ?kind,
+        "New worker detected via Target.targetCreated"
+    );
+
+    let attach_result: Result<AttachToTargetResult, _> = connection
+        .send_command(
+            "Target.attachToTarget",
+            Some(AttachToTargetParams {
+                target_id: info.target_id.clone(),
+                flatten: Some(true),
+            }),
+            None,
+        )
+        .await;
+
+    let attach_result = match attach_result {
+        Ok(r) => r,
+        Err(e) => {
+            debug!(
+                target_id = %info.target_id,
+                error = %e,
+                "Failed to attach to worker"
+            );
+            return;
+        }
+    };
+
+    let init_script = clock_state.read().await.init_script();
+    let worker = match Worker::attach(
+        connection.clone(),
+        attach_result.session_id,
+        info,
+        None,
+        init_script.as_deref(),
+    )
+    .await
+    {
+        Ok(worker) => worker,
+        Err(e) => {
+            debug!(
+                target_id = %info.target_id,
+                error = %e,
+                "Failed to set up worker"
+            );
+            return;
+        }
+    };
+
+    workers.write().await.push(worker.clone());
+    match kind {
+        WorkerKind::Service => event_manager.emit_service_worker(worker).await,
+        WorkerKind::Shared => event_manager.emit_shared_worker(worker).await,
+    }
+}
@@ -1,49 +1,162 @@
 //! Action handle for tracking action duration in traces.
 
 use std::sync::Arc;
+
 use tokio::sync::RwLock;
+use viewpoint_cdp::CdpConnection;
 
-use super::types::TracingState;
+use super::capture;
+use super::types::{ActionEntry, TracingState, now_ms};
 
 /// Handle for tracking an action's duration in the trace.
 pub struct ActionHandle {
     state: Arc<RwLock<TracingState>>,
     index: usize,
+    /// Call ID of the action, used to detect a trace restarted mid-action.
+    call_id: String,
+    /// Page session used to capture the "after" screenshot and snapshot.
+    capture_target: Option<(Arc<CdpConnection>, String)>,
 }
 
 impl ActionHandle {
     /// Create a new action handle.
-    pub(crate) fn new(state: Arc<RwLock<TracingState>>, index: usize) -> Self {
-        Self { state, index }
+    pub(crate) fn new(state: Arc<RwLock<TracingState>>, index: usize, call_id: String) -> Self {
+        Self {
+            state,
+            index,
+            call_id,
+            capture_target: None,
+        }
     }
 
-    /// Complete the action with success.
-    pub async fn complete(self, result: Option<serde_json::Value>) {
-        let end_time = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs_f64()
-            * 1000.0;
+    /// Begin recording an action performed on a page.
+    ///
+    /// Returns `None` when tracing is not active, so callers pay only for a
+    /// read lock when no trace is being recorded. When screenshots or snapshots
+    /// are enabled, the page state is captured before the action is recorded.
+    pub(crate) async fn begin(
+        connection: &Arc<CdpConnection>,
+        state: &Arc<RwLock<TracingState>>,
+        session_id: &str,
+        page_id: &str,
+        action_type: &str,
+        selector: Option<String>,
+        params: Option<serde_json::Value>,
+    ) -> Option<Self> {
+        if !state.read().await.is_recording {
+            return None;
+        }
 
-        let mut state = self.state.write().await;
-        if let Some(action) = state.actions.get_mut(self.index) {
-            action.end_time = Some(end_time);
-            action.result = result;
+        let (before_screenshot, before_snapshot) =
+            capture::capture_action_context(connection, state, session_id, Some(action_type)).await;
+
+        let mut state_write = state.write().await;
+        if !state_write.is_recording {
+            return None;
+        }
+
+        let index = state_write.actions.len();
+        let start_time = now_ms();
+        let call_id = state_write.next_call_id();
+        let mut action = ActionEntry::new(call_id.clone(), action_type, start_time);
+        action.selector = selector;
+        action.page_id = Some(page_id.to_string());
+        if let Some(params) = &params {
+            action.url = params
+                .get("url")
+                .and_then(serde_json::Value::as_str)
+                .map(ToString::to_string);
+            action.value = params
+                .get("value")
+                .and_then(serde_json::Value::as_str)
+                .map(ToString::to_string);
         }
+        action.params = params;
+        action.before_screenshot = before_screenshot;
+        action.before_snapshot = before_snapshot;
+
+        state_write.events.push(serde_json::json!({
+            "type": "before",
+            "callId": action.call_id,
+            "startTime": start_time,
+            "apiName": action_type,
+            "selector": action.selector,
+            "params": action.params,
+            "pageId": page_id,
+        }));
+        state_write.actions.push(action);
+
+        Some(Self {
+            state: state.clone(),
+            index,
+            call_id,
+            capture_target: Some((connection.clone(), session_id.to_string())),
+        })
+    }
+
+    /// Complete the action with success.
+    pub async fn complete(self, result: Option<serde_json::Value>) {
+        self.finish(result, None).await;
     }
 
     /// Complete the action with an error.
     pub async fn fail(self, error: &str) {
-        let end_time = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs_f64()
-            * 1000.0;
+        let result = Some(serde_json::json!({ "error": error }));
+        self.finish(result, Some(error.to_string())).await;
+    }
+
+    /// Capture the "after" state and record the end of the action.
+    async fn finish(self, result: Option<serde_json::Value>, error: Option<String>) {
+        let (after_screenshot, after_snapshot) = match &self.capture_target {
+            Some((connection, session_id)) => {
+                let name = self.action_type().await;
+                capture::capture_action_context(
+                    connection,
+                    &self.state,
+                    session_id,
+                    name.as_deref(),
+                )
+                .await
+            }
+            None => (None, None),
+        };
+
+        let end_time = now_ms();
 
         let mut state = self.state.write().await;
-        if let Some(action) = state.actions.get_mut(self.index) {
-            action.end_time = Some(end_time);
-            action.result = Some(serde_json::json!({ "error": error }));
+        let Some(action) = state
+            .actions
+            .get_mut(self.index)
+            .filter(|action| action.call_id == self.call_id)
+        else {
+            return;
+        };
+        action.end_time = Some(end_time);
+        action.result = result;
+        action.error = error;
+        action.after_screenshot = after_screenshot;
+        action.after_snapshot = after_snapshot;
+        action.screenshot = after_screenshot.or(action.before_screenshot);
+        action.snapshot = after_snapshot.or(action.before_snapshot);
+
+        let event = serde_json::json!({
+            "type": "after",
+            "callId": action.call_id,
+            "endTime": end_time,
+            "error": action.error,
+        });
+
+        if self.capture_target.is_some() {
+            state.events.push(event);
         }
     }
+
+    /// Look up the action type for naming captured resources.
+    async fn action_type(&self) -> Option<String> {
+        let state = self.state.read().await;
+        state
+            .actions
+            .get(self.index)
+            .map(|action| action.action_type.clone())
+    }
 }
@@ -10,16 +10,18 @@ use super::types::{ScreenshotEntry, TracingState};
 use crate::error::ContextError;
 
 /// Capture a screenshot and add it to the trace state.
+///
+/// Returns the index of the stored screenshot, or `None` if screenshots are disabled.
 pub(super) async fn capture_screenshot(
     connection: &Arc<CdpConnection>,
     state: &Arc<RwLock<TracingState>>,
     session_id: &str,
     name: Option<&str>,
-) -> Result<(), ContextError> {
+) -> Result<Option<usize>, ContextError> {
     {
         let state_read = state.read().await;
         if !state_read.options.screenshots {
-            return Ok(());
+            return Ok(None);
         }
     }
 
@@ -46,25 +48,28 @@ pub(super) async fn capture_screenshot(
         * 1000.0;
 
     let mut state_write = state.write().await;
+    let index = state_write.screenshots.len();
     state_write.screenshots.push(ScreenshotEntry {
         data: result.data,
         timestamp,
         name: name.map(ToString::to_string),
     });
 
-    Ok(())
+    Ok(Some(index))
 }
 
 /// Capture a DOM snapshot and add it to the trace state.
+///
+/// Returns the index of the stored snapshot, or `None` if snapshots are disabled.
 pub(super) async fn capture_dom_snapshot(
     connection: &Arc<CdpConnection>,
     state: &Arc<RwLock<TracingState>>,
     session_id: &str,
-) -> Result<(), ContextError> {
+) -> Result<Option<usize>, ContextError> {
     {
         let state_read = state.read().await;
         if !state_read.options.snapshots {
-            return Ok(());
+            return Ok(None);
         }
     }
 
@@ -94,27 +99,37 @@ pub(super) async fn capture_dom_snapshot(
     });
 
     let mut state_write = state.write().await;
+    let index = state_write.snapshots.len();
     state_write.snapshots.push(snapshot);
 
-    Ok(())
+    Ok(Some(index))
 }
 
 /// Capture action context (screenshot + snapshot) if enabled.
+///
+/// Capture failures are logged and ignored so they never fail the traced action.
+/// Returns the `(screenshot, snapshot)` indices that were stored.
 pub(super) async fn capture_action_context(
     connection: &Arc<CdpConnection>,
     state: &Arc<RwLock<TracingState>>,
     session_id: &str,
     action_name: Option<&str>,
-) -> Result<(), ContextError> {
+) -> (Option<usize>, Option<usize>) {
     // Capture screenshot if enabled
-    if let Err(e) = capture_screenshot(connection, state, session_id, action_name).await {
-        debug!("Failed to capture screenshot: {}", e);
-    }
+    let screenshot = capture_screenshot(connection, state, session_id, action_name)
+        .await
+        .unwrap_or_else(|e| {
+            debug!("Failed to capture screenshot: {}", e);
+            None
+        });
 
     // Capture DOM snapshot if enabled
-    if let Err(e) = capture_dom_snapshot(connection, state, session_id).await {
-        debug!("Failed to capture DOM snapshot: {}", e);
-    }
-
-    Ok(())
+    let snapshot = capture_dom_snapshot(connection, state, session_id)
+        .await
+        .unwrap_or_else(|e| {
+            debug!("Failed to capture DOM snapshot: {}", e);
+            None
+        });
+
+    (screenshot, snapshot)
 }
@@ -9,15 +9,21 @@
 mod action_handle;
 mod capture;
 mod network;
+mod playwright;
+mod reader;
 mod sources;
 mod tracing_manager;
 mod types;
 mod writer;
 
+#[cfg(test)]
+mod tests;
+
 // Re-export public types
 pub use action_handle::ActionHandle;
+pub use reader::{TraceArchive, TraceScreenshot, TraceSource};
 pub use tracing_manager::Tracing;
-pub use types::{ActionEntry, TracingOptions};
+pub use types::{ActionEntry, TraceFormat, TracingOptions};
 
 // Internal re-exports
 pub(crate) use types::TracingState;
@@ -0,0 +1,209 @@
+//! Conversion of CDP DOM snapshots into Playwright frame snapshots.
+//!
+//! Playwright stores the DOM as nested arrays: a text node is a string and an
+//! element is `[nodeName, {attributes}, ...children]`. CDP's
+//! `DOMSnapshot.captureSnapshot` returns flat, string-table indexed arrays, so
+//! the tree is rebuilt here from the `parentIndex` column.
+
+use serde_json::{Map, Value};
+
+/// Viewport used when the snapshot does not report the document size.
+const DEFAULT_VIEWPORT: (f64, f64) = (1280.0, 720.0);
+
+/// Elements dropped from snapshots so the viewer never re-executes page code.
+const SKIPPED_ELEMENTS: [&str; 2] = ["SCRIPT", "NOSCRIPT"];
+
+/// The main document of a captured snapshot, ready to be written as a
+/// `frame-snapshot` event.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct FrameDocument {
+    /// Root `<html>` element in Playwright's nested-array form.
+    pub html: Value,
+    /// Doctype name (e.g. `html`), if the document declares one.
+    pub doctype: Option<String>,
+    /// CDP frame ID of the document.
+    pub frame_id: String,
+    /// Document URL.
+    pub url: String,
+    /// Document width and height.
+    pub viewport: (f64, f64),
+}
+
+/// Convert the result of `DOMSnapshot.captureSnapshot` into a frame document.
+///
+/// Only the first (main frame) document is converted. Returns `None` if the
+/// snapshot has no document or no root element.
+pub(super) fn convert_snapshot(snapshot: &Value) -> Option<FrameDocument> {
+    let strings: Vec<&str> = snapshot
+        .get("strings")?
+        .as_array()?
+        .iter()
+        .map(|s| s.as_str().unwrap_or_default())
+        .collect();
+    let document = snapshot.get("documents")?.as_array()?.first()?;
+    let tree = NodeTree::new(document.get("nodes")?, &strings);
+
+    let root = tree.parent.iter().position(|&parent| parent < 0)?;
+    let mut doctype = None;
+    let mut html = None;
+    for &child in &tree.children[root] {
+        match tree.node_type(child) {
+            10 => doctype = Some(tree.node_name(child).to_string()),
+            1 if html.is_none() => html = tree.convert(child),
+            _ => {}
+        }
+    }
+
+    let width = document.get("contentWidth").and_then(Value::as_f64);
+    let height = document.get("contentHeight").and_then(Value::as_f64);
+
+    Some(FrameDocument {
+        html: html?,
+        doctype,
+        frame_id: lookup(&strings, document.get("frameId")).to_string(),
+        url: lookup(&strings, document.get("documentURL")).to_string(),
+        viewport: (
+            width.unwrap_or(DEFAULT_VIEWPORT.0),
+            height.unwrap_or(DEFAULT_VIEWPORT.1),
+        ),
+    })
+}
+
+/// Flat node columns from a CDP `NodeTreeSnapshot` with child lists rebuilt.
+struct NodeTree<'a> {
+    strings: &'a [&'a str],
+    parent: Vec<i64>,
+    children: Vec<Vec<usize>>,
+    node_type: Vec<i64>,
+    node_name: Vec<i64>,
+    node_value: Vec<i64>,
+    attributes: Vec<Vec<i64>>,
+    input_value: Vec<(usize, i64)>,
+    input_checked: Vec<usize>,
+    option_selected: Vec<usize>,
+}
+
+impl<'a> NodeTree<'a> {
+    fn new(nodes: &Value, strings: &'a [&'a str]) -> Self {
+        let parent = int_column(nodes.get("parentIndex"));
+        let mut children = vec![Vec::new(); parent.len()];
+        for (index, &p) in parent.iter().enumerate() {
+            if let Some(siblings) = usize::try_from(p).ok().and_then(|p| children.get_mut(p)) {
+                siblings.push(index);
+            }
+        }
+
+        let attributes = nodes
+            .get("attributes")
+            .and_then(Value::as_array)
+            .map(|rows| rows.iter().map(|row| int_column(Some(row))).collect())
+            .unwrap_or_default();
+
+        let rare_value = nodes.get("inputValue");
+        let input_value = rare_index(rare_value)
+            .into_iter()
+            .zip(int_column(rare_value.and_then(|v| v.get("value"))))
+            .collect();
+
+        Self {
+            strings,
+            children,
+            node_type: int_column(nodes.get("nodeType")),
+            node_name: int_column(nodes.get("nodeName")),
+            node_value: int_column(nodes.get("nodeValue")),
+            attributes,
+            input_value,
+            input_checked: rare_index(nodes.get("inputChecked")),
+            option_selected: rare_index(nodes.get("optionSelected")),
+            parent,
+        }
+    }
+
+    fn node_type(&self, node: usize) -> i64 {
+        self.node_type.get(node).copied().unwrap_or_default()
+    }
+
+    fn node_name(&self, node: usize) -> &'a str {
+        string_at(self.strings, self.node_name.get(node).copied())
+    }
+
+    /// Convert a node and its subtree, returning `None` for skipped nodes.
+    fn convert(&self, node: usize) -> Option<Value> {
+        match self.node_type(node) {
+            3 => Some(Value::String(
+                string_at(self.strings, self.node_value.get(node).copied()).to_string(),
+            )),
+            1 => {
+                let name = self.node_name(node);
+                if SKIPPED_ELEMENTS.contains(&name) {
+                    return None;
+                }
+
+                let mut element = vec![
+                    Value::String(name.to_string()),
+                    Value::Object(self.element_attributes(node)),
+                ];
+                element.extend(self.children[node].iter().filter_map(|&c| self.convert(c)));
+                Some(Value::Array(element))
+            }
+            _ => None,
+        }
+    }
+
+    /// Collect an element's attributes, including live form state.
+    fn element_attributes(&self, node: usize) -> Map<String, Value> {
+        let mut attrs = Map::new();
+        if let Some(pairs) = self.attributes.get(node) {
+            for pair in pairs.chunks_exact(2) {
+                attrs.insert(
+                    string_at(self.strings, Some(pair[0])).to_string(),
+                    Value::String(string_at(self.strings, Some(pair[1])).to_string()),
+                );
+            }
+        }
+
+        if let Some(&(_, value)) = self.input_value.iter().find(|(i, _)| *i == node) {
+            attrs.insert(
+                "value".to_string(),
+                Value::String(string_at(self.strings, Some(value)).to_string()),
+            );
+        }
+        if self.input_checked.contains(&node) {
+            attrs.insert("checked".to_string(), Value::String(String::new()));
+        }
+        if self.option_selected.contains(&node) {
+            attrs.insert("selected".to_string(), Value::String(String::new()));
+        }
+
+        attrs
+    }
+}
+
+/// Read an array of integers, treating anything else as empty.
+fn int_column(value: Option<&Value>) -> Vec<i64> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| items.iter().map(|v| v.as_i64().unwrap_or(-1)).collect())
+        .unwrap_or_default()
+}
+
+/// Read the node indices of a CDP `Rare*Data` column.
+fn rare_index(value: Option<&Value>) -> Vec<usize> {
+    int_column(value.and_then(|v| v.get("index")))
+        .into_iter()
+        .filter_map(|i| usize::try_from(i).ok())
+        .collect()
+}
+
+/// Look up an optional string-table index stored as a JSON value.
+fn lookup<'a>(strings: &[&'a str], index: Option<&Value>) -> &'a str {
+    string_at(strings, index.and_then(Value::as_i64))
+}
+
+/// Look up a string-table index; `-1` and out-of-range indices map to `""`.
+fn string_at<'a>(strings: &[&'a str], index: Option<i64>) -> &'a str {
+    index
+        .and_then(|i| usize::try_from(i).ok())
+        .and_then(|i| strings.get(i).copied())
+        .unwrap_or_default()
+}
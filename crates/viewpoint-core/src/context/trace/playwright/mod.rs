@@ -0,0 +1,322 @@
+//! Playwright-compatible trace archive writer.
+//!
+//! Writes the layout understood by the Playwright Trace Viewer
+//! (`npx playwright show-trace`):
+//! - trace.trace: JSONL stream of context, action, screencast and snapshot events
+//! - trace.network: JSONL stream of `resource-snapshot` events (HAR entries)
+//! - resources/: Screenshots and source files keyed by SHA-1
+
+mod dom;
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashSet;
+use std::io::Write;
+
+use base64::Engine;
+use serde_json::{Value, json};
+use sha1::{Digest, Sha1};
+
+use crate::error::ContextError;
+
+use super::types::{ActionEntry, TracingState};
+use super::writer::build_har_entry;
+
+/// Trace format version emitted in the `context-options` event.
+const TRACE_VERSION: u32 = 6;
+
+/// Page ID used for resources that cannot be tied to a page.
+const UNKNOWN_PAGE: &str = "page@unknown";
+
+/// Write a trace to a zip file in Playwright's format.
+pub(super) fn write_trace_zip(
+    path: &std::path::Path,
+    state: &TracingState,
+) -> Result<(), ContextError> {
+    let file = std::fs::File::create(path)
+        .map_err(|e| ContextError::Internal(format!("Failed to create trace file: {e}")))?;
+
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    let mut resources = Vec::new();
+    let trace_events = build_trace_events(state, &mut resources)?;
+    let network_events = build_network_events(state)?;
+
+    write_entry(&mut zip, options, "trace.trace", &to_jsonl(&trace_events)?)?;
+    write_entry(
+        &mut zip,
+        options,
+        "trace.network",
+        &to_jsonl(&network_events)?,
+    )?;
+
+    for source in &state.source_files {
+        resources.push((
+            format!("src@{}.txt", sha1_hex(source.path.as_bytes())),
+            source.content.as_bytes().to_vec(),
+        ));
+    }
+
+    let mut written = HashSet::new();
+    for (name, data) in resources {
+        if written.insert(name.clone()) {
+            write_entry(&mut zip, options, &format!("resources/{name}"), &data)?;
+        }
+    }
+
+    zip.finish()
+        .map_err(|e| ContextError::Internal(format!("Failed to finalize zip: {e}")))?;
+
+    Ok(())
+}
+
+/// Build the `trace.trace` event stream.
+///
+/// Screenshot bytes referenced by `screencast-frame` events are appended to
+/// `resources` as `(name, data)` pairs.
+fn build_trace_events(
+    state: &TracingState,
+    resources: &mut Vec<(String, Vec<u8>)>,
+) -> Result<Vec<Value>, ContextError> {
+    let mut events: Vec<(f64, Value)> = Vec::new();
+
+    for action in &state.actions {
+        let page_id = page_ref(action.page_id.as_deref());
+        if let Some(event) = action
+            .before_snapshot
+            .and_then(|i| frame_snapshot_event(state, i, action, "before", &page_id))
+        {
+            events.push(event);
+        }
+        events.push((action.start_time, before_event(action, &page_id)));
+
+        if let Some(end_time) = action.end_time {
+            if let Some(event) = action
+                .after_snapshot
+                .and_then(|i| frame_snapshot_event(state, i, action, "after", &page_id))
+            {
+                events.push(event);
+            }
+            events.push((end_time, after_event(action, end_time)));
+        }
+    }
+
+    for (index, screenshot) in state.screenshots.iter().enumerate() {
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(&screenshot.data)
+            .map_err(|e| ContextError::Internal(format!("Failed to decode screenshot: {e}")))?;
+        let (width, height) = png_dimensions(&data).unwrap_or_default();
+        let name = format!("{}.png", sha1_hex(&data));
+
+        let page_id = state
+            .actions
+            .iter()
+            .find(|a| a.before_screenshot == Some(index) || a.after_screenshot == Some(index))
+            .map_or_else(
+                || UNKNOWN_PAGE.to_string(),
+                |a| page_ref(a.page_id.as_deref()),
+            );
+
+        events.push((
+            screenshot.timestamp,
+            json!({
+                "type": "screencast-frame",
+                "pageId": page_id,
+                "sha1": name,
+                "width": width,
+                "height": height,
+                "timestamp": screenshot.timestamp,
+            }),
+        ));
+        resources.push((name, data));
+    }
+
+    events.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut trace = Vec::with_capacity(events.len() + 1);
+    trace.push(context_options_event(state));
+    trace.extend(events.into_iter().map(|(_, event)| event));
+    Ok(trace)
+}
+
+/// Build the `context-options` event that opens every trace.
+fn context_options_event(state: &TracingState) -> Value {
+    let start = if state.started_at > 0.0 {
+        state.started_at
+    } else {
+        state.actions.first().map_or(0.0, |a| a.start_time)
+    };
+
+    json!({
+        "version": TRACE_VERSION,
+        "type": "context-options",
+        "origin": "library",
+        "browserName": "chromium",
+        "platform": std::env::consts::OS,
+        "wallTime": start,
+        "monotonicTime": start,
+        "title": state.options.title.as_ref().or(state.options.name.as_ref()),
+        "options": {},
+    })
+}
+
+/// Build the `before` event for an action.
+pub(super) fn before_event(action: &ActionEntry, page_id: &str) -> Value {
+    let (class, api_name) = api_name(action);
+
+    let mut params = match &action.params {
+        Some(Value::Object(params)) => params.clone(),
+        _ => serde_json::Map::new(),
+    };
+    if let Some(selector) = &action.selector {
+        params.insert("selector".to_string(), Value::String(selector.clone()));
+    }
+
+    let mut event = json!({
+        "type": "before",
+        "callId": action.call_id,
+        "startTime": action.start_time,
+        "apiName": api_name,
+        "class": class,
+        "method": action.action_type,
+        "params": params,
+        "pageId": page_id,
+    });
+    if action.before_snapshot.is_some() {
+        event["beforeSnapshot"] = Value::String(snapshot_name("before", action));
+    }
+    event
+}
+
+/// Build the `after` event for a finished action.
+pub(super) fn after_event(action: &ActionEntry, end_time: f64) -> Value {
+    let mut event = json!({
+        "type": "after",
+        "callId": action.call_id,
+        "endTime": end_time,
+    });
+    if action.after_snapshot.is_some() {
+        event["afterSnapshot"] = Value::String(snapshot_name("after", action));
+    }
+    if let Some(error) = &action.error {
+        event["error"] = json!({ "message": error, "name": "Error" });
+    }
+    event
+}
+
+/// Build a `frame-snapshot` event for the DOM snapshot at `index`.
+fn frame_snapshot_event(
+    state: &TracingState,
+    index: usize,
+    action: &ActionEntry,
+    phase: &str,
+    page_id: &str,
+) -> Option<(f64, Value)> {
+    let snapshot = state.snapshots.get(index)?;
+    let timestamp = snapshot.get("timestamp").and_then(Value::as_f64)?;
+    let document = dom::convert_snapshot(snapshot.get("data")?)?;
+
+    Some((
+        timestamp,
+        json!({
+            "type": "frame-snapshot",
+            "snapshot": {
+                "callId": action.call_id,
+                "snapshotName": snapshot_name(phase, action),
+                "pageId": page_id,
+                "frameId": document.frame_id,
+                "frameUrl": document.url,
+                "timestamp": timestamp,
+                "collectionTime": 0,
+                "doctype": document.doctype,
+                "html": document.html,
+                "resourceOverrides": [],
+                "viewport": {
+                    "width": document.viewport.0,
+                    "height": document.viewport.1,
+                },
+                "isMainFrame": true,
+            },
+        }),
+    ))
+}
+
+/// Build the `trace.network` event stream.
+fn build_network_events(state: &TracingState) -> Result<Vec<Value>, ContextError> {
+    state
+        .network_entries
+        .iter()
+        .map(|entry_state| {
+            let entry = build_har_entry(entry_state, state.current_page_id.as_ref());
+            let mut snapshot = serde_json::to_value(entry).map_err(|e| {
+                ContextError::Internal(format!("Failed to serialize network entry: {e}"))
+            })?;
+            snapshot["_monotonicTime"] = json!(entry_state.request.wall_time * 1000.0);
+            Ok(json!({ "type": "resource-snapshot", "snapshot": snapshot }))
+        })
+        .collect()
+}
+
+/// Map an action to Playwright's `(class, apiName)` pair.
+pub(super) fn api_name(action: &ActionEntry) -> (&'static str, String) {
+    let method = &action.action_type;
+    match method.as_str() {
+        "goto" | "setContent" => ("Frame", format!("page.{method}")),
+        "goBack" | "goForward" | "reload" => ("Page", format!("page.{method}")),
+        _ if action.selector.is_some() => ("Frame", format!("locator.{method}")),
+        _ => ("Page", format!("page.{method}")),
+    }
+}
+
+/// Name of the snapshot taken `phase` ("before"/"after") an action.
+fn snapshot_name(phase: &str, action: &ActionEntry) -> String {
+    format!("{phase}@{}", action.call_id)
+}
+
+/// Playwright page ID for a CDP target ID.
+fn page_ref(target_id: Option<&str>) -> String {
+    target_id.map_or_else(|| UNKNOWN_PAGE.to_string(), |id| format!("page@{id}"))
+}
+
+/// Read the width and height from a PNG's IHDR chunk.
+pub(super) fn png_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    if data.len() < 24 || data[..8] != SIGNATURE {
+        return None;
+    }
+    let width = u32::from_be_bytes(data[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(data[20..24].try_into().ok()?);
+    Some((width, height))
+}
+
+/// Lowercase hex SHA-1 digest of `data`.
+fn sha1_hex(data: &[u8]) -> String {
+    format!("{:x}", Sha1::digest(data))
+}
+
+/// Serialize events as newline-delimited JSON.
+fn to_jsonl(events: &[Value]) -> Result<Vec<u8>, ContextError> {
+    let mut out = Vec::new();
+    for event in events {
+        serde_json::to_writer(&mut out, event)
+            .map_err(|e| ContextError::Internal(format!("Failed to serialize trace event: {e}")))?;
+        out.push(b'\n');
+    }
+    Ok(out)
+}
+
+/// Write a single file into the zip archive.
+fn write_entry<W: Write + std::io::Seek>(
+    zip: &mut zip::ZipWriter<W>,
+    options: zip::write::SimpleFileOptions,
+    name: &str,
+    data: &[u8],
+) -> Result<(), ContextError> {
+    zip.start_file(name, options)
+        .map_err(|e| ContextError::Internal(format!("Failed to write {name}: {e}")))?;
+    zip.write_all(data)
+        .map_err(|e| ContextError::Internal(format!("Failed to write {name} data: {e}")))
+}
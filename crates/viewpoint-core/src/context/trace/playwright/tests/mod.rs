@@ -0,0 +1,159 @@
+use std::io::Read;
+
+use serde_json::{Value, json};
+
+use super::dom::convert_snapshot;
+use super::*;
+use crate::context::trace::types::{TraceFormat, TracingOptions};
+
+fn cdp_snapshot() -> Value {
+    // <!DOCTYPE html><html><body><input value="hi"><script>x()</script>Hello</body></html>
+    json!({
+        "strings": [
+            "https://example.com/", "FRAME1", "html", "#document", "HTML",
+            "BODY", "INPUT", "type", "text", "SCRIPT", "#text", "Hello", "typed"
+        ],
+        "documents": [{
+            "documentURL": 0,
+            "frameId": 1,
+            "contentWidth": 800.0,
+            "contentHeight": 600.0,
+            "nodes": {
+                "parentIndex": [-1, 0, 0, 2, 3, 3, 3],
+                "nodeType": [9, 10, 1, 1, 1, 1, 3],
+                "nodeName": [3, 2, 4, 5, 6, 9, 10],
+                "nodeValue": [-1, -1, -1, -1, -1, -1, 11],
+                "attributes": [[], [], [], [], [7, 8], [], []],
+                "inputValue": { "index": [4], "value": [12] }
+            }
+        }]
+    })
+}
+
+fn finished_action(call_id: &str, action_type: &str, selector: Option<&str>) -> ActionEntry {
+    let mut action = ActionEntry::new(call_id.to_string(), action_type, 1000.0);
+    action.selector = selector.map(ToString::to_string);
+    action.page_id = Some("TARGET1".to_string());
+    action.end_time = Some(1100.0);
+    action
+}
+
+#[test]
+fn test_convert_snapshot_builds_nested_tree() {
+    let document = convert_snapshot(&cdp_snapshot()).unwrap();
+
+    assert_eq!(document.doctype.as_deref(), Some("html"));
+    assert_eq!(document.frame_id, "FRAME1");
+    assert_eq!(document.url, "https://example.com/");
+    assert_eq!(document.viewport, (800.0, 600.0));
+    assert_eq!(
+        document.html,
+        json!([
+            "HTML",
+            {},
+            ["BODY", {}, ["INPUT", { "type": "text", "value": "typed" }], "Hello"]
+        ])
+    );
+}
+
+#[test]
+fn test_convert_snapshot_without_documents() {
+    assert!(convert_snapshot(&json!({ "strings": [], "documents": [] })).is_none());
+}
+
+#[test]
+fn test_api_name_mapping() {
+    let click = finished_action("call@0", "click", Some("css=button"));
+    let goto = finished_action("call@1", "goto", None);
+    let reload = finished_action("call@2", "reload", None);
+
+    assert_eq!(api_name(&click), ("Frame", "locator.click".to_string()));
+    assert_eq!(api_name(&goto), ("Frame", "page.goto".to_string()));
+    assert_eq!(api_name(&reload), ("Page", "page.reload".to_string()));
+}
+
+#[test]
+fn test_before_and_after_events() {
+    let mut action = finished_action("call@3", "fill", Some("css=input"));
+    action.params = Some(json!({ "value": "hello" }));
+    action.before_snapshot = Some(0);
+    action.error = Some("element not editable".to_string());
+
+    let before = before_event(&action, "page@TARGET1");
+    assert_eq!(before["type"], "before");
+    assert_eq!(before["apiName"], "locator.fill");
+    assert_eq!(before["params"]["value"], "hello");
+    assert_eq!(before["params"]["selector"], "css=input");
+    assert_eq!(before["beforeSnapshot"], "before@call@3");
+
+    let after = after_event(&action, 1100.0);
+    assert_eq!(after["callId"], "call@3");
+    assert_eq!(after["error"]["message"], "element not editable");
+    assert!(after.get("afterSnapshot").is_none());
+}
+
+#[test]
+fn test_png_dimensions() {
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    png.extend_from_slice(&[0, 0, 0, 13]);
+    png.extend_from_slice(b"IHDR");
+    png.extend_from_slice(&640u32.to_be_bytes());
+    png.extend_from_slice(&480u32.to_be_bytes());
+
+    assert_eq!(png_dimensions(&png), Some((640, 480)));
+    assert_eq!(png_dimensions(b"not a png"), None);
+}
+
+#[test]
+fn test_write_trace_zip_layout() {
+    let mut state = TracingState {
+        options: TracingOptions::new()
+            .title("layout")
+            .format(TraceFormat::Playwright),
+        started_at: 900.0,
+        ..Default::default()
+    };
+    let mut action = finished_action("call@0", "click", Some("css=button"));
+    action.after_snapshot = Some(0);
+    state.actions.push(action);
+    state
+        .snapshots
+        .push(json!({ "timestamp": 1050.0, "data": cdp_snapshot() }));
+    state
+        .source_files
+        .push(crate::context::trace::types::SourceFileEntry {
+            path: "tests/example.rs".to_string(),
+            content: "fn main() {}".to_string(),
+        });
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("trace.zip");
+    write_trace_zip(&path, &state).unwrap();
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(&path).unwrap()).unwrap();
+    let mut trace = String::new();
+    archive
+        .by_name("trace.trace")
+        .unwrap()
+        .read_to_string(&mut trace)
+        .unwrap();
+    assert!(archive.by_name("trace.network").is_ok());
+    let source = format!("resources/src@{}.txt", sha1_hex(b"tests/example.rs"));
+    assert!(archive.by_name(&source).is_ok());
+
+    let events: Vec<Value> = trace
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let types: Vec<&str> = events.iter().map(|e| e["type"].as_str().unwrap()).collect();
+    assert_eq!(
+        types,
+        ["context-options", "before", "frame-snapshot", "after"]
+    );
+    assert_eq!(events[0]["version"], 6);
+    assert_eq!(events[0]["wallTime"], 900.0);
+    assert_eq!(events[0]["title"], "layout");
+    assert_eq!(events[2]["snapshot"]["snapshotName"], "after@call@0");
+    assert_eq!(events[2]["snapshot"]["pageId"], "page@TARGET1");
+    assert_eq!(events[3]["afterSnapshot"], "after@call@0");
+}
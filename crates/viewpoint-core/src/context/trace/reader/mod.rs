@@ -0,0 +1,187 @@
+//! Trace reader - loading and querying recorded trace archives.
+
+mod parse;
+
+#[cfg(test)]
+mod tests;
+
+use std::io::{Read, Seek};
+use std::path::Path;
+
+use chrono::DateTime;
+
+use crate::error::ContextError;
+use crate::network::har::HarEntry;
+
+use super::types::{ActionEntry, TraceFormat};
+
+/// A screenshot stored in a trace archive.
+#[derive(Debug, Clone)]
+pub struct TraceScreenshot {
+    /// Name the screenshot was recorded with (usually the action type).
+    pub name: Option<String>,
+    /// Timestamp when captured (ms since epoch).
+    pub timestamp: f64,
+    /// Raw PNG bytes.
+    pub data: Vec<u8>,
+}
+
+/// A source file stored in a trace archive.
+#[derive(Debug, Clone)]
+pub struct TraceSource {
+    /// Path of the source file.
+    ///
+    /// Playwright-format archives only store a hash of the path, so the
+    /// resource name (e.g. `src@<sha1>.txt`) is used instead.
+    pub path: String,
+    /// Content of the source file.
+    pub content: String,
+}
+
+/// A recorded trace loaded back from a trace zip.
+///
+/// Both the default Viewpoint layout and the Playwright layout written by
+/// [`Tracing::stop`](super::Tracing::stop) can be read. The archive is parsed
+/// into typed actions, HAR network entries, screenshots and source files that
+/// can be queried to build custom failure reports or assertions.
+///
+/// # Example
+///
+/// ```no_run
+/// use viewpoint_core::TraceArchive;
+///
+/// # fn example() -> Result<(), viewpoint_core::error::ContextError> {
+/// let trace = TraceArchive::open("trace.zip")?;
+///
+/// for (index, action) in trace.actions().iter().enumerate() {
+///     if action.is_failed() {
+///         println!("{} failed: {:?}", action.action_type, action.error);
+///         for entry in trace.requests_during_action(index) {
+///             println!("  {} {}", entry.request.method, entry.request.url);
+///         }
+///         if let Some(screenshot) = trace.screenshot_nearest(action.start_time) {
+///             std::fs::write("failure.png", &screenshot.data).unwrap();
+///         }
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TraceArchive {
+    format: TraceFormat,
+    name: Option<String>,
+    title: Option<String>,
+    actions: Vec<ActionEntry>,
+    network_entries: Vec<HarEntry>,
+    screenshots: Vec<TraceScreenshot>,
+    sources: Vec<TraceSource>,
+}
+
+impl TraceArchive {
+    /// Open and parse a trace zip file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not a zip archive, or
+    /// does not contain a recognized trace.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ContextError> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path).map_err(|e| {
+            ContextError::Internal(format!("Failed to open trace file {}: {e}", path.display()))
+        })?;
+        Self::from_reader(file)
+    }
+
+    /// Parse a trace zip from any seekable reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not a zip archive or does not contain a
+    /// recognized trace.
+    pub fn from_reader<R: Read + Seek>(reader: R) -> Result<Self, ContextError> {
+        let mut zip = zip::ZipArchive::new(reader)
+            .map_err(|e| ContextError::Internal(format!("Failed to read trace zip: {e}")))?;
+        parse::parse_archive(&mut zip)
+    }
+
+    /// Format the archive was written in.
+    pub fn format(&self) -> TraceFormat {
+        self.format
+    }
+
+    /// Trace name, if one was set.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Trace title, if one was set.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Recorded actions in the order they started.
+    pub fn actions(&self) -> &[ActionEntry] {
+        &self.actions
+    }
+
+    /// Recorded network requests.
+    pub fn network_entries(&self) -> &[HarEntry] {
+        &self.network_entries
+    }
+
+    /// Screenshots in the order they were captured.
+    pub fn screenshots(&self) -> &[TraceScreenshot] {
+        &self.screenshots
+    }
+
+    /// Source files included in the trace.
+    pub fn sources(&self) -> &[TraceSource] {
+        &self.sources
+    }
+
+    /// Find an action by its call ID (e.g. `"call@3"`).
+    pub fn action(&self, call_id: &str) -> Option<&ActionEntry> {
+        self.actions.iter().find(|a| a.call_id == call_id)
+    }
+
+    /// Actions that finished with an error.
+    pub fn failed_actions(&self) -> Vec<&ActionEntry> {
+        self.actions.iter().filter(|a| a.is_failed()).collect()
+    }
+
+    /// Network requests started while the action at `index` was running.
+    ///
+    /// An action that never finished is treated as running until the end of
+    /// the trace. Returns an empty list if `index` is out of range.
+    pub fn requests_during_action(&self, index: usize) -> Vec<&HarEntry> {
+        let Some(action) = self.actions.get(index) else {
+            return Vec::new();
+        };
+        let end = action.end_time.unwrap_or(f64::INFINITY);
+
+        self.network_entries
+            .iter()
+            .filter(|entry| {
+                entry_start_ms(entry)
+                    .is_some_and(|start| start >= action.start_time && start <= end)
+            })
+            .collect()
+    }
+
+    /// The screenshot captured closest to `timestamp` (ms since epoch).
+    pub fn screenshot_nearest(&self, timestamp: f64) -> Option<&TraceScreenshot> {
+        self.screenshots.iter().min_by(|a, b| {
+            (a.timestamp - timestamp)
+                .abs()
+                .total_cmp(&(b.timestamp - timestamp).abs())
+        })
+    }
+}
+
+/// Start time of a HAR entry in milliseconds since the Unix epoch.
+fn entry_start_ms(entry: &HarEntry) -> Option<f64> {
+    DateTime::parse_from_rfc3339(&entry.started_date_time)
+        .ok()
+        .map(|t| t.timestamp_micros() as f64 / 1000.0)
+}
@@ -0,0 +1,248 @@
+//! Parsing of trace archives in both supported layouts.
+
+use std::io::{Read, Seek};
+
+use serde_json::Value;
+
+use crate::error::ContextError;
+use crate::network::har::{Har, HarEntry};
+
+use super::super::types::{ActionEntry, TraceFile, TraceFormat};
+use super::{TraceArchive, TraceScreenshot, TraceSource};
+
+/// Parse a trace zip, detecting its format from the files it contains.
+pub(super) fn parse_archive<R: Read + Seek>(
+    zip: &mut zip::ZipArchive<R>,
+) -> Result<TraceArchive, ContextError> {
+    if zip.index_for_name("trace.json").is_some() {
+        parse_viewpoint(zip)
+    } else if zip.index_for_name("trace.trace").is_some() {
+        parse_playwright(zip)
+    } else {
+        Err(ContextError::Internal(
+            "Not a trace archive: missing trace.json or trace.trace".to_string(),
+        ))
+    }
+}
+
+/// Parse an archive written in Viewpoint's own format.
+fn parse_viewpoint<R: Read + Seek>(
+    zip: &mut zip::ZipArchive<R>,
+) -> Result<TraceArchive, ContextError> {
+    let trace: TraceFile = serde_json::from_slice(&read_file(zip, "trace.json")?)
+        .map_err(|e| ContextError::Internal(format!("Failed to parse trace.json: {e}")))?;
+
+    let network_entries = match &trace.network {
+        Some(name) if zip.index_for_name(name).is_some() => {
+            let har: Har = serde_json::from_slice(&read_file(zip, name)?)
+                .map_err(|e| ContextError::Internal(format!("Failed to parse {name}: {e}")))?;
+            har.log.entries
+        }
+        _ => Vec::new(),
+    };
+
+    let mut screenshots = Vec::new();
+    let mut sources = Vec::new();
+    for resource in &trace.resources {
+        match resource.resource_type.as_str() {
+            "screenshot" => screenshots.push(TraceScreenshot {
+                name: Some(resource.name.clone()),
+                timestamp: resource.timestamp,
+                data: read_file(zip, &resource.path)?,
+            }),
+            "source" => sources.push(TraceSource {
+                path: resource.name.clone(),
+                content: read_string(zip, &resource.path)?,
+            }),
+            _ => {}
+        }
+    }
+
+    Ok(TraceArchive {
+        format: TraceFormat::Viewpoint,
+        name: trace.name,
+        title: trace.title,
+        actions: trace.actions,
+        network_entries,
+        screenshots,
+        sources,
+    })
+}
+
+/// Parse an archive written in Playwright's format.
+fn parse_playwright<R: Read + Seek>(
+    zip: &mut zip::ZipArchive<R>,
+) -> Result<TraceArchive, ContextError> {
+    let mut title = None;
+    let mut actions: Vec<ActionEntry> = Vec::new();
+    let mut frames = Vec::new();
+
+    for event in read_jsonl(zip, "trace.trace")? {
+        match event.get("type").and_then(Value::as_str) {
+            Some("context-options") => {
+                title = event
+                    .get("title")
+                    .and_then(Value::as_str)
+                    .map(ToString::to_string);
+            }
+            Some("before") => actions.push(action_from_before(&event)),
+            Some("after") => {
+                let call_id = event.get("callId").and_then(Value::as_str);
+                if let Some(action) = actions
+                    .iter_mut()
+                    .find(|a| Some(a.call_id.as_str()) == call_id)
+                {
+                    apply_after(action, &event);
+                }
+            }
+            Some("screencast-frame") => {
+                if let Some(sha1) = event.get("sha1").and_then(Value::as_str) {
+                    let timestamp = event
+                        .get("timestamp")
+                        .and_then(Value::as_f64)
+                        .unwrap_or_default();
+                    frames.push((sha1.to_string(), timestamp));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut screenshots = Vec::with_capacity(frames.len());
+    for (sha1, timestamp) in frames {
+        screenshots.push(TraceScreenshot {
+            name: None,
+            timestamp,
+            data: read_file(zip, &format!("resources/{sha1}"))?,
+        });
+    }
+
+    let network_entries = if zip.index_for_name("trace.network").is_some() {
+        read_jsonl(zip, "trace.network")?
+            .into_iter()
+            .filter(|event| event.get("type").and_then(Value::as_str) == Some("resource-snapshot"))
+            .filter_map(|mut event| {
+                serde_json::from_value::<HarEntry>(event["snapshot"].take()).ok()
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let source_names: Vec<String> = zip
+        .file_names()
+        .filter_map(|name| name.strip_prefix("resources/"))
+        .filter(|name| name.starts_with("src@"))
+        .map(ToString::to_string)
+        .collect();
+    let mut sources = Vec::with_capacity(source_names.len());
+    for name in source_names {
+        sources.push(TraceSource {
+            content: read_string(zip, &format!("resources/{name}"))?,
+            path: name,
+        });
+    }
+
+    Ok(TraceArchive {
+        format: TraceFormat::Playwright,
+        name: None,
+        title,
+        actions,
+        network_entries,
+        screenshots,
+        sources,
+    })
+}
+
+/// Build an action from a Playwright `before` event.
+fn action_from_before(event: &Value) -> ActionEntry {
+    let call_id = event
+        .get("callId")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let method = event
+        .get("method")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let start_time = event
+        .get("startTime")
+        .and_then(Value::as_f64)
+        .unwrap_or_default();
+
+    let mut action = ActionEntry::new(call_id.to_string(), method, start_time);
+    action.page_id = event
+        .get("pageId")
+        .and_then(Value::as_str)
+        .map(|id| id.strip_prefix("page@").unwrap_or(id).to_string());
+
+    if let Some(Value::Object(params)) = event.get("params") {
+        let mut params = params.clone();
+        action.selector = params
+            .remove("selector")
+            .and_then(|s| s.as_str().map(ToString::to_string));
+        action.url = params
+            .get("url")
+            .and_then(Value::as_str)
+            .map(ToString::to_string);
+        action.value = params
+            .get("value")
+            .and_then(Value::as_str)
+            .map(ToString::to_string);
+        if !params.is_empty() {
+            action.params = Some(Value::Object(params));
+        }
+    }
+
+    action
+}
+
+/// Apply a Playwright `after` event to its action.
+fn apply_after(action: &mut ActionEntry, event: &Value) {
+    action.end_time = event.get("endTime").and_then(Value::as_f64);
+    action.error = event
+        .get("error")
+        .and_then(|e| e.get("message"))
+        .and_then(Value::as_str)
+        .map(ToString::to_string);
+    if let Some(error) = &action.error {
+        action.result = Some(serde_json::json!({ "error": error }));
+    }
+}
+
+/// Read a JSONL file, skipping blank lines.
+fn read_jsonl<R: Read + Seek>(
+    zip: &mut zip::ZipArchive<R>,
+    name: &str,
+) -> Result<Vec<Value>, ContextError> {
+    read_string(zip, name)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line)
+                .map_err(|e| ContextError::Internal(format!("Failed to parse {name}: {e}")))
+        })
+        .collect()
+}
+
+/// Read a file from the archive as UTF-8 text.
+fn read_string<R: Read + Seek>(
+    zip: &mut zip::ZipArchive<R>,
+    name: &str,
+) -> Result<String, ContextError> {
+    String::from_utf8(read_file(zip, name)?)
+        .map_err(|e| ContextError::Internal(format!("{name} is not valid UTF-8: {e}")))
+}
+
+/// Read a file from the archive.
+fn read_file<R: Read + Seek>(
+    zip: &mut zip::ZipArchive<R>,
+    name: &str,
+) -> Result<Vec<u8>, ContextError> {
+    let mut file = zip
+        .by_name(name)
+        .map_err(|e| ContextError::Internal(format!("Failed to read {name} from trace: {e}")))?;
+    let mut data = Vec::new();
+    file.read_to_end(&mut data)
+        .map_err(|e| ContextError::Internal(format!("Failed to read {name} from trace: {e}")))?;
+    Ok(data)
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use base64::Engine;
+use chrono::{DateTime, Utc};
+
+use super::*;
+use crate::context::trace::types::{
+    NetworkEntryState, PendingRequest, ScreenshotEntry, SourceFileEntry, TracingOptions,
+    TracingState,
+};
+use crate::context::trace::writer::write_trace_zip;
+
+fn network_entry(url: &str, wall_time_ms: f64) -> NetworkEntryState {
+    NetworkEntryState {
+        request: PendingRequest {
+            request_id: url.to_string(),
+            url: url.to_string(),
+            method: "GET".to_string(),
+            headers: HashMap::new(),
+            post_data: None,
+            resource_type: "Document".to_string(),
+            started_at: DateTime::<Utc>::default(),
+            wall_time: wall_time_ms / 1000.0,
+        },
+        status: 200,
+        status_text: "OK".to_string(),
+        response_headers: HashMap::new(),
+        mime_type: "text/html".to_string(),
+        timing: None,
+        server_ip: None,
+        failed: false,
+        error_text: None,
+        encoded_data_length: None,
+    }
+}
+
+fn screenshot(bytes: &[u8], timestamp: f64) -> ScreenshotEntry {
+    ScreenshotEntry {
+        data: base64::engine::general_purpose::STANDARD.encode(bytes),
+        timestamp,
+        name: Some("click".to_string()),
+    }
+}
+
+fn recorded_state(format: TraceFormat) -> TracingState {
+    let mut state = TracingState {
+        options: TracingOptions::new().title("checkout").format(format),
+        started_at: 1_000_000.0,
+        ..Default::default()
+    };
+
+    let mut goto = ActionEntry::new("call@0".to_string(), "goto", 1_000_100.0);
+    goto.page_id = Some("TARGET1".to_string());
+    goto.params = Some(serde_json::json!({ "url": "https://example.com/" }));
+    goto.end_time = Some(1_000_500.0);
+
+    let mut click = ActionEntry::new("call@1".to_string(), "click", 1_000_600.0);
+    click.selector = Some("css=#buy".to_string());
+    click.page_id = Some("TARGET1".to_string());
+    click.end_time = Some(1_000_900.0);
+    click.error = Some("element not found".to_string());
+    click.after_screenshot = Some(1);
+
+    state.actions = vec![goto, click];
+    state.network_entries = vec![
+        network_entry("https://example.com/", 1_000_200.0),
+        network_entry("https://example.com/buy", 1_000_700.0),
+    ];
+    state.screenshots = vec![
+        screenshot(b"first", 1_000_400.0),
+        screenshot(b"second", 1_000_850.0),
+    ];
+    state.source_files.push(SourceFileEntry {
+        path: "tests/checkout.rs".to_string(),
+        content: "// checkout test".to_string(),
+    });
+    state
+}
+
+fn round_trip(format: TraceFormat) -> TraceArchive {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("trace.zip");
+    write_trace_zip(&path, &recorded_state(format)).unwrap();
+    TraceArchive::open(&path).unwrap()
+}
+
+#[test]
+fn test_read_viewpoint_format() {
+    let trace = round_trip(TraceFormat::Viewpoint);
+
+    assert_eq!(trace.format(), TraceFormat::Viewpoint);
+    assert_eq!(trace.title(), Some("checkout"));
+    assert_eq!(trace.actions().len(), 2);
+    assert_eq!(trace.network_entries().len(), 2);
+    assert_eq!(trace.screenshots()[1].data, b"second");
+    assert_eq!(trace.sources()[0].path, "tests/checkout.rs");
+    assert_eq!(trace.sources()[0].content, "// checkout test");
+}
+
+#[test]
+fn test_read_playwright_format() {
+    let trace = round_trip(TraceFormat::Playwright);
+
+    assert_eq!(trace.format(), TraceFormat::Playwright);
+    assert_eq!(trace.title(), Some("checkout"));
+
+    let goto = trace.action("call@0").unwrap();
+    assert_eq!(goto.action_type, "goto");
+    assert_eq!(goto.url.as_deref(), Some("https://example.com/"));
+    assert_eq!(goto.page_id.as_deref(), Some("TARGET1"));
+
+    let click = trace.action("call@1").unwrap();
+    assert_eq!(click.selector.as_deref(), Some("css=#buy"));
+    assert_eq!(click.duration_ms(), Some(300.0));
+
+    assert_eq!(trace.network_entries().len(), 2);
+    assert_eq!(trace.screenshots().len(), 2);
+    assert_eq!(trace.sources()[0].content, "// checkout test");
+}
+
+#[test]
+fn test_failed_actions() {
+    for format in [TraceFormat::Viewpoint, TraceFormat::Playwright] {
+        let trace = round_trip(format);
+        let failed = trace.failed_actions();
+
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].call_id, "call@1");
+        assert_eq!(failed[0].error.as_deref(), Some("element not found"));
+    }
+}
+
+#[test]
+fn test_requests_during_action() {
+    for format in [TraceFormat::Viewpoint, TraceFormat::Playwright] {
+        let trace = round_trip(format);
+
+        let goto_requests = trace.requests_during_action(0);
+        assert_eq!(goto_requests.len(), 1);
+        assert_eq!(goto_requests[0].request.url, "https://example.com/");
+
+        let click_requests = trace.requests_during_action(1);
+        assert_eq!(click_requests.len(), 1);
+        assert_eq!(click_requests[0].request.url, "https://example.com/buy");
+
+        assert!(trace.requests_during_action(5).is_empty());
+    }
+}
+
+#[test]
+fn test_screenshot_nearest() {
+    let trace = round_trip(TraceFormat::Viewpoint);
+
+    assert_eq!(
+        trace.screenshot_nearest(1_000_000.0).unwrap().data,
+        b"first"
+    );
+    assert_eq!(
+        trace.screenshot_nearest(1_000_800.0).unwrap().data,
+        b"second"
+    );
+}
+
+#[test]
+fn test_open_rejects_non_trace_archive() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("empty.zip");
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+    zip.start_file("readme.txt", zip::write::SimpleFileOptions::default())
+        .unwrap();
+    zip.finish().unwrap();
+
+    assert!(TraceArchive::open(&path).is_err());
+    assert!(TraceArchive::open(dir.path().join("missing.zip")).is_err());
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use super::types::{ActionEntry, TracingState};
+use super::*;
+
+fn state_with_action(action_type: &str) -> (Arc<RwLock<TracingState>>, ActionHandle) {
+    let mut state = TracingState::default();
+    let call_id = state.next_call_id();
+    state
+        .actions
+        .push(ActionEntry::new(call_id.clone(), action_type, 1000.0));
+    let state = Arc::new(RwLock::new(state));
+    let handle = ActionHandle::new(state.clone(), 0, call_id);
+    (state, handle)
+}
+
+#[test]
+fn test_call_ids_are_unique() {
+    let mut state = TracingState::default();
+    assert_eq!(state.next_call_id(), "call@0");
+    assert_eq!(state.next_call_id(), "call@1");
+    assert_eq!(state.next_call_id(), "call@2");
+}
+
+#[test]
+fn test_action_entry_duration() {
+    let mut action = ActionEntry::new("call@0".to_string(), "click", 1000.0);
+    assert_eq!(action.duration_ms(), None);
+
+    action.end_time = Some(1250.0);
+    assert_eq!(action.duration_ms(), Some(250.0));
+    assert!(!action.is_failed());
+}
+
+#[test]
+fn test_action_entry_deserializes_without_new_fields() {
+    let json = r#"{"actionType":"click","selector":"button","startTime":1.0,"endTime":2.0}"#;
+    let action: ActionEntry = serde_json::from_str(json).unwrap();
+
+    assert_eq!(action.action_type, "click");
+    assert_eq!(action.call_id, "");
+    assert!(action.params.is_none());
+    assert!(action.error.is_none());
+    assert!(action.before_screenshot.is_none());
+}
+
+#[test]
+fn test_action_entry_serialization_skips_empty_fields() {
+    let action = ActionEntry::new("call@0".to_string(), "fill", 1.0);
+    let json = serde_json::to_value(&action).unwrap();
+
+    assert_eq!(json["callId"], "call@0");
+    assert_eq!(json["actionType"], "fill");
+    assert!(json.get("error").is_none());
+    assert!(json.get("beforeScreenshot").is_none());
+}
+
+#[tokio::test]
+async fn test_action_handle_complete() {
+    let (state, handle) = state_with_action("click");
+
+    handle.complete(None).await;
+
+    let state = state.read().await;
+    let action = &state.actions[0];
+    assert!(action.end_time.is_some());
+    assert!(!action.is_failed());
+}
+
+#[tokio::test]
+async fn test_action_handle_fail_records_error() {
+    let (state, handle) = state_with_action("fill");
+
+    handle.fail("element not found").await;
+
+    let state = state.read().await;
+    let action = &state.actions[0];
+    assert!(action.end_time.is_some());
+    assert_eq!(action.error.as_deref(), Some("element not found"));
+    assert_eq!(
+        action.result,
+        Some(serde_json::json!({ "error": "element not found" }))
+    );
+}
+
+#[tokio::test]
+async fn test_stale_action_handle_is_ignored() {
+    let (state, handle) = state_with_action("click");
+
+    // Simulate the trace being restarted while the action was running.
+    {
+        let mut state = state.write().await;
+        state.actions.clear();
+        let call_id = state.next_call_id();
+        state
+            .actions
+            .push(ActionEntry::new(call_id, "goto", 2000.0));
+    }
+
+    handle.fail("page closed").await;
+
+    let state = state.read().await;
+    assert!(state.actions[0].end_time.is_none());
+    assert!(state.actions[0].error.is_none());
+}
@@ -17,7 +17,7 @@ use super::action_handle::ActionHandle;
 use super::capture;
 use super::network;
 use super::sources;
-use super::types::{ActionEntry, SourceFileEntry, TracingOptions, TracingState};
+use super::types::{ActionEntry, SourceFileEntry, TracingOptions, TracingState, now_ms};
 use super::writer;
 
 /// Tracing manager for recording test execution traces.
@@ -25,6 +25,11 @@ use super::writer;
 /// Traces record screenshots, DOM snapshots, network activity, and action
 /// history. They can be viewed using Playwright's Trace Viewer.
 ///
+/// Locator and page actions (click, fill, goto, press, ...) performed on any
+/// page in the context are recorded automatically while tracing is active,
+/// including their parameters, duration and error. When screenshots or
+/// snapshots are enabled they are captured before and after each action.
+///
 /// **Note:** At least one page must exist in the context before starting tracing.
 /// The tracing state is shared across all `context.tracing()` calls within the
 /// same context, so you can call `start()` and `stop()` from separate `tracing()`
@@ -196,6 +201,7 @@ impl Tracing {
         // Initialize state
         state.is_recording = true;
         state.options = options;
+        state.started_at = now_ms();
         state.actions.clear();
         state.events.clear();
         state.screenshots.clear();
@@ -218,11 +224,16 @@ impl Tracing {
 
     /// Stop tracing and save the trace to a file.
     ///
-    /// The trace is saved as a zip file containing:
+    /// With the default [`TraceFormat::Viewpoint`](super::TraceFormat::Viewpoint) the trace is saved as a zip
+    /// file containing:
     /// - trace.json: The trace data
     /// - resources/: Screenshots and other resources
     /// - network.har: Network activity in HAR format
     ///
+    /// With [`TraceFormat::Playwright`](super::TraceFormat::Playwright) the archive uses Playwright's
+    /// `trace.trace`/`trace.network` layout instead, so it can be opened with
+    /// `npx playwright show-trace`.
+    ///
     /// # Errors
     ///
     /// Returns an error if tracing is not active or saving the trace fails.
@@ -420,30 +431,15 @@ impl Tracing {
         selector: Option<&str>,
         page_id: Option<&str>,
     ) -> ActionHandle {
-        let start_time = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs_f64()
-            * 1000.0;
-
-        let action = ActionEntry {
-            action_type: action_type.to_string(),
-            selector: selector.map(ToString::to_string),
-            page_id: page_id.map(ToString::to_string),
-            start_time,
-            end_time: None,
-            result: None,
-            value: None,
-            url: None,
-            screenshot: None,
-            snapshot: None,
-        };
-
         let mut state = self.state.write().await;
         let index = state.actions.len();
+        let call_id = state.next_call_id();
+        let mut action = ActionEntry::new(call_id.clone(), action_type, now_ms());
+        action.selector = selector.map(ToString::to_string);
+        action.page_id = page_id.map(ToString::to_string);
         state.actions.push(action);
 
-        ActionHandle::new(self.state.clone(), index)
+        ActionHandle::new(self.state.clone(), index, call_id)
     }
 
     /// Record a page being created.
@@ -461,12 +457,14 @@ impl Tracing {
         session_id: &str,
         name: Option<&str>,
     ) -> Result<(), ContextError> {
-        capture::capture_screenshot(&self.connection, &self.state, session_id, name).await
+        capture::capture_screenshot(&self.connection, &self.state, session_id, name).await?;
+        Ok(())
     }
 
     /// Capture a DOM snapshot and add it to the trace.
     pub(crate) async fn capture_dom_snapshot(&self, session_id: &str) -> Result<(), ContextError> {
-        capture::capture_dom_snapshot(&self.connection, &self.state, session_id).await
+        capture::capture_dom_snapshot(&self.connection, &self.state, session_id).await?;
+        Ok(())
     }
 
     /// Capture action context (screenshot + snapshot) if enabled.
@@ -476,6 +474,7 @@ impl Tracing {
         action_name: Option<&str>,
     ) -> Result<(), ContextError> {
         capture::capture_action_context(&self.connection, &self.state, session_id, action_name)
-            .await
+            .await;
+        Ok(())
     }
 }
@@ -9,6 +9,26 @@ use serde::{Deserialize, Serialize};
 
 use crate::network::har::HarTimings;
 
+/// Current wall-clock time in milliseconds since the Unix epoch.
+pub(super) fn now_ms() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
+        * 1000.0
+}
+
+/// On-disk layout of a saved trace archive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// Viewpoint's own layout: `trace.json`, `network.har`, `resources/` and `sources/`.
+    #[default]
+    Viewpoint,
+    /// Playwright's layout: `trace.trace` and `trace.network` event streams with
+    /// `resources/` keyed by SHA-1, loadable by the Playwright Trace Viewer.
+    Playwright,
+}
+
 /// Options for starting a trace.
 #[derive(Debug, Clone, Default)]
 pub struct TracingOptions {
@@ -22,6 +42,8 @@ pub struct TracingOptions {
     pub sources: bool,
     /// Title to display in Trace Viewer.
     pub title: Option<String>,
+    /// Format the trace archive is written in.
+    pub format: TraceFormat,
 }
 
 impl TracingOptions {
@@ -64,6 +86,13 @@ impl TracingOptions {
         self.title = Some(title.into());
         self
     }
+
+    /// Set the format the trace archive is written in.
+    #[must_use]
+    pub fn format(mut self, format: TraceFormat) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 /// A source file entry in the trace.
@@ -103,6 +132,22 @@ pub(crate) struct TracingState {
     pub(super) current_page_id: Option<String>,
     /// Source files to include.
     pub(super) source_files: Vec<SourceFileEntry>,
+    /// Counter for assigning call IDs to actions.
+    ///
+    /// Not reset between traces so a handle from a previous trace can never
+    /// complete an action in the current one.
+    pub(super) call_counter: usize,
+    /// Wall-clock time the current trace was started (ms since epoch).
+    pub(super) started_at: f64,
+}
+
+impl TracingState {
+    /// Allocate the next action call ID.
+    pub(super) fn next_call_id(&mut self) -> String {
+        let id = format!("call@{}", self.call_counter);
+        self.call_counter += 1;
+        id
+    }
 }
 
 /// A pending network request.
@@ -166,6 +211,9 @@ pub(super) struct ScreenshotEntry {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActionEntry {
+    /// Unique call ID within the trace (e.g., "call@3").
+    #[serde(default)]
+    pub call_id: String,
     /// Action type (e.g., "click", "fill", "navigate").
     pub action_type: String,
     /// Target selector or description.
@@ -182,6 +230,12 @@ pub struct ActionEntry {
     /// Action result.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<serde_json::Value>,
+    /// Parameters the action was invoked with (e.g., click position, typed text).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub params: Option<serde_json::Value>,
+    /// Error message if the action failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
     /// Input value for fill actions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
@@ -194,10 +248,57 @@ pub struct ActionEntry {
     /// Associated snapshot index.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snapshot: Option<usize>,
+    /// Screenshot index captured before the action ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before_screenshot: Option<usize>,
+    /// Screenshot index captured after the action finished.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after_screenshot: Option<usize>,
+    /// DOM snapshot index captured before the action ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before_snapshot: Option<usize>,
+    /// DOM snapshot index captured after the action finished.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after_snapshot: Option<usize>,
+}
+
+impl ActionEntry {
+    /// Create a new in-progress action entry starting at `start_time`.
+    pub(crate) fn new(call_id: String, action_type: &str, start_time: f64) -> Self {
+        Self {
+            call_id,
+            action_type: action_type.to_string(),
+            selector: None,
+            page_id: None,
+            start_time,
+            end_time: None,
+            result: None,
+            params: None,
+            error: None,
+            value: None,
+            url: None,
+            screenshot: None,
+            snapshot: None,
+            before_screenshot: None,
+            after_screenshot: None,
+            before_snapshot: None,
+            after_snapshot: None,
+        }
+    }
+
+    /// Duration of the action in milliseconds, if it has finished.
+    pub fn duration_ms(&self) -> Option<f64> {
+        self.end_time.map(|end| end - self.start_time)
+    }
+
+    /// Whether the action finished with an error.
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some()
+    }
 }
 
 /// Trace file structure.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(super) struct TraceFile {
     pub version: String,
@@ -205,15 +306,18 @@ pub(super) struct TraceFile {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(default)]
     pub actions: Vec<ActionEntry>,
+    #[serde(default)]
     pub events: Vec<serde_json::Value>,
+    #[serde(default)]
     pub resources: Vec<ResourceEntry>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
 }
 
 /// Resource entry in the trace.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(super) struct ResourceEntry {
     pub name: String,
@@ -7,16 +7,25 @@ use chrono::DateTime;
 use crate::error::ContextError;
 use crate::network::har::{Har, HarEntry, HarRequest, HarResponse};
 
-use super::types::{ResourceEntry, TraceFile, TracingState};
+use super::playwright;
+use super::types::{ResourceEntry, TraceFile, TraceFormat, TracingState};
 
-/// Write a trace to a zip file.
+/// Write a trace to a zip file in the format selected by the tracing options.
+pub fn write_trace_zip(path: &std::path::Path, state: &TracingState) -> Result<(), ContextError> {
+    match state.options.format {
+        TraceFormat::Viewpoint => write_viewpoint_zip(path, state),
+        TraceFormat::Playwright => playwright::write_trace_zip(path, state),
+    }
+}
+
+/// Write a trace to a zip file in Viewpoint's own format.
 ///
 /// Creates a zip archive containing:
 /// - trace.json: The trace data
 /// - network.har: Network activity in HAR format
 /// - resources/: Screenshots and snapshots
 /// - sources/: Source files
-pub fn write_trace_zip(path: &std::path::Path, state: &TracingState) -> Result<(), ContextError> {
+fn write_viewpoint_zip(path: &std::path::Path, state: &TracingState) -> Result<(), ContextError> {
     use std::fs::File;
 
     // Create the output file
@@ -188,7 +197,7 @@ fn build_har(state: &TracingState) -> Result<String, ContextError> {
 }
 
 /// Build a single HAR entry from network state.
-fn build_har_entry(
+pub(super) fn build_har_entry(
     entry_state: &super::types::NetworkEntryState,
     current_page_id: Option<&String>,
 ) -> HarEntry {
@@ -14,6 +14,7 @@ use super::{
 
 /// Options for creating a browser context.
 #[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)] // Independent on/off switches mirroring browser settings
 pub struct ContextOptions {
     /// Storage state to restore.
     pub storage_state: Option<StorageStateSource>,
@@ -55,6 +56,14 @@ pub struct ContextOptions {
     pub record_video: Option<crate::page::VideoOptions>,
     /// Proxy configuration for network requests.
     pub proxy: Option<ProxyConfig>,
+    /// Whether to ignore HTTPS certificate errors.
+    pub ignore_https_errors: bool,
+    /// Whether to bypass the page's Content-Security-Policy.
+    pub bypass_csp: bool,
+    /// Whether JavaScript is enabled. Defaults to enabled.
+    pub java_script_enabled: Option<bool>,
+    /// Base URL for resolving relative URLs in navigation and URL patterns.
+    pub base_url: Option<String>,
 }
 
 /// Source for storage state.
@@ -340,6 +349,34 @@ impl ContextOptionsBuilder {
         self
     }
 
+    /// Ignore HTTPS certificate errors, e.g. for self-signed certificates.
+    #[must_use]
+    pub fn ignore_https_errors(mut self, ignore: bool) -> Self {
+        self.options.ignore_https_errors = ignore;
+        self
+    }
+
+    /// Bypass the page's Content-Security-Policy.
+    #[must_use]
+    pub fn bypass_csp(mut self, bypass: bool) -> Self {
+        self.options.bypass_csp = bypass;
+        self
+    }
+
+    /// Enable or disable JavaScript.
+    #[must_use]
+    pub fn java_script_enabled(mut self, enabled: bool) -> Self {
+        self.options.java_script_enabled = Some(enabled);
+        self
+    }
+
+    /// Set the base URL for resolving relative URLs.
+    #[must_use]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.options.base_url = Some(base_url.into());
+        self
+    }
+
     /// Build the options.
     pub fn build(self) -> ContextOptions {
         self.options
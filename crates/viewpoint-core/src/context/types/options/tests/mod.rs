@@ -53,3 +53,32 @@ fn test_context_options_builder_with_proxy_credentials() {
     assert_eq!(proxy.password, Some("password".to_string()));
     assert_eq!(proxy.bypass, Some("localhost,127.0.0.1".to_string()));
 }
+
+#[test]
+fn test_context_options_builder_security_options() {
+    let options = ContextOptionsBuilder::new().build();
+    assert!(!options.ignore_https_errors);
+    assert!(!options.bypass_csp);
+    assert!(options.java_script_enabled.is_none());
+
+    let options = ContextOptionsBuilder::new()
+        .ignore_https_errors(true)
+        .bypass_csp(true)
+        .java_script_enabled(false)
+        .build();
+    assert!(options.ignore_https_errors);
+    assert!(options.bypass_csp);
+    assert_eq!(options.java_script_enabled, Some(false));
+}
+
+#[test]
+fn test_context_options_builder_base_url() {
+    let options = ContextOptionsBuilder::new()
+        .base_url("https://staging.example.com")
+        .build();
+
+    assert_eq!(
+        options.base_url.as_deref(),
+        Some("https://staging.example.com")
+    );
+}
@@ -9,14 +9,14 @@ use super::cookies::Cookie;
 
 /// Browser storage state.
 ///
-/// Contains cookies and localStorage data for persistence across test runs.
+/// Contains cookies and per-origin web storage data for persistence across test runs.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct StorageState {
     /// Cookies.
     #[serde(default)]
     pub cookies: Vec<Cookie>,
-    /// Origins with localStorage data.
+    /// Origins with localStorage, sessionStorage and `IndexedDB` data.
     #[serde(default)]
     pub origins: Vec<StorageOrigin>,
 }
@@ -70,19 +70,94 @@ impl StorageState {
     /// # Ok(())
     /// # }
     pub fn to_local_storage_init_script(&self) -> String {
-        if self.origins.is_empty() {
+        let storage_data = self.origin_entries_js(|origin| &origin.local_storage);
+        if storage_data.is_empty() {
             return String::new();
         }
 
-        // Build a map of origin -> [(key, value), ...]
+        // Generate JavaScript that restores localStorage for the current origin
+        js! {
+            (function() {
+                const storageData = new Map([@{storage_data}]);
+                const currentOrigin = window.location.origin;
+                const entries = storageData.get(currentOrigin);
+                if (entries) {
+                    for (const [key, value] of entries) {
+                        try {
+                            localStorage.setItem(key, value);
+                        } catch (e) {
+                            console.warn("Failed to restore localStorage item:", key, e);
+                        }
+                    }
+                }
+            })()
+        }
+    }
+
+    /// Generate a JavaScript init script to restore sessionStorage for all origins.
+    ///
+    /// sessionStorage is scoped to a single tab and outlives reloads, so the
+    /// entries are only restored by the first document of an origin in a tab.
+    /// Reloads, history traversals and later same-origin navigations keep
+    /// whatever the page has changed or removed since. Entries already present,
+    /// e.g. copied from the opener of a popup, are not overwritten, and nothing
+    /// but the restored entries is written to the page's storage.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::{Browser, StorageState};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let browser = Browser::launch().headless(true).launch().await?;
+    /// # let context = browser.new_context().await?;
+    /// let state = StorageState::load("state.json").await?;
+    /// let script = state.to_session_storage_init_script();
+    /// context.add_init_script(&script).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_session_storage_init_script(&self) -> String {
+        let storage_data = self.origin_entries_js(|origin| &origin.session_storage);
+        if storage_data.is_empty() {
+            return String::new();
+        }
+
+        js! {
+            (function() {
+                const storageData = new Map([@{storage_data}]);
+                const entries = storageData.get(window.location.origin);
+                if (!entries) return;
+
+                // Only restore on the first visit to the origin in this tab
+                const timing = performance.getEntriesByType("navigation")[0];
+                if (timing && timing.type !== "navigate") return;
+                const nav = window.navigation;
+                if (nav && nav.currentEntry && nav.currentEntry.index > 0) return;
+
+                for (const [key, value] of entries) {
+                    try {
+                        if (sessionStorage.getItem(key) !== null) continue;
+                        sessionStorage.setItem(key, value);
+                    } catch (e) {
+                        console.warn("Failed to restore sessionStorage item:", key, e);
+                    }
+                }
+            })()
+        }
+    }
+
+    /// Build `[origin, [[key, value], ...]]` pairs for the entries selected
+    /// from each origin, skipping origins without entries.
+    fn origin_entries_js(&self, select: impl Fn(&StorageOrigin) -> &[LocalStorageEntry]) -> String {
         let mut origin_data = Vec::new();
         for origin in &self.origins {
-            if origin.local_storage.is_empty() {
+            let entries = select(origin);
+            if entries.is_empty() {
                 continue;
             }
 
-            let entries: Vec<String> = origin
-                .local_storage
+            let entries: Vec<String> = entries
                 .iter()
                 .map(|entry| {
                     format!(
@@ -100,28 +175,7 @@ impl StorageState {
             ));
         }
 
-        if origin_data.is_empty() {
-            return String::new();
-        }
-
-        // Generate JavaScript that restores localStorage for the current origin
-        let storage_data = origin_data.join(",");
-        js! {
-            (function() {
-                const storageData = new Map([@{storage_data}]);
-                const currentOrigin = window.location.origin;
-                const entries = storageData.get(currentOrigin);
-                if (entries) {
-                    for (const [key, value] of entries) {
-                        try {
-                            localStorage.setItem(key, value);
-                        } catch (e) {
-                            console.warn("Failed to restore localStorage item:", key, e);
-                        }
-                    }
-                }
-            })()
-        }
+        origin_data.join(",")
     }
 
     /// Generate a JavaScript init script to restore `IndexedDB` for all origins.
@@ -266,7 +320,7 @@ impl StorageState {
     }
 }
 
-/// Origin with localStorage data.
+/// Origin with localStorage, sessionStorage and `IndexedDB` data.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StorageOrigin {
@@ -275,6 +329,9 @@ pub struct StorageOrigin {
     /// localStorage entries.
     #[serde(default)]
     pub local_storage: Vec<LocalStorageEntry>,
+    /// sessionStorage entries (optional).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub session_storage: Vec<LocalStorageEntry>,
     /// `IndexedDB` databases (optional).
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub indexed_db: Vec<IndexedDbDatabase>,
@@ -286,6 +343,7 @@ impl StorageOrigin {
         Self {
             origin: origin.into(),
             local_storage: Vec::new(),
+            session_storage: Vec::new(),
             indexed_db: Vec::new(),
         }
     }
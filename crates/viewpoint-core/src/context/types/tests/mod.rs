@@ -45,3 +45,68 @@ fn test_geolocation() {
         assert_eq!(geo_accurate.accuracy, 100.0);
     }
 }
+
+#[test]
+fn test_storage_origin_without_session_storage_deserializes() {
+    let json = r#"{
+        "cookies": [],
+        "origins": [
+            {
+                "origin": "https://example.com",
+                "localStorage": [{ "name": "theme", "value": "dark" }]
+            }
+        ]
+    }"#;
+
+    let state: StorageState = serde_json::from_str(json).unwrap();
+    assert_eq!(state.origins.len(), 1);
+    assert_eq!(state.origins[0].local_storage.len(), 1);
+    assert!(state.origins[0].session_storage.is_empty());
+}
+
+#[test]
+fn test_storage_origin_session_storage_serialization() {
+    let mut origin = StorageOrigin::new("https://example.com");
+    let json = serde_json::to_string(&origin).unwrap();
+    assert!(!json.contains("sessionStorage"));
+
+    origin
+        .session_storage
+        .push(LocalStorageEntry::new("sso_token", "abc123"));
+    let json = serde_json::to_string(&origin).unwrap();
+    assert!(json.contains("\"sessionStorage\":[{\"name\":\"sso_token\",\"value\":\"abc123\"}]"));
+
+    let parsed: StorageOrigin = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.session_storage.len(), 1);
+    assert_eq!(parsed.session_storage[0].name, "sso_token");
+}
+
+#[test]
+fn test_session_storage_init_script() {
+    let mut state = StorageState::new();
+    assert!(state.to_session_storage_init_script().is_empty());
+
+    let mut origin = StorageOrigin::new("https://example.com");
+    origin
+        .local_storage
+        .push(LocalStorageEntry::new("theme", "dark"));
+    state.origins.push(origin.clone());
+    assert!(state.to_session_storage_init_script().is_empty());
+
+    origin
+        .session_storage
+        .push(LocalStorageEntry::new("sso_token", "abc123"));
+    state.origins = vec![origin];
+
+    let script = state.to_session_storage_init_script();
+    assert!(script.contains("sessionStorage.setItem"));
+    assert!(script.contains("\"navigate\""));
+    assert!(script.contains("currentEntry.index"));
+    assert!(script.contains(r#"["https://example.com",[["sso_token","abc123"]]]"#));
+    assert!(!script.contains("theme"));
+    assert!(!script.contains("__viewpoint"));
+
+    let local_script = state.to_local_storage_init_script();
+    assert!(local_script.contains(r#"[["theme","dark"]]"#));
+    assert!(!local_script.contains("sso_token"));
+}
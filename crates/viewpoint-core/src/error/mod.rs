@@ -3,6 +3,8 @@
 use std::time::Duration;
 use thiserror::Error;
 
+use crate::browser::DisconnectReason;
+
 /// Errors that can occur in the core domain.
 #[derive(Error, Debug)]
 pub enum CoreError {
@@ -74,6 +76,18 @@ pub enum BrowserError {
     #[error("browser is closed")]
     Closed,
 
+    /// The browser process exited or the connection to it was lost.
+    #[error("browser disconnected: {0}")]
+    Disconnected(DisconnectReason),
+
+    /// Browser-level tracing failed.
+    #[error("tracing error: {0}")]
+    Tracing(String),
+
+    /// A selector engine could not be registered.
+    #[error("invalid selector engine: {0}")]
+    InvalidSelectorEngine(String),
+
     /// Context error during browser operation.
     #[error("context error: {0}")]
     Context(#[from] ContextError),
@@ -98,6 +112,10 @@ pub enum ContextError {
     #[error("internal error: {0}")]
     Internal(String),
 
+    /// A selector engine could not be registered.
+    #[error("invalid selector engine: {0}")]
+    InvalidSelectorEngine(String),
+
     /// Operation timed out.
     #[error("{operation} timed out after {duration:?}")]
     Timeout {
@@ -127,9 +145,35 @@ pub enum PageError {
     #[error("evaluation failed: {0}")]
     EvaluationFailed(String),
 
+    /// An argument passed to a page operation is invalid.
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+
+    /// Waiting for a page event timed out.
+    #[error("timeout after {0:?}")]
+    Timeout(Duration),
+
+    /// Browser events were dropped while waiting for a page event, so it
+    /// may have been missed.
+    #[error("missed {0} browser events while waiting")]
+    EventsMissed(u64),
+
+    /// The page's renderer process crashed.
+    #[error("page crashed")]
+    Crashed,
+
     /// CDP error during page operation.
     #[error("CDP error: {0}")]
-    Cdp(#[from] viewpoint_cdp::CdpError),
+    Cdp(viewpoint_cdp::CdpError),
+}
+
+impl From<viewpoint_cdp::CdpError> for PageError {
+    fn from(err: viewpoint_cdp::CdpError) -> Self {
+        match err {
+            viewpoint_cdp::CdpError::TargetCrashed(_) => Self::Crashed,
+            other => Self::Cdp(other),
+        }
+    }
 }
 
 /// Errors related to wait operations.
@@ -146,6 +190,10 @@ pub enum WaitError {
     /// Page was closed during wait.
     #[error("page closed during wait")]
     PageClosed,
+
+    /// Page crashed during wait.
+    #[error("page crashed during wait")]
+    PageCrashed,
 }
 
 /// Errors related to navigation operations.
@@ -167,13 +215,35 @@ pub enum NavigationError {
     #[error("navigation cancelled")]
     Cancelled,
 
+    /// The page crashed during navigation.
+    #[error("page crashed during navigation")]
+    PageCrashed,
+
     /// CDP error during navigation.
     #[error("CDP error: {0}")]
-    Cdp(#[from] viewpoint_cdp::CdpError),
+    Cdp(viewpoint_cdp::CdpError),
 
     /// Wait error during navigation.
     #[error("wait error: {0}")]
-    Wait(#[from] WaitError),
+    Wait(WaitError),
+}
+
+impl From<viewpoint_cdp::CdpError> for NavigationError {
+    fn from(err: viewpoint_cdp::CdpError) -> Self {
+        match err {
+            viewpoint_cdp::CdpError::TargetCrashed(_) => Self::PageCrashed,
+            other => Self::Cdp(other),
+        }
+    }
+}
+
+impl From<WaitError> for NavigationError {
+    fn from(err: WaitError) -> Self {
+        match err {
+            WaitError::PageCrashed => Self::PageCrashed,
+            other => Self::Wait(other),
+        }
+    }
 }
 
 /// Errors related to locator operations.
@@ -209,12 +279,16 @@ pub enum LocatorError {
 
     /// CDP error during locator operation.
     #[error("CDP error: {0}")]
-    Cdp(#[from] viewpoint_cdp::CdpError),
+    Cdp(viewpoint_cdp::CdpError),
 
     /// Page is closed.
     #[error("page is closed")]
     PageClosed,
 
+    /// Page crashed.
+    #[error("page crashed")]
+    PageCrashed,
+
     /// Touch not enabled.
     #[error(
         "touch not enabled: call page.enable_touch() or set hasTouch: true in browser context options before using touch actions"
@@ -223,7 +297,25 @@ pub enum LocatorError {
 
     /// Wait error during action (e.g., navigation timeout after click).
     #[error("wait error: {0}")]
-    WaitError(#[from] WaitError),
+    WaitError(WaitError),
+}
+
+impl From<viewpoint_cdp::CdpError> for LocatorError {
+    fn from(err: viewpoint_cdp::CdpError) -> Self {
+        match err {
+            viewpoint_cdp::CdpError::TargetCrashed(_) => Self::PageCrashed,
+            other => Self::Cdp(other),
+        }
+    }
+}
+
+impl From<WaitError> for LocatorError {
+    fn from(err: WaitError) -> Self {
+        match err {
+            WaitError::PageCrashed => Self::PageCrashed,
+            other => Self::WaitError(other),
+        }
+    }
 }
 
 /// Errors related to network operations.
@@ -13,12 +13,18 @@
 //! - **Element Interaction**: Click, type, and interact with page elements via [`Locator`]
 //! - **Network Interception**: Route, modify, and mock network requests
 //! - **Device Emulation**: Emulate mobile devices, geolocation, and media features
+//! - **Throttling**: Emulate slow networks with [`NetworkConditions`] presets and throttle the CPU
 //! - **Input Devices**: Keyboard, mouse, and touchscreen control
 //! - **Screenshots & PDF**: Capture screenshots and generate PDFs
-//! - **Clock Mocking**: Control time in tests with [`Clock`]
+//! - **Clock Mocking**: Control time in tests with [`Clock`], or across a context with [`ContextClock`]
+//! - **Virtual Time**: Render pages deterministically with browser-level [`VirtualTime`]
+//! - **Accessibility**: Inspect the browser's accessibility tree and audit pages with [`Accessibility`]
 //! - **Event Handling**: Dialogs, downloads, file choosers, console messages
-//! - **Tracing**: Record traces for debugging
+//! - **Tracing**: Record traces for debugging, or browser-wide Chrome traces with [`Browser::start_tracing`]
+//! - **Performance**: Read [`PerformanceMetrics`] and collect Web Vitals with [`WebVitals`]
 //! - **Video Recording**: Record page interactions as video
+//! - **Code Coverage**: Collect JavaScript and CSS coverage with Istanbul/LCOV export
+//! - **Workers**: Evaluate code in Web Workers and Service Workers
 //!
 //! ## Quick Start
 //!
@@ -89,9 +95,12 @@
 //! # let browser = Browser::launch().headless(true).launch().await?;
 //! # let context = browser.new_context().await?;
 //! # let page = context.new_page().await?;
-//! // CSS selector
+//! // CSS selector (pierces open shadow roots)
 //! page.locator("button.primary").click().await?;
 //!
+//! // XPath selector
+//! page.locator("xpath=//button[@type='submit']").click().await?;
+//!
 //! // Text selector
 //! page.get_by_text("Submit").click().await?;
 //!
@@ -117,6 +126,7 @@
 //!     .first()
 //!     .fill("value")
 //!     .await?;
+//! page.locator("settings-panel >> text=\"Save\"").click().await?;
 //! # Ok(())
 //! # }
 //! ```
@@ -273,22 +283,28 @@ pub mod network;
 pub mod page;
 pub mod wait;
 
-pub use browser::{Browser, BrowserBuilder, ConnectOverCdpBuilder, NewContextBuilder, UserDataDir};
+pub use browser::{
+    Browser, BrowserBuilder, ChromeTrace, ConnectOverCdpBuilder, DEFAULT_TRACE_CATEGORIES,
+    DisconnectReason, NewContextBuilder, Selectors, UserDataDir,
+};
 pub use context::{
-    BrowserContext, ClearCookiesBuilder, ColorScheme, ContextEventManager, ContextOptions,
-    ContextOptionsBuilder, Cookie, ForcedColors, Geolocation, HandlerId, HttpCredentials,
-    IndexedDbDatabase, IndexedDbEntry, IndexedDbIndex, IndexedDbObjectStore, LocalStorageEntry,
-    Permission, ProxyConfig, ReducedMotion, SameSite, SetGeolocationBuilder, StorageOrigin,
-    StorageState, StorageStateBuilder, StorageStateOptions, StorageStateSource, Tracing,
-    TracingOptions, ViewportSize as ContextViewportSize,
+    BrowserContext, ClearCookiesBuilder, ColorScheme, ContextClock, ContextEventManager,
+    ContextOptions, ContextOptionsBuilder, Cookie, ForcedColors, Geolocation, HandlerId,
+    HttpCredentials, IndexedDbDatabase, IndexedDbEntry, IndexedDbIndex, IndexedDbObjectStore,
+    LocalStorageEntry, Permission, ProxyConfig, ReducedMotion, SameSite, SetGeolocationBuilder,
+    StorageOrigin, StorageState, StorageStateBuilder, StorageStateOptions, StorageStateSource,
+    TraceArchive, TraceFormat, TraceScreenshot, TraceSource, Tracing, TracingOptions,
+    ViewportSize as ContextViewportSize,
 };
 pub use error::CoreError;
 pub use network::{
     AbortError,
+    ConnectionType,
     ContinueBuilder,
     FetchedResponse,
     FulfillBuilder,
     HeaderEntry,
+    NetworkConditions,
     NetworkEvent,
     NetworkEventListener,
     RemoteAddress,
@@ -313,13 +329,26 @@ pub use network::{
     WebSocket,
     WebSocketFrame,
     WebSocketManager,
+    // WebSocket routing
+    WebSocketMessage,
+    WebSocketRoute,
+    WebSocketServerRoute,
 };
 pub use page::{
+    // Accessibility
+    Accessibility,
+    AccessibilityAuditBuilder,
+    AccessibilityAuditReport,
+    AccessibilityNode,
+    AccessibilityQueryBuilder,
+    AccessibilitySnapshotBuilder,
     // Screenshot & PDF
     Animations,
     AriaCheckedState,
     AriaRole,
     AriaSnapshot,
+    AuditRule,
+    AuditViolation,
     // Element handles and bounding boxes
     BoundingBox,
     BoxModel,
@@ -330,6 +359,10 @@ pub use page::{
     ConsoleMessage,
     ConsoleMessageLocation,
     ConsoleMessageType,
+    // Code coverage
+    Coverage,
+    CssCoverageEntry,
+    CssCoverageRange,
     // Dialog, Download, FileChooser
     Dialog,
     DialogType,
@@ -345,12 +378,16 @@ pub use page::{
     FilterBuilder,
     // Frame support
     Frame,
+    FrameClickBuilder,
     FrameElementLocator,
+    FrameFilterBuilder,
     FrameLocator,
     FrameRoleLocatorBuilder,
+    FrameTapBuilder,
     // Navigation
     GotoBuilder,
     JsArg,
+    JsCoverageEntry,
     // JavaScript evaluation
     JsHandle,
     Keyboard,
@@ -367,7 +404,11 @@ pub use page::{
     Page,
     PageErrorInfo,
     PaperFormat,
+    // Snapshot ref resolution
+    ParsedRef,
     PdfBuilder,
+    // Performance
+    PerformanceMetrics,
     Polling,
     RoleLocatorBuilder,
     ScreenshotBuilder,
@@ -380,16 +421,29 @@ pub use page::{
     // Snapshot options
     SnapshotOptions,
     StyleTagBuilder,
+    TextMatcher,
     TextOptions,
     TimeValue,
     Touchscreen,
     // Video recording
     Video,
+    VideoFormat,
     VideoOptions,
     // Viewport
     ViewportSize,
+    // Virtual time
+    VirtualTime,
+    VirtualTimeBudget,
+    VirtualTimePolicy,
+    VirtualTimePolicyBuilder,
     VisionDeficiency,
     WaitForFunctionBuilder,
     WebError,
+    WebVitals,
+    WebVitalsReport,
+    // Workers
+    Worker,
+    format_ref,
+    parse_ref,
 };
 pub use wait::DocumentLoadState;
@@ -0,0 +1,137 @@
+//! Network throttling conditions.
+//!
+//! [`NetworkConditions`] describe the latency and throughput of an emulated
+//! connection. They can be applied to a whole context with
+//! `BrowserContext::emulate_network` or to a single page with
+//! `Page::emulate_network`.
+
+use std::time::Duration;
+
+use viewpoint_cdp::protocol::network::EmulateNetworkConditionsParams;
+
+pub use viewpoint_cdp::protocol::network::ConnectionType;
+
+/// Emulated network conditions.
+///
+/// Throughput is in bytes per second; `None` disables throttling in that
+/// direction.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use viewpoint_core::NetworkConditions;
+///
+/// // A built-in preset
+/// let slow = NetworkConditions::SLOW_3G;
+///
+/// // Custom conditions
+/// let satellite = NetworkConditions {
+///     latency: Duration::from_millis(600),
+///     download_bps: Some(1_000_000),
+///     upload_bps: Some(250_000),
+///     connection_type: None,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkConditions {
+    /// Minimum latency from request sent to response headers received.
+    pub latency: Duration,
+    /// Maximum download throughput in bytes per second.
+    pub download_bps: Option<u64>,
+    /// Maximum upload throughput in bytes per second.
+    pub upload_bps: Option<u64>,
+    /// Connection type reported to the page.
+    pub connection_type: Option<ConnectionType>,
+}
+
+impl NetworkConditions {
+    /// Slow 3G, matching the Chrome DevTools preset.
+    pub const SLOW_3G: Self = Self {
+        latency: Duration::from_secs(2),
+        download_bps: Some(50_000),
+        upload_bps: Some(50_000),
+        connection_type: Some(ConnectionType::Cellular3g),
+    };
+
+    /// Fast 3G, matching the Chrome DevTools preset.
+    pub const FAST_3G: Self = Self {
+        latency: Duration::from_micros(562_500),
+        download_bps: Some(180_000),
+        upload_bps: Some(84_375),
+        connection_type: Some(ConnectionType::Cellular3g),
+    };
+
+    /// 4G, matching the Chrome DevTools "Fast 4G" preset.
+    pub const FAST_4G: Self = Self {
+        latency: Duration::from_millis(165),
+        download_bps: Some(1_012_500),
+        upload_bps: Some(168_750),
+        connection_type: Some(ConnectionType::Cellular4g),
+    };
+
+    /// DSL: 2 Mbit/s down, 1 Mbit/s up, 5 ms latency.
+    ///
+    /// A megabit is 10^6 bits, so 2 Mbit/s is 250,000 bytes per second.
+    pub const DSL: Self = Self {
+        latency: Duration::from_millis(5),
+        download_bps: Some(250_000),
+        upload_bps: Some(125_000),
+        connection_type: Some(ConnectionType::Ethernet),
+    };
+
+    /// No throttling.
+    pub const NO_THROTTLING: Self = Self {
+        latency: Duration::ZERO,
+        download_bps: None,
+        upload_bps: None,
+        connection_type: None,
+    };
+
+    /// Convert to CDP parameters.
+    pub(crate) fn to_params(self) -> EmulateNetworkConditionsParams {
+        let throughput = |bps: Option<u64>| bps.map_or(-1.0, |bps| bps as f64);
+        EmulateNetworkConditionsParams {
+            offline: false,
+            latency: self.latency.as_secs_f64() * 1000.0,
+            download_throughput: throughput(self.download_bps),
+            upload_throughput: throughput(self.upload_bps),
+            connection_type: self.connection_type,
+        }
+    }
+}
+
+impl Default for NetworkConditions {
+    fn default() -> Self {
+        Self::NO_THROTTLING
+    }
+}
+
+/// Network emulation state shared by the pages of a context.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct NetworkEmulation {
+    /// Whether the context is offline.
+    pub(crate) offline: bool,
+    /// Context-wide throttling.
+    pub(crate) conditions: Option<NetworkConditions>,
+}
+
+impl NetworkEmulation {
+    /// Effective parameters for a page, given its own override.
+    ///
+    /// Returns `None` if the page needs no emulation.
+    pub(crate) fn params_for(
+        self,
+        page_override: Option<NetworkConditions>,
+    ) -> Option<EmulateNetworkConditionsParams> {
+        if self.offline {
+            return Some(EmulateNetworkConditionsParams::offline());
+        }
+        page_override
+            .or(self.conditions)
+            .map(NetworkConditions::to_params)
+    }
+}
+
+#[cfg(test)]
+mod tests;
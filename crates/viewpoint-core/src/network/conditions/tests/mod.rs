@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn test_preset_to_params() {
+    let params = NetworkConditions::SLOW_3G.to_params();
+    assert!(!params.offline);
+    assert!((params.latency - 2000.0).abs() < f64::EPSILON);
+    assert!((params.download_throughput - 50_000.0).abs() < f64::EPSILON);
+    assert!((params.upload_throughput - 50_000.0).abs() < f64::EPSILON);
+    assert_eq!(params.connection_type, Some(ConnectionType::Cellular3g));
+}
+
+#[test]
+fn test_unthrottled_direction() {
+    let params = NetworkConditions {
+        latency: Duration::from_millis(100),
+        download_bps: Some(1000),
+        upload_bps: None,
+        connection_type: None,
+    }
+    .to_params();
+    assert!((params.latency - 100.0).abs() < f64::EPSILON);
+    assert!((params.upload_throughput + 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_params_for_offline_wins() {
+    let emulation = NetworkEmulation {
+        offline: true,
+        conditions: Some(NetworkConditions::FAST_3G),
+    };
+    let params = emulation
+        .params_for(Some(NetworkConditions::DSL))
+        .expect("Offline needs emulation");
+    assert!(params.offline);
+}
+
+#[test]
+fn test_params_for_page_override() {
+    let emulation = NetworkEmulation {
+        offline: false,
+        conditions: Some(NetworkConditions::FAST_3G),
+    };
+    let params = emulation
+        .params_for(Some(NetworkConditions::DSL))
+        .expect("Override needs emulation");
+    assert!((params.latency - 5.0).abs() < f64::EPSILON);
+    assert!((params.download_throughput - 2_000_000.0 / 8.0).abs() < f64::EPSILON);
+    assert!((params.upload_throughput - 1_000_000.0 / 8.0).abs() < f64::EPSILON);
+
+    let params = emulation
+        .params_for(None)
+        .expect("Context conditions apply");
+    assert!((params.latency - 562.5).abs() < f64::EPSILON);
+
+    assert!(NetworkEmulation::default().params_for(None).is_none());
+}
@@ -0,0 +1,156 @@
+//! Handlers and streams for network events.
+//!
+//! Handlers and streams pick the events they care about with the
+//! `NetworkEvent::into_*` accessors.
+
+use std::future::Future;
+
+use futures::Stream;
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use super::{NetworkEvent, NetworkEventListener};
+use crate::context::HandlerId;
+use crate::network::{Request, Response};
+
+impl NetworkEvent {
+    /// The request, if this is a [`NetworkEvent::Request`].
+    pub fn into_request(self) -> Option<Request> {
+        match self {
+            Self::Request(event) => Some(event.request),
+            _ => None,
+        }
+    }
+
+    /// The response, if this is a [`NetworkEvent::Response`].
+    pub fn into_response(self) -> Option<Response> {
+        match self {
+            Self::Response(event) => Some(event.response),
+            _ => None,
+        }
+    }
+
+    /// The request, if this is a [`NetworkEvent::RequestFinished`].
+    pub fn into_finished_request(self) -> Option<Request> {
+        match self {
+            Self::RequestFinished(event) => Some(event.request),
+            _ => None,
+        }
+    }
+
+    /// The request, if this is a [`NetworkEvent::RequestFailed`].
+    ///
+    /// The error is available from [`Request::failure`].
+    pub fn into_failed_request(self) -> Option<Request> {
+        match self {
+            Self::RequestFailed(event) => Some(event.request),
+            _ => None,
+        }
+    }
+}
+
+/// Kind of network event a handler is registered for.
+///
+/// A handler ID only removes a handler of the kind it was registered as, so
+/// passing a request handler's ID to `off_response` leaves it in place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NetworkEventKind {
+    /// [`NetworkEvent::Request`].
+    Request,
+    /// [`NetworkEvent::Response`].
+    Response,
+    /// [`NetworkEvent::RequestFinished`].
+    RequestFinished,
+    /// [`NetworkEvent::RequestFailed`].
+    RequestFailed,
+}
+
+impl NetworkEventListener {
+    /// Register a handler of `kind`, called with every event picked by `select`.
+    ///
+    /// Handlers run in order on a task of their own, so a slow handler does
+    /// not hold up the page or other handlers.
+    pub(crate) async fn add_handler<T, F, Fut>(
+        &self,
+        kind: NetworkEventKind,
+        select: fn(NetworkEvent) -> Option<T>,
+        handler: F,
+    ) -> HandlerId
+    where
+        T: Send + 'static,
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut events = self.subscribe();
+        let task = tokio::spawn(async move {
+            while let Some(event) = next_event(&mut events).await {
+                if let Some(value) = select(event) {
+                    handler(value).await;
+                }
+            }
+        });
+
+        let id = HandlerId::new();
+        self.handlers.lock().await.insert(id, (kind, task));
+        id
+    }
+
+    /// Remove a handler of `kind` registered with
+    /// [`add_handler`](Self::add_handler).
+    ///
+    /// Returns `true` if a handler was removed; an ID registered for another
+    /// kind removes nothing.
+    pub(crate) async fn remove_handler(&self, kind: NetworkEventKind, id: HandlerId) -> bool {
+        let mut handlers = self.handlers.lock().await;
+        match handlers.get(&id) {
+            Some((registered, _)) if *registered == kind => {
+                if let Some((_, task)) = handlers.remove(&id) {
+                    task.abort();
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Stream every event picked by `select` from now on.
+    pub(crate) fn stream<T: Send + 'static>(
+        &self,
+        select: fn(NetworkEvent) -> Option<T>,
+    ) -> impl Stream<Item = T> + Send + use<T> {
+        futures::stream::unfold(self.subscribe(), move |mut events| async move {
+            while let Some(event) = next_event(&mut events).await {
+                if let Some(value) = select(event) {
+                    return Some((value, events));
+                }
+            }
+            None
+        })
+    }
+
+    /// Relay every event of this listener to `target`.
+    pub(crate) fn forward_to(&self, target: &NetworkEventListener) {
+        let mut events = self.subscribe();
+        let target_tx = target.event_tx.clone();
+        tokio::spawn(async move {
+            while let Some(event) = next_event(&mut events).await {
+                let _ = target_tx.send(event);
+            }
+        });
+    }
+}
+
+/// Receive the next event, skipping over events missed by a slow receiver.
+pub(super) async fn next_event(
+    events: &mut broadcast::Receiver<NetworkEvent>,
+) -> Option<NetworkEvent> {
+    loop {
+        match events.recv().await {
+            Ok(event) => return Some(event),
+            Err(broadcast::error::RecvError::Lagged(missed)) => {
+                warn!(missed, "Network event receiver lagged behind");
+            }
+            Err(broadcast::error::RecvError::Closed) => return None,
+        }
+    }
+}
@@ -1,10 +1,13 @@
 //! Network event handling.
 
+mod handlers;
+
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
-use tokio::sync::broadcast;
+use tokio::sync::{Mutex, broadcast};
+use tokio::task::JoinHandle;
 use viewpoint_cdp::CdpConnection;
 use viewpoint_cdp::protocol::network::{
     LoadingFailedEvent, LoadingFinishedEvent, RequestWillBeSentEvent, ResponseReceivedEvent,
@@ -12,9 +15,12 @@ use viewpoint_cdp::protocol::network::{
 
 use super::request::Request;
 use super::response::Response;
-use super::types::{ResourceType, UrlMatcher};
+use super::types::{ResourceType, UrlMatcher, matches_with_base_url};
+use crate::context::HandlerId;
 use crate::error::NetworkError;
 
+pub(crate) use handlers::NetworkEventKind;
+
 /// Event emitted when a request is made.
 #[derive(Debug, Clone)]
 pub struct RequestEvent {
@@ -59,6 +65,10 @@ pub enum NetworkEvent {
 }
 
 /// Network event listener for a page.
+///
+/// Events are broadcast to every subscriber, handler and stream. A listener
+/// that is not started only relays events forwarded to it, which is how a
+/// context observes the traffic of all its pages.
 #[derive(Debug)]
 pub struct NetworkEventListener {
     /// CDP connection.
@@ -67,6 +77,8 @@ pub struct NetworkEventListener {
     session_id: String,
     /// Event sender.
     event_tx: broadcast::Sender<NetworkEvent>,
+    /// Tasks running registered handlers, with the kind they handle.
+    handlers: Mutex<HashMap<HandlerId, (NetworkEventKind, JoinHandle<()>)>>,
 }
 
 impl NetworkEventListener {
@@ -77,6 +89,7 @@ impl NetworkEventListener {
             connection,
             session_id,
             event_tx,
+            handlers: Mutex::new(HashMap::new()),
         }
     }
 
@@ -119,8 +132,30 @@ impl NetworkEventListener {
                                     None
                                 };
 
-                                let request =
+                                // The redirect response completes the previous request
+                                if let (Some(previous), Some(redirect_response)) =
+                                    (&previous_request, &req_event.redirect_response)
+                                {
+                                    let response = Response::new(
+                                        redirect_response.clone(),
+                                        previous.clone(),
+                                        connection.clone(),
+                                        session_id.clone(),
+                                        req_event.request_id.clone(),
+                                    );
+                                    let _ = event_tx
+                                        .send(NetworkEvent::Response(ResponseEvent { response }));
+                                    let _ = event_tx.send(NetworkEvent::RequestFinished(
+                                        RequestFinishedEvent {
+                                            request: previous.clone(),
+                                        },
+                                    ));
+                                }
+
+                                let mut request =
                                     parse_request_will_be_sent(&req_event, previous_request);
+                                request.connection = Some(connection.clone());
+                                request.session_id = Some(session_id.clone());
                                 pending_requests
                                     .insert(req_event.request_id.clone(), request.clone());
                                 let _ =
@@ -170,9 +205,10 @@ impl NetworkEventListener {
                             if let Ok(failed_event) =
                                 serde_json::from_value::<LoadingFailedEvent>(params.clone())
                             {
-                                if let Some(request) =
+                                if let Some(mut request) =
                                     pending_requests.remove(&failed_event.request_id)
                                 {
+                                    request.failure_text = Some(failed_event.error_text.clone());
                                     let _ = event_tx.send(NetworkEvent::RequestFailed(
                                         RequestFailedEvent {
                                             request,
@@ -190,7 +226,6 @@ impl NetworkEventListener {
     }
 }
 
-/// Parse a `RequestWillBeSentEvent` into a Request.
 /// Parse a `RequestWillBeSentEvent` into a Request.
 ///
 /// If `previous_request` is provided, it will be set as the `redirected_from` source.
@@ -250,6 +285,8 @@ pub struct WaitForRequestBuilder<'a, M> {
     session_id: &'a str,
     /// Pattern to match.
     pattern: M,
+    /// Base URL for resolving relative patterns.
+    base_url: Option<String>,
     /// Timeout duration.
     timeout: Duration,
 }
@@ -261,10 +298,18 @@ impl<'a, M: UrlMatcher + Clone + 'static> WaitForRequestBuilder<'a, M> {
             connection,
             session_id,
             pattern,
+            base_url: None,
             timeout: Duration::from_secs(30),
         }
     }
 
+    /// Set the base URL for resolving relative glob patterns.
+    #[must_use]
+    pub(crate) fn with_base_url(mut self, base_url: Option<String>) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
     /// Set the timeout duration.
     #[must_use]
     pub fn timeout(mut self, timeout: Duration) -> Self {
@@ -282,6 +327,7 @@ impl<'a, M: UrlMatcher + Clone + 'static> WaitForRequestBuilder<'a, M> {
         let mut events = self.connection.subscribe_events();
         let session_id = self.session_id.to_string();
         let pattern = self.pattern;
+        let base_url = self.base_url;
         let timeout = self.timeout;
 
         tokio::time::timeout(timeout, async move {
@@ -296,7 +342,11 @@ impl<'a, M: UrlMatcher + Clone + 'static> WaitForRequestBuilder<'a, M> {
                         if let Ok(req_event) =
                             serde_json::from_value::<RequestWillBeSentEvent>(params.clone())
                         {
-                            if pattern.matches(&req_event.request.url) {
+                            if matches_with_base_url(
+                                &pattern,
+                                &req_event.request.url,
+                                base_url.as_deref(),
+                            ) {
                                 return Ok(parse_request_will_be_sent(&req_event, None));
                             }
                         }
@@ -319,6 +369,8 @@ pub struct WaitForResponseBuilder<'a, M> {
     session_id: &'a str,
     /// Pattern to match.
     pattern: M,
+    /// Base URL for resolving relative patterns.
+    base_url: Option<String>,
     /// Timeout duration.
     timeout: Duration,
 }
@@ -330,10 +382,18 @@ impl<'a, M: UrlMatcher + Clone + 'static> WaitForResponseBuilder<'a, M> {
             connection,
             session_id,
             pattern,
+            base_url: None,
             timeout: Duration::from_secs(30),
         }
     }
 
+    /// Set the base URL for resolving relative glob patterns.
+    #[must_use]
+    pub(crate) fn with_base_url(mut self, base_url: Option<String>) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
     /// Set the timeout duration.
     #[must_use]
     pub fn timeout(mut self, timeout: Duration) -> Self {
@@ -351,6 +411,7 @@ impl<'a, M: UrlMatcher + Clone + 'static> WaitForResponseBuilder<'a, M> {
         let mut events = self.connection.subscribe_events();
         let session_id = self.session_id.to_string();
         let pattern = self.pattern;
+        let base_url = self.base_url;
         let timeout = self.timeout;
         let connection = self.connection.clone();
 
@@ -380,7 +441,11 @@ impl<'a, M: UrlMatcher + Clone + 'static> WaitForResponseBuilder<'a, M> {
                             if let Ok(resp_event) =
                                 serde_json::from_value::<ResponseReceivedEvent>(params.clone())
                             {
-                                if pattern.matches(&resp_event.response.url) {
+                                if matches_with_base_url(
+                                    &pattern,
+                                    &resp_event.response.url,
+                                    base_url.as_deref(),
+                                ) {
                                     // Get the associated request or create a minimal one
                                     let request = pending_requests
                                         .get(&resp_event.request_id)
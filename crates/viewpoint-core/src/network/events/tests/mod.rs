@@ -8,6 +8,66 @@ fn test_network_event_types() {
     });
 }
 
+#[test]
+fn test_network_event_selectors() {
+    let request = NetworkEvent::Request(RequestEvent {
+        request: create_test_request(),
+    });
+    assert_eq!(
+        request.clone().into_request().map(|r| r.url().to_string()),
+        Some("https://example.com".to_string())
+    );
+    assert!(request.clone().into_response().is_none());
+    assert!(request.clone().into_finished_request().is_none());
+    assert!(request.into_failed_request().is_none());
+
+    let finished = NetworkEvent::RequestFinished(RequestFinishedEvent {
+        request: create_test_request(),
+    });
+    assert!(finished.clone().into_request().is_none());
+    assert!(finished.into_finished_request().is_some());
+
+    let mut failed_request = create_test_request();
+    failed_request.failure_text = Some("net::ERR_FAILED".to_string());
+    let failed = NetworkEvent::RequestFailed(RequestFailedEvent {
+        request: failed_request,
+        error: "net::ERR_FAILED".to_string(),
+    });
+    assert!(failed.clone().into_finished_request().is_none());
+    assert_eq!(
+        failed
+            .into_failed_request()
+            .and_then(|r| r.failure().map(str::to_string)),
+        Some("net::ERR_FAILED".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_remove_handler_only_removes_its_kind() {
+    let (to_browser, _browser_in) = tokio::io::duplex(1024);
+    let (_browser_out, from_browser) = tokio::io::duplex(1024);
+    let listener = NetworkEventListener::new(
+        Arc::new(CdpConnection::connect_pipe(from_browser, to_browser)),
+        "session".to_string(),
+    );
+
+    let id = listener
+        .add_handler(
+            NetworkEventKind::Request,
+            NetworkEvent::into_request,
+            |_| async {},
+        )
+        .await;
+
+    assert!(
+        !listener
+            .remove_handler(NetworkEventKind::Response, id)
+            .await
+    );
+    assert!(listener.remove_handler(NetworkEventKind::Request, id).await);
+    assert!(!listener.remove_handler(NetworkEventKind::Request, id).await);
+}
+
 fn create_test_request() -> Request {
     // Create a minimal test request
     use super::super::types::ResourceType;
@@ -12,6 +12,8 @@
 //! - **HAR Recording**: Record network traffic for debugging
 //! - **HAR Replay**: Replay recorded traffic for testing
 //! - **WebSocket Monitoring**: Track WebSocket connections and messages
+//! - **WebSocket Routing**: Mock WebSocket servers or rewrite frames in flight
+//! - **Network Throttling**: Emulate slow connections and offline mode
 //!
 //! ## Mock API Responses
 //!
@@ -276,6 +278,7 @@
 //! ```
 
 pub mod auth;
+pub mod conditions;
 pub mod events;
 pub(crate) mod handler;
 mod handler_fetch;
@@ -291,7 +294,11 @@ mod route_builders;
 mod route_fetch;
 mod types;
 pub mod websocket;
+mod websocket_route;
+mod websocket_route_script;
 
+pub use conditions::{ConnectionType, NetworkConditions};
+pub(crate) use events::NetworkEventKind;
 pub use events::{
     NetworkEvent, NetworkEventListener, RequestEvent, RequestFailedEvent, RequestFinishedEvent,
     ResponseEvent, WaitForRequestBuilder, WaitForResponseBuilder,
@@ -308,7 +315,13 @@ pub use route::{Route, RouteAction, RouteHandler};
 pub use route_builders::{ContinueBuilder, FulfillBuilder};
 pub use route_fetch::{FetchBuilder, FetchedResponse};
 pub use types::{AbortError, ResourceType, UrlMatcher, UrlPattern};
+pub(crate) use types::{resolve_glob, resolve_url};
 pub use websocket::{WebSocket, WebSocketFrame, WebSocketManager};
+pub use websocket_route::{
+    WebSocketCloseHandler, WebSocketMessage, WebSocketMessageHandler, WebSocketRoute,
+    WebSocketServerRoute,
+};
+pub(crate) use websocket_route::{WebSocketRouteManager, WebSocketRouteRegistry};
 
 // Re-export CDP types that are used directly
 pub use viewpoint_cdp::protocol::fetch::HeaderEntry;
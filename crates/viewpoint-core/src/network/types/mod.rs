@@ -205,6 +205,17 @@ impl UrlPattern {
             Self::Regex(regex) => regex.is_match(url),
         }
     }
+
+    /// Resolve a relative glob pattern against a base URL.
+    ///
+    /// Glob patterns starting with `*` and regex patterns are left unchanged.
+    #[must_use]
+    pub(crate) fn with_base_url(self, base_url: Option<&str>) -> Self {
+        match self {
+            Self::Glob(pattern) => Self::Glob(resolve_glob(base_url, &pattern)),
+            regex @ Self::Regex(_) => regex,
+        }
+    }
 }
 
 impl From<&str> for UrlPattern {
@@ -229,24 +240,47 @@ impl From<regex::Regex> for UrlPattern {
 pub trait UrlMatcher: Send + Sync {
     /// Check if the URL matches.
     fn matches(&self, url: &str) -> bool;
+
+    /// The glob pattern this matcher matches with, if it is one.
+    ///
+    /// Relative glob patterns are resolved against the context's base URL.
+    /// Other matchers see absolute URLs and return `None`.
+    fn glob(&self) -> Option<&str> {
+        None
+    }
 }
 
 impl UrlMatcher for UrlPattern {
     fn matches(&self, url: &str) -> bool {
         self.matches(url)
     }
+
+    fn glob(&self) -> Option<&str> {
+        match self {
+            Self::Glob(pattern) => Some(pattern),
+            Self::Regex(_) => None,
+        }
+    }
 }
 
 impl UrlMatcher for str {
     fn matches(&self, url: &str) -> bool {
         glob_match(self, url)
     }
+
+    fn glob(&self) -> Option<&str> {
+        Some(self)
+    }
 }
 
 impl UrlMatcher for String {
     fn matches(&self, url: &str) -> bool {
         glob_match(self, url)
     }
+
+    fn glob(&self) -> Option<&str> {
+        Some(self)
+    }
 }
 
 impl UrlMatcher for regex::Regex {
@@ -264,6 +298,41 @@ where
     }
 }
 
+/// Check if `url` matches `matcher`, resolving a relative glob pattern against
+/// a base URL.
+pub(crate) fn matches_with_base_url<M: UrlMatcher + ?Sized>(
+    matcher: &M,
+    url: &str,
+    base_url: Option<&str>,
+) -> bool {
+    match matcher.glob() {
+        Some(pattern) => glob_match(&resolve_glob(base_url, pattern), url),
+        None => matcher.matches(url),
+    }
+}
+
+/// Resolve a possibly relative URL against a base URL.
+///
+/// Absolute URLs, and any URL when there is no valid base URL, are returned
+/// unchanged.
+pub(crate) fn resolve_url(base_url: Option<&str>, url: &str) -> String {
+    base_url
+        .and_then(|base| url::Url::parse(base).ok())
+        .and_then(|base| base.join(url).ok())
+        .map_or_else(|| url.to_string(), String::from)
+}
+
+/// Resolve a relative glob pattern against a base URL.
+///
+/// Patterns starting with `*` already match any origin and are left unchanged.
+pub(crate) fn resolve_glob(base_url: Option<&str>, pattern: &str) -> String {
+    if pattern.starts_with('*') {
+        pattern.to_string()
+    } else {
+        resolve_url(base_url, pattern)
+    }
+}
+
 /// Match a URL against a glob pattern.
 ///
 /// Pattern syntax:
@@ -320,7 +389,8 @@ fn glob_match(pattern: &str, url: &str) -> bool {
     regex_pattern.push('$');
 
     // Compile and match
-    regex::Regex::new(&regex_pattern)
-        .map(|re| re.is_match(url))
-        .unwrap_or(false)
+    regex::Regex::new(&regex_pattern).is_ok_and(|re| re.is_match(url))
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,55 @@
+use super::*;
+
+const BASE_URL: &str = "https://staging.example.com/app/";
+
+#[test]
+fn test_resolve_url() {
+    assert_eq!(
+        resolve_url(Some(BASE_URL), "/login"),
+        "https://staging.example.com/login"
+    );
+    assert_eq!(
+        resolve_url(Some(BASE_URL), "settings"),
+        "https://staging.example.com/app/settings"
+    );
+    assert_eq!(
+        resolve_url(Some(BASE_URL), "https://other.example.com/"),
+        "https://other.example.com/"
+    );
+    assert_eq!(resolve_url(None, "/login"), "/login");
+    assert_eq!(resolve_url(Some("not a url"), "/login"), "/login");
+}
+
+#[test]
+fn test_url_pattern_with_base_url() {
+    let pattern = UrlPattern::glob("/api/**").with_base_url(Some(BASE_URL));
+    assert!(pattern.matches("https://staging.example.com/api/users/1"));
+    assert!(!pattern.matches("https://other.example.com/api/users/1"));
+
+    // Patterns that already match any origin are unchanged
+    let pattern = UrlPattern::glob("**/api/**").with_base_url(Some(BASE_URL));
+    assert!(pattern.matches("https://other.example.com/api/users/1"));
+}
+
+#[test]
+fn test_url_matcher_with_base_url() {
+    let url = "https://staging.example.com/api/users";
+
+    assert!(matches_with_base_url("/api/*", url, Some(BASE_URL)));
+    assert!(matches_with_base_url(
+        &"/api/*".to_string(),
+        url,
+        Some(BASE_URL)
+    ));
+    assert!(matches_with_base_url(
+        &UrlPattern::glob("/api/*"),
+        url,
+        Some(BASE_URL)
+    ));
+    assert!(!matches_with_base_url("/api/*", url, None));
+
+    let regex = regex::Regex::new(r"/api/").unwrap();
+    assert!(matches_with_base_url(&regex, url, Some(BASE_URL)));
+    let predicate = |url: &str| url.ends_with("/users");
+    assert!(matches_with_base_url(&predicate, url, Some(BASE_URL)));
+}
@@ -0,0 +1,446 @@
+//! Registration and dispatch of WebSocket routes.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Weak};
+
+use tokio::sync::{RwLock, broadcast, mpsc};
+use tracing::{debug, warn};
+use viewpoint_cdp::protocol::page::AddScriptToEvaluateOnNewDocumentParams;
+use viewpoint_cdp::protocol::runtime::{AddBindingParams, BindingCalledEvent, EvaluateParams};
+use viewpoint_cdp::{CdpConnection, CdpEvent};
+
+use super::protocol::{SocketCommand, SocketEvent, decode_message};
+use super::{RoutedSocket, WebSocketRoute};
+use crate::error::NetworkError;
+use crate::network::UrlPattern;
+use crate::network::websocket_route_script::{WEBSOCKET_ROUTE_BINDING, WEBSOCKET_ROUTE_SCRIPT};
+
+/// A WebSocket route handler function.
+pub(crate) type WebSocketRouteHandler = Arc<
+    dyn Fn(WebSocketRoute) -> Pin<Box<dyn Future<Output = Result<(), NetworkError>> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// A registered WebSocket route.
+struct RegisteredRoute {
+    /// Pattern to match WebSocket URLs.
+    pattern: UrlPattern,
+    /// The handler function.
+    handler: WebSocketRouteHandler,
+}
+
+/// WebSocket route handlers for a page or a browser context.
+///
+/// Context registries also track the managers of their pages so that adding
+/// a route installs the WebSocket mock in pages that are already open.
+#[derive(Default)]
+pub(crate) struct WebSocketRouteRegistry {
+    /// Registered routes (in registration order - last registered is tried first).
+    routes: RwLock<Vec<RegisteredRoute>>,
+    /// Weak references to the managers of pages using this registry.
+    managers: RwLock<Vec<Weak<WebSocketRouteManager>>>,
+}
+
+impl WebSocketRouteRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a route handler and install the mock in all tracked pages.
+    pub async fn route<M, H, Fut>(&self, pattern: M, handler: H) -> Result<(), NetworkError>
+    where
+        M: Into<UrlPattern>,
+        H: Fn(WebSocketRoute) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), NetworkError>> + Send + 'static,
+    {
+        let handler: WebSocketRouteHandler = Arc::new(move |route| Box::pin(handler(route)));
+        self.routes.write().await.push(RegisteredRoute {
+            pattern: pattern.into(),
+            handler,
+        });
+
+        let managers: Vec<_> = self
+            .managers
+            .read()
+            .await
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect();
+        for manager in managers {
+            manager.ensure_installed().await?;
+        }
+        Ok(())
+    }
+
+    /// Unregister routes matching the given pattern.
+    pub async fn unroute(&self, pattern: &str) {
+        self.routes
+            .write()
+            .await
+            .retain(|route| !route.pattern.matches(pattern));
+    }
+
+    /// Unregister all routes.
+    pub async fn unroute_all(&self) {
+        self.routes.write().await.clear();
+    }
+
+    /// Check if any routes are registered.
+    pub async fn is_empty(&self) -> bool {
+        self.routes.read().await.is_empty()
+    }
+
+    /// Find the most recently registered route matching the URL.
+    pub async fn find(&self, url: &str) -> Option<WebSocketRouteHandler> {
+        self.routes
+            .read()
+            .await
+            .iter()
+            .rev()
+            .find(|route| route.pattern.matches(url))
+            .map(|route| route.handler.clone())
+    }
+
+    /// Track a page's manager so future routes are installed in it.
+    pub async fn register_manager(&self, manager: &Arc<WebSocketRouteManager>) {
+        let mut managers = self.managers.write().await;
+        managers.retain(|weak| weak.strong_count() > 0);
+        managers.push(Arc::downgrade(manager));
+    }
+}
+
+/// Manager for WebSocket routes on a page.
+///
+/// The WebSocket mock is only installed once a route could apply to the
+/// page, so pages without routes keep the native `WebSocket`.
+pub(crate) struct WebSocketRouteManager {
+    /// CDP connection.
+    connection: Arc<CdpConnection>,
+    /// Session ID.
+    session_id: String,
+    /// Page-level routes.
+    routes: Arc<WebSocketRouteRegistry>,
+    /// Context-level routes, consulted when no page route matches.
+    context_routes: Option<Arc<WebSocketRouteRegistry>>,
+    /// Whether the binding and mock script have been installed.
+    installed: AtomicBool,
+}
+
+impl WebSocketRouteManager {
+    /// Create a new WebSocket route manager for a page.
+    pub fn new(connection: Arc<CdpConnection>, session_id: String) -> Self {
+        Self {
+            connection,
+            session_id,
+            routes: Arc::new(WebSocketRouteRegistry::new()),
+            context_routes: None,
+            installed: AtomicBool::new(false),
+        }
+    }
+
+    /// Create a manager that falls back to the context's routes.
+    pub async fn with_context_routes(
+        connection: Arc<CdpConnection>,
+        session_id: String,
+        context_routes: Arc<WebSocketRouteRegistry>,
+    ) -> Arc<Self> {
+        let manager = Arc::new(Self {
+            context_routes: Some(context_routes.clone()),
+            ..Self::new(connection, session_id)
+        });
+        context_routes.register_manager(&manager).await;
+        manager
+    }
+
+    /// Register a page-level route handler.
+    pub async fn route<M, H, Fut>(&self, pattern: M, handler: H) -> Result<(), NetworkError>
+    where
+        M: Into<UrlPattern>,
+        H: Fn(WebSocketRoute) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), NetworkError>> + Send + 'static,
+    {
+        self.routes.route(pattern, handler).await?;
+        self.ensure_installed().await
+    }
+
+    /// Unregister page-level routes matching the given pattern.
+    pub async fn unroute(&self, pattern: &str) {
+        self.routes.unroute(pattern).await;
+    }
+
+    /// Unregister all page-level routes.
+    pub async fn unroute_all(&self) {
+        self.routes.unroute_all().await;
+    }
+
+    /// Install the mock if the context already has WebSocket routes.
+    pub async fn install_for_context_routes(&self) -> Result<(), NetworkError> {
+        match &self.context_routes {
+            Some(routes) if !routes.is_empty().await => self.ensure_installed().await,
+            _ => Ok(()),
+        }
+    }
+
+    /// Install the binding and mock script, once.
+    pub async fn ensure_installed(&self) -> Result<(), NetworkError> {
+        if self.installed.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+        let result = self.install().await;
+        if result.is_err() {
+            self.installed.store(false, Ordering::SeqCst);
+        }
+        result
+    }
+
+    async fn install(&self) -> Result<(), NetworkError> {
+        debug!(session_id = %self.session_id, "Installing WebSocket routing");
+
+        // Subscribe before installing so no connection report is missed
+        let events = self.connection.subscribe_events();
+
+        self.connection
+            .send_command::<_, serde_json::Value>(
+                "Runtime.addBinding",
+                Some(AddBindingParams {
+                    name: WEBSOCKET_ROUTE_BINDING.to_string(),
+                    execution_context_id: None,
+                    execution_context_name: None,
+                }),
+                Some(&self.session_id),
+            )
+            .await?;
+
+        self.connection
+            .send_command::<_, serde_json::Value>(
+                "Page.addScriptToEvaluateOnNewDocument",
+                Some(AddScriptToEvaluateOnNewDocumentParams {
+                    source: WEBSOCKET_ROUTE_SCRIPT.to_string(),
+                    world_name: None,
+                    include_command_line_api: None,
+                    run_immediately: None,
+                }),
+                Some(&self.session_id),
+            )
+            .await?;
+
+        // Also install in the current document
+        self.connection
+            .send_command::<_, serde_json::Value>(
+                "Runtime.evaluate",
+                Some(EvaluateParams {
+                    expression: WEBSOCKET_ROUTE_SCRIPT.to_string(),
+                    object_group: None,
+                    include_command_line_api: None,
+                    silent: Some(true),
+                    context_id: None,
+                    return_by_value: Some(true),
+                    await_promise: Some(false),
+                }),
+                Some(&self.session_id),
+            )
+            .await?;
+
+        self.spawn_listener(events);
+        Ok(())
+    }
+
+    /// Forward binding calls to a task that handles them in order.
+    fn spawn_listener(&self, mut events: broadcast::Receiver<CdpEvent>) {
+        let (tx, mut rx) = mpsc::unbounded_channel::<BindingCalledEvent>();
+        let session_id = self.session_id.clone();
+
+        tokio::spawn(async move {
+            while let Ok(event) = events.recv().await {
+                if event.session_id.as_deref() != Some(session_id.as_str())
+                    || event.method != "Runtime.bindingCalled"
+                {
+                    continue;
+                }
+                let Some(params) = event.params else {
+                    continue;
+                };
+                let Ok(called) = serde_json::from_value::<BindingCalledEvent>(params) else {
+                    continue;
+                };
+                if called.name == WEBSOCKET_ROUTE_BINDING && tx.send(called).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut dispatcher = Dispatcher {
+            connection: self.connection.clone(),
+            session_id: self.session_id.clone(),
+            routes: self.routes.clone(),
+            context_routes: self.context_routes.clone(),
+            sockets: HashMap::new(),
+        };
+        tokio::spawn(async move {
+            while let Some(called) = rx.recv().await {
+                dispatcher.handle(called).await;
+            }
+            debug!("WebSocket route dispatcher stopped");
+        });
+    }
+}
+
+/// Handles socket events for one page, in the order the mock reported them.
+struct Dispatcher {
+    connection: Arc<CdpConnection>,
+    session_id: String,
+    routes: Arc<WebSocketRouteRegistry>,
+    context_routes: Option<Arc<WebSocketRouteRegistry>>,
+    /// Routed sockets indexed by mock socket ID.
+    sockets: HashMap<String, Arc<RoutedSocket>>,
+}
+
+impl Dispatcher {
+    async fn handle(&mut self, called: BindingCalledEvent) {
+        let event: SocketEvent = match serde_json::from_str(&called.payload) {
+            Ok(event) => event,
+            Err(e) => {
+                warn!(error = %e, "Failed to parse WebSocket route payload");
+                return;
+            }
+        };
+
+        match event {
+            SocketEvent::Connect { id, url, protocols } => {
+                let socket = Arc::new(RoutedSocket::new(
+                    id,
+                    url,
+                    protocols,
+                    called.execution_context_id,
+                    self.connection.clone(),
+                    self.session_id.clone(),
+                ));
+                self.handle_connect(socket).await;
+            }
+            SocketEvent::Send {
+                id,
+                data,
+                is_base64,
+            } => {
+                let Some(socket) = self.sockets.get(&id) else {
+                    return;
+                };
+                let message = match decode_message(data, is_base64) {
+                    Ok(message) => message,
+                    Err(e) => {
+                        warn!(error = %e, "Dropping WebSocket message from page");
+                        return;
+                    }
+                };
+                let handler = socket.page.message.read().await.clone();
+                if let Some(handler) = handler {
+                    handler(message).await;
+                } else if socket.is_server_connected() {
+                    log_failure(socket.send_to_server(&message).await);
+                }
+            }
+            SocketEvent::Close { id, code, reason } => {
+                let Some(socket) = self.sockets.get(&id).cloned() else {
+                    return;
+                };
+                if !socket.is_server_connected() {
+                    self.sockets.remove(&id);
+                }
+                let handler = socket.page.close.read().await.clone();
+                if let Some(handler) = handler {
+                    handler(code, reason).await;
+                } else if socket.is_server_connected() {
+                    log_failure(socket.close_server(code, reason).await);
+                }
+            }
+            SocketEvent::ServerMessage {
+                id,
+                data,
+                is_base64,
+            } => {
+                let Some(socket) = self.sockets.get(&id) else {
+                    return;
+                };
+                let message = match decode_message(data, is_base64) {
+                    Ok(message) => message,
+                    Err(e) => {
+                        warn!(error = %e, "Dropping WebSocket message from server");
+                        return;
+                    }
+                };
+                let handler = socket.server.message.read().await.clone();
+                if let Some(handler) = handler {
+                    handler(message).await;
+                } else {
+                    log_failure(socket.send_to_page(&message).await);
+                }
+            }
+            SocketEvent::ServerClose { id, code, reason } => {
+                let Some(socket) = self.sockets.remove(&id) else {
+                    return;
+                };
+                let handler = socket.server.close.read().await.clone();
+                if let Some(handler) = handler {
+                    handler(code, reason).await;
+                } else {
+                    log_failure(socket.close_page(code, reason).await);
+                }
+            }
+        }
+    }
+
+    /// Run the matching route handler, or let the socket pass through.
+    async fn handle_connect(&mut self, socket: Arc<RoutedSocket>) {
+        let mut handler = self.routes.find(&socket.url).await;
+        if handler.is_none() {
+            if let Some(ref context_routes) = self.context_routes {
+                handler = context_routes.find(&socket.url).await;
+            }
+        }
+
+        let Some(handler) = handler else {
+            debug!(url = %socket.url, "No WebSocket route matched, passing through");
+            log_failure(
+                socket
+                    .dispatch(&SocketCommand::Passthrough {
+                        id: socket.id.clone(),
+                    })
+                    .await,
+            );
+            return;
+        };
+
+        debug!(url = %socket.url, "Routing WebSocket");
+        self.sockets.insert(socket.id.clone(), socket.clone());
+
+        match handler(WebSocketRoute::new(socket.clone())).await {
+            Ok(()) => {
+                // Sockets connected to the server open when the server accepts
+                if !socket.is_server_connected() {
+                    log_failure(
+                        socket
+                            .dispatch(&SocketCommand::Open {
+                                id: socket.id.clone(),
+                            })
+                            .await,
+                    );
+                }
+            }
+            Err(e) => {
+                warn!(url = %socket.url, error = %e, "WebSocket route handler failed");
+                log_failure(socket.close_page(Some(1011), Some(e.to_string())).await);
+            }
+        }
+    }
+}
+
+fn log_failure(result: Result<(), NetworkError>) {
+    if let Err(e) = result {
+        debug!(error = %e, "Failed to drive routed WebSocket");
+    }
+}
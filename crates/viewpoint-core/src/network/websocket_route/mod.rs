@@ -0,0 +1,450 @@
+//! WebSocket routing.
+//!
+//! Routes intercept WebSocket connections opened by a page before they reach
+//! the network. A route handler can mock the server entirely, or connect to
+//! the real server and inspect or rewrite frames flowing in either direction.
+//!
+//! # Mocking a server
+//!
+//! ```no_run
+//! use viewpoint_core::{Page, WebSocketMessage};
+//!
+//! # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+//! page.route_web_socket("**/ws", |ws| async move {
+//!     let reply = ws.clone();
+//!     ws.on_message(move |message| {
+//!         let reply = reply.clone();
+//!         async move {
+//!             if message.as_text() == Some("ping") {
+//!                 let _ = reply.send("pong").await;
+//!             }
+//!         }
+//!     }).await;
+//!     Ok(())
+//! }).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Proxying to the real server
+//!
+//! Once connected to the server, messages are forwarded automatically in
+//! both directions. Registering `on_message` on either side replaces the
+//! forwarding for that direction.
+//!
+//! ```no_run
+//! use viewpoint_core::{Page, WebSocketMessage};
+//!
+//! # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+//! page.route_web_socket("wss://example.com/feed", |ws| async move {
+//!     let server = ws.connect_to_server().await?;
+//!     let page_side = ws.clone();
+//!     server.on_message(move |message| {
+//!         let page_side = page_side.clone();
+//!         async move {
+//!             let rewritten = match message {
+//!                 WebSocketMessage::Text(text) => text.replace("price", "cost").into(),
+//!                 other => other,
+//!             };
+//!             let _ = page_side.send(rewritten).await;
+//!         }
+//!     }).await;
+//!     Ok(())
+//! }).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod manager;
+mod protocol;
+
+pub(crate) use manager::{WebSocketRouteManager, WebSocketRouteRegistry};
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tokio::sync::RwLock;
+use viewpoint_cdp::CdpConnection;
+use viewpoint_cdp::protocol::runtime::{EvaluateParams, ExecutionContextId};
+
+use super::websocket_route_script::WEBSOCKET_ROUTE_DISPATCH;
+use crate::error::NetworkError;
+use protocol::{SocketCommand, encode_message};
+
+/// A message sent over a routed WebSocket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebSocketMessage {
+    /// A text frame.
+    Text(String),
+    /// A binary frame.
+    Binary(Vec<u8>),
+}
+
+impl WebSocketMessage {
+    /// Get the text of a text message.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            Self::Binary(_) => None,
+        }
+    }
+
+    /// Get the raw bytes of the message.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Text(text) => text.as_bytes(),
+            Self::Binary(bytes) => bytes,
+        }
+    }
+
+    /// Check if this is a binary message.
+    pub fn is_binary(&self) -> bool {
+        matches!(self, Self::Binary(_))
+    }
+}
+
+impl From<&str> for WebSocketMessage {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for WebSocketMessage {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Vec<u8>> for WebSocketMessage {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Binary(bytes)
+    }
+}
+
+impl From<&[u8]> for WebSocketMessage {
+    fn from(bytes: &[u8]) -> Self {
+        Self::Binary(bytes.to_vec())
+    }
+}
+
+/// Handler for messages arriving on one side of a routed WebSocket.
+pub type WebSocketMessageHandler =
+    Arc<dyn Fn(WebSocketMessage) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
+/// Handler for one side of a routed WebSocket closing, called with the close code and reason.
+pub type WebSocketCloseHandler = Arc<
+    dyn Fn(Option<u16>, Option<String>) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync,
+>;
+
+/// Handlers registered for one side of a routed WebSocket.
+#[derive(Default)]
+struct SideHandlers {
+    message: RwLock<Option<WebSocketMessageHandler>>,
+    close: RwLock<Option<WebSocketCloseHandler>>,
+}
+
+/// Shared state of a WebSocket intercepted by a route.
+pub(crate) struct RoutedSocket {
+    /// Socket ID assigned by the mock.
+    id: String,
+    /// The WebSocket URL.
+    url: String,
+    /// Subprotocols requested by the page.
+    protocols: Vec<String>,
+    /// Execution context the socket was created in.
+    execution_context_id: ExecutionContextId,
+    /// CDP connection.
+    connection: Arc<CdpConnection>,
+    /// Session ID.
+    session_id: String,
+    /// Whether `connect_to_server()` was called.
+    server_connected: AtomicBool,
+    /// Handlers for messages and close coming from the page.
+    page: SideHandlers,
+    /// Handlers for messages and close coming from the server.
+    server: SideHandlers,
+}
+
+impl RoutedSocket {
+    fn new(
+        id: String,
+        url: String,
+        protocols: Vec<String>,
+        execution_context_id: ExecutionContextId,
+        connection: Arc<CdpConnection>,
+        session_id: String,
+    ) -> Self {
+        Self {
+            id,
+            url,
+            protocols,
+            execution_context_id,
+            connection,
+            session_id,
+            server_connected: AtomicBool::new(false),
+            page: SideHandlers::default(),
+            server: SideHandlers::default(),
+        }
+    }
+
+    fn is_server_connected(&self) -> bool {
+        self.server_connected.load(Ordering::SeqCst)
+    }
+
+    /// Run a command against the mock in the socket's execution context.
+    async fn dispatch(&self, command: &SocketCommand) -> Result<(), NetworkError> {
+        let payload = serde_json::to_string(command).expect("socket commands serialize to JSON");
+        self.connection
+            .send_command::<_, serde_json::Value>(
+                "Runtime.evaluate",
+                Some(EvaluateParams {
+                    expression: format!("window.{WEBSOCKET_ROUTE_DISPATCH}({payload})"),
+                    object_group: None,
+                    include_command_line_api: None,
+                    silent: Some(true),
+                    context_id: Some(self.execution_context_id),
+                    return_by_value: Some(true),
+                    await_promise: Some(false),
+                }),
+                Some(&self.session_id),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn send_to_page(&self, message: &WebSocketMessage) -> Result<(), NetworkError> {
+        let (data, is_base64) = encode_message(message);
+        self.dispatch(&SocketCommand::Message {
+            id: self.id.clone(),
+            data,
+            is_base64,
+        })
+        .await
+    }
+
+    async fn send_to_server(&self, message: &WebSocketMessage) -> Result<(), NetworkError> {
+        let (data, is_base64) = encode_message(message);
+        self.dispatch(&SocketCommand::ServerSend {
+            id: self.id.clone(),
+            data,
+            is_base64,
+        })
+        .await
+    }
+
+    async fn close_page(
+        &self,
+        code: Option<u16>,
+        reason: Option<String>,
+    ) -> Result<(), NetworkError> {
+        self.dispatch(&SocketCommand::Close {
+            id: self.id.clone(),
+            code,
+            reason,
+        })
+        .await
+    }
+
+    async fn close_server(
+        &self,
+        code: Option<u16>,
+        reason: Option<String>,
+    ) -> Result<(), NetworkError> {
+        self.dispatch(&SocketCommand::ServerClose {
+            id: self.id.clone(),
+            code,
+            reason,
+        })
+        .await
+    }
+}
+
+async fn set_message_handler<F, Fut>(side: &SideHandlers, handler: F)
+where
+    F: Fn(WebSocketMessage) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let handler: WebSocketMessageHandler = Arc::new(move |message| Box::pin(handler(message)));
+    *side.message.write().await = Some(handler);
+}
+
+async fn set_close_handler<F, Fut>(side: &SideHandlers, handler: F)
+where
+    F: Fn(Option<u16>, Option<String>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let handler: WebSocketCloseHandler =
+        Arc::new(move |code, reason| Box::pin(handler(code, reason)));
+    *side.close.write().await = Some(handler);
+}
+
+/// A WebSocket connection intercepted by `route_web_socket()`.
+///
+/// This is the page side of the connection: `send()` delivers messages to
+/// the page and `on_message()` receives what the page sends. The page's
+/// socket opens when the route handler returns, or when the real server
+/// accepts the connection if `connect_to_server()` was called.
+#[derive(Clone)]
+pub struct WebSocketRoute {
+    socket: Arc<RoutedSocket>,
+}
+
+impl std::fmt::Debug for WebSocketRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocketRoute")
+            .field("id", &self.socket.id)
+            .field("url", &self.socket.url)
+            .field("server_connected", &self.socket.is_server_connected())
+            .finish()
+    }
+}
+
+impl WebSocketRoute {
+    pub(crate) fn new(socket: Arc<RoutedSocket>) -> Self {
+        Self { socket }
+    }
+
+    /// Get the WebSocket URL.
+    pub fn url(&self) -> &str {
+        &self.socket.url
+    }
+
+    /// Get the subprotocols requested by the page.
+    pub fn protocols(&self) -> &[String] {
+        &self.socket.protocols
+    }
+
+    /// Send a message to the page.
+    ///
+    /// Messages sent before the socket opens are delivered once it does.
+    pub async fn send(&self, message: impl Into<WebSocketMessage>) -> Result<(), NetworkError> {
+        self.socket.send_to_page(&message.into()).await
+    }
+
+    /// Close the page's socket with a normal closure.
+    pub async fn close(&self) -> Result<(), NetworkError> {
+        self.socket.close_page(None, None).await
+    }
+
+    /// Close the page's socket with the given close code and reason.
+    pub async fn close_with(&self, code: u16, reason: &str) -> Result<(), NetworkError> {
+        self.socket
+            .close_page(Some(code), Some(reason.to_string()))
+            .await
+    }
+
+    /// Register a handler for messages sent by the page.
+    ///
+    /// This replaces automatic forwarding to the server.
+    pub async fn on_message<F, Fut>(&self, handler: F)
+    where
+        F: Fn(WebSocketMessage) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        set_message_handler(&self.socket.page, handler).await;
+    }
+
+    /// Register a handler for the page closing the socket.
+    ///
+    /// This replaces automatically closing the server connection.
+    pub async fn on_close<F, Fut>(&self, handler: F)
+    where
+        F: Fn(Option<u16>, Option<String>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        set_close_handler(&self.socket.page, handler).await;
+    }
+
+    /// Connect to the real server.
+    ///
+    /// Messages and close events are then forwarded between the page and
+    /// the server unless handlers are registered to intercept them.
+    /// Calling this more than once returns the same server connection.
+    pub async fn connect_to_server(&self) -> Result<WebSocketServerRoute, NetworkError> {
+        if !self.socket.server_connected.swap(true, Ordering::SeqCst) {
+            let result = self
+                .socket
+                .dispatch(&SocketCommand::ConnectServer {
+                    id: self.socket.id.clone(),
+                })
+                .await;
+            if let Err(e) = result {
+                self.socket.server_connected.store(false, Ordering::SeqCst);
+                return Err(e);
+            }
+        }
+        Ok(WebSocketServerRoute {
+            socket: self.socket.clone(),
+        })
+    }
+}
+
+/// The server side of a routed WebSocket, returned by
+/// [`WebSocketRoute::connect_to_server`].
+///
+/// `send()` delivers messages to the real server and `on_message()`
+/// receives what the server sends.
+#[derive(Clone)]
+pub struct WebSocketServerRoute {
+    socket: Arc<RoutedSocket>,
+}
+
+impl std::fmt::Debug for WebSocketServerRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocketServerRoute")
+            .field("id", &self.socket.id)
+            .field("url", &self.socket.url)
+            .finish()
+    }
+}
+
+impl WebSocketServerRoute {
+    /// Get the WebSocket URL.
+    pub fn url(&self) -> &str {
+        &self.socket.url
+    }
+
+    /// Send a message to the server.
+    pub async fn send(&self, message: impl Into<WebSocketMessage>) -> Result<(), NetworkError> {
+        self.socket.send_to_server(&message.into()).await
+    }
+
+    /// Close the server connection with a normal closure.
+    pub async fn close(&self) -> Result<(), NetworkError> {
+        self.socket.close_server(None, None).await
+    }
+
+    /// Close the server connection with the given close code and reason.
+    pub async fn close_with(&self, code: u16, reason: &str) -> Result<(), NetworkError> {
+        self.socket
+            .close_server(Some(code), Some(reason.to_string()))
+            .await
+    }
+
+    /// Register a handler for messages sent by the server.
+    ///
+    /// This replaces automatic forwarding to the page.
+    pub async fn on_message<F, Fut>(&self, handler: F)
+    where
+        F: Fn(WebSocketMessage) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        set_message_handler(&self.socket.server, handler).await;
+    }
+
+    /// Register a handler for the server closing the connection.
+    ///
+    /// This replaces automatically closing the page's socket.
+    pub async fn on_close<F, Fut>(&self, handler: F)
+    where
+        F: Fn(Option<u16>, Option<String>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        set_close_handler(&self.socket.server, handler).await;
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,109 @@
+//! Messages exchanged with the injected WebSocket mock.
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use super::WebSocketMessage;
+use crate::error::NetworkError;
+
+/// An event reported by the mock through the routing binding.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub(super) enum SocketEvent {
+    /// The page constructed a WebSocket.
+    Connect {
+        id: String,
+        url: String,
+        #[serde(default)]
+        protocols: Vec<String>,
+    },
+    /// The page sent a message.
+    #[serde(rename_all = "camelCase")]
+    Send {
+        id: String,
+        data: String,
+        is_base64: bool,
+    },
+    /// The page closed the socket.
+    Close {
+        id: String,
+        code: Option<u16>,
+        reason: Option<String>,
+    },
+    /// The real server sent a message.
+    #[serde(rename_all = "camelCase")]
+    ServerMessage {
+        id: String,
+        data: String,
+        is_base64: bool,
+    },
+    /// The real server connection closed.
+    ServerClose {
+        id: String,
+        code: Option<u16>,
+        reason: Option<String>,
+    },
+}
+
+/// A command sent to the mock through its dispatch function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub(super) enum SocketCommand {
+    /// No route matched; proxy a native WebSocket transparently.
+    Passthrough { id: String },
+    /// Open the page's socket without a server.
+    Open { id: String },
+    /// Connect the routed socket to the real server.
+    ConnectServer { id: String },
+    /// Deliver a message to the page.
+    #[serde(rename_all = "camelCase")]
+    Message {
+        id: String,
+        data: String,
+        is_base64: bool,
+    },
+    /// Close the page's socket.
+    Close {
+        id: String,
+        code: Option<u16>,
+        reason: Option<String>,
+    },
+    /// Send a message to the real server.
+    #[serde(rename_all = "camelCase")]
+    ServerSend {
+        id: String,
+        data: String,
+        is_base64: bool,
+    },
+    /// Close the real server connection.
+    ServerClose {
+        id: String,
+        code: Option<u16>,
+        reason: Option<String>,
+    },
+}
+
+/// Encode a message as `(data, is_base64)` for the mock.
+pub(super) fn encode_message(message: &WebSocketMessage) -> (String, bool) {
+    match message {
+        WebSocketMessage::Text(text) => (text.clone(), false),
+        WebSocketMessage::Binary(bytes) => (
+            base64::engine::general_purpose::STANDARD.encode(bytes),
+            true,
+        ),
+    }
+}
+
+/// Decode a message reported by the mock.
+pub(super) fn decode_message(
+    data: String,
+    is_base64: bool,
+) -> Result<WebSocketMessage, NetworkError> {
+    if !is_base64 {
+        return Ok(WebSocketMessage::Text(data));
+    }
+    base64::engine::general_purpose::STANDARD
+        .decode(data)
+        .map(WebSocketMessage::Binary)
+        .map_err(|e| NetworkError::InvalidResponse(format!("invalid WebSocket payload: {e}")))
+}
@@ -0,0 +1,128 @@
+use serde_json::json;
+
+use super::protocol::{SocketCommand, SocketEvent, decode_message, encode_message};
+use super::*;
+
+#[test]
+fn test_message_conversions() {
+    let text = WebSocketMessage::from("hello");
+    assert_eq!(text.as_text(), Some("hello"));
+    assert_eq!(text.as_bytes(), b"hello");
+    assert!(!text.is_binary());
+
+    let binary = WebSocketMessage::from(vec![0u8, 159, 146, 150]);
+    assert_eq!(binary.as_text(), None);
+    assert_eq!(binary.as_bytes(), &[0, 159, 146, 150]);
+    assert!(binary.is_binary());
+}
+
+#[test]
+fn test_message_wire_roundtrip() {
+    for message in [
+        WebSocketMessage::Text("{\"op\":\"ping\"}".to_string()),
+        WebSocketMessage::Binary(vec![0, 1, 2, 254, 255]),
+    ] {
+        let (data, is_base64) = encode_message(&message);
+        assert_eq!(is_base64, message.is_binary());
+        assert_eq!(decode_message(data, is_base64).unwrap(), message);
+    }
+}
+
+#[test]
+fn test_decode_invalid_base64() {
+    let result = decode_message("not base64!".to_string(), true);
+    assert!(matches!(result, Err(NetworkError::InvalidResponse(_))));
+}
+
+#[test]
+fn test_parse_socket_events() {
+    let connect: SocketEvent =
+        serde_json::from_value(json!({ "type": "connect", "id": "1", "url": "ws://a/ws" }))
+            .unwrap();
+    assert_eq!(
+        connect,
+        SocketEvent::Connect {
+            id: "1".to_string(),
+            url: "ws://a/ws".to_string(),
+            protocols: vec![],
+        }
+    );
+
+    let send: SocketEvent = serde_json::from_value(
+        json!({ "type": "send", "id": "1", "data": "AAE=", "isBase64": true }),
+    )
+    .unwrap();
+    assert_eq!(
+        send,
+        SocketEvent::Send {
+            id: "1".to_string(),
+            data: "AAE=".to_string(),
+            is_base64: true,
+        }
+    );
+
+    let close: SocketEvent = serde_json::from_value(
+        json!({ "type": "serverClose", "id": "2", "code": 1000, "reason": "" }),
+    )
+    .unwrap();
+    assert_eq!(
+        close,
+        SocketEvent::ServerClose {
+            id: "2".to_string(),
+            code: Some(1000),
+            reason: Some(String::new()),
+        }
+    );
+}
+
+#[test]
+fn test_serialize_socket_commands() {
+    let message = SocketCommand::ServerSend {
+        id: "3".to_string(),
+        data: "hi".to_string(),
+        is_base64: false,
+    };
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({ "type": "serverSend", "id": "3", "data": "hi", "isBase64": false })
+    );
+
+    let close = SocketCommand::Close {
+        id: "3".to_string(),
+        code: Some(4000),
+        reason: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&close).unwrap(),
+        json!({ "type": "close", "id": "3", "code": 4000, "reason": null })
+    );
+}
+
+#[tokio::test]
+async fn test_registry_matching_and_unroute() {
+    let registry = WebSocketRouteRegistry::new();
+    assert!(registry.is_empty().await);
+    assert!(registry.find("ws://localhost/chat").await.is_none());
+
+    registry
+        .route("**/chat", |_route| async { Ok(()) })
+        .await
+        .unwrap();
+    registry
+        .route("ws://localhost/**", |_route| async {
+            Err(NetworkError::Aborted)
+        })
+        .await
+        .unwrap();
+
+    assert!(registry.find("ws://localhost/feed").await.is_some());
+    assert!(registry.find("wss://example.com/chat").await.is_some());
+    assert!(registry.find("wss://example.com/feed").await.is_none());
+
+    registry.unroute("ws://localhost/**").await;
+    assert!(registry.find("ws://localhost/feed").await.is_none());
+    assert!(registry.find("ws://localhost/chat").await.is_some());
+
+    registry.unroute_all().await;
+    assert!(registry.is_empty().await);
+}
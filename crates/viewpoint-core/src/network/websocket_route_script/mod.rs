@@ -0,0 +1,287 @@
+//! WebSocket routing JavaScript library.
+//!
+//! This module contains the JavaScript code that gets injected into pages
+//! to replace `window.WebSocket` with a mock that reports connections to
+//! Viewpoint and lets route handlers drive both ends of the connection.
+
+/// Name of the CDP binding the mock uses to report socket events.
+pub const WEBSOCKET_ROUTE_BINDING: &str = "__viewpointWebSocketRoute";
+
+/// Name of the global function Viewpoint calls to drive mocked sockets.
+pub const WEBSOCKET_ROUTE_DISPATCH: &str = "__viewpointWebSocketRouteDispatch";
+
+/// JavaScript code for the WebSocket routing mock.
+///
+/// Every socket the page opens is reported with a `connect` event. Viewpoint
+/// answers with `passthrough` when no route matches, in which case the mock
+/// transparently proxies a native WebSocket. Otherwise the route handler
+/// decides when the socket opens and which messages each side receives.
+pub const WEBSOCKET_ROUTE_SCRIPT: &str = r#"
+(function() {
+    // Only install once per document
+    if (window.__viewpointWebSocketRouteDispatch) return;
+
+    const binding = window.__viewpointWebSocketRoute;
+    if (typeof binding !== 'function') return;
+
+    const NativeWebSocket = window.WebSocket;
+    const sockets = new Map(); // id -> MockWebSocket
+    let lastId = 0;
+
+    function notify(payload) {
+        binding(JSON.stringify(payload));
+    }
+
+    function bytesToBase64(bytes) {
+        let binary = '';
+        for (let i = 0; i < bytes.length; i += 0x8000) {
+            binary += String.fromCharCode.apply(null, bytes.subarray(i, i + 0x8000));
+        }
+        return btoa(binary);
+    }
+
+    function base64ToBytes(data) {
+        const binary = atob(data);
+        const bytes = new Uint8Array(binary.length);
+        for (let i = 0; i < binary.length; i++) {
+            bytes[i] = binary.charCodeAt(i);
+        }
+        return bytes;
+    }
+
+    // Serialize WebSocket data into { data, isBase64 }
+    async function encode(data) {
+        if (typeof data === 'string') return { data, isBase64: false };
+        if (data instanceof Blob) {
+            return { data: bytesToBase64(new Uint8Array(await data.arrayBuffer())), isBase64: true };
+        }
+        if (ArrayBuffer.isView(data)) {
+            return {
+                data: bytesToBase64(new Uint8Array(data.buffer, data.byteOffset, data.byteLength)),
+                isBase64: true,
+            };
+        }
+        if (data instanceof ArrayBuffer) {
+            return { data: bytesToBase64(new Uint8Array(data)), isBase64: true };
+        }
+        return { data: String(data), isBase64: false };
+    }
+
+    class MockWebSocket extends EventTarget {
+        constructor(url, protocols) {
+            super();
+            const resolved = new URL(url, window.location.href);
+            if (resolved.protocol === 'http:') resolved.protocol = 'ws:';
+            if (resolved.protocol === 'https:') resolved.protocol = 'wss:';
+            if (resolved.protocol !== 'ws:' && resolved.protocol !== 'wss:') {
+                throw new DOMException(
+                    "Failed to construct 'WebSocket': The URL's scheme must be either 'http', 'https', 'ws', or 'wss'.",
+                    'SyntaxError'
+                );
+            }
+            resolved.hash = '';
+
+            this._id = String(++lastId);
+            this._url = resolved.href;
+            this._protocols = protocols === undefined
+                ? []
+                : (Array.isArray(protocols) ? protocols.map(String) : [String(protocols)]);
+            this._readyState = 0;
+            this._protocol = '';
+            this._extensions = '';
+            this._binaryType = 'blob';
+            this._passthrough = false;
+            this._server = null;
+            this._serverClosed = false;
+            this._pending = []; // messages delivered before the socket opened
+            this._outgoing = Promise.resolve();
+            this._incoming = Promise.resolve();
+
+            sockets.set(this._id, this);
+            notify({ type: 'connect', id: this._id, url: this._url, protocols: this._protocols });
+        }
+
+        get url() { return this._url; }
+        get readyState() { return this._readyState; }
+        get protocol() { return this._protocol; }
+        get extensions() { return this._extensions; }
+        get bufferedAmount() { return 0; }
+        get binaryType() { return this._binaryType; }
+        set binaryType(value) {
+            if (value === 'blob' || value === 'arraybuffer') this._binaryType = value;
+        }
+
+        send(data) {
+            if (this._readyState === 0) {
+                throw new DOMException(
+                    "Failed to execute 'send' on 'WebSocket': Still in CONNECTING state.",
+                    'InvalidStateError'
+                );
+            }
+            if (this._readyState !== 1) return;
+            if (this._passthrough) {
+                this._server.send(data);
+                return;
+            }
+            const id = this._id;
+            this._outgoing = this._outgoing
+                .then(() => encode(data))
+                .then(message => notify({ type: 'send', id, ...message }));
+        }
+
+        close(code, reason) {
+            if (code !== undefined && code !== 1000 && (code < 3000 || code > 4999)) {
+                throw new DOMException(
+                    "Failed to execute 'close' on 'WebSocket': The close code must be either 1000, or between 3000 and 4999.",
+                    'InvalidAccessError'
+                );
+            }
+            if (this._readyState === 2 || this._readyState === 3) return;
+            if (this._passthrough) {
+                this._readyState = 2;
+                this._server.close(code, reason);
+                return;
+            }
+            const id = this._id;
+            const payload = { type: 'close', id, code: code ?? null, reason: reason ?? null };
+            this._outgoing = this._outgoing.then(() => notify(payload));
+            this._finishClose(code ?? 1005, reason ?? '', true);
+        }
+
+        _open(protocol, extensions) {
+            if (this._readyState !== 0) return;
+            this._readyState = 1;
+            this._protocol = protocol;
+            this._extensions = extensions;
+            this.dispatchEvent(new Event('open'));
+            const pending = this._pending;
+            this._pending = [];
+            for (const data of pending) this._deliver(data);
+        }
+
+        // Deliver a string or ArrayBuffer to the page
+        _deliver(data) {
+            if (this._readyState === 0) {
+                this._pending.push(data);
+                return;
+            }
+            if (this._readyState !== 1) return;
+            const payload = typeof data === 'string' || this._binaryType === 'arraybuffer'
+                ? data
+                : new Blob([data]);
+            this.dispatchEvent(new MessageEvent('message', {
+                data: payload,
+                origin: new URL(this._url).origin,
+            }));
+        }
+
+        _finishClose(code, reason, wasClean) {
+            if (this._readyState === 3) return;
+            const failed = this._readyState === 0 && !wasClean;
+            this._readyState = 3;
+            if (failed) this.dispatchEvent(new Event('error'));
+            this.dispatchEvent(new CloseEvent('close', { code, reason, wasClean }));
+            this._release();
+        }
+
+        _release() {
+            if (this._readyState === 3 && (!this._server || this._serverClosed)) {
+                sockets.delete(this._id);
+            }
+        }
+
+        _connectServer(passthrough) {
+            if (this._server) return;
+            const id = this._id;
+            const server = new NativeWebSocket(this._url, this._protocols);
+            server.binaryType = 'arraybuffer';
+            this._passthrough = passthrough;
+            this._server = server;
+
+            server.addEventListener('open', () => {
+                this._open(server.protocol, server.extensions);
+            });
+            server.addEventListener('message', event => {
+                if (passthrough) {
+                    this._deliver(event.data);
+                    return;
+                }
+                this._incoming = this._incoming
+                    .then(() => encode(event.data))
+                    .then(message => notify({ type: 'serverMessage', id, ...message }));
+            });
+            server.addEventListener('error', () => {
+                if (passthrough && this._readyState === 1) this.dispatchEvent(new Event('error'));
+            });
+            server.addEventListener('close', event => {
+                this._serverClosed = true;
+                if (passthrough) {
+                    this._finishClose(event.code, event.reason, event.wasClean);
+                    return;
+                }
+                const payload = { type: 'serverClose', id, code: event.code, reason: event.reason };
+                this._incoming = this._incoming.then(() => notify(payload));
+                this._release();
+            });
+        }
+    }
+
+    for (const [name, value] of [['CONNECTING', 0], ['OPEN', 1], ['CLOSING', 2], ['CLOSED', 3]]) {
+        Object.defineProperty(MockWebSocket, name, { value, enumerable: true });
+        Object.defineProperty(MockWebSocket.prototype, name, { value, enumerable: true });
+    }
+
+    // Event handler attributes (onopen, onmessage, ...) backed by listeners
+    for (const type of ['open', 'message', 'error', 'close']) {
+        const key = '_on' + type;
+        Object.defineProperty(MockWebSocket.prototype, 'on' + type, {
+            get() { return this[key] ?? null; },
+            set(handler) {
+                if (this[key]) this.removeEventListener(type, this[key]);
+                this[key] = typeof handler === 'function' ? handler : null;
+                if (this[key]) this.addEventListener(type, this[key]);
+            },
+        });
+    }
+
+    Object.defineProperty(MockWebSocket, 'name', { value: 'WebSocket' });
+
+    window.__viewpointWebSocketRouteDispatch = function(command) {
+        const socket = sockets.get(command.id);
+        if (!socket) return;
+        switch (command.type) {
+            case 'passthrough':
+                socket._connectServer(true);
+                break;
+            case 'open':
+                socket._open('', '');
+                break;
+            case 'connectServer':
+                socket._connectServer(false);
+                break;
+            case 'message':
+                socket._deliver(command.isBase64 ? base64ToBytes(command.data).buffer : command.data);
+                break;
+            case 'close':
+                socket._finishClose(command.code ?? 1005, command.reason ?? '', true);
+                break;
+            case 'serverSend':
+                if (socket._server && socket._server.readyState === 1) {
+                    socket._server.send(command.isBase64 ? base64ToBytes(command.data) : command.data);
+                }
+                break;
+            case 'serverClose':
+                if (socket._server) {
+                    try {
+                        socket._server.close(command.code ?? undefined, command.reason ?? undefined);
+                    } catch (e) {
+                        socket._server.close();
+                    }
+                }
+                break;
+        }
+    };
+
+    window.WebSocket = MockWebSocket;
+})();
+"#;
@@ -0,0 +1,293 @@
+//! Automated accessibility audits.
+
+use std::fmt;
+
+use serde::Deserialize;
+use tracing::{debug, instrument};
+use viewpoint_cdp::protocol::accessibility::{AXNode, AXValue};
+use viewpoint_cdp::protocol::dom::{DescribeNodeParams, DescribeNodeResult, NodeDescription};
+
+use super::audit_js::audit_js;
+use crate::error::PageError;
+use crate::page::Page;
+
+/// Roles that must have an accessible name.
+const NAMED_ROLES: &[&str] = &[
+    "button",
+    "checkbox",
+    "combobox",
+    "image",
+    "img",
+    "link",
+    "listbox",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "searchbox",
+    "slider",
+    "spinbutton",
+    "switch",
+    "tab",
+    "textbox",
+    "treeitem",
+];
+
+/// Attributes included when describing an element.
+const DESCRIBED_ATTRIBUTES: &[&str] = &["id", "class", "role", "name", "type", "href", "src"];
+
+/// An accessibility audit rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuditRule {
+    /// Interactive elements and images must have an accessible name.
+    MissingName,
+    /// ARIA roles and attributes must be valid and reference existing elements.
+    InvalidAria,
+    /// Text must have sufficient contrast against its background (WCAG AA).
+    ColorContrast,
+    /// Interactive elements must be keyboard focusable in the natural order.
+    FocusOrder,
+}
+
+impl AuditRule {
+    /// All audit rules.
+    pub const ALL: [AuditRule; 4] = [
+        AuditRule::MissingName,
+        AuditRule::InvalidAria,
+        AuditRule::ColorContrast,
+        AuditRule::FocusOrder,
+    ];
+
+    /// The rule's identifier, e.g. `"color-contrast"`.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::MissingName => "missing-name",
+            Self::InvalidAria => "invalid-aria",
+            Self::ColorContrast => "color-contrast",
+            Self::FocusOrder => "focus-order",
+        }
+    }
+
+    /// Look up a rule by its identifier.
+    pub(crate) fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|rule| rule.id() == id)
+    }
+}
+
+impl fmt::Display for AuditRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// A single accessibility problem found by an audit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditViolation {
+    /// The rule that was violated.
+    pub rule: AuditRule,
+    /// What is wrong.
+    pub message: String,
+    /// Short description of the offending element, e.g. `<button class="icon">`.
+    pub element: String,
+}
+
+impl fmt::Display for AuditViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}: {}", self.rule, self.element, self.message)
+    }
+}
+
+/// The result of an accessibility audit.
+#[derive(Debug, Clone, Default)]
+pub struct AccessibilityAuditReport {
+    pub(super) violations: Vec<AuditViolation>,
+}
+
+impl AccessibilityAuditReport {
+    /// Whether the audit found no violations.
+    pub fn passed(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// All violations found.
+    pub fn violations(&self) -> &[AuditViolation] {
+        &self.violations
+    }
+
+    /// Violations of a specific rule.
+    pub fn violations_of(&self, rule: AuditRule) -> impl Iterator<Item = &AuditViolation> {
+        self.violations.iter().filter(move |v| v.rule == rule)
+    }
+}
+
+impl fmt::Display for AccessibilityAuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.passed() {
+            return f.write_str("no accessibility violations");
+        }
+        write!(f, "{} accessibility violation(s):", self.violations.len())?;
+        for violation in &self.violations {
+            write!(f, "\n  {violation}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Builder for running an accessibility audit.
+///
+/// Obtain it with [`Accessibility::audit`](super::Accessibility::audit).
+#[derive(Debug)]
+pub struct AccessibilityAuditBuilder<'a> {
+    page: &'a Page,
+    rules: Vec<AuditRule>,
+}
+
+impl<'a> AccessibilityAuditBuilder<'a> {
+    pub(super) fn new(page: &'a Page) -> Self {
+        Self {
+            page,
+            rules: AuditRule::ALL.to_vec(),
+        }
+    }
+
+    /// Only run the given rules. All rules run by default.
+    #[must_use]
+    pub fn rules(mut self, rules: impl IntoIterator<Item = AuditRule>) -> Self {
+        self.rules = rules.into_iter().collect();
+        self
+    }
+
+    /// Skip a rule.
+    #[must_use]
+    pub fn skip(mut self, rule: AuditRule) -> Self {
+        self.rules.retain(|r| *r != rule);
+        self
+    }
+
+    /// Run the audit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page is closed or the audit cannot be evaluated.
+    #[instrument(level = "debug", skip(self), fields(rules = ?self.rules))]
+    pub async fn run(self) -> Result<AccessibilityAuditReport, PageError> {
+        if self.page.is_closed() {
+            return Err(PageError::Closed);
+        }
+
+        let mut violations = Vec::new();
+
+        if self.rules.contains(&AuditRule::MissingName) {
+            let nodes = super::full_ax_tree(self.page).await?;
+            for node in unnamed_nodes(&nodes) {
+                let element = match node.backend_dom_node_id {
+                    Some(id) => describe_backend_node(self.page, id).await,
+                    None => None,
+                }
+                .unwrap_or_else(|| format!("<{}>", role_of(node)));
+                violations.push(AuditViolation {
+                    rule: AuditRule::MissingName,
+                    message: format!(
+                        "Element with role \"{}\" has no accessible name",
+                        role_of(node)
+                    ),
+                    element,
+                });
+            }
+        }
+
+        let dom_rules: Vec<&str> = self
+            .rules
+            .iter()
+            .filter(|r| **r != AuditRule::MissingName)
+            .map(AuditRule::id)
+            .collect();
+        if !dom_rules.is_empty() {
+            let rules_json = serde_json::to_string(&dom_rules)
+                .map_err(|e| PageError::EvaluationFailed(e.to_string()))?;
+            let found: Vec<JsViolation> = self
+                .page
+                .evaluate(&format!("({})({rules_json})", audit_js()))
+                .await?;
+            violations.extend(found.into_iter().filter_map(JsViolation::into_violation));
+        }
+
+        debug!(count = violations.len(), "Accessibility audit complete");
+        Ok(AccessibilityAuditReport { violations })
+    }
+}
+
+/// A violation reported by the audit script.
+#[derive(Debug, Deserialize)]
+struct JsViolation {
+    rule: String,
+    message: String,
+    element: String,
+}
+
+impl JsViolation {
+    fn into_violation(self) -> Option<AuditViolation> {
+        Some(AuditViolation {
+            rule: AuditRule::from_id(&self.rule)?,
+            message: self.message,
+            element: self.element,
+        })
+    }
+}
+
+/// Get the role of an AX node.
+fn role_of(node: &AXNode) -> &str {
+    node.role.as_ref().and_then(AXValue::as_str).unwrap_or("")
+}
+
+/// Find nodes whose role requires an accessible name but that have none.
+pub(super) fn unnamed_nodes(nodes: &[AXNode]) -> impl Iterator<Item = &AXNode> {
+    nodes.iter().filter(|node| {
+        !node.ignored
+            && NAMED_ROLES.contains(&role_of(node))
+            && node
+                .name
+                .as_ref()
+                .and_then(AXValue::as_str)
+                .is_none_or(|name| name.trim().is_empty())
+    })
+}
+
+/// Describe a DOM node by its backend ID.
+async fn describe_backend_node(page: &Page, backend_node_id: i32) -> Option<String> {
+    let result: DescribeNodeResult = page
+        .connection()
+        .send_command(
+            "DOM.describeNode",
+            Some(DescribeNodeParams {
+                node_id: None,
+                backend_node_id: Some(backend_node_id),
+                object_id: None,
+                depth: Some(0),
+                pierce: None,
+            }),
+            Some(page.session_id()),
+        )
+        .await
+        .ok()?;
+    Some(describe_element(&result.node))
+}
+
+/// Describe an element like `<button id="save" class="icon">`.
+pub(super) fn describe_element(node: &NodeDescription) -> String {
+    let mut text = format!("<{}", node.local_name);
+    let attributes = node.attributes.as_deref().unwrap_or_default();
+    for name in DESCRIBED_ATTRIBUTES {
+        let value = attributes
+            .chunks_exact(2)
+            .find(|pair| pair[0] == *name)
+            .map(|pair| pair[1].as_str());
+        if let Some(value) = value.filter(|v| !v.is_empty()) {
+            let value: String = value.chars().take(40).collect();
+            text.push_str(&format!(" {name}=\"{value}\""));
+        }
+    }
+    text.push('>');
+    text
+}
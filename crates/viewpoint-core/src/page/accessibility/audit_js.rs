@@ -0,0 +1,234 @@
+//! JavaScript for the DOM-based accessibility audit rules.
+
+use viewpoint_js::js;
+
+/// JavaScript function running the DOM-based audit rules.
+///
+/// The function takes an array of rule IDs (`"invalid-aria"`,
+/// `"color-contrast"`, `"focus-order"`) and returns an array of
+/// `{ rule, message, element }` violations, where `element` is a short
+/// description of the offending element.
+pub(super) fn audit_js() -> &'static str {
+    js! {
+        (function(rules) {
+            const ROLES = new Set([
+                "alert", "alertdialog", "application", "article", "banner", "blockquote",
+                "button", "caption", "cell", "checkbox", "code", "columnheader", "combobox",
+                "complementary", "contentinfo", "definition", "deletion", "dialog", "directory",
+                "document", "emphasis", "feed", "figure", "form", "generic", "grid", "gridcell",
+                "group", "heading", "img", "image", "insertion", "link", "list", "listbox",
+                "listitem", "log", "main", "mark", "marquee", "math", "menu", "menubar",
+                "menuitem", "menuitemcheckbox", "menuitemradio", "meter", "navigation", "none",
+                "note", "option", "paragraph", "presentation", "progressbar", "radio",
+                "radiogroup", "region", "row", "rowgroup", "rowheader", "scrollbar", "search",
+                "searchbox", "separator", "slider", "spinbutton", "status", "strong",
+                "subscript", "superscript", "switch", "tab", "table", "tablist", "tabpanel",
+                "term", "textbox", "time", "timer", "toolbar", "tooltip", "tree", "treegrid",
+                "treeitem"
+            ]);
+            const ATTRIBUTES = new Set([
+                "aria-activedescendant", "aria-atomic", "aria-autocomplete", "aria-braillelabel",
+                "aria-brailleroledescription", "aria-busy", "aria-checked", "aria-colcount",
+                "aria-colindex", "aria-colindextext", "aria-colspan", "aria-controls",
+                "aria-current", "aria-describedby", "aria-description", "aria-details",
+                "aria-disabled", "aria-dropeffect", "aria-errormessage", "aria-expanded",
+                "aria-flowto", "aria-grabbed", "aria-haspopup", "aria-hidden", "aria-invalid",
+                "aria-keyshortcuts", "aria-label", "aria-labelledby", "aria-level", "aria-live",
+                "aria-modal", "aria-multiline", "aria-multiselectable", "aria-orientation",
+                "aria-owns", "aria-placeholder", "aria-posinset", "aria-pressed",
+                "aria-readonly", "aria-relevant", "aria-required", "aria-roledescription",
+                "aria-rowcount", "aria-rowindex", "aria-rowindextext", "aria-rowspan",
+                "aria-selected", "aria-setsize", "aria-sort", "aria-valuemax", "aria-valuemin",
+                "aria-valuenow", "aria-valuetext"
+            ]);
+            const IDREF_ATTRIBUTES = [
+                "aria-activedescendant", "aria-controls", "aria-describedby", "aria-details",
+                "aria-errormessage", "aria-flowto", "aria-labelledby", "aria-owns"
+            ];
+            const INTERACTIVE_ROLES = [
+                "button", "checkbox", "combobox", "link", "radio", "slider", "spinbutton",
+                "switch", "textbox"
+            ];
+            const FOCUSABLE = "a[href], button, input, select, textarea, summary, iframe, [tabindex], [contenteditable=\"true\"]";
+
+            const violations = [];
+
+            function describe(el) {
+                let text = "<" + el.tagName.toLowerCase();
+                for (const name of ["id", "class", "role", "name", "type", "href", "src"]) {
+                    const value = el.getAttribute(name);
+                    if (value) {
+                        text += " " + name + "=\"" + value.slice(0, 40) + "\"";
+                    }
+                }
+                return text + ">";
+            }
+
+            function report(rule, el, message) {
+                violations.push({ rule: rule, message: message, element: describe(el) });
+            }
+
+            function isVisible(el) {
+                const style = getComputedStyle(el);
+                if (style.visibility === "hidden" || style.display === "none") {
+                    return false;
+                }
+                const rect = el.getBoundingClientRect();
+                return rect.width > 0 && rect.height > 0;
+            }
+
+            function isFocusable(el) {
+                return el.matches(FOCUSABLE) && !el.disabled && el.getAttribute("tabindex") !== "-1";
+            }
+
+            function checkAria() {
+                for (const el of document.querySelectorAll("*")) {
+                    const role = el.getAttribute("role");
+                    if (role !== null) {
+                        const tokens = role.trim().split(" ").filter(t => t);
+                        if (tokens.length === 0 || !tokens.some(t => ROLES.has(t))) {
+                            report("invalid-aria", el, "Invalid ARIA role \"" + role + "\"");
+                        }
+                    }
+                    for (const attr of el.getAttributeNames()) {
+                        if (attr.startsWith("aria-") && !ATTRIBUTES.has(attr)) {
+                            report("invalid-aria", el, "Unknown ARIA attribute \"" + attr + "\"");
+                        }
+                    }
+                    for (const attr of IDREF_ATTRIBUTES) {
+                        const value = el.getAttribute(attr);
+                        if (value === null) {
+                            continue;
+                        }
+                        for (const id of value.trim().split(" ").filter(t => t)) {
+                            if (!document.getElementById(id)) {
+                                report("invalid-aria", el, attr + " references missing id \"" + id + "\"");
+                            }
+                        }
+                    }
+                    if (el.getAttribute("aria-hidden") === "true" && isFocusable(el)) {
+                        report("invalid-aria", el, "Focusable element is hidden with aria-hidden");
+                    }
+                }
+            }
+
+            function parseColor(value) {
+                const parts = value.slice(value.indexOf("(") + 1, value.indexOf(")"))
+                    .split(",")
+                    .map(p => parseFloat(p));
+                if (parts.length < 3 || parts.some(p => isNaN(p))) {
+                    return null;
+                }
+                return { r: parts[0], g: parts[1], b: parts[2], a: parts.length > 3 ? parts[3] : 1 };
+            }
+
+            function blend(top, bottom) {
+                const a = top.a + bottom.a * (1 - top.a);
+                if (a === 0) {
+                    return { r: 0, g: 0, b: 0, a: 0 };
+                }
+                const mix = (t, b) => (t * top.a + b * bottom.a * (1 - top.a)) / a;
+                return { r: mix(top.r, bottom.r), g: mix(top.g, bottom.g), b: mix(top.b, bottom.b), a: a };
+            }
+
+            function background(el) {
+                const layers = [];
+                for (let node = el; node; node = node.parentElement) {
+                    const style = getComputedStyle(node);
+                    if (style.backgroundImage !== "none") {
+                        return null;
+                    }
+                    const color = parseColor(style.backgroundColor);
+                    if (color && color.a > 0) {
+                        layers.push(color);
+                        if (color.a >= 1) {
+                            break;
+                        }
+                    }
+                }
+                let result = { r: 255, g: 255, b: 255, a: 1 };
+                for (const layer of layers.reverse()) {
+                    result = blend(layer, result);
+                }
+                return result;
+            }
+
+            function luminance(color) {
+                const channel = c => {
+                    const v = c / 255;
+                    return v <= 0.03928 ? v / 12.92 : Math.pow((v + 0.055) / 1.055, 2.4);
+                };
+                return 0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b);
+            }
+
+            function checkContrast() {
+                for (const el of document.body ? document.body.querySelectorAll("*") : []) {
+                    const hasText = Array.from(el.childNodes)
+                        .some(n => n.nodeType === Node.TEXT_NODE && n.textContent.trim());
+                    if (!hasText || el.disabled || !isVisible(el)) {
+                        continue;
+                    }
+                    const style = getComputedStyle(el);
+                    const bg = background(el);
+                    const fg = parseColor(style.color);
+                    if (!bg || !fg) {
+                        continue;
+                    }
+                    const text = blend(fg, bg);
+                    const l1 = luminance(text);
+                    const l2 = luminance(bg);
+                    const ratio = (Math.max(l1, l2) + 0.05) / (Math.min(l1, l2) + 0.05);
+                    const size = parseFloat(style.fontSize);
+                    const bold = parseInt(style.fontWeight, 10) >= 700;
+                    const required = size >= 24 || (bold && size >= 18.66) ? 3 : 4.5;
+                    if (ratio < required) {
+                        report(
+                            "color-contrast",
+                            el,
+                            "Contrast ratio " + ratio.toFixed(2) + ":1 is below " + required + ":1"
+                        );
+                    }
+                }
+            }
+
+            function checkFocusOrder() {
+                for (const el of document.querySelectorAll("[tabindex]")) {
+                    const tabindex = parseInt(el.getAttribute("tabindex"), 10);
+                    if (tabindex > 0) {
+                        report(
+                            "focus-order",
+                            el,
+                            "Positive tabindex " + tabindex + " overrides the natural focus order"
+                        );
+                    }
+                }
+                for (const el of document.querySelectorAll("[role]")) {
+                    const role = el.getAttribute("role").trim();
+                    if (
+                        INTERACTIVE_ROLES.includes(role) &&
+                        !el.matches(FOCUSABLE) &&
+                        el.getAttribute("aria-disabled") !== "true" &&
+                        isVisible(el)
+                    ) {
+                        report(
+                            "focus-order",
+                            el,
+                            "Element with role \"" + role + "\" cannot receive keyboard focus"
+                        );
+                    }
+                }
+            }
+
+            if (rules.includes("invalid-aria")) {
+                checkAria();
+            }
+            if (rules.includes("color-contrast")) {
+                checkContrast();
+            }
+            if (rules.includes("focus-order")) {
+                checkFocusOrder();
+            }
+            return violations;
+        })
+    }
+}
@@ -0,0 +1,297 @@
+//! Browser-computed accessibility tree and accessibility audits.
+//!
+//! Unlike [`aria_snapshot`](super::Page::aria_snapshot), which computes roles
+//! and names with injected JavaScript, this module reads Chromium's own
+//! accessibility tree through the CDP `Accessibility` domain. This is the
+//! tree assistive technologies actually see.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use viewpoint_core::{AuditRule, Page};
+//!
+//! # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+//! // Browser-computed accessibility tree
+//! let tree = page.accessibility().snapshot().capture().await?;
+//! if let Some(button) = tree.as_ref().and_then(|t| t.find("button", Some("Submit"))) {
+//!     println!("focusable: {:?}", button.property("focusable"));
+//! }
+//!
+//! // Audit the page for common WCAG problems
+//! let report = page.accessibility().audit().skip(AuditRule::ColorContrast).run().await?;
+//! assert!(report.passed(), "{report}");
+//! # Ok(())
+//! # }
+//! ```
+
+mod audit;
+mod audit_js;
+mod node;
+
+use tracing::{debug, instrument};
+use viewpoint_cdp::protocol::accessibility::{
+    AXNode, GetFullAXTreeParams, GetFullAXTreeResult, GetPartialAXTreeParams,
+    GetPartialAXTreeResult, QueryAXTreeParams, QueryAXTreeResult,
+};
+use viewpoint_cdp::protocol::dom::{
+    DescribeNodeParams, DescribeNodeResult, GetDocumentParams, GetDocumentResult,
+};
+
+pub use audit::{AccessibilityAuditBuilder, AccessibilityAuditReport, AuditRule, AuditViolation};
+pub use node::AccessibilityNode;
+
+use super::{Locator, Page};
+use crate::error::{LocatorError, PageError};
+
+/// Accessibility inspection for a page.
+///
+/// Obtain it with [`Page::accessibility`].
+#[derive(Debug)]
+pub struct Accessibility<'a> {
+    page: &'a Page,
+}
+
+impl<'a> Accessibility<'a> {
+    /// Capture the browser's computed accessibility tree.
+    pub fn snapshot(&self) -> AccessibilitySnapshotBuilder<'a, '_> {
+        AccessibilitySnapshotBuilder {
+            page: self.page,
+            interesting_only: true,
+            root: None,
+        }
+    }
+
+    /// Query the accessibility tree by role and accessible name.
+    pub fn query(&self) -> AccessibilityQueryBuilder<'a> {
+        AccessibilityQueryBuilder {
+            page: self.page,
+            role: None,
+            name: None,
+        }
+    }
+
+    /// Get the accessibility node computed for an element.
+    ///
+    /// Returns `None` if the element is not exposed to assistive technology.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be found or the CDP command fails.
+    #[instrument(level = "debug", skip(self, locator))]
+    pub async fn node(
+        &self,
+        locator: &Locator<'_>,
+    ) -> Result<Option<AccessibilityNode>, LocatorError> {
+        let handle = locator.element_handle().await?;
+        let result: GetPartialAXTreeResult = self
+            .page
+            .connection()
+            .send_command(
+                "Accessibility.getPartialAXTree",
+                Some(GetPartialAXTreeParams {
+                    object_id: Some(handle.object_id().to_string()),
+                    fetch_relatives: Some(false),
+                    ..Default::default()
+                }),
+                Some(self.page.session_id()),
+            )
+            .await?;
+
+        Ok(result
+            .nodes
+            .iter()
+            .find(|node| !node.ignored)
+            .map(AccessibilityNode::from_ax_node))
+    }
+
+    /// Audit the page for common accessibility problems.
+    ///
+    /// Checks for missing accessible names, invalid ARIA, insufficient color
+    /// contrast and broken focus order.
+    pub fn audit(&self) -> AccessibilityAuditBuilder<'a> {
+        AccessibilityAuditBuilder::new(self.page)
+    }
+}
+
+/// Builder for capturing an accessibility snapshot.
+#[derive(Debug)]
+pub struct AccessibilitySnapshotBuilder<'a, 'l> {
+    page: &'a Page,
+    interesting_only: bool,
+    root: Option<&'l Locator<'l>>,
+}
+
+impl<'l> AccessibilitySnapshotBuilder<'_, 'l> {
+    /// Whether to prune nodes that are uninteresting to assistive technology,
+    /// such as generic containers and duplicated text. Defaults to `true`.
+    #[must_use]
+    pub fn interesting_only(mut self, interesting_only: bool) -> Self {
+        self.interesting_only = interesting_only;
+        self
+    }
+
+    /// Capture only the subtree of the given element.
+    #[must_use]
+    pub fn root(mut self, locator: &'l Locator<'l>) -> Self {
+        self.root = Some(locator);
+        self
+    }
+
+    /// Capture the snapshot.
+    ///
+    /// Returns `None` if the root element is not exposed to assistive
+    /// technology.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page is closed, the root element cannot be
+    /// found, or the CDP command fails.
+    #[instrument(level = "debug", skip(self), fields(interesting_only = self.interesting_only))]
+    pub async fn capture(self) -> Result<Option<AccessibilityNode>, PageError> {
+        if self.page.is_closed() {
+            return Err(PageError::Closed);
+        }
+
+        let root = match self.root {
+            Some(locator) => Some(backend_node_id(self.page, locator).await?),
+            None => None,
+        };
+
+        let nodes = full_ax_tree(self.page).await?;
+        debug!(count = nodes.len(), "Fetched accessibility tree");
+        Ok(node::build_tree(&nodes, self.interesting_only, root))
+    }
+}
+
+/// Builder for querying the accessibility tree.
+#[derive(Debug)]
+pub struct AccessibilityQueryBuilder<'a> {
+    page: &'a Page,
+    role: Option<String>,
+    name: Option<String>,
+}
+
+impl AccessibilityQueryBuilder<'_> {
+    /// Match nodes with this computed role.
+    #[must_use]
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
+    /// Match nodes with this computed accessible name.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Find all matching nodes in the document.
+    ///
+    /// The returned nodes do not include their children.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page is closed or the CDP command fails.
+    #[instrument(level = "debug", skip(self), fields(role = ?self.role, name = ?self.name))]
+    pub async fn all(self) -> Result<Vec<AccessibilityNode>, PageError> {
+        if self.page.is_closed() {
+            return Err(PageError::Closed);
+        }
+
+        let document: GetDocumentResult = self
+            .page
+            .connection()
+            .send_command(
+                "DOM.getDocument",
+                Some(GetDocumentParams {
+                    depth: Some(0),
+                    pierce: None,
+                }),
+                Some(self.page.session_id()),
+            )
+            .await?;
+
+        let result: QueryAXTreeResult = self
+            .page
+            .connection()
+            .send_command(
+                "Accessibility.queryAXTree",
+                Some(QueryAXTreeParams {
+                    node_id: Some(document.root.node_id),
+                    accessible_name: self.name,
+                    role: self.role,
+                    ..Default::default()
+                }),
+                Some(self.page.session_id()),
+            )
+            .await?;
+
+        Ok(result
+            .nodes
+            .iter()
+            .filter(|node| !node.ignored)
+            .map(AccessibilityNode::from_ax_node)
+            .collect())
+    }
+}
+
+/// Fetch the full accessibility tree of the page's main frame.
+pub(super) async fn full_ax_tree(page: &Page) -> Result<Vec<AXNode>, PageError> {
+    let result: GetFullAXTreeResult = page
+        .connection()
+        .send_command(
+            "Accessibility.getFullAXTree",
+            Some(GetFullAXTreeParams::default()),
+            Some(page.session_id()),
+        )
+        .await?;
+    Ok(result.nodes)
+}
+
+/// Resolve the backend node ID of the element a locator points to.
+async fn backend_node_id(page: &Page, locator: &Locator<'_>) -> Result<i32, PageError> {
+    let handle = locator
+        .element_handle()
+        .await
+        .map_err(|e| PageError::EvaluationFailed(e.to_string()))?;
+    let result: DescribeNodeResult = page
+        .connection()
+        .send_command(
+            "DOM.describeNode",
+            Some(DescribeNodeParams {
+                node_id: None,
+                backend_node_id: None,
+                object_id: Some(handle.object_id().to_string()),
+                depth: Some(0),
+                pierce: None,
+            }),
+            Some(page.session_id()),
+        )
+        .await?;
+    Ok(result.node.backend_node_id)
+}
+
+impl Page {
+    /// Get the accessibility inspector for this page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// let buttons = page.accessibility().query().role("button").all().await?;
+    /// for button in buttons {
+    ///     println!("button: {}", button.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn accessibility(&self) -> Accessibility<'_> {
+        Accessibility { page: self }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,167 @@
+//! Accessibility tree nodes built from the browser's computed AX tree.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use viewpoint_cdp::protocol::accessibility::{AXNode, AXValue};
+use viewpoint_cdp::protocol::dom::BackendNodeId;
+
+/// Roles that only group content and carry no meaning of their own.
+const GENERIC_ROLES: &[&str] = &["generic", "none", "presentation", "LineBreak"];
+
+/// A node in the browser's computed accessibility tree.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AccessibilityNode {
+    /// The computed role, e.g. `"button"` or `"heading"`.
+    pub role: String,
+    /// The computed accessible name.
+    pub name: String,
+    /// The computed accessible description, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The node's value, e.g. the text of a text box.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Other computed properties, e.g. `focusable`, `checked` or `level`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, serde_json::Value>,
+    /// Child nodes.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<AccessibilityNode>,
+    /// Backend ID of the DOM node this node was computed for.
+    #[serde(skip)]
+    pub(crate) backend_node_id: Option<BackendNodeId>,
+}
+
+impl AccessibilityNode {
+    /// Get a property value by name.
+    pub fn property(&self, name: &str) -> Option<&serde_json::Value> {
+        self.properties.get(name)
+    }
+
+    /// Find the first node in this subtree with the given role and,
+    /// optionally, the given accessible name.
+    pub fn find(&self, role: &str, name: Option<&str>) -> Option<&AccessibilityNode> {
+        if self.role == role && name.is_none_or(|name| self.name == name) {
+            return Some(self);
+        }
+        self.children
+            .iter()
+            .find_map(|child| child.find(role, name))
+    }
+
+    /// Iterate over this node and all of its descendants, depth first.
+    pub fn descendants(&self) -> impl Iterator<Item = &AccessibilityNode> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// Convert a single CDP node, without children.
+    pub(crate) fn from_ax_node(node: &AXNode) -> Self {
+        let properties = node
+            .properties
+            .iter()
+            .flatten()
+            .filter_map(|p| p.value.value.clone().map(|v| (p.name.clone(), v)))
+            .collect();
+
+        Self {
+            role: string_value(node.role.as_ref()).unwrap_or_default(),
+            name: string_value(node.name.as_ref()).unwrap_or_default(),
+            description: string_value(node.description.as_ref()).filter(|d| !d.is_empty()),
+            value: string_value(node.value.as_ref()),
+            properties,
+            children: Vec::new(),
+            backend_node_id: node.backend_dom_node_id,
+        }
+    }
+}
+
+/// Get an AX value as a string, stringifying non-string values.
+fn string_value(value: Option<&AXValue>) -> Option<String> {
+    match value?.value.as_ref()? {
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Null => None,
+        other => Some(other.to_string()),
+    }
+}
+
+/// Build a tree from the flat node list returned by the Accessibility domain.
+///
+/// Ignored nodes are always collapsed into their parent. With
+/// `interesting_only`, generic containers without a name and text nodes that
+/// repeat their parent's name are collapsed too. If `root` is given, the tree
+/// is rooted at the node computed for that DOM node.
+pub(crate) fn build_tree(
+    nodes: &[AXNode],
+    interesting_only: bool,
+    root: Option<BackendNodeId>,
+) -> Option<AccessibilityNode> {
+    let by_id: HashMap<&str, &AXNode> = nodes.iter().map(|n| (n.node_id.as_str(), n)).collect();
+
+    let root = match root {
+        Some(backend_id) => nodes
+            .iter()
+            .find(|n| n.backend_dom_node_id == Some(backend_id))?,
+        None => nodes.iter().find(|n| {
+            n.parent_id
+                .as_ref()
+                .is_none_or(|p| !by_id.contains_key(p.as_str()))
+        })?,
+    };
+
+    let mut converted = AccessibilityNode::from_ax_node(root);
+    converted.children = collect_children(root, &converted.name, &by_id, interesting_only);
+    Some(converted)
+}
+
+/// Convert the children of a node, hoisting the children of collapsed nodes.
+fn collect_children(
+    node: &AXNode,
+    parent_name: &str,
+    by_id: &HashMap<&str, &AXNode>,
+    interesting_only: bool,
+) -> Vec<AccessibilityNode> {
+    let mut children = Vec::new();
+    for child in node
+        .child_ids
+        .iter()
+        .flatten()
+        .filter_map(|id| by_id.get(id.as_str()))
+    {
+        let mut converted = AccessibilityNode::from_ax_node(child);
+        let grandchildren = collect_children(child, &converted.name, by_id, interesting_only);
+
+        if is_collapsed(child, &converted, parent_name, interesting_only) {
+            children.extend(grandchildren);
+        } else {
+            converted.children = grandchildren;
+            children.push(converted);
+        }
+    }
+    children
+}
+
+/// Whether a node should be left out of the tree.
+fn is_collapsed(
+    node: &AXNode,
+    converted: &AccessibilityNode,
+    parent_name: &str,
+    interesting_only: bool,
+) -> bool {
+    if node.ignored {
+        return true;
+    }
+    if !interesting_only {
+        return false;
+    }
+    match converted.role.as_str() {
+        "InlineTextBox" => true,
+        "StaticText" => converted.name.is_empty() || converted.name == parent_name,
+        role => GENERIC_ROLES.contains(&role) && converted.name.is_empty(),
+    }
+}
@@ -0,0 +1,139 @@
+use serde_json::json;
+use viewpoint_cdp::protocol::accessibility::AXNode;
+use viewpoint_cdp::protocol::dom::NodeDescription;
+
+use super::audit::{describe_element, unnamed_nodes};
+use super::node::build_tree;
+use super::{AccessibilityAuditReport, AuditRule, AuditViolation};
+
+fn ax_node(id: &str, role: &str, name: &str, parent: Option<&str>, children: &[&str]) -> AXNode {
+    serde_json::from_value(json!({
+        "nodeId": id,
+        "ignored": false,
+        "role": { "type": "role", "value": role },
+        "name": { "type": "computedString", "value": name },
+        "parentId": parent,
+        "childIds": children,
+        "backendDOMNodeId": id.parse::<i32>().unwrap(),
+    }))
+    .unwrap()
+}
+
+fn sample_tree() -> Vec<AXNode> {
+    vec![
+        ax_node("1", "RootWebArea", "Page", None, &["2"]),
+        ax_node("2", "generic", "", Some("1"), &["3", "5"]),
+        ax_node("3", "button", "Save", Some("2"), &["4"]),
+        ax_node("4", "StaticText", "Save", Some("3"), &[]),
+        ax_node("5", "heading", "Title", Some("2"), &[]),
+    ]
+}
+
+#[test]
+fn test_build_tree_interesting_only() {
+    let tree = build_tree(&sample_tree(), true, None).unwrap();
+
+    assert_eq!(tree.role, "RootWebArea");
+    let roles: Vec<&str> = tree.children.iter().map(|c| c.role.as_str()).collect();
+    assert_eq!(roles, ["button", "heading"]);
+    assert!(tree.children[0].children.is_empty());
+}
+
+#[test]
+fn test_build_tree_all_nodes() {
+    let tree = build_tree(&sample_tree(), false, None).unwrap();
+
+    assert_eq!(tree.descendants().count(), 5);
+    assert_eq!(tree.children[0].role, "generic");
+}
+
+#[test]
+fn test_build_tree_collapses_ignored_nodes() {
+    let mut nodes = sample_tree();
+    nodes[1].ignored = true;
+
+    let tree = build_tree(&nodes, false, None).unwrap();
+    assert_eq!(tree.children.len(), 2);
+    assert_eq!(tree.children[0].role, "button");
+}
+
+#[test]
+fn test_build_tree_with_root() {
+    let tree = build_tree(&sample_tree(), false, Some(3)).unwrap();
+
+    assert_eq!(tree.role, "button");
+    assert_eq!(tree.name, "Save");
+    assert_eq!(tree.children.len(), 1);
+
+    assert!(build_tree(&sample_tree(), false, Some(42)).is_none());
+}
+
+#[test]
+fn test_find_node() {
+    let tree = build_tree(&sample_tree(), true, None).unwrap();
+
+    assert!(tree.find("button", Some("Save")).is_some());
+    assert!(tree.find("heading", None).is_some());
+    assert!(tree.find("button", Some("Cancel")).is_none());
+}
+
+#[test]
+fn test_unnamed_nodes() {
+    let nodes = vec![
+        ax_node("1", "button", "Save", None, &[]),
+        ax_node("2", "button", "  ", None, &[]),
+        ax_node("3", "link", "", None, &[]),
+        ax_node("4", "generic", "", None, &[]),
+    ];
+
+    let ids: Vec<&str> = unnamed_nodes(&nodes).map(|n| n.node_id.as_str()).collect();
+    assert_eq!(ids, ["2", "3"]);
+}
+
+#[test]
+fn test_describe_element() {
+    let node: NodeDescription = serde_json::from_value(json!({
+        "nodeId": 0,
+        "backendNodeId": 7,
+        "nodeType": 1,
+        "nodeName": "BUTTON",
+        "localName": "button",
+        "nodeValue": "",
+        "attributes": ["class", "icon", "data-x", "1", "id", "save"],
+    }))
+    .unwrap();
+
+    assert_eq!(
+        describe_element(&node),
+        "<button id=\"save\" class=\"icon\">"
+    );
+}
+
+#[test]
+fn test_audit_rule_ids() {
+    for rule in AuditRule::ALL {
+        assert_eq!(AuditRule::from_id(rule.id()), Some(rule));
+    }
+    assert_eq!(AuditRule::from_id("unknown"), None);
+}
+
+#[test]
+fn test_audit_report_display() {
+    let report = AccessibilityAuditReport::default();
+    assert!(report.passed());
+    assert_eq!(report.to_string(), "no accessibility violations");
+
+    let report = AccessibilityAuditReport {
+        violations: vec![AuditViolation {
+            rule: AuditRule::ColorContrast,
+            message: "Contrast ratio 1.5:1 is below 4.5:1".to_string(),
+            element: "<p>".to_string(),
+        }],
+    };
+    assert!(!report.passed());
+    assert_eq!(report.violations_of(AuditRule::FocusOrder).count(), 0);
+    assert_eq!(
+        report.to_string(),
+        "1 accessibility violation(s):\n  [color-contrast] <p>: Contrast ratio 1.5:1 is below 4.5:1"
+    );
+}
@@ -0,0 +1,66 @@
+//! Automatic action recording into context traces.
+//!
+//! Every locator and page action is routed through [`Page::trace_action`], which
+//! records before/after events, parameters, duration and errors into the owning
+//! context's trace while tracing is active. When no trace is being recorded the
+//! action runs directly.
+
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use super::Page;
+use crate::context::trace::{ActionHandle, TracingState};
+
+impl Page {
+    /// Attach the context's shared tracing state to this page.
+    pub(crate) fn with_tracing_state(mut self, state: Arc<RwLock<TracingState>>) -> Self {
+        self.tracing_state = Some(state);
+        self
+    }
+
+    /// Run `action`, recording it in the context trace if tracing is active.
+    ///
+    /// The action's outcome is returned unchanged; failures are recorded in the
+    /// trace with their error message.
+    pub(crate) async fn trace_action<T, E, F>(
+        &self,
+        action_type: &str,
+        selector: Option<String>,
+        params: Option<serde_json::Value>,
+        action: F,
+    ) -> Result<T, E>
+    where
+        E: Display,
+        F: Future<Output = Result<T, E>>,
+    {
+        let handle = match &self.tracing_state {
+            Some(state) => {
+                ActionHandle::begin(
+                    &self.connection,
+                    state,
+                    &self.session_id,
+                    &self.target_id,
+                    action_type,
+                    selector,
+                    params,
+                )
+                .await
+            }
+            None => None,
+        };
+
+        let result = action.await;
+
+        if let Some(handle) = handle {
+            match &result {
+                Ok(_) => handle.complete(None).await,
+                Err(e) => handle.fail(&e.to_string()).await,
+            }
+        }
+
+        result
+    }
+}
@@ -122,10 +122,10 @@ impl Page {
     ///
     /// # Cross-Origin Frames
     ///
-    /// For cross-origin frames, CDP may still be able to capture content through
-    /// out-of-process iframe (OOPIF) handling. However, some content may be
-    /// inaccessible due to browser security policies. In such cases, the frame
-    /// boundary will remain with `is_frame: true` but may have limited or no children.
+    /// Cross-origin frames isolated in their own process (out-of-process
+    /// iframes) are captured through their own CDP session, like any other
+    /// frame. If a frame cannot be captured, e.g. because it is still loading,
+    /// its boundary remains with `is_frame: true` and no children.
     ///
     /// # Example
     ///
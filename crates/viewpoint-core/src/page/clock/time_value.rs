@@ -32,3 +32,24 @@ impl From<String> for TimeValue {
         TimeValue::IsoString(s)
     }
 }
+
+impl TimeValue {
+    /// Convert to a Unix timestamp in milliseconds.
+    ///
+    /// Strings are parsed as RFC 3339 date-times or as `YYYY-MM-DD` dates at
+    /// midnight UTC. Returns `None` if the string cannot be parsed.
+    pub(crate) fn to_millis(&self) -> Option<i64> {
+        match self {
+            TimeValue::Timestamp(ts) => Some(*ts),
+            TimeValue::IsoString(s) => chrono::DateTime::parse_from_rfc3339(s)
+                .map(|dt| dt.timestamp_millis())
+                .ok()
+                .or_else(|| {
+                    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                        .ok()?
+                        .and_hms_opt(0, 0, 0)
+                        .map(|dt| dt.and_utc().timestamp_millis())
+                }),
+        }
+    }
+}
@@ -5,8 +5,11 @@
 
 /// JavaScript code for clock mocking library.
 /// This gets injected into the page and provides clock control functions.
+/// It also runs in worker scopes, where the global object is not `window`.
 pub const CLOCK_MOCK_SCRIPT: &str = r"
 (function() {
+    const window = globalThis;
+
     // Only install once
     if (window.__viewpointClock) return;
     
@@ -289,6 +292,27 @@ pub const CLOCK_MOCK_SCRIPT: &str = r"
             startRealTime = originalDate.now();
         },
         
+        // Adopt a timeline shared with other pages and workers
+        setTimeline(timeline) {
+            if (timeline.fixedTime !== null) {
+                fixedTime = timeline.fixedTime;
+                currentTime = fixedTime;
+                paused = true;
+            } else {
+                fixedTime = null;
+                paused = false;
+                startMockedTime = timeline.startMockedTime;
+                startRealTime = timeline.startRealTime;
+                currentTime = this.now();
+            }
+        },
+        
+        // Get the time of the last scheduled timer, or null if there is none
+        lastTimerTime() {
+            if (timers.size === 0) return null;
+            return Math.max(...Array.from(timers.values()).map(t => t.time));
+        },
+        
         // Run all pending timers
         runAllTimers() {
             if (!installed) return 0;
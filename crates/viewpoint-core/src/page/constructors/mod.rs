@@ -8,9 +8,13 @@ use tokio::sync::RwLock;
 use viewpoint_cdp::CdpConnection;
 
 use crate::error::NetworkError;
-use crate::network::{RouteHandlerRegistry, WebSocketManager};
+use crate::network::{
+    NetworkEventListener, RouteHandlerRegistry, WebSocketManager, WebSocketRouteManager,
+    WebSocketRouteRegistry,
+};
 
 use super::binding;
+use super::coverage::Coverage;
 use super::events::PageEventManager;
 use super::frame::ExecutionContextRegistry;
 use super::keyboard::Keyboard;
@@ -19,6 +23,7 @@ use super::mouse::Mouse;
 use super::popup;
 use super::touchscreen::Touchscreen;
 use super::video::{Video, VideoOptions};
+use super::worker::WorkerManager;
 use super::{DEFAULT_TEST_ID_ATTRIBUTE, Page};
 
 impl Page {
@@ -142,6 +147,7 @@ impl Page {
         let event_manager = Arc::new(PageEventManager::new(
             connection.clone(),
             session_id.clone(),
+            target_id.clone(),
         ));
         let locator_handler_manager = Arc::new(LocatorHandlerManager::new());
         let popup_manager = Arc::new(popup::PopupManager::new(
@@ -153,10 +159,33 @@ impl Page {
             connection.clone(),
             session_id.clone(),
         ));
+        let network_events = Arc::new(NetworkEventListener::new(
+            connection.clone(),
+            session_id.clone(),
+        ));
+        network_events.start();
+        let websocket_route_manager = Arc::new(WebSocketRouteManager::new(
+            connection.clone(),
+            session_id.clone(),
+        ));
         let binding_manager = Arc::new(binding::BindingManager::new(
             connection.clone(),
             session_id.clone(),
         ));
+        // Create and start the execution context registry. It must subscribe
+        // before the worker manager enables auto-attach, or it can miss the
+        // out-of-process iframes attached right away.
+        let context_registry = Arc::new(ExecutionContextRegistry::new(
+            connection.clone(),
+            session_id.clone(),
+        ));
+        context_registry.start_listening();
+        let worker_manager = Arc::new(WorkerManager::new(
+            connection.clone(),
+            session_id.clone(),
+            event_manager.clone(),
+        ));
+        let coverage = Arc::new(Coverage::new(connection.clone(), session_id.clone()));
         let video_controller = video_options.map(|opts| {
             Arc::new(Video::with_options(
                 connection.clone(),
@@ -165,13 +194,6 @@ impl Page {
             ))
         });
 
-        // Create and start the execution context registry
-        let context_registry = Arc::new(ExecutionContextRegistry::new(
-            connection.clone(),
-            session_id.clone(),
-        ));
-        context_registry.start_listening();
-
         Self {
             connection,
             target_id,
@@ -186,17 +208,25 @@ impl Page {
             touchscreen,
             event_manager,
             locator_handler_manager,
+            coverage,
             video_controller,
             opener_target_id,
             popup_manager,
             websocket_manager,
+            network_events,
+            websocket_route_manager,
             binding_manager,
+            worker_manager,
             test_id_attribute: DEFAULT_TEST_ID_ATTRIBUTE.to_string(),
             context_registry,
             ref_map: std::sync::Arc::new(
                 parking_lot::RwLock::new(std::collections::HashMap::new()),
             ),
             context_pages: None,
+            tracing_state: None,
+            network_emulation: None,
+            network_override: Arc::new(RwLock::new(None)),
+            base_url: None,
         }
     }
 
@@ -215,6 +245,12 @@ impl Page {
         self
     }
 
+    /// Set the context's base URL for resolving relative URLs.
+    pub(crate) fn with_base_url(mut self, base_url: Option<String>) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
     /// Set context-level routes for this page.
     ///
     /// Context routes are checked as a fallback when no page-level route matches.
@@ -266,6 +302,34 @@ impl Page {
         }
     }
 
+    /// Set context-level WebSocket routes for this page.
+    ///
+    /// Context WebSocket routes are checked as a fallback when no page-level
+    /// WebSocket route matches. The page is tracked by the context so that
+    /// future `context.route_web_socket()` calls also apply to it.
+    pub(crate) async fn with_context_websocket_routes(
+        self,
+        context_routes: Arc<WebSocketRouteRegistry>,
+    ) -> Self {
+        let websocket_route_manager = WebSocketRouteManager::with_context_routes(
+            self.connection.clone(),
+            self.session_id.clone(),
+            context_routes,
+        )
+        .await;
+        Self {
+            websocket_route_manager,
+            ..self
+        }
+    }
+
+    /// Install WebSocket routing if there are context-level WebSocket routes.
+    pub(crate) async fn enable_context_websocket_routes(&self) -> Result<(), NetworkError> {
+        self.websocket_route_manager
+            .install_for_context_routes()
+            .await
+    }
+
     /// Enable Fetch domain if there are context-level routes.
     ///
     /// This is called after page creation to ensure the Fetch domain is enabled
@@ -298,15 +362,23 @@ impl Page {
             touchscreen: Touchscreen::new(self.connection.clone(), self.session_id.clone()),
             event_manager: self.event_manager.clone(),
             locator_handler_manager: self.locator_handler_manager.clone(),
+            coverage: self.coverage.clone(),
             video_controller: self.video_controller.clone(),
             opener_target_id: self.opener_target_id.clone(),
             popup_manager: self.popup_manager.clone(),
             websocket_manager: self.websocket_manager.clone(),
+            network_events: self.network_events.clone(),
+            websocket_route_manager: self.websocket_route_manager.clone(),
             binding_manager: self.binding_manager.clone(),
+            worker_manager: self.worker_manager.clone(),
             test_id_attribute: self.test_id_attribute.clone(),
             context_registry: self.context_registry.clone(),
             ref_map: self.ref_map.clone(),
             context_pages: self.context_pages.clone(),
+            tracing_state: self.tracing_state.clone(),
+            network_emulation: self.network_emulation.clone(),
+            network_override: self.network_override.clone(),
+            base_url: self.base_url.clone(),
         }
     }
 
@@ -13,6 +13,7 @@ use crate::error::PageError;
 use crate::wait::DocumentLoadState;
 
 use super::Page;
+use super::evaluate::EvaluationTarget;
 
 /// Script type for injection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -27,7 +28,7 @@ pub enum ScriptType {
 /// Builder for injecting script tags.
 #[derive(Debug)]
 pub struct ScriptTagBuilder<'a> {
-    page: &'a Page,
+    target: EvaluationTarget<'a>,
     url: Option<String>,
     content: Option<String>,
     script_type: ScriptType,
@@ -35,9 +36,9 @@ pub struct ScriptTagBuilder<'a> {
 
 impl<'a> ScriptTagBuilder<'a> {
     /// Create a new script tag builder.
-    pub(crate) fn new(page: &'a Page) -> Self {
+    pub(crate) fn new(target: EvaluationTarget<'a>) -> Self {
         Self {
-            page,
+            target,
             url: None,
             content: None,
             script_type: ScriptType::default(),
@@ -65,16 +66,14 @@ impl<'a> ScriptTagBuilder<'a> {
         self
     }
 
-    /// Inject the script tag into the page.
+    /// Inject the script tag into the page or frame.
     ///
     /// # Errors
     ///
     /// Returns an error if the injection fails.
     #[instrument(level = "debug", skip(self), fields(has_url = self.url.is_some(), has_content = self.content.is_some()))]
     pub async fn inject(self) -> Result<(), PageError> {
-        if self.page.is_closed() {
-            return Err(PageError::Closed);
-        }
+        self.target.ensure_usable()?;
 
         let script_js = if let Some(url) = self.url {
             format!(
@@ -120,7 +119,7 @@ impl<'a> ScriptTagBuilder<'a> {
 
         debug!("Injecting script tag");
 
-        self.page
+        self.target
             .connection()
             .send_command::<_, serde_json::Value>(
                 "Runtime.evaluate",
@@ -129,11 +128,11 @@ impl<'a> ScriptTagBuilder<'a> {
                     object_group: None,
                     include_command_line_api: None,
                     silent: Some(false),
-                    context_id: None,
+                    context_id: self.target.context_id(),
                     return_by_value: Some(true),
                     await_promise: Some(true),
                 }),
-                Some(self.page.session_id()),
+                Some(self.target.session_id()),
             )
             .await?;
 
@@ -144,16 +143,16 @@ impl<'a> ScriptTagBuilder<'a> {
 /// Builder for injecting style tags.
 #[derive(Debug)]
 pub struct StyleTagBuilder<'a> {
-    page: &'a Page,
+    target: EvaluationTarget<'a>,
     url: Option<String>,
     content: Option<String>,
 }
 
 impl<'a> StyleTagBuilder<'a> {
     /// Create a new style tag builder.
-    pub(crate) fn new(page: &'a Page) -> Self {
+    pub(crate) fn new(target: EvaluationTarget<'a>) -> Self {
         Self {
-            page,
+            target,
             url: None,
             content: None,
         }
@@ -173,16 +172,14 @@ impl<'a> StyleTagBuilder<'a> {
         self
     }
 
-    /// Inject the style into the page.
+    /// Inject the style into the page or frame.
     ///
     /// # Errors
     ///
     /// Returns an error if the injection fails.
     #[instrument(level = "debug", skip(self), fields(has_url = self.url.is_some(), has_content = self.content.is_some()))]
     pub async fn inject(self) -> Result<(), PageError> {
-        if self.page.is_closed() {
-            return Err(PageError::Closed);
-        }
+        self.target.ensure_usable()?;
 
         let style_js = if let Some(url) = self.url {
             format!(
@@ -217,7 +214,7 @@ impl<'a> StyleTagBuilder<'a> {
 
         debug!("Injecting style tag");
 
-        self.page
+        self.target
             .connection()
             .send_command::<_, serde_json::Value>(
                 "Runtime.evaluate",
@@ -226,11 +223,11 @@ impl<'a> StyleTagBuilder<'a> {
                     object_group: None,
                     include_command_line_api: None,
                     silent: Some(false),
-                    context_id: None,
+                    context_id: self.target.context_id(),
                     return_by_value: Some(true),
                     await_promise: Some(true),
                 }),
-                Some(self.page.session_id()),
+                Some(self.target.session_id()),
             )
             .await?;
 
@@ -279,6 +276,17 @@ impl<'a> SetContentBuilder<'a> {
     /// Returns an error if setting content fails.
     #[instrument(level = "info", skip(self), fields(html_len = self.html.len(), wait_until = ?self.wait_until))]
     pub async fn set(self) -> Result<(), PageError> {
+        let page = self.page;
+        let params = serde_json::json!({
+            "htmlLength": self.html.len(),
+            "waitUntil": format!("{:?}", self.wait_until),
+        });
+        page.trace_action("setContent", None, Some(params), self.set_untraced())
+            .await
+    }
+
+    /// Set the page content without recording it in the trace.
+    async fn set_untraced(self) -> Result<(), PageError> {
         if self.page.is_closed() {
             return Err(PageError::Closed);
         }
@@ -413,7 +421,7 @@ impl Page {
     /// # }
     /// ```
     pub fn add_script_tag(&self) -> ScriptTagBuilder<'_> {
-        ScriptTagBuilder::new(self)
+        ScriptTagBuilder::new(EvaluationTarget::Page(self))
     }
 
     /// Create a builder for injecting style tags.
@@ -431,6 +439,6 @@ impl Page {
     /// # }
     /// ```
     pub fn add_style_tag(&self) -> StyleTagBuilder<'_> {
-        StyleTagBuilder::new(self)
+        StyleTagBuilder::new(EvaluationTarget::Page(self))
     }
 }
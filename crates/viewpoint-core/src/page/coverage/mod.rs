@@ -0,0 +1,411 @@
+//! JavaScript and CSS code coverage collection.
+//!
+//! JavaScript coverage uses V8's precise block coverage via the CDP Profiler
+//! domain. CSS coverage uses rule usage tracking from the CSS domain.
+//! Collected JavaScript coverage can be exported to Istanbul JSON or LCOV
+//! with [`to_istanbul`] and [`to_lcov`], so it can be merged with coverage
+//! from unit tests.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "integration")]
+//! # tokio_test::block_on(async {
+//! # use viewpoint_core::Browser;
+//! use viewpoint_core::page::coverage;
+//! # let browser = Browser::launch().headless(true).launch().await.unwrap();
+//! # let context = browser.new_context().await.unwrap();
+//! # let page = context.new_page().await.unwrap();
+//!
+//! page.coverage().start_js_coverage().await.unwrap();
+//! page.coverage().start_css_coverage().await.unwrap();
+//!
+//! page.goto("https://example.com").goto().await.unwrap();
+//!
+//! let js = page.coverage().stop_js_coverage().await.unwrap();
+//! let css = page.coverage().stop_css_coverage().await.unwrap();
+//!
+//! std::fs::write("lcov.info", coverage::to_lcov(&js)).unwrap();
+//! # });
+//! ```
+
+mod report;
+
+pub use report::{to_istanbul, to_lcov};
+pub use viewpoint_cdp::protocol::profiler::{CoverageRange, FunctionCoverage};
+
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, instrument};
+use viewpoint_cdp::CdpConnection;
+use viewpoint_cdp::protocol::css::{
+    CssStyleSheetHeader, GetStyleSheetTextParams, GetStyleSheetTextResult, RuleUsage,
+    StopRuleUsageTrackingResult, StyleSheetAddedEvent,
+};
+use viewpoint_cdp::protocol::debugger::{GetScriptSourceParams, GetScriptSourceResult};
+use viewpoint_cdp::protocol::profiler::{StartPreciseCoverageParams, TakePreciseCoverageResult};
+
+use crate::error::PageError;
+
+/// Coverage collected for a single JavaScript script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsCoverageEntry {
+    /// URL of the script.
+    pub url: String,
+    /// CDP script ID.
+    pub script_id: String,
+    /// Script source, if it could still be retrieved when coverage stopped.
+    pub source: Option<String>,
+    /// Per-function ranges and execution counts.
+    ///
+    /// Offsets are in UTF-16 code units from the start of the script.
+    pub functions: Vec<FunctionCoverage>,
+}
+
+/// A range of a stylesheet containing used CSS rules.
+///
+/// Offsets are in UTF-16 code units from the start of the stylesheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CssCoverageRange {
+    /// Start offset (inclusive).
+    pub start: usize,
+    /// End offset (exclusive).
+    pub end: usize,
+}
+
+/// Coverage collected for a single stylesheet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssCoverageEntry {
+    /// URL of the stylesheet.
+    pub url: String,
+    /// Stylesheet text, if it could still be retrieved when coverage stopped.
+    pub text: Option<String>,
+    /// Merged ranges of used rules, sorted by offset.
+    pub ranges: Vec<CssCoverageRange>,
+}
+
+/// Internal state for coverage collection.
+#[derive(Debug, Default)]
+struct CoverageState {
+    /// Whether JavaScript coverage is being collected.
+    js_enabled: bool,
+    /// Whether CSS coverage is being collected.
+    css_enabled: bool,
+    /// Stylesheets reported while CSS coverage is enabled.
+    style_sheets: Vec<CssStyleSheetHeader>,
+    /// Task recording `CSS.styleSheetAdded` events.
+    css_listener: Option<JoinHandle<()>>,
+}
+
+/// Code coverage controller for a page.
+///
+/// Obtain it with [`Page::coverage`](super::Page::coverage).
+///
+/// Scripts and stylesheets without a URL (for example code passed to
+/// `evaluate`) are not reported.
+#[derive(Debug)]
+pub struct Coverage {
+    /// CDP connection.
+    connection: Arc<CdpConnection>,
+    /// Session ID.
+    session_id: String,
+    /// Internal state.
+    state: Arc<RwLock<CoverageState>>,
+}
+
+impl Coverage {
+    /// Create a new coverage controller.
+    pub(crate) fn new(connection: Arc<CdpConnection>, session_id: String) -> Self {
+        Self {
+            connection,
+            session_id,
+            state: Arc::new(RwLock::new(CoverageState::default())),
+        }
+    }
+
+    /// Start collecting JavaScript coverage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if JavaScript coverage is already being collected or
+    /// the CDP commands fail.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn start_js_coverage(&self) -> Result<(), PageError> {
+        let mut state = self.state.write().await;
+        if state.js_enabled {
+            return Err(PageError::EvaluationFailed(
+                "JS coverage is already enabled".to_string(),
+            ));
+        }
+
+        self.send("Profiler.enable", None).await?;
+        self.connection
+            .send_command::<_, serde_json::Value>(
+                "Profiler.startPreciseCoverage",
+                Some(
+                    StartPreciseCoverageParams::new()
+                        .call_count(true)
+                        .detailed(true),
+                ),
+                Some(&self.session_id),
+            )
+            .await?;
+        // The Debugger domain is needed to read script sources when stopping.
+        self.send("Debugger.enable", None).await?;
+        self.send(
+            "Debugger.setSkipAllPauses",
+            Some(serde_json::json!({ "skip": true })),
+        )
+        .await?;
+
+        state.js_enabled = true;
+        info!("Started JS coverage");
+        Ok(())
+    }
+
+    /// Stop collecting JavaScript coverage and return the results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if JavaScript coverage was not started or the CDP
+    /// commands fail.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn stop_js_coverage(&self) -> Result<Vec<JsCoverageEntry>, PageError> {
+        let mut state = self.state.write().await;
+        if !state.js_enabled {
+            return Err(PageError::EvaluationFailed(
+                "JS coverage was not started".to_string(),
+            ));
+        }
+        state.js_enabled = false;
+
+        let coverage: TakePreciseCoverageResult = self
+            .connection
+            .send_command(
+                "Profiler.takePreciseCoverage",
+                None::<()>,
+                Some(&self.session_id),
+            )
+            .await?;
+        self.send("Profiler.stopPreciseCoverage", None).await?;
+        self.send("Profiler.disable", None).await?;
+
+        let mut entries = Vec::new();
+        for script in coverage.result {
+            if script.url.is_empty() {
+                continue;
+            }
+            // The script may have been collected since; report it without source.
+            let source = self
+                .connection
+                .send_command::<_, GetScriptSourceResult>(
+                    "Debugger.getScriptSource",
+                    Some(GetScriptSourceParams {
+                        script_id: script.script_id.clone(),
+                    }),
+                    Some(&self.session_id),
+                )
+                .await
+                .ok()
+                .map(|r| r.script_source);
+            entries.push(JsCoverageEntry {
+                url: script.url,
+                script_id: script.script_id,
+                source,
+                functions: script.functions,
+            });
+        }
+
+        self.send("Debugger.disable", None).await?;
+
+        info!(scripts = entries.len(), "Stopped JS coverage");
+        Ok(entries)
+    }
+
+    /// Start collecting CSS coverage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if CSS coverage is already being collected or the
+    /// CDP commands fail.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn start_css_coverage(&self) -> Result<(), PageError> {
+        let mut state = self.state.write().await;
+        if state.css_enabled {
+            return Err(PageError::EvaluationFailed(
+                "CSS coverage is already enabled".to_string(),
+            ));
+        }
+        state.style_sheets.clear();
+
+        // Listen before enabling CSS so existing stylesheets are reported too.
+        // The listener records events once the state lock is released.
+        let listener = self.start_style_sheet_listener();
+
+        let enabled = async {
+            self.send("DOM.enable", None).await?;
+            self.send("CSS.enable", None).await?;
+            self.send("CSS.startRuleUsageTracking", None).await
+        }
+        .await;
+        if let Err(e) = enabled {
+            listener.abort();
+            return Err(e);
+        }
+
+        state.css_enabled = true;
+        state.css_listener = Some(listener);
+        info!("Started CSS coverage");
+        Ok(())
+    }
+
+    /// Record stylesheets added while CSS coverage is enabled.
+    fn start_style_sheet_listener(&self) -> JoinHandle<()> {
+        let mut events = self.connection.subscribe_events();
+        let session_id = self.session_id.clone();
+        let state = self.state.clone();
+
+        tokio::spawn(async move {
+            while let Ok(event) = events.recv().await {
+                if event.session_id.as_deref() != Some(&session_id) {
+                    continue;
+                }
+                if event.method != "CSS.styleSheetAdded" {
+                    continue;
+                }
+
+                let mut s = state.write().await;
+                if !s.css_enabled {
+                    break;
+                }
+                if let Some(added) = event
+                    .params
+                    .and_then(|p| serde_json::from_value::<StyleSheetAddedEvent>(p).ok())
+                {
+                    debug!(url = %added.header.source_url, "Stylesheet added");
+                    s.style_sheets.push(added.header);
+                }
+            }
+        })
+    }
+
+    /// Stop collecting CSS coverage and return the results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if CSS coverage was not started or the CDP commands
+    /// fail.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn stop_css_coverage(&self) -> Result<Vec<CssCoverageEntry>, PageError> {
+        let mut state = self.state.write().await;
+        if !state.css_enabled {
+            return Err(PageError::EvaluationFailed(
+                "CSS coverage was not started".to_string(),
+            ));
+        }
+        state.css_enabled = false;
+        if let Some(listener) = state.css_listener.take() {
+            listener.abort();
+        }
+        let style_sheets = std::mem::take(&mut state.style_sheets);
+        drop(state);
+
+        let usage: StopRuleUsageTrackingResult = self
+            .connection
+            .send_command(
+                "CSS.stopRuleUsageTracking",
+                None::<()>,
+                Some(&self.session_id),
+            )
+            .await?;
+
+        let mut entries = Vec::new();
+        for header in style_sheets {
+            if header.source_url.is_empty() {
+                continue;
+            }
+            let text = self
+                .connection
+                .send_command::<_, GetStyleSheetTextResult>(
+                    "CSS.getStyleSheetText",
+                    Some(GetStyleSheetTextParams {
+                        style_sheet_id: header.style_sheet_id.clone(),
+                    }),
+                    Some(&self.session_id),
+                )
+                .await
+                .ok()
+                .map(|r| r.text);
+            entries.push(CssCoverageEntry {
+                url: header.source_url,
+                text,
+                ranges: used_ranges(&usage.rule_usage, &header.style_sheet_id),
+            });
+        }
+
+        self.send("CSS.disable", None).await?;
+        self.send("DOM.disable", None).await?;
+
+        info!(style_sheets = entries.len(), "Stopped CSS coverage");
+        Ok(entries)
+    }
+
+    /// Send a command whose result is not needed.
+    async fn send(&self, method: &str, params: Option<serde_json::Value>) -> Result<(), PageError> {
+        self.connection
+            .send_command::<_, serde_json::Value>(method, params, Some(&self.session_id))
+            .await?;
+        Ok(())
+    }
+}
+
+/// Collect the used rule ranges of a stylesheet, merging overlapping ranges.
+fn used_ranges(rule_usage: &[RuleUsage], style_sheet_id: &str) -> Vec<CssCoverageRange> {
+    let mut ranges: Vec<CssCoverageRange> = rule_usage
+        .iter()
+        .filter(|rule| rule.used && rule.style_sheet_id == style_sheet_id)
+        .map(|rule| CssCoverageRange {
+            start: rule.start_offset as usize,
+            end: rule.end_offset as usize,
+        })
+        .collect();
+    ranges.sort_by_key(|r| (r.start, r.end));
+
+    let mut merged: Vec<CssCoverageRange> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+// Page impl for coverage methods
+impl super::Page {
+    /// Get the code coverage controller for this page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::page::Page;
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// page.coverage().start_js_coverage().await?;
+    /// page.goto("https://example.com").goto().await?;
+    /// let entries = page.coverage().stop_js_coverage().await?;
+    /// for entry in &entries {
+    ///     println!("{}: {} functions", entry.url, entry.functions.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn coverage(&self) -> &Coverage {
+        &self.coverage
+    }
+}
+
+#[cfg(test)]
+mod tests;
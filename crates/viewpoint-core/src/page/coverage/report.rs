@@ -0,0 +1,339 @@
+//! Export of JavaScript coverage to Istanbul and LCOV formats.
+//!
+//! V8 reports coverage as nested source ranges with execution counts. The
+//! count of an offset is that of the innermost range containing it, so ranges
+//! are painted outermost first onto a per-offset count map. Each line is then
+//! attributed the count of its first non-whitespace character.
+
+use std::fmt::Write as _;
+
+use serde_json::{Map, Value, json};
+
+use super::JsCoverageEntry;
+
+/// A line of source, in UTF-16 offsets.
+#[derive(Debug)]
+struct Line {
+    /// Offset of the first character of the line.
+    start: usize,
+    /// Offset just past the last character, excluding the line terminator.
+    end: usize,
+    /// Offset of the first non-whitespace character, if any.
+    first_code: Option<usize>,
+}
+
+/// Line and count information for one script.
+#[derive(Debug)]
+struct ScriptReport<'a> {
+    /// The coverage entry.
+    entry: &'a JsCoverageEntry,
+    /// Source lines.
+    lines: Vec<Line>,
+    /// Execution count of each UTF-16 offset.
+    counts: Vec<u32>,
+}
+
+/// A function reported in the coverage output.
+#[derive(Debug)]
+struct ReportedFunction {
+    /// Function name, with anonymous functions numbered.
+    name: String,
+    /// Start offset.
+    start: usize,
+    /// End offset.
+    end: usize,
+    /// Number of calls.
+    count: u32,
+}
+
+impl<'a> ScriptReport<'a> {
+    /// Build the report for an entry. Returns `None` if the source is unknown.
+    fn new(entry: &'a JsCoverageEntry) -> Option<Self> {
+        let source = entry.source.as_deref()?;
+        let lines = split_lines(source);
+        let len = lines.last().map_or(0, |line| line.end);
+
+        let mut ranges: Vec<_> = entry
+            .functions
+            .iter()
+            .flat_map(|function| function.ranges.iter())
+            .collect();
+        // Outer ranges start earlier, or at the same offset and end later.
+        ranges.sort_by(|a, b| {
+            a.start_offset
+                .cmp(&b.start_offset)
+                .then(b.end_offset.cmp(&a.end_offset))
+        });
+
+        let mut counts = vec![0; len];
+        for range in ranges {
+            let start = (range.start_offset as usize).min(len);
+            let end = (range.end_offset as usize).min(len);
+            if start < end {
+                counts[start..end].fill(range.count);
+            }
+        }
+
+        Some(Self {
+            entry,
+            lines,
+            counts,
+        })
+    }
+
+    /// Lines containing code, with their 1-based number and execution count.
+    fn line_hits(&self) -> impl Iterator<Item = (usize, &Line, u32)> {
+        self.lines.iter().enumerate().filter_map(|(i, line)| {
+            let offset = line.first_code?;
+            Some((i + 1, line, self.counts[offset]))
+        })
+    }
+
+    /// Functions in the script, excluding the top-level script body.
+    fn functions(&self) -> Vec<ReportedFunction> {
+        let mut anonymous = 0;
+        self.entry
+            .functions
+            .iter()
+            .filter_map(|function| {
+                let range = function.ranges.first()?;
+                if function.function_name.is_empty() && range.start_offset == 0 {
+                    return None;
+                }
+                let name = if function.function_name.is_empty() {
+                    anonymous += 1;
+                    format!("(anonymous_{anonymous})")
+                } else {
+                    function.function_name.clone()
+                };
+                Some(ReportedFunction {
+                    name,
+                    start: range.start_offset as usize,
+                    end: range.end_offset as usize,
+                    count: range.count,
+                })
+            })
+            .collect()
+    }
+
+    /// Block ranges, as `(function index, block index, start, end, count)`.
+    fn branches(&self) -> Vec<(usize, usize, usize, usize, u32)> {
+        self.entry
+            .functions
+            .iter()
+            .enumerate()
+            .flat_map(|(function, f)| {
+                f.ranges.iter().enumerate().skip(1).map(move |(block, r)| {
+                    (
+                        function,
+                        block - 1,
+                        r.start_offset as usize,
+                        r.end_offset as usize,
+                        r.count,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Convert an offset to a 1-based line and 0-based column.
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let index = self
+            .lines
+            .partition_point(|line| line.start <= offset)
+            .saturating_sub(1);
+        let start = self.lines.get(index).map_or(0, |line| line.start);
+        (index + 1, offset.saturating_sub(start))
+    }
+
+    /// Istanbul location object for an offset range.
+    fn location(&self, start: usize, end: usize) -> Value {
+        let (start_line, start_column) = self.position(start);
+        let (end_line, end_column) = self.position(end);
+        json!({
+            "start": { "line": start_line, "column": start_column },
+            "end": { "line": end_line, "column": end_column },
+        })
+    }
+}
+
+/// Split source into lines, tracking UTF-16 offsets.
+fn split_lines(source: &str) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+    let mut first_code = None;
+    let mut previous = None;
+
+    for ch in source.chars() {
+        if ch == '\n' {
+            // The "\r" of a CRLF ending belongs to the terminator.
+            let end = if previous == Some('\r') {
+                offset - 1
+            } else {
+                offset
+            };
+            lines.push(Line {
+                start,
+                end,
+                first_code: first_code.take(),
+            });
+            offset += 1;
+            start = offset;
+        } else {
+            if !ch.is_whitespace() && first_code.is_none() {
+                first_code = Some(offset);
+            }
+            offset += ch.len_utf16();
+        }
+        previous = Some(ch);
+    }
+    lines.push(Line {
+        start,
+        end: offset,
+        first_code,
+    });
+    lines
+}
+
+/// Export JavaScript coverage as an Istanbul coverage map.
+///
+/// The result has the shape of the `coverage-final.json` file written by
+/// `nyc` and `c8`, keyed by script URL, and can be merged with unit-test
+/// coverage using standard Istanbul tooling. Every line containing code is a
+/// statement, every function a function, and every V8 block range a branch.
+///
+/// Entries whose source is unknown are skipped.
+pub fn to_istanbul(entries: &[JsCoverageEntry]) -> Value {
+    let mut files = Map::new();
+
+    for report in entries.iter().filter_map(ScriptReport::new) {
+        let mut statement_map = Map::new();
+        let mut s = Map::new();
+        for (i, (number, line, count)) in report.line_hits().enumerate() {
+            let key = i.to_string();
+            let first_code = line.first_code.unwrap_or(line.start);
+            statement_map.insert(
+                key.clone(),
+                json!({
+                    "start": { "line": number, "column": first_code - line.start },
+                    "end": { "line": number, "column": line.end - line.start },
+                }),
+            );
+            s.insert(key, json!(count));
+        }
+
+        let mut fn_map = Map::new();
+        let mut f = Map::new();
+        for (i, function) in report.functions().into_iter().enumerate() {
+            let key = i.to_string();
+            let loc = report.location(function.start, function.end);
+            fn_map.insert(
+                key.clone(),
+                json!({
+                    "name": function.name,
+                    "decl": loc,
+                    "loc": loc,
+                    "line": report.position(function.start).0,
+                }),
+            );
+            f.insert(key, json!(function.count));
+        }
+
+        let mut branch_map = Map::new();
+        let mut b = Map::new();
+        for (i, (_, _, start, end, count)) in report.branches().into_iter().enumerate() {
+            let key = i.to_string();
+            let loc = report.location(start, end);
+            branch_map.insert(
+                key.clone(),
+                json!({
+                    "loc": loc,
+                    "type": "branch",
+                    "locations": [loc],
+                    "line": report.position(start).0,
+                }),
+            );
+            b.insert(key, json!([count]));
+        }
+
+        let url = report.entry.url.clone();
+        files.insert(
+            url.clone(),
+            json!({
+                "path": url,
+                "statementMap": statement_map,
+                "fnMap": fn_map,
+                "branchMap": branch_map,
+                "s": s,
+                "f": f,
+                "b": b,
+            }),
+        );
+    }
+
+    Value::Object(files)
+}
+
+/// Export JavaScript coverage as an LCOV tracefile.
+///
+/// Produces one record per script with function (`FN`/`FNDA`), branch
+/// (`BRDA`) and line (`DA`) data.
+///
+/// Entries whose source is unknown are skipped.
+pub fn to_lcov(entries: &[JsCoverageEntry]) -> String {
+    let mut out = String::new();
+
+    for report in entries.iter().filter_map(ScriptReport::new) {
+        let _ = writeln!(out, "TN:");
+        let _ = writeln!(out, "SF:{}", report.entry.url);
+
+        let functions = report.functions();
+        for function in &functions {
+            let _ = writeln!(
+                out,
+                "FN:{},{}",
+                report.position(function.start).0,
+                function.name
+            );
+        }
+        for function in &functions {
+            let _ = writeln!(out, "FNDA:{},{}", function.count, function.name);
+        }
+        let _ = writeln!(out, "FNF:{}", functions.len());
+        let _ = writeln!(
+            out,
+            "FNH:{}",
+            functions.iter().filter(|f| f.count > 0).count()
+        );
+
+        let branches = report.branches();
+        for (function, block, start, _, count) in &branches {
+            let _ = writeln!(
+                out,
+                "BRDA:{},{},{},{}",
+                report.position(*start).0,
+                function,
+                block,
+                count
+            );
+        }
+        let _ = writeln!(out, "BRF:{}", branches.len());
+        let _ = writeln!(out, "BRH:{}", branches.iter().filter(|b| b.4 > 0).count());
+
+        let mut found = 0;
+        let mut hit = 0;
+        for (number, _, count) in report.line_hits() {
+            let _ = writeln!(out, "DA:{number},{count}");
+            found += 1;
+            if count > 0 {
+                hit += 1;
+            }
+        }
+        let _ = writeln!(out, "LF:{found}");
+        let _ = writeln!(out, "LH:{hit}");
+        let _ = writeln!(out, "end_of_record");
+    }
+
+    out
+}
@@ -0,0 +1,204 @@
+use viewpoint_cdp::protocol::css::RuleUsage;
+
+use super::*;
+
+fn range(start_offset: u32, end_offset: u32, count: u32) -> CoverageRange {
+    CoverageRange {
+        start_offset,
+        end_offset,
+        count,
+    }
+}
+
+/// A script with a called function `a` and an uncalled function `b`.
+fn sample_entry() -> JsCoverageEntry {
+    let source = "function a() {\n  return 1;\n}\nfunction b() {\n  return 2;\n}\na();\n";
+    let b_start = source.find("function b").unwrap() as u32;
+    let b_end = source.find("\na();").unwrap() as u32;
+    JsCoverageEntry {
+        url: "https://example.com/app.js".to_string(),
+        script_id: "1".to_string(),
+        source: Some(source.to_string()),
+        functions: vec![
+            FunctionCoverage {
+                function_name: String::new(),
+                ranges: vec![range(0, source.len() as u32, 1)],
+                is_block_coverage: true,
+            },
+            FunctionCoverage {
+                function_name: "a".to_string(),
+                ranges: vec![range(0, 29, 1)],
+                is_block_coverage: true,
+            },
+            FunctionCoverage {
+                function_name: "b".to_string(),
+                ranges: vec![range(b_start, b_end, 0)],
+                is_block_coverage: true,
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_used_ranges_merges_overlaps() {
+    let rule = |start_offset: f64, end_offset: f64, used: bool| RuleUsage {
+        style_sheet_id: "s1".to_string(),
+        start_offset,
+        end_offset,
+        used,
+    };
+    let usage = vec![
+        rule(20.0, 30.0, true),
+        rule(0.0, 10.0, true),
+        rule(5.0, 12.0, true),
+        rule(40.0, 50.0, false),
+        RuleUsage {
+            style_sheet_id: "s2".to_string(),
+            ..rule(60.0, 70.0, true)
+        },
+    ];
+
+    assert_eq!(
+        used_ranges(&usage, "s1"),
+        vec![
+            CssCoverageRange { start: 0, end: 12 },
+            CssCoverageRange { start: 20, end: 30 },
+        ]
+    );
+}
+
+#[test]
+fn test_to_lcov() {
+    let lcov = to_lcov(&[sample_entry()]);
+
+    assert!(lcov.starts_with("TN:\nSF:https://example.com/app.js\n"));
+    assert!(lcov.contains("FN:1,a\n"));
+    assert!(lcov.contains("FN:4,b\n"));
+    assert!(lcov.contains("FNDA:1,a\n"));
+    assert!(lcov.contains("FNDA:0,b\n"));
+    assert!(lcov.contains("FNF:2\nFNH:1\n"));
+    assert!(lcov.contains("DA:2,1\n"));
+    assert!(lcov.contains("DA:5,0\n"));
+    assert!(lcov.contains("DA:7,1\n"));
+    assert!(lcov.contains("LF:7\nLH:4\n"));
+    assert!(lcov.ends_with("end_of_record\n"));
+}
+
+#[test]
+fn test_to_istanbul() {
+    let map = to_istanbul(&[sample_entry()]);
+    let file = &map["https://example.com/app.js"];
+
+    assert_eq!(file["path"], "https://example.com/app.js");
+    assert_eq!(file["fnMap"]["1"]["name"], "b");
+    assert_eq!(file["fnMap"]["1"]["decl"]["start"]["line"], 4);
+    assert_eq!(file["f"]["0"], 1);
+    assert_eq!(file["f"]["1"], 0);
+    assert_eq!(file["statementMap"]["1"]["start"]["line"], 2);
+    assert_eq!(file["statementMap"]["1"]["start"]["column"], 2);
+    assert_eq!(file["s"]["4"], 0);
+    assert_eq!(file["s"]["6"], 1);
+}
+
+#[test]
+fn test_export_skips_entries_without_source() {
+    let entry = JsCoverageEntry {
+        source: None,
+        ..sample_entry()
+    };
+
+    assert!(to_lcov(std::slice::from_ref(&entry)).is_empty());
+    assert_eq!(to_istanbul(&[entry]), serde_json::json!({}));
+}
+
+#[test]
+fn test_export_handles_crlf_and_utf16_offsets() {
+    // "😀" is two UTF-16 units; offsets and columns are in UTF-16 units.
+    let source = "var s = '😀';\r\nfunction f() {}\r\n";
+    let f_start = source.encode_utf16().count() as u32 - 17;
+    let entry = JsCoverageEntry {
+        url: "https://example.com/u.js".to_string(),
+        script_id: "2".to_string(),
+        source: Some(source.to_string()),
+        functions: vec![FunctionCoverage {
+            function_name: "f".to_string(),
+            ranges: vec![range(f_start, f_start + 15, 0)],
+            is_block_coverage: true,
+        }],
+    };
+
+    let map = to_istanbul(&[entry]);
+    let file = &map["https://example.com/u.js"];
+    assert_eq!(file["fnMap"]["0"]["loc"]["start"]["line"], 2);
+    assert_eq!(file["fnMap"]["0"]["loc"]["start"]["column"], 0);
+    assert_eq!(file["fnMap"]["0"]["loc"]["end"]["column"], 15);
+    assert_eq!(file["statementMap"]["0"]["end"]["column"], 13);
+}
+
+/// Connect to a fake browser over a pipe that answers each command with
+/// `respond(method)`: `Ok` sends an empty result, `Err` a protocol error.
+fn fake_browser(respond: fn(&str) -> Result<(), &'static str>) -> Arc<CdpConnection> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let (to_browser, browser_in) = tokio::io::duplex(4096);
+    let (mut browser_out, from_browser) = tokio::io::duplex(4096);
+
+    tokio::spawn(async move {
+        let mut reader = BufReader::new(browser_in);
+        loop {
+            let mut buf = Vec::new();
+            if reader.read_until(0, &mut buf).await.unwrap_or(0) == 0 {
+                break;
+            }
+            buf.pop();
+            let request: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+            let method = request["method"].as_str().unwrap_or_default();
+            let response = match respond(method) {
+                Ok(()) => serde_json::json!({ "id": request["id"], "result": {} }),
+                Err(message) => serde_json::json!({
+                    "id": request["id"],
+                    "error": { "code": -32000, "message": message }
+                }),
+            };
+            browser_out
+                .write_all(response.to_string().as_bytes())
+                .await
+                .unwrap();
+            browser_out.write_all(&[0]).await.unwrap();
+        }
+    });
+
+    Arc::new(CdpConnection::connect_pipe(from_browser, to_browser))
+}
+
+#[tokio::test]
+async fn test_failed_css_coverage_start_can_be_retried() {
+    let coverage = Coverage::new(
+        fake_browser(|method| match method {
+            "CSS.enable" => Err("CSS agent unavailable"),
+            _ => Ok(()),
+        }),
+        "session".to_string(),
+    );
+
+    assert!(coverage.start_css_coverage().await.is_err());
+    {
+        let state = coverage.state.read().await;
+        assert!(!state.css_enabled);
+        assert!(state.css_listener.is_none());
+    }
+
+    // Not stuck in the "already enabled" state
+    let err = coverage.start_css_coverage().await.unwrap_err();
+    assert!(!err.to_string().contains("already enabled"), "{err}");
+    assert!(coverage.stop_css_coverage().await.is_err());
+}
+
+#[tokio::test]
+async fn test_css_coverage_start_and_stop() {
+    let coverage = Coverage::new(fake_browser(|_| Ok(())), "session".to_string());
+
+    coverage.start_css_coverage().await.unwrap();
+    assert!(coverage.state.read().await.css_listener.is_some());
+    assert!(coverage.start_css_coverage().await.is_err());
+}
@@ -1,4 +1,7 @@
-//! Page emulation features for media and vision deficiency emulation.
+//! Page emulation features for media, vision deficiency, network and CPU
+//! emulation.
+
+mod throttling;
 
 use std::sync::Arc;
 use tracing::{debug, info, instrument};
@@ -0,0 +1,141 @@
+//! Network and CPU throttling emulation for pages.
+
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use tracing::{debug, instrument};
+use viewpoint_cdp::protocol::emulation::SetCpuThrottlingRateParams;
+use viewpoint_cdp::protocol::network::EmulateNetworkConditionsParams;
+
+use crate::error::PageError;
+use crate::network::NetworkConditions;
+use crate::network::conditions::NetworkEmulation;
+use crate::page::Page;
+
+impl Page {
+    /// Attach the context's shared network emulation state to this page.
+    pub(crate) fn with_network_emulation(mut self, state: Arc<RwLock<NetworkEmulation>>) -> Self {
+        self.network_emulation = Some(state);
+        self
+    }
+
+    /// Emulate network conditions on this page.
+    ///
+    /// This overrides the context's network conditions for this page only.
+    /// Offline mode set on the context still takes precedence.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::{NetworkConditions, Page};
+    ///
+    /// # async fn example(page: Page) -> Result<(), viewpoint_core::CoreError> {
+    /// page.emulate_network(NetworkConditions::SLOW_3G).await?;
+    /// page.goto("https://example.com").goto().await?;
+    ///
+    /// // Back to the context's conditions
+    /// page.clear_network_emulation().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page is closed or the CDP command fails.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn emulate_network(&self, conditions: NetworkConditions) -> Result<(), PageError> {
+        if self.closed {
+            return Err(PageError::Closed);
+        }
+        *self.network_override.write().await = Some(conditions);
+        self.apply_network_emulation().await
+    }
+
+    /// Remove this page's network conditions override.
+    ///
+    /// The page falls back to the context's network conditions, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page is closed or the CDP command fails.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn clear_network_emulation(&self) -> Result<(), PageError> {
+        if self.closed {
+            return Err(PageError::Closed);
+        }
+        *self.network_override.write().await = None;
+        self.apply_network_emulation().await
+    }
+
+    /// Send the effective network conditions of this page to the browser.
+    pub(crate) async fn apply_network_emulation(&self) -> Result<(), PageError> {
+        let page_override = *self.network_override.read().await;
+        let context = match &self.network_emulation {
+            Some(state) => *state.read().await,
+            None => NetworkEmulation::default(),
+        };
+        let params = context
+            .params_for(page_override)
+            .unwrap_or_else(EmulateNetworkConditionsParams::online);
+
+        debug!(
+            offline = params.offline,
+            latency = params.latency,
+            "Applying network emulation"
+        );
+        self.connection
+            .send_command::<_, serde_json::Value>(
+                "Network.emulateNetworkConditions",
+                Some(params),
+                Some(&self.session_id),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Throttle the page's CPU.
+    ///
+    /// `rate` is a slowdown factor: 1 is no throttling, 4 makes the page run
+    /// four times slower. This makes performance budgets reproducible on
+    /// fast machines.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # async fn example(page: Page) -> Result<(), viewpoint_core::CoreError> {
+    /// // Emulate a mid-range mobile device
+    /// page.emulate_cpu_throttling(4.0).await?;
+    ///
+    /// // Disable throttling
+    /// page.emulate_cpu_throttling(1.0).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page is closed, `rate` is less than 1, or the
+    /// CDP command fails.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn emulate_cpu_throttling(&self, rate: f64) -> Result<(), PageError> {
+        if self.closed {
+            return Err(PageError::Closed);
+        }
+        if rate.is_nan() || rate < 1.0 {
+            return Err(PageError::InvalidArgument(format!(
+                "CPU throttling rate must be at least 1, got {rate}"
+            )));
+        }
+
+        self.connection
+            .send_command::<_, serde_json::Value>(
+                "Emulation.setCPUThrottlingRate",
+                Some(SetCpuThrottlingRateParams { rate }),
+                Some(&self.session_id),
+            )
+            .await?;
+        Ok(())
+    }
+}
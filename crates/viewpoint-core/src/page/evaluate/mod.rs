@@ -2,17 +2,19 @@
 //!
 //! This module provides methods for executing JavaScript in the page context.
 
+use std::sync::Arc;
 use std::time::Duration;
 
 use serde::{Serialize, de::DeserializeOwned};
 use tracing::{debug, instrument, trace};
+use viewpoint_cdp::CdpConnection;
 use viewpoint_cdp::protocol::runtime::{
-    CallFunctionOnParams, EvaluateParams, EvaluateResult, ReleaseObjectParams,
+    CallFunctionOnParams, EvaluateParams, EvaluateResult, ExecutionContextId, ReleaseObjectParams,
 };
 
 use crate::error::PageError;
 
-use super::Page;
+use super::{Frame, Page};
 
 mod wait;
 
@@ -21,6 +23,52 @@ pub use wait::{Polling, WaitForFunctionBuilder};
 /// Default evaluation timeout (30 seconds, matching Playwright).
 pub(super) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// Where evaluation builders run their scripts.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum EvaluationTarget<'a> {
+    /// The main frame of a page.
+    Page(&'a Page),
+    /// A frame of a page, evaluated in its main world.
+    Frame(&'a Frame),
+}
+
+impl EvaluationTarget<'_> {
+    /// Fail if the page is closed or the frame detached.
+    pub(crate) fn ensure_usable(&self) -> Result<(), PageError> {
+        match self {
+            Self::Page(page) if page.is_closed() => Err(PageError::Closed),
+            Self::Frame(frame) if frame.is_detached() => {
+                Err(PageError::EvaluationFailed("Frame is detached".to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the CDP connection.
+    pub(crate) fn connection(&self) -> &Arc<CdpConnection> {
+        match self {
+            Self::Page(page) => page.connection(),
+            Self::Frame(frame) => frame.connection(),
+        }
+    }
+
+    /// Get the session to evaluate in.
+    pub(crate) fn session_id(&self) -> &str {
+        match self {
+            Self::Page(page) => page.session_id(),
+            Self::Frame(frame) => frame.session_id(),
+        }
+    }
+
+    /// Get the execution context to evaluate in, if not the session's default.
+    pub(crate) fn context_id(&self) -> Option<ExecutionContextId> {
+        match self {
+            Self::Page(_) => None,
+            Self::Frame(frame) => frame.main_world_context_id(),
+        }
+    }
+}
+
 /// A handle to a JavaScript object in the page context.
 ///
 /// Handles are useful for referencing complex objects that cannot be serialized
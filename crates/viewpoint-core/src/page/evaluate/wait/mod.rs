@@ -11,7 +11,7 @@ use viewpoint_cdp::protocol::runtime::{EvaluateParams, EvaluateResult, ReleaseOb
 use crate::error::PageError;
 use crate::page::Page;
 
-use super::{DEFAULT_TIMEOUT, JsHandle, wrap_expression};
+use super::{DEFAULT_TIMEOUT, EvaluationTarget, JsHandle, wrap_expression};
 
 /// Polling mode for `wait_for_function`.
 #[derive(Debug, Clone, Copy, Default)]
@@ -26,7 +26,7 @@ pub enum Polling {
 /// Builder for `wait_for_function`.
 #[derive(Debug)]
 pub struct WaitForFunctionBuilder<'a> {
-    page: &'a Page,
+    target: EvaluationTarget<'a>,
     expression: String,
     arg: Option<serde_json::Value>,
     timeout: Duration,
@@ -35,9 +35,9 @@ pub struct WaitForFunctionBuilder<'a> {
 
 impl<'a> WaitForFunctionBuilder<'a> {
     /// Create a new builder.
-    pub(crate) fn new(page: &'a Page, expression: String) -> Self {
+    pub(crate) fn new(target: EvaluationTarget<'a>, expression: String) -> Self {
         Self {
-            page,
+            target,
             expression,
             arg: None,
             timeout: DEFAULT_TIMEOUT,
@@ -92,9 +92,7 @@ impl<'a> WaitForFunctionBuilder<'a> {
     /// ```
     #[instrument(level = "debug", skip(self), fields(expression = %self.expression, timeout_ms = self.timeout.as_millis()))]
     pub async fn wait(self) -> Result<Option<JsHandle>, PageError> {
-        if self.page.closed {
-            return Err(PageError::Closed);
-        }
+        self.target.ensure_usable()?;
 
         let start = std::time::Instant::now();
 
@@ -151,18 +149,18 @@ impl<'a> WaitForFunctionBuilder<'a> {
             object_group: Some("viewpoint-wait".to_string()),
             include_command_line_api: None,
             silent: Some(false),
-            context_id: None,
+            context_id: self.target.context_id(),
             return_by_value: Some(false),
             await_promise: Some(true),
         };
 
         let result: EvaluateResult = self
-            .page
-            .connection
+            .target
+            .connection()
             .send_command(
                 "Runtime.evaluate",
                 Some(params),
-                Some(&self.page.session_id),
+                Some(self.target.session_id()),
             )
             .await?;
 
@@ -177,20 +175,20 @@ impl<'a> WaitForFunctionBuilder<'a> {
             result.result.object_id.map(|id| {
                 JsHandle::new(
                     id,
-                    self.page.session_id.clone(),
-                    self.page.connection.clone(),
+                    self.target.session_id().to_string(),
+                    self.target.connection().clone(),
                 )
             })
         } else {
             // Release non-truthy object references
             if let Some(object_id) = result.result.object_id {
                 let _ = self
-                    .page
-                    .connection
+                    .target
+                    .connection()
                     .send_command::<_, serde_json::Value>(
                         "Runtime.releaseObject",
                         Some(ReleaseObjectParams { object_id }),
-                        Some(&self.page.session_id),
+                        Some(self.target.session_id()),
                     )
                     .await;
             }
@@ -269,7 +267,7 @@ impl Page {
     /// # }
     /// ```
     pub fn wait_for_function(&self, expression: impl Into<String>) -> WaitForFunctionBuilder<'_> {
-        WaitForFunctionBuilder::new(self, expression.into())
+        WaitForFunctionBuilder::new(EvaluationTarget::Page(self), expression.into())
     }
 
     /// Wait for a JavaScript function with an argument to return a truthy value.
@@ -278,6 +276,6 @@ impl Page {
         expression: impl Into<String>,
         arg: A,
     ) -> WaitForFunctionBuilder<'_> {
-        WaitForFunctionBuilder::new(self, expression.into()).arg(arg)
+        WaitForFunctionBuilder::new(EvaluationTarget::Page(self), expression.into()).arg(arg)
     }
 }
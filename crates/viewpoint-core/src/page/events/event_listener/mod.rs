@@ -1,16 +1,18 @@
 //! Background event listener for page events.
 //!
 //! This module contains the CDP event listener that processes console, error,
-//! dialog, frame, and download events.
+//! dialog, frame, crash, and download events.
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use tokio::sync::{Mutex, RwLock, oneshot, watch};
 use tracing::{debug, warn};
 use viewpoint_cdp::CdpConnection;
 use viewpoint_cdp::protocol::page::{FrameAttachedEvent, FrameDetachedEvent, FrameNavigatedEvent};
 use viewpoint_cdp::protocol::runtime::{ConsoleApiCalledEvent, ExceptionThrownEvent};
+use viewpoint_cdp::protocol::target_domain::TargetCrashedEvent;
 use viewpoint_cdp::protocol::{
     DownloadProgressEvent, DownloadWillBeginEvent, JavascriptDialogOpeningEvent,
 };
@@ -22,21 +24,24 @@ use super::super::frame::Frame;
 use super::super::page_error::PageError as PageErrorInfo;
 use super::download_handling::DownloadTracker;
 use super::types::{
-    ConsoleHandler, DialogHandler, DownloadHandler, FrameAttachedHandler, FrameDetachedHandler,
-    FrameNavigatedHandler, PageErrorHandler,
+    ConsoleHandler, CrashHandler, DialogHandler, DownloadHandler, FrameAttachedHandler,
+    FrameDetachedHandler, FrameNavigatedHandler, PageErrorHandler,
 };
 
-/// Start the background event listener for console, pageerror, dialog, frame, and download events.
+/// Start the background event listener for console, pageerror, dialog, frame, crash, and download events.
 #[allow(clippy::too_many_arguments)]
 pub(super) fn start_event_listener(
     connection: Arc<CdpConnection>,
     session_id: String,
+    target_id: String,
     console_handler: Arc<RwLock<Option<ConsoleHandler>>>,
     pageerror_handler: Arc<RwLock<Option<PageErrorHandler>>>,
     dialog_handler: Arc<RwLock<Option<DialogHandler>>>,
     frameattached_handler: Arc<RwLock<Option<FrameAttachedHandler>>>,
     framenavigated_handler: Arc<RwLock<Option<FrameNavigatedHandler>>>,
     framedetached_handler: Arc<RwLock<Option<FrameDetachedHandler>>>,
+    crash_handler: Arc<RwLock<Option<CrashHandler>>>,
+    crashed: Arc<AtomicBool>,
     wait_for_console_tx: Arc<Mutex<Option<oneshot::Sender<ConsoleMessage>>>>,
     wait_for_pageerror_tx: Arc<Mutex<Option<oneshot::Sender<PageErrorInfo>>>>,
     wait_for_dialog_tx: Arc<Mutex<Option<oneshot::Sender<Dialog>>>>,
@@ -70,6 +75,19 @@ pub(super) fn start_event_listener(
                     }
                     continue;
                 }
+                // Reported on the browser session, identified by target ID
+                "Target.targetCrashed" => {
+                    let crashed_target = event.params.and_then(|params| {
+                        serde_json::from_value::<TargetCrashedEvent>(params).ok()
+                    });
+                    if let Some(crashed_target) = crashed_target {
+                        if crashed_target.target_id == target_id {
+                            debug!(status = %crashed_target.status, error_code = crashed_target.error_code, "Page target crashed");
+                            handle_crash(&crashed, &crash_handler).await;
+                        }
+                    }
+                    continue;
+                }
                 _ => {}
             }
 
@@ -124,6 +142,9 @@ pub(super) fn start_event_listener(
                     )
                     .await;
                 }
+                "Inspector.targetCrashed" => {
+                    handle_crash(&crashed, &crash_handler).await;
+                }
                 "Page.javascriptDialogOpening" => {
                     handle_dialog_event(
                         event.params.as_ref(),
@@ -140,6 +161,19 @@ pub(super) fn start_event_listener(
     });
 }
 
+/// Mark the page crashed and call the crash handler once.
+async fn handle_crash(crashed: &AtomicBool, crash_handler: &Arc<RwLock<Option<CrashHandler>>>) {
+    if crashed.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    warn!("Page crashed");
+
+    let handler = crash_handler.read().await;
+    if let Some(ref h) = *handler {
+        h().await;
+    }
+}
+
 async fn handle_console_event(
     params: Option<&serde_json::Value>,
     connection: &Arc<CdpConnection>,
@@ -154,25 +188,33 @@ async fn handle_console_event(
                 connection.clone(),
                 session_id.to_string(),
             );
+            dispatch_console_message(message, console_handler, wait_for_console_tx).await;
+        }
+    }
+}
 
-            // Check if there's a waiter
-            {
-                let mut waiter = wait_for_console_tx.lock().await;
-                if let Some(tx) = waiter.take() {
-                    let _ = tx.send(message);
-                    return;
-                }
-            }
-
-            // Check if there's a handler
-            let handler = console_handler.read().await;
-            if let Some(ref h) = *handler {
-                h(message).await;
-            } else {
-                debug!("Console message (no handler): received");
-            }
+/// Deliver a console message to the waiter, if any, or else to the handler.
+pub(super) async fn dispatch_console_message(
+    message: ConsoleMessage,
+    console_handler: &Arc<RwLock<Option<ConsoleHandler>>>,
+    wait_for_console_tx: &Arc<Mutex<Option<oneshot::Sender<ConsoleMessage>>>>,
+) {
+    // Check if there's a waiter
+    {
+        let mut waiter = wait_for_console_tx.lock().await;
+        if let Some(tx) = waiter.take() {
+            let _ = tx.send(message);
+            return;
         }
     }
+
+    // Check if there's a handler
+    let handler = console_handler.read().await;
+    if let Some(ref h) = *handler {
+        h(message).await;
+    } else {
+        debug!("Console message (no handler): received");
+    }
 }
 
 async fn handle_exception_event(
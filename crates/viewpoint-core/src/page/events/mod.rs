@@ -13,6 +13,7 @@ use std::collections::HashMap;
 use std::future::Future;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use tokio::sync::{Mutex, RwLock, oneshot};
@@ -28,8 +29,8 @@ use super::page_error::PageError as PageErrorInfo;
 use crate::error::PageError;
 
 pub use types::{
-    ConsoleHandler, DialogHandler, DownloadHandler, FileChooserHandler, FrameAttachedHandler,
-    FrameDetachedHandler, FrameNavigatedHandler, PageErrorHandler,
+    ConsoleHandler, CrashHandler, DialogHandler, DownloadHandler, FileChooserHandler,
+    FrameAttachedHandler, FrameDetachedHandler, FrameNavigatedHandler, PageErrorHandler,
 };
 
 use download_handling::DownloadTracker;
@@ -40,6 +41,8 @@ pub struct PageEventManager {
     connection: Arc<CdpConnection>,
     /// Session ID.
     session_id: String,
+    /// Target ID of the page.
+    target_id: String,
     /// Dialog handler.
     dialog_handler: Arc<RwLock<Option<DialogHandler>>>,
     /// Download handler.
@@ -56,6 +59,10 @@ pub struct PageEventManager {
     framenavigated_handler: Arc<RwLock<Option<FrameNavigatedHandler>>>,
     /// Frame detached handler.
     framedetached_handler: Arc<RwLock<Option<FrameDetachedHandler>>>,
+    /// Crash handler.
+    crash_handler: Arc<RwLock<Option<CrashHandler>>>,
+    /// Whether the page's renderer has crashed.
+    crashed: Arc<AtomicBool>,
     /// Active downloads.
     downloads: Arc<Mutex<HashMap<String, DownloadTracker>>>,
     /// Download directory.
@@ -86,11 +93,12 @@ impl std::fmt::Debug for PageEventManager {
 
 impl PageEventManager {
     /// Create a new page event manager.
-    pub fn new(connection: Arc<CdpConnection>, session_id: String) -> Self {
+    pub fn new(connection: Arc<CdpConnection>, session_id: String, target_id: String) -> Self {
         let download_dir = std::env::temp_dir().join("viewpoint-downloads");
         let manager = Self {
             connection: connection.clone(),
             session_id: session_id.clone(),
+            target_id,
             dialog_handler: Arc::new(RwLock::new(None)),
             download_handler: Arc::new(RwLock::new(None)),
             file_chooser_handler: Arc::new(RwLock::new(None)),
@@ -99,6 +107,8 @@ impl PageEventManager {
             frameattached_handler: Arc::new(RwLock::new(None)),
             framenavigated_handler: Arc::new(RwLock::new(None)),
             framedetached_handler: Arc::new(RwLock::new(None)),
+            crash_handler: Arc::new(RwLock::new(None)),
+            crashed: Arc::new(AtomicBool::new(false)),
             downloads: Arc::new(Mutex::new(HashMap::new())),
             download_dir,
             file_chooser_intercepted: Arc::new(RwLock::new(false)),
@@ -115,17 +125,20 @@ impl PageEventManager {
         manager
     }
 
-    /// Start the background event listener for console, pageerror, dialog, frame, and download events.
+    /// Start the background event listener for console, pageerror, dialog, frame, crash, and download events.
     fn start_event_listener(&self) {
         event_listener::start_event_listener(
             self.connection.clone(),
             self.session_id.clone(),
+            self.target_id.clone(),
             self.console_handler.clone(),
             self.pageerror_handler.clone(),
             self.dialog_handler.clone(),
             self.frameattached_handler.clone(),
             self.framenavigated_handler.clone(),
             self.framedetached_handler.clone(),
+            self.crash_handler.clone(),
+            self.crashed.clone(),
             self.wait_for_console_tx.clone(),
             self.wait_for_pageerror_tx.clone(),
             self.wait_for_dialog_tx.clone(),
@@ -188,6 +201,17 @@ impl PageEventManager {
         *console_handler = None;
     }
 
+    /// Deliver a console message that did not originate from the page's own
+    /// session, such as one logged by a worker.
+    pub(crate) async fn emit_console(&self, message: ConsoleMessage) {
+        event_listener::dispatch_console_message(
+            message,
+            &self.console_handler,
+            &self.wait_for_console_tx,
+        )
+        .await;
+    }
+
     /// Set the page error handler.
     pub async fn set_pageerror_handler<F, Fut>(&self, handler: F)
     where
@@ -252,6 +276,27 @@ impl PageEventManager {
         *framedetached_handler = None;
     }
 
+    /// Set the crash handler.
+    pub async fn set_crash_handler<F, Fut>(&self, handler: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut crash_handler = self.crash_handler.write().await;
+        *crash_handler = Some(Box::new(move || Box::pin(handler())));
+    }
+
+    /// Remove the crash handler.
+    pub async fn remove_crash_handler(&self) {
+        let mut crash_handler = self.crash_handler.write().await;
+        *crash_handler = None;
+    }
+
+    /// Check whether the page's renderer has crashed.
+    pub fn is_crashed(&self) -> bool {
+        self.crashed.load(Ordering::SeqCst)
+    }
+
     /// Handle frame attached event externally.
     pub async fn handle_frame_attached(&self, frame: Frame) {
         let handler = self.frameattached_handler.read().await;
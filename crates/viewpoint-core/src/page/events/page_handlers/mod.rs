@@ -236,6 +236,47 @@ impl Page {
         self.event_manager.remove_framedetached_handler().await;
     }
 
+    // =========================================================================
+    // Crash Event Methods
+    // =========================================================================
+
+    /// Set a handler for the page's renderer process crashing.
+    ///
+    /// The handler is called once, when the renderer crashes or is killed, for
+    /// example because it ran out of memory. Pending and subsequent operations
+    /// on a crashed page fail with [`PageError::Crashed`] (or the matching
+    /// crash variant of the operation's error type) instead of timing out.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # async fn example(page: Page) -> Result<(), viewpoint_core::CoreError> {
+    /// page.on_crash(|| async move {
+    ///     eprintln!("Page crashed");
+    /// }).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_crash<F, Fut>(&self, handler: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.event_manager.set_crash_handler(handler).await;
+    }
+
+    /// Remove the crash handler.
+    pub async fn off_crash(&self) {
+        self.event_manager.remove_crash_handler().await;
+    }
+
+    /// Check if the page's renderer process has crashed.
+    pub fn is_crashed(&self) -> bool {
+        self.event_manager.is_crashed()
+    }
+
     // =========================================================================
     // Expect Methods (Wait for events triggered by actions)
     // =========================================================================
@@ -35,6 +35,9 @@ pub type ConsoleHandler =
 pub type PageErrorHandler =
     Box<dyn Fn(PageErrorInfo) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
 
+/// Type alias for page crash handler function.
+pub type CrashHandler = Box<dyn Fn() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
 /// Type alias for frame attached handler function.
 pub type FrameAttachedHandler =
     Box<dyn Fn(Frame) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
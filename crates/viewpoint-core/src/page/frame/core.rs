@@ -285,6 +285,16 @@ impl Frame {
         &self.session_id
     }
 
+    /// Get the session of the page the frame belongs to.
+    ///
+    /// This differs from [`session_id`](Self::session_id) for frames of
+    /// out-of-process iframes; input is dispatched to the page session.
+    pub(crate) fn page_session_id(&self) -> &str {
+        self.context_registry
+            .as_ref()
+            .map_or(&self.session_id, |registry| &registry.session_id)
+    }
+
     /// Get the connection.
     pub(crate) fn connection(&self) -> &Arc<CdpConnection> {
         &self.connection
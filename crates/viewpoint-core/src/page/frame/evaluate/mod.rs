@@ -0,0 +1,172 @@
+//! Frame JavaScript evaluation (evaluate, handles, waiting, script tags).
+
+use serde::{Serialize, de::DeserializeOwned};
+use tracing::{instrument, trace};
+use viewpoint_cdp::protocol::runtime::{EvaluateParams, EvaluateResult};
+
+use super::Frame;
+use crate::error::PageError;
+use crate::page::content::{ScriptTagBuilder, StyleTagBuilder};
+use crate::page::evaluate::{EvaluationTarget, JsHandle, WaitForFunctionBuilder, wrap_expression};
+
+impl Frame {
+    /// Evaluate JavaScript in the frame's main world.
+    ///
+    /// The expression is evaluated and the result is deserialized to the specified type.
+    /// Promises are automatically awaited.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example(page: viewpoint_core::Page) -> Result<(), viewpoint_core::CoreError> {
+    /// for frame in page.frames().await? {
+    ///     let title: String = frame.evaluate("document.title").await?;
+    ///     println!("{}: {title}", frame.url());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The frame is detached
+    /// - The JavaScript throws an error
+    /// - The result cannot be deserialized
+    #[instrument(level = "debug", skip(self), fields(frame_id = %self.id, expression = %expression))]
+    pub async fn evaluate<T: DeserializeOwned>(&self, expression: &str) -> Result<T, PageError> {
+        let result = self.evaluate_raw(wrap_expression(expression), true).await?;
+
+        // Handle undefined return values - use null if no value present
+        let value = result.result.value.unwrap_or(serde_json::Value::Null);
+
+        serde_json::from_value(value)
+            .map_err(|e| PageError::EvaluationFailed(format!("Failed to deserialize: {e}")))
+    }
+
+    /// Evaluate JavaScript with an argument in the frame's main world.
+    ///
+    /// The argument is serialized to JSON and passed to the function.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frame is detached, the argument cannot be
+    /// serialized, the JavaScript throws an error or the result cannot be
+    /// deserialized.
+    #[instrument(level = "debug", skip(self, arg), fields(frame_id = %self.id, expression = %expression))]
+    pub async fn evaluate_with_arg<T: DeserializeOwned, A: Serialize>(
+        &self,
+        expression: &str,
+        arg: A,
+    ) -> Result<T, PageError> {
+        let arg_json = serde_json::to_string(&arg).map_err(|e| {
+            PageError::EvaluationFailed(format!("Failed to serialize argument: {e}"))
+        })?;
+
+        let result = self
+            .evaluate_raw(format!("({expression})({arg_json})"), true)
+            .await?;
+
+        let value = result.result.value.unwrap_or(serde_json::Value::Null);
+
+        serde_json::from_value(value)
+            .map_err(|e| PageError::EvaluationFailed(format!("Failed to deserialize: {e}")))
+    }
+
+    /// Evaluate JavaScript in the frame and return a handle to the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frame is detached, the JavaScript throws an
+    /// error, or the result is not an object.
+    #[instrument(level = "debug", skip(self), fields(frame_id = %self.id, expression = %expression))]
+    pub async fn evaluate_handle(&self, expression: &str) -> Result<JsHandle, PageError> {
+        let result = self
+            .evaluate_raw(wrap_expression(expression), false)
+            .await?;
+
+        let object_id = result
+            .result
+            .object_id
+            .ok_or_else(|| PageError::EvaluationFailed("Result is not an object".to_string()))?;
+
+        Ok(JsHandle::new(
+            object_id,
+            self.session_id.clone(),
+            self.connection.clone(),
+        ))
+    }
+
+    /// Wait for a JavaScript function to return a truthy value in the frame.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example(page: viewpoint_core::Page) -> Result<(), viewpoint_core::CoreError> {
+    /// if let Some(frame) = page.frame("checkout").await? {
+    ///     frame.wait_for_function("() => window.paymentReady")
+    ///         .wait()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_function(&self, expression: impl Into<String>) -> WaitForFunctionBuilder<'_> {
+        WaitForFunctionBuilder::new(EvaluationTarget::Frame(self), expression.into())
+    }
+
+    /// Wait for a JavaScript function with an argument to return a truthy
+    /// value in the frame.
+    pub fn wait_for_function_with_arg<A: Serialize>(
+        &self,
+        expression: impl Into<String>,
+        arg: A,
+    ) -> WaitForFunctionBuilder<'_> {
+        WaitForFunctionBuilder::new(EvaluationTarget::Frame(self), expression.into()).arg(arg)
+    }
+
+    /// Create a builder for injecting script tags into the frame.
+    pub fn add_script_tag(&self) -> ScriptTagBuilder<'_> {
+        ScriptTagBuilder::new(EvaluationTarget::Frame(self))
+    }
+
+    /// Create a builder for injecting style tags into the frame.
+    pub fn add_style_tag(&self) -> StyleTagBuilder<'_> {
+        StyleTagBuilder::new(EvaluationTarget::Frame(self))
+    }
+
+    /// Send `Runtime.evaluate` to the frame's main world.
+    async fn evaluate_raw(
+        &self,
+        expression: String,
+        return_by_value: bool,
+    ) -> Result<EvaluateResult, PageError> {
+        if self.is_detached() {
+            return Err(PageError::EvaluationFailed("Frame is detached".to_string()));
+        }
+
+        let context_id = self.main_world_context_id();
+        trace!(context_id = ?context_id, expression = %expression, "Evaluating JavaScript in frame");
+
+        let params = EvaluateParams {
+            expression,
+            object_group: (!return_by_value).then(|| "viewpoint".to_string()),
+            include_command_line_api: None,
+            silent: Some(false),
+            context_id,
+            return_by_value: Some(return_by_value),
+            await_promise: Some(true),
+        };
+
+        let result: EvaluateResult = self
+            .connection
+            .send_command("Runtime.evaluate", Some(params), Some(&self.session_id))
+            .await?;
+
+        if let Some(exception) = result.exception_details {
+            return Err(PageError::EvaluationFailed(exception.text));
+        }
+
+        Ok(result)
+    }
+}
@@ -3,16 +3,22 @@
 //! This module provides a registry for tracking JavaScript execution contexts
 //! across frames. Each frame can have multiple execution contexts (main world
 //! and isolated worlds), and this registry maps frame IDs to their contexts.
+//!
+//! Cross-origin iframes may run out of process (OOPIF) and are then served by
+//! their own CDP session; the registry also tracks those sessions so frames
+//! can be routed to the session that owns them.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use parking_lot::RwLock;
 use tracing::{debug, trace};
 use viewpoint_cdp::CdpConnection;
+use viewpoint_cdp::protocol::page::FrameAttachedEvent;
 use viewpoint_cdp::protocol::runtime::{
     ExecutionContextCreatedEvent, ExecutionContextDestroyedEvent, ExecutionContextId,
 };
+use viewpoint_cdp::protocol::target_domain::{AttachedToTargetEvent, DetachedFromTargetEvent};
 
 /// Key for the main world execution context.
 pub const MAIN_WORLD_KEY: &str = "";
@@ -24,13 +30,19 @@ pub const MAIN_WORLD_KEY: &str = "";
 #[derive(Debug)]
 pub struct ExecutionContextRegistry {
     /// Session ID for this registry.
-    session_id: String,
+    pub(super) session_id: String,
     /// CDP connection (for subscribing to events).
-    connection: Arc<CdpConnection>,
+    pub(super) connection: Arc<CdpConnection>,
     /// Map of frame_id -> (world_name -> context_id).
     contexts: RwLock<HashMap<String, HashMap<String, ExecutionContextId>>>,
     /// Reverse map of context_id -> frame_id for efficient removal.
     context_to_frame: RwLock<HashMap<ExecutionContextId, String>>,
+    /// Sessions of out-of-process iframes attached to the page.
+    pub(super) oopif_sessions: RwLock<HashSet<String>>,
+    /// Out-of-process iframes in attach order, as (frame_id, session_id).
+    pub(super) oopif_frames: RwLock<Vec<(String, String)>>,
+    /// Map of frame_id -> parent frame_id for frames attached to any session.
+    pub(super) frame_parents: RwLock<HashMap<String, String>>,
 }
 
 impl ExecutionContextRegistry {
@@ -41,13 +53,17 @@ impl ExecutionContextRegistry {
             connection,
             contexts: RwLock::new(HashMap::new()),
             context_to_frame: RwLock::new(HashMap::new()),
+            oopif_sessions: RwLock::new(HashSet::new()),
+            oopif_frames: RwLock::new(Vec::new()),
+            frame_parents: RwLock::new(HashMap::new()),
         }
     }
 
     /// Start listening for execution context events.
     ///
     /// This spawns a background task that handles `Runtime.executionContextCreated`
-    /// and `Runtime.executionContextDestroyed` events.
+    /// and `Runtime.executionContextDestroyed` events, and attaches to the
+    /// out-of-process iframes of the page and of those iframes.
     pub fn start_listening(self: &Arc<Self>) {
         let registry = Arc::clone(self);
         let mut events = registry.connection.subscribe_events();
@@ -55,8 +71,11 @@ impl ExecutionContextRegistry {
 
         tokio::spawn(async move {
             while let Ok(event) = events.recv().await {
-                // Filter for this session
-                if event.session_id.as_deref() != Some(&session_id) {
+                // Filter for this session and its out-of-process iframes
+                let Some(event_session) = event.session_id.as_deref() else {
+                    continue;
+                };
+                if event_session != session_id && !registry.is_oopif_session(event_session) {
                     continue;
                 }
 
@@ -82,6 +101,36 @@ impl ExecutionContextRegistry {
                             }
                         }
                     }
+                    "Target.attachedToTarget" => {
+                        if let Some(params) = event.params.as_ref() {
+                            if let Ok(attached_event) =
+                                serde_json::from_value::<AttachedToTargetEvent>(params.clone())
+                            {
+                                registry.handle_attached_to_target(event_session, attached_event);
+                            }
+                        }
+                    }
+                    "Target.detachedFromTarget" => {
+                        if let Some(params) = event.params.as_ref() {
+                            if let Ok(detached_event) =
+                                serde_json::from_value::<DetachedFromTargetEvent>(params.clone())
+                            {
+                                registry.handle_detached_from_target(detached_event);
+                            }
+                        }
+                    }
+                    "Page.frameAttached" => {
+                        if let Some(params) = event.params.as_ref() {
+                            if let Ok(attached_event) =
+                                serde_json::from_value::<FrameAttachedEvent>(params.clone())
+                            {
+                                registry.frame_parents.write().insert(
+                                    attached_event.frame_id,
+                                    attached_event.parent_frame_id,
+                                );
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -189,6 +238,15 @@ impl ExecutionContextRegistry {
             .and_then(|frame_contexts| frame_contexts.get(MAIN_WORLD_KEY).copied())
     }
 
+    /// Get the main world execution context IDs of all frames.
+    pub fn main_world_contexts(&self) -> Vec<ExecutionContextId> {
+        let contexts = self.contexts.read();
+        contexts
+            .values()
+            .filter_map(|frame_contexts| frame_contexts.get(MAIN_WORLD_KEY).copied())
+            .collect()
+    }
+
     /// Get an execution context ID for a frame by world name.
     pub fn get_context(&self, frame_id: &str, world_name: &str) -> Option<ExecutionContextId> {
         let contexts = self.contexts.read();
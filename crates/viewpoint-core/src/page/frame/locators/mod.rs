@@ -0,0 +1,89 @@
+//! Locator factory methods for frames.
+//!
+//! Locators created from a frame search the frame's document directly,
+//! including frames of out-of-process iframes.
+
+use super::Frame;
+use crate::page::frame_locator::{FrameElementLocator, FrameRoleLocatorBuilder, FrameScope};
+use crate::page::locator::{AriaRole, Selector, TextMatcher};
+
+impl Frame {
+    /// Create a locator for elements matching a selector within this frame.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// if let Some(frame) = page.frame("checkout").await? {
+    ///     frame.locator("#card").fill("4242 4242 4242 4242").await?;
+    ///     frame.locator("button[type=submit]").click().await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn locator(&self, selector: impl Into<String>) -> FrameElementLocator<'_> {
+        self.element_locator(Selector::parse(&selector.into()))
+    }
+
+    /// Create a locator for elements containing the specified text within this frame.
+    ///
+    /// Accepts a [`TextMatcher`] for exact, case-insensitive or regex matching.
+    pub fn get_by_text(&self, text: impl Into<TextMatcher>) -> FrameElementLocator<'_> {
+        self.element_locator(Selector::Text(text.into().resolve(false)))
+    }
+
+    /// Create a locator for elements with exact text content within this frame.
+    pub fn get_by_text_exact(&self, text: impl Into<String>) -> FrameElementLocator<'_> {
+        self.element_locator(Selector::Text(TextMatcher::Exact(text.into())))
+    }
+
+    /// Create a locator for elements with the specified ARIA role within this frame.
+    pub fn get_by_role(&self, role: AriaRole) -> FrameRoleLocatorBuilder<'_> {
+        FrameRoleLocatorBuilder::new(FrameScope::Frame(self), role)
+    }
+
+    /// Create a locator for elements with the specified test ID within this frame.
+    pub fn get_by_test_id(&self, test_id: impl Into<String>) -> FrameElementLocator<'_> {
+        self.element_locator(Selector::TestId(test_id.into()))
+    }
+
+    /// Create a locator for form controls by their associated label text within this frame.
+    pub fn get_by_label(&self, label: impl Into<TextMatcher>) -> FrameElementLocator<'_> {
+        self.element_locator(Selector::Label(label.into().resolve(true)))
+    }
+
+    /// Create a locator for inputs by their placeholder text within this frame.
+    pub fn get_by_placeholder(
+        &self,
+        placeholder: impl Into<TextMatcher>,
+    ) -> FrameElementLocator<'_> {
+        self.element_locator(Selector::Placeholder(placeholder.into().resolve(true)))
+    }
+
+    /// Create a locator for images by their alt text within this frame.
+    pub fn get_by_alt_text(&self, alt: impl Into<TextMatcher>) -> FrameElementLocator<'_> {
+        self.element_locator(Selector::AltText(alt.into().resolve(false)))
+    }
+
+    /// Create a locator for images with exact alt text within this frame.
+    pub fn get_by_alt_text_exact(&self, alt: impl Into<String>) -> FrameElementLocator<'_> {
+        self.element_locator(Selector::AltText(TextMatcher::Exact(alt.into())))
+    }
+
+    /// Create a locator for elements by their title attribute within this frame.
+    pub fn get_by_title(&self, title: impl Into<TextMatcher>) -> FrameElementLocator<'_> {
+        self.element_locator(Selector::Title(title.into().resolve(false)))
+    }
+
+    /// Create a locator for elements with exact title attribute within this frame.
+    pub fn get_by_title_exact(&self, title: impl Into<String>) -> FrameElementLocator<'_> {
+        self.element_locator(Selector::Title(TextMatcher::Exact(title.into())))
+    }
+
+    /// Create a locator searching this frame's document.
+    fn element_locator(&self, selector: Selector) -> FrameElementLocator<'_> {
+        FrameElementLocator::new(FrameScope::Frame(self), selector)
+    }
+}
@@ -7,8 +7,11 @@
 mod aria;
 mod content;
 mod core;
+mod evaluate;
 mod execution_context;
+mod locators;
 mod navigation;
+mod oopif;
 mod tree;
 
 pub use core::Frame;
@@ -0,0 +1,306 @@
+//! Out-of-process iframe (OOPIF) session tracking.
+//!
+//! With site isolation, cross-origin iframes render in their own process and
+//! are exposed as separate `iframe` targets. The page session auto-attaches
+//! to them with flat sessions; this module records which session serves which
+//! frame and prepares new sessions before they are resumed.
+
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tracing::debug;
+use viewpoint_cdp::CdpConnection;
+use viewpoint_cdp::protocol::dom::{
+    GetFrameOwnerParams, GetFrameOwnerResult, ResolveNodeParams, ResolveNodeResult,
+};
+use viewpoint_cdp::protocol::page::SetLifecycleEventsEnabledParams;
+use viewpoint_cdp::protocol::runtime::{CallFunctionOnParams, CallFunctionOnResult};
+use viewpoint_cdp::protocol::target_domain::{
+    AttachedToTargetEvent, DetachedFromTargetEvent, SetAutoAttachParams,
+};
+use viewpoint_js::js;
+
+use super::Frame;
+use super::execution_context::ExecutionContextRegistry;
+use crate::page::frame_locator::frame_content_position_js;
+
+impl ExecutionContextRegistry {
+    /// Handle a `Target.attachedToTarget` event received on `parent_session`.
+    pub(super) fn handle_attached_to_target(
+        &self,
+        parent_session: &str,
+        event: AttachedToTargetEvent,
+    ) {
+        let connection = self.connection.clone();
+        let session_id = event.session_id;
+
+        if event.target_info.target_type != "iframe" {
+            // Targets of the page session (e.g. workers) are handled by the
+            // page; those of out-of-process iframes must not stay paused.
+            if parent_session != self.session_id && event.waiting_for_debugger {
+                tokio::spawn(async move {
+                    run_if_waiting_for_debugger(&connection, &session_id).await;
+                });
+            }
+            return;
+        }
+
+        // The target ID of an iframe target is the ID of its frame
+        let frame_id = event.target_info.target_id;
+        debug!(
+            frame_id = %frame_id,
+            session_id = %session_id,
+            url = %event.target_info.url,
+            "Attached to out-of-process iframe"
+        );
+
+        self.oopif_sessions.write().insert(session_id.clone());
+        {
+            let mut frames = self.oopif_frames.write();
+            frames.retain(|(id, _)| id != &frame_id);
+            frames.push((frame_id, session_id.clone()));
+        }
+
+        tokio::spawn(async move {
+            init_oopif_session(&connection, &session_id).await;
+        });
+    }
+
+    /// Handle a `Target.detachedFromTarget` event.
+    pub(super) fn handle_detached_from_target(&self, event: DetachedFromTargetEvent) {
+        if !self.oopif_sessions.write().remove(&event.session_id) {
+            return;
+        }
+
+        let detached: Vec<String> = {
+            let mut frames = self.oopif_frames.write();
+            let detached = frames
+                .iter()
+                .filter(|(_, session)| session == &event.session_id)
+                .map(|(frame_id, _)| frame_id.clone())
+                .collect();
+            frames.retain(|(_, session)| session != &event.session_id);
+            detached
+        };
+
+        for frame_id in detached {
+            debug!(frame_id = %frame_id, "Detached from out-of-process iframe");
+            self.clear_frame_contexts(&frame_id);
+        }
+    }
+
+    /// Check whether a session belongs to an out-of-process iframe of the page.
+    pub fn is_oopif_session(&self, session_id: &str) -> bool {
+        self.oopif_sessions.read().contains(session_id)
+    }
+
+    /// Get the session of an out-of-process iframe.
+    ///
+    /// Returns `None` if the frame is rendered in the process of its parent.
+    pub fn oopif_session(&self, frame_id: &str) -> Option<String> {
+        self.oopif_frames
+            .read()
+            .iter()
+            .find(|(id, _)| id == frame_id)
+            .map(|(_, session)| session.clone())
+    }
+
+    /// Get the out-of-process iframes as `(frame_id, session_id)` pairs, in
+    /// the order they were attached.
+    pub fn oopif_frames(&self) -> Vec<(String, String)> {
+        self.oopif_frames.read().clone()
+    }
+
+    /// Get the parent frame ID of a frame, as reported by `Page.frameAttached`.
+    pub fn parent_frame_id(&self, frame_id: &str) -> Option<String> {
+        self.frame_parents.read().get(frame_id).cloned()
+    }
+
+    /// Get the position of a session's viewport within the page's viewport.
+    ///
+    /// Walks up the iframe elements owning out-of-process iframes until the
+    /// page session is reached. Owners that cannot be located (e.g. while
+    /// detaching) end the walk.
+    pub(crate) async fn session_offset(&self, session_id: &str) -> (f64, f64) {
+        let mut offset = (0.0, 0.0);
+        let mut session = session_id.to_string();
+
+        while session != self.session_id {
+            let frames = self.oopif_frames();
+            let Some((frame_id, _)) = frames.iter().find(|(_, s)| *s == session) else {
+                break;
+            };
+
+            // The owner lives in the page session or another iframe session
+            let candidates = std::iter::once(self.session_id.clone())
+                .chain(frames.iter().map(|(_, s)| s.clone()))
+                .filter(|candidate| *candidate != session);
+            let mut owner = None;
+            for candidate in candidates {
+                if let Some(position) =
+                    frame_owner_position(&self.connection, &candidate, frame_id).await
+                {
+                    owner = Some((candidate, position));
+                    break;
+                }
+            }
+
+            let Some((parent_session, (x, y))) = owner else {
+                break;
+            };
+            offset = (offset.0 + x, offset.1 + y);
+            session = parent_session;
+        }
+
+        offset
+    }
+}
+
+impl Frame {
+    /// Get the position of the frame's session viewport within the page's
+    /// viewport.
+    ///
+    /// This is `(0, 0)` unless the frame is rendered in an out-of-process iframe.
+    pub(crate) async fn viewport_offset(&self) -> (f64, f64) {
+        match &self.context_registry {
+            Some(registry) => registry.session_offset(&self.session_id).await,
+            None => (0.0, 0.0),
+        }
+    }
+}
+
+/// Position of an iframe's content box within the viewport of `session_id`.
+///
+/// Returns `None` if the iframe is not owned by a document of the session.
+async fn frame_owner_position(
+    connection: &Arc<CdpConnection>,
+    session_id: &str,
+    frame_id: &str,
+) -> Option<(f64, f64)> {
+    #[derive(Deserialize)]
+    struct Position {
+        x: f64,
+        y: f64,
+    }
+
+    let owner: GetFrameOwnerResult = connection
+        .send_command(
+            "DOM.getFrameOwner",
+            Some(GetFrameOwnerParams {
+                frame_id: frame_id.to_string(),
+            }),
+            Some(session_id),
+        )
+        .await
+        .ok()?;
+
+    let resolved: ResolveNodeResult = connection
+        .send_command(
+            "DOM.resolveNode",
+            Some(ResolveNodeParams {
+                node_id: None,
+                backend_node_id: Some(owner.backend_node_id),
+                object_group: Some("viewpoint-frame-owner".to_string()),
+                execution_context_id: None,
+            }),
+            Some(session_id),
+        )
+        .await
+        .ok()?;
+    let object_id = resolved.object.object_id?;
+
+    let content_position = frame_content_position_js();
+    let js_fn = js! {
+        (function() {
+            return (@{content_position})(this);
+        })
+    };
+    // Strip outer parentheses for CDP functionDeclaration
+    let js_fn = js_fn.trim_start_matches('(').trim_end_matches(')');
+
+    let result: Result<CallFunctionOnResult, _> = connection
+        .send_command(
+            "Runtime.callFunctionOn",
+            Some(CallFunctionOnParams {
+                function_declaration: js_fn.to_string(),
+                object_id: Some(object_id.clone()),
+                arguments: None,
+                silent: Some(true),
+                return_by_value: Some(true),
+                generate_preview: None,
+                user_gesture: None,
+                await_promise: None,
+                execution_context_id: None,
+                object_group: None,
+                throw_on_side_effect: None,
+                unique_context_id: None,
+                serialization_options: None,
+            }),
+            Some(session_id),
+        )
+        .await;
+
+    let _ = connection
+        .send_command::<_, serde_json::Value>(
+            "Runtime.releaseObject",
+            Some(serde_json::json!({ "objectId": object_id })),
+            Some(session_id),
+        )
+        .await;
+
+    let position: Position = serde_json::from_value(result.ok()?.result.value?).ok()?;
+    Some((position.x, position.y))
+}
+
+/// Enable the domains and events frames rely on in a new OOPIF session,
+/// auto-attach to its own out-of-process children and resume it.
+async fn init_oopif_session(connection: &Arc<CdpConnection>, session_id: &str) {
+    for method in ["Page.enable", "Runtime.enable"] {
+        if let Err(e) = connection
+            .send_command::<(), serde_json::Value>(method, None, Some(session_id))
+            .await
+        {
+            debug!(error = %e, method, "Failed to enable domain for out-of-process iframe");
+        }
+    }
+
+    // Frame navigation waits on lifecycle events
+    if let Err(e) = connection
+        .send_command::<_, serde_json::Value>(
+            "Page.setLifecycleEventsEnabled",
+            Some(SetLifecycleEventsEnabledParams { enabled: true }),
+            Some(session_id),
+        )
+        .await
+    {
+        debug!(error = %e, "Failed to enable lifecycle events for out-of-process iframe");
+    }
+
+    if let Err(e) = connection
+        .send_command::<_, serde_json::Value>(
+            "Target.setAutoAttach",
+            Some(SetAutoAttachParams {
+                auto_attach: true,
+                wait_for_debugger_on_start: true,
+                flatten: Some(true),
+            }),
+            Some(session_id),
+        )
+        .await
+    {
+        debug!(error = %e, "Failed to enable auto-attach for out-of-process iframe");
+    }
+
+    run_if_waiting_for_debugger(connection, session_id).await;
+}
+
+/// Resume a target paused on start.
+async fn run_if_waiting_for_debugger(connection: &Arc<CdpConnection>, session_id: &str) {
+    let _ = connection
+        .send_command::<(), serde_json::Value>(
+            "Runtime.runIfWaitingForDebugger",
+            None,
+            Some(session_id),
+        )
+        .await;
+}
@@ -7,12 +7,14 @@ use viewpoint_cdp::CdpConnection;
 use viewpoint_cdp::protocol::runtime::ExecutionContextId;
 
 use super::Frame;
+use super::execution_context::ExecutionContextRegistry;
 use crate::error::PageError;
 
 impl Frame {
     /// Get child frames of this frame.
     ///
-    /// Returns a list of frames that are direct children of this frame.
+    /// Returns a list of frames that are direct children of this frame,
+    /// including out-of-process iframes.
     ///
     /// # Errors
     ///
@@ -30,13 +32,29 @@ impl Frame {
             .await?;
 
         // Find this frame in the tree and return its children
-        let children = find_child_frames(
+        let mut children = find_child_frames(
             &result.frame_tree,
             &self.id,
             &self.connection,
             &self.session_id,
         );
 
+        // Out-of-process children live in sessions of their own
+        if let Some(registry) = &self.context_registry {
+            children.retain(|child| registry.oopif_session(child.id()).is_none());
+            for (frame_id, session_id) in registry.oopif_frames() {
+                if registry.parent_frame_id(&frame_id).as_deref() != Some(self.id.as_str()) {
+                    continue;
+                }
+                if let Some(frame) = self
+                    .frame_in_session(&frame_id, &session_id, registry)
+                    .await
+                {
+                    children.push(frame);
+                }
+            }
+        }
+
         Ok(children)
     }
 
@@ -71,8 +89,68 @@ impl Frame {
             &self.connection,
             &self.session_id,
         );
+        if parent.is_some() {
+            return Ok(parent);
+        }
+
+        // The parent of an out-of-process iframe lives in another session
+        let Some(registry) = &self.context_registry else {
+            return Ok(None);
+        };
+        let Some(parent_id) = self
+            .parent_id
+            .clone()
+            .or_else(|| registry.parent_frame_id(&self.id))
+        else {
+            return Ok(None);
+        };
+        let sessions = std::iter::once(registry.session_id.clone()).chain(
+            registry
+                .oopif_frames()
+                .into_iter()
+                .map(|(_, session)| session),
+        );
+        for session_id in sessions {
+            if session_id == self.session_id {
+                continue;
+            }
+            if let Some(frame) = self
+                .frame_in_session(&parent_id, &session_id, registry)
+                .await
+            {
+                return Ok(Some(frame));
+            }
+        }
 
-        Ok(parent)
+        Ok(None)
+    }
+
+    /// Look up a frame in the frame tree of a session.
+    async fn frame_in_session(
+        &self,
+        frame_id: &str,
+        session_id: &str,
+        registry: &Arc<ExecutionContextRegistry>,
+    ) -> Option<Frame> {
+        let result: viewpoint_cdp::protocol::page::GetFrameTreeResult = self
+            .connection
+            .send_command("Page.getFrameTree", None::<()>, Some(session_id))
+            .await
+            .ok()?;
+
+        let info = find_frame_info(&result.frame_tree, frame_id)?;
+        Some(Frame::with_context_registry(
+            self.connection.clone(),
+            session_id.to_string(),
+            info.id.clone(),
+            info.parent_id
+                .clone()
+                .or_else(|| registry.parent_frame_id(frame_id)),
+            info.loader_id.clone(),
+            info.url.clone(),
+            info.name.clone().unwrap_or_default(),
+            registry.clone(),
+        ))
     }
 
     /// Get or create an isolated world execution context for this frame.
@@ -141,6 +219,20 @@ impl Frame {
     }
 }
 
+/// Recursively find the info of a frame by ID.
+fn find_frame_info<'a>(
+    tree: &'a viewpoint_cdp::protocol::page::FrameTree,
+    frame_id: &str,
+) -> Option<&'a viewpoint_cdp::protocol::page::Frame> {
+    if tree.frame.id == frame_id {
+        return Some(&tree.frame);
+    }
+    tree.child_frames
+        .iter()
+        .flatten()
+        .find_map(|child| find_frame_info(child, frame_id))
+}
+
 /// Recursively find child frames of a given frame ID.
 pub(super) fn find_child_frames(
     tree: &viewpoint_cdp::protocol::page::FrameTree,
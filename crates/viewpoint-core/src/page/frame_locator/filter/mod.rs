@@ -0,0 +1,121 @@
+//! Filter builder for frame element locators.
+
+use super::{FrameElementLocator, FrameScope};
+use crate::page::locator::{LocatorOptions, Selector, TextMatcher};
+
+/// Builder for filtering frame element locators by various criteria.
+///
+/// Created via [`FrameElementLocator::filter`]. Filters build the same
+/// selectors as [`FilterBuilder`](crate::page::FilterBuilder), evaluated in
+/// the frame's document.
+#[derive(Debug)]
+pub struct FrameFilterBuilder<'a> {
+    scope: FrameScope<'a>,
+    base_selector: Selector,
+    options: LocatorOptions,
+}
+
+impl<'a> FrameFilterBuilder<'a> {
+    pub(super) fn new(
+        scope: FrameScope<'a>,
+        base_selector: Selector,
+        options: LocatorOptions,
+    ) -> Self {
+        Self {
+            scope,
+            base_selector,
+            options,
+        }
+    }
+
+    /// Filter to elements that contain the specified text.
+    ///
+    /// Accepts a [`TextMatcher`] for exact, case-insensitive or regex matching.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # fn example(page: &Page) {
+    /// let items = page.frame_locator("#cart").locator("li").filter().has_text("Product");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn has_text(self, text: impl Into<TextMatcher>) -> FrameElementLocator<'a> {
+        self.filter_text(text.into().resolve(false), false)
+    }
+
+    /// Filter to elements that contain the exact text.
+    #[must_use]
+    pub fn has_text_exact(self, text: impl Into<String>) -> FrameElementLocator<'a> {
+        self.filter_text(TextMatcher::Exact(text.into()), false)
+    }
+
+    /// Filter to elements that do NOT contain the specified text.
+    ///
+    /// Accepts a [`TextMatcher`] for exact, case-insensitive or regex matching.
+    #[must_use]
+    pub fn has_not_text(self, text: impl Into<TextMatcher>) -> FrameElementLocator<'a> {
+        self.filter_text(text.into().resolve(false), true)
+    }
+
+    /// Filter to elements that do NOT contain the exact text.
+    #[must_use]
+    pub fn has_not_text_exact(self, text: impl Into<String>) -> FrameElementLocator<'a> {
+        self.filter_text(TextMatcher::Exact(text.into()), true)
+    }
+
+    /// Filter to elements that have a descendant matching the given locator.
+    ///
+    /// Only the selector of `child` is used; it is matched in this frame.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # fn example(page: &Page) {
+    /// let table = page.frame_locator("#report");
+    /// let rows = table.locator("tr").filter().has(table.locator(".active"));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn has(self, child: FrameElementLocator<'_>) -> FrameElementLocator<'a> {
+        self.filter_has(child.selector, false)
+    }
+
+    /// Filter to elements that do NOT have a descendant matching the given locator.
+    ///
+    /// Only the selector of `child` is used; it is matched in this frame.
+    #[must_use]
+    pub fn has_not(self, child: FrameElementLocator<'_>) -> FrameElementLocator<'a> {
+        self.filter_has(child.selector, true)
+    }
+
+    /// Filter by text in this builder's frame.
+    fn filter_text(self, text: TextMatcher, has_not: bool) -> FrameElementLocator<'a> {
+        FrameElementLocator {
+            scope: self.scope,
+            selector: Selector::FilterText {
+                base: Box::new(self.base_selector),
+                text,
+                has_not,
+            },
+            options: self.options,
+        }
+    }
+
+    /// Filter by descendant in this builder's frame.
+    fn filter_has(self, child: Selector, has_not: bool) -> FrameElementLocator<'a> {
+        FrameElementLocator {
+            scope: self.scope,
+            selector: Selector::FilterHas {
+                base: Box::new(self.base_selector),
+                child: Box::new(child),
+                has_not,
+            },
+            options: self.options,
+        }
+    }
+}
@@ -1,16 +1,25 @@
 //! Frame locator for interacting with iframe content.
 //!
 //! `FrameLocator` provides a way to locate and interact with elements inside
-//! iframes without needing to directly access Frame objects.
+//! iframes without needing to directly access Frame objects. The
+//! `FrameElementLocator`s it creates are also created by [`Frame::locator`](crate::page::Frame::locator)
+//! and the frame's `get_by_*` methods.
 
 // Allow dead code for frame locator methods (spec: frames)
 
+mod filter;
+mod resolve;
+
 use std::time::Duration;
 
-use super::locator::{AriaRole, LocatorOptions, Selector};
+use super::locator::{AriaRole, LocatorOptions, Selector, TextMatcher};
 use crate::Page;
+use crate::page::Frame;
+pub use filter::FrameFilterBuilder;
+pub(crate) use resolve::{
+    FrameScope, ResolvedFrame, evaluate_in_session, frame_content_position_js,
+};
 use viewpoint_js::js;
-use viewpoint_js_core::escape_js_string_single;
 
 /// Default timeout for frame locator operations.
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
@@ -120,49 +129,60 @@ impl<'a> FrameLocator<'a> {
     /// # }
     /// ```
     pub fn locator(&self, selector: impl Into<String>) -> FrameElementLocator<'a> {
-        FrameElementLocator::new(self.clone(), Selector::Css(selector.into()))
+        FrameElementLocator::new(
+            FrameScope::Locator(self.clone()),
+            Selector::parse(&selector.into()),
+        )
     }
 
     /// Create a locator for elements containing the specified text within this frame.
-    pub fn get_by_text(&self, text: impl Into<String>) -> FrameElementLocator<'a> {
+    ///
+    /// Accepts a [`TextMatcher`] for exact, case-insensitive or regex matching.
+    pub fn get_by_text(&self, text: impl Into<TextMatcher>) -> FrameElementLocator<'a> {
         FrameElementLocator::new(
-            self.clone(),
-            Selector::Text {
-                text: text.into(),
-                exact: false,
-            },
+            FrameScope::Locator(self.clone()),
+            Selector::Text(text.into().resolve(false)),
         )
     }
 
     /// Create a locator for elements with exact text content within this frame.
     pub fn get_by_text_exact(&self, text: impl Into<String>) -> FrameElementLocator<'a> {
         FrameElementLocator::new(
-            self.clone(),
-            Selector::Text {
-                text: text.into(),
-                exact: true,
-            },
+            FrameScope::Locator(self.clone()),
+            Selector::Text(TextMatcher::Exact(text.into())),
         )
     }
 
     /// Create a locator for elements with the specified ARIA role within this frame.
     pub fn get_by_role(&self, role: AriaRole) -> FrameRoleLocatorBuilder<'a> {
-        FrameRoleLocatorBuilder::new(self.clone(), role)
+        FrameRoleLocatorBuilder::new(FrameScope::Locator(self.clone()), role)
     }
 
     /// Create a locator for elements with the specified test ID within this frame.
     pub fn get_by_test_id(&self, test_id: impl Into<String>) -> FrameElementLocator<'a> {
-        FrameElementLocator::new(self.clone(), Selector::TestId(test_id.into()))
+        FrameElementLocator::new(
+            FrameScope::Locator(self.clone()),
+            Selector::TestId(test_id.into()),
+        )
     }
 
     /// Create a locator for form controls by their associated label text within this frame.
-    pub fn get_by_label(&self, label: impl Into<String>) -> FrameElementLocator<'a> {
-        FrameElementLocator::new(self.clone(), Selector::Label(label.into()))
+    pub fn get_by_label(&self, label: impl Into<TextMatcher>) -> FrameElementLocator<'a> {
+        FrameElementLocator::new(
+            FrameScope::Locator(self.clone()),
+            Selector::Label(label.into().resolve(true)),
+        )
     }
 
     /// Create a locator for inputs by their placeholder text within this frame.
-    pub fn get_by_placeholder(&self, placeholder: impl Into<String>) -> FrameElementLocator<'a> {
-        FrameElementLocator::new(self.clone(), Selector::Placeholder(placeholder.into()))
+    pub fn get_by_placeholder(
+        &self,
+        placeholder: impl Into<TextMatcher>,
+    ) -> FrameElementLocator<'a> {
+        FrameElementLocator::new(
+            FrameScope::Locator(self.clone()),
+            Selector::Placeholder(placeholder.into().resolve(true)),
+        )
     }
 
     /// Create a frame locator for a nested iframe within this frame.
@@ -200,49 +220,22 @@ impl<'a> FrameLocator<'a> {
     pub fn parent_selectors(&self) -> &[String] {
         &self.parent_selectors
     }
-
-    /// Build the JavaScript expression to access the frame's content document.
-    ///
-    /// Note: This function builds JavaScript dynamically at runtime because it processes
-    /// a variable number of parent frame selectors. It uses `viewpoint_js_core` escaping
-    /// utilities to ensure proper string escaping.
-    pub(crate) fn to_js_frame_access(&self) -> String {
-        let mut js = String::new();
-
-        // Start from the top-level document
-        js.push_str("(function() {\n");
-        js.push_str("  let doc = document;\n");
-
-        // Navigate through parent frames
-        for parent_selector in &self.parent_selectors {
-            let escaped_selector = escape_js_string_single(parent_selector);
-            js.push_str("  const parent = doc.querySelector(");
-            js.push_str(&escaped_selector);
-            js.push_str(");\n");
-            js.push_str("  if (!parent || !parent.contentDocument) return null;\n");
-            js.push_str("  doc = parent.contentDocument;\n");
-        }
-
-        // Access the final frame
-        let escaped_frame_selector = escape_js_string_single(&self.frame_selector);
-        js.push_str("  const frame = doc.querySelector(");
-        js.push_str(&escaped_frame_selector);
-        js.push_str(");\n");
-        js.push_str("  if (!frame || !frame.contentDocument) return null;\n");
-        js.push_str("  return frame.contentDocument;\n");
-        js.push_str("})()");
-        js
-    }
 }
 
 /// A locator for elements within a frame.
 ///
-/// This combines a `FrameLocator` with an element `Selector` to locate
-/// elements inside an iframe.
+/// This combines a `FrameLocator` or a [`Frame`] with an
+/// element `Selector` to locate elements inside an iframe.
+///
+/// Frame element locators mirror [`Locator`](crate::page::Locator), with two
+/// exceptions: there is no `element_handle`, as element handles are bound to
+/// the page's session while frame elements may live in an out-of-process
+/// iframe's session; and actions never wait for navigation, so they take no
+/// `no_wait_after` option.
 #[derive(Debug, Clone)]
 pub struct FrameElementLocator<'a> {
-    /// The frame locator.
-    frame_locator: FrameLocator<'a>,
+    /// The frame the element is searched in.
+    scope: FrameScope<'a>,
     /// The element selector within the frame.
     selector: Selector,
     /// Locator options.
@@ -251,9 +244,9 @@ pub struct FrameElementLocator<'a> {
 
 impl<'a> FrameElementLocator<'a> {
     /// Create a new frame element locator.
-    fn new(frame_locator: FrameLocator<'a>, selector: Selector) -> Self {
+    pub(crate) fn new(scope: FrameScope<'a>, selector: Selector) -> Self {
         Self {
-            frame_locator,
+            scope,
             selector,
             options: LocatorOptions::default(),
         }
@@ -270,10 +263,10 @@ impl<'a> FrameElementLocator<'a> {
     #[must_use]
     pub fn locator(&self, selector: impl Into<String>) -> FrameElementLocator<'a> {
         FrameElementLocator {
-            frame_locator: self.frame_locator.clone(),
+            scope: self.scope.clone(),
             selector: Selector::Chained(
                 Box::new(self.selector.clone()),
-                Box::new(Selector::Css(selector.into())),
+                Box::new(Selector::parse(&selector.into())),
             ),
             options: self.options.clone(),
         }
@@ -283,7 +276,7 @@ impl<'a> FrameElementLocator<'a> {
     #[must_use]
     pub fn first(&self) -> FrameElementLocator<'a> {
         FrameElementLocator {
-            frame_locator: self.frame_locator.clone(),
+            scope: self.scope.clone(),
             selector: Selector::Nth {
                 base: Box::new(self.selector.clone()),
                 index: 0,
@@ -296,7 +289,7 @@ impl<'a> FrameElementLocator<'a> {
     #[must_use]
     pub fn last(&self) -> FrameElementLocator<'a> {
         FrameElementLocator {
-            frame_locator: self.frame_locator.clone(),
+            scope: self.scope.clone(),
             selector: Selector::Nth {
                 base: Box::new(self.selector.clone()),
                 index: -1,
@@ -309,7 +302,7 @@ impl<'a> FrameElementLocator<'a> {
     #[must_use]
     pub fn nth(&self, index: i32) -> FrameElementLocator<'a> {
         FrameElementLocator {
-            frame_locator: self.frame_locator.clone(),
+            scope: self.scope.clone(),
             selector: Selector::Nth {
                 base: Box::new(self.selector.clone()),
                 index,
@@ -318,9 +311,63 @@ impl<'a> FrameElementLocator<'a> {
         }
     }
 
-    /// Get the frame locator.
-    pub fn frame_locator(&self) -> &FrameLocator<'a> {
-        &self.frame_locator
+    /// Create a locator that matches elements that match both this locator and `other`.
+    ///
+    /// Only the selector of `other` is used; it is matched in this frame.
+    #[must_use]
+    pub fn and(&self, other: FrameElementLocator<'_>) -> FrameElementLocator<'a> {
+        FrameElementLocator {
+            scope: self.scope.clone(),
+            selector: Selector::And(Box::new(self.selector.clone()), Box::new(other.selector)),
+            options: self.options.clone(),
+        }
+    }
+
+    /// Create a locator that matches elements that match either this locator or `other`.
+    ///
+    /// Only the selector of `other` is used; it is matched in this frame.
+    #[must_use]
+    pub fn or(&self, other: FrameElementLocator<'_>) -> FrameElementLocator<'a> {
+        FrameElementLocator {
+            scope: self.scope.clone(),
+            selector: Selector::Or(Box::new(self.selector.clone()), Box::new(other.selector)),
+            options: self.options.clone(),
+        }
+    }
+
+    /// Create a filter builder to narrow down the elements matched by this locator.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # fn example(page: &Page) {
+    /// let item = page.frame_locator("#cart").locator("li").filter().has_text("Product");
+    /// # }
+    /// ```
+    pub fn filter(&self) -> FrameFilterBuilder<'a> {
+        FrameFilterBuilder::new(
+            self.scope.clone(),
+            self.selector.clone(),
+            self.options.clone(),
+        )
+    }
+
+    /// Get the frame locator, if this locator was created from one.
+    pub fn frame_locator(&self) -> Option<&FrameLocator<'a>> {
+        match &self.scope {
+            FrameScope::Locator(frame_locator) => Some(frame_locator),
+            FrameScope::Frame(_) => None,
+        }
+    }
+
+    /// Get the frame, if this locator was created from one.
+    pub fn frame(&self) -> Option<&'a Frame> {
+        match self.scope {
+            FrameScope::Frame(frame) => Some(frame),
+            FrameScope::Locator(_) => None,
+        }
     }
 
     /// Get the selector.
@@ -333,28 +380,29 @@ impl<'a> FrameElementLocator<'a> {
         &self.options
     }
 
-    /// Build the JavaScript expression to query elements within the frame.
-    fn to_js_expression(&self) -> String {
-        let frame_access = self.frame_locator.to_js_frame_access();
+    /// Get the frame the element is searched in.
+    pub(crate) fn scope(&self) -> &FrameScope<'a> {
+        &self.scope
+    }
+
+    /// Build the JavaScript expression evaluating to the array of matching
+    /// elements within a resolved frame.
+    ///
+    /// The array is empty if the frame's document cannot be reached.
+    pub(crate) fn elements_js(&self, frame: &ResolvedFrame) -> String {
+        let frame_access = frame.to_js_frame_access();
         let element_selector = self.selector.to_js_expression();
 
         js! {
             (function() {
                 const frameDoc = @{frame_access};
-                if (!frameDoc) return { found: false, count: 0, error: "Frame not found or not accessible" };
-
-                // Override document for the selector expression
-                const originalDocument = document;
-                try {
-                    // Create a modified expression that uses frameDoc instead of document
-                    const elements = (function() {
-                        const document = frameDoc;
-                        return Array.from(@{element_selector});
-                    })();
-                    return elements;
-                } catch (e) {
-                    return [];
-                }
+                if (!frameDoc) return [];
+
+                // Evaluate the selector expression against the frame's document
+                return (function() {
+                    const document = frameDoc;
+                    return Array.from(@{element_selector});
+                })();
             })()
         }
     }
@@ -363,31 +411,34 @@ impl<'a> FrameElementLocator<'a> {
 /// Builder for role-based frame locators.
 #[derive(Debug)]
 pub struct FrameRoleLocatorBuilder<'a> {
-    frame_locator: FrameLocator<'a>,
+    scope: FrameScope<'a>,
     role: AriaRole,
-    name: Option<String>,
+    name: Option<TextMatcher>,
 }
 
 impl<'a> FrameRoleLocatorBuilder<'a> {
-    fn new(frame_locator: FrameLocator<'a>, role: AriaRole) -> Self {
+    pub(crate) fn new(scope: FrameScope<'a>, role: AriaRole) -> Self {
         Self {
-            frame_locator,
+            scope,
             role,
             name: None,
         }
     }
 
     /// Filter by accessible name.
+    ///
+    /// Plain strings match the whole name; pass a [`TextMatcher`] to match
+    /// otherwise.
     #[must_use]
-    pub fn with_name(mut self, name: impl Into<String>) -> Self {
-        self.name = Some(name.into());
+    pub fn with_name(mut self, name: impl Into<TextMatcher>) -> Self {
+        self.name = Some(name.into().resolve(true));
         self
     }
 
     /// Build the locator.
     pub fn build(self) -> FrameElementLocator<'a> {
         FrameElementLocator::new(
-            self.frame_locator,
+            self.scope,
             Selector::Role {
                 role: self.role,
                 name: self.name,
@@ -401,3 +452,6 @@ impl<'a> From<FrameRoleLocatorBuilder<'a>> for FrameElementLocator<'a> {
         builder.build()
     }
 }
+
+#[cfg(test)]
+mod tests;
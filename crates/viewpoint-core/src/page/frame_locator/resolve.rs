@@ -0,0 +1,302 @@
+//! Resolution of frame locators across out-of-process iframes.
+//!
+//! Same-process iframes are entered through `contentDocument`. Cross-origin
+//! iframes isolated in their own process have no accessible document; they
+//! are entered by switching to the CDP session of the iframe, found through
+//! the iframe element's frame ID.
+//!
+//! Locators created from a [`Frame`] skip the lookup and evaluate in the
+//! frame's own session and execution context.
+
+use std::sync::Arc;
+
+use serde::Deserialize;
+use viewpoint_cdp::CdpConnection;
+use viewpoint_cdp::protocol::dom::{DescribeNodeParams, DescribeNodeResult};
+use viewpoint_cdp::protocol::runtime::{
+    EvaluateParams, EvaluateResult, ExecutionContextId, RemoteObject,
+};
+use viewpoint_js::js;
+
+use super::FrameLocator;
+use crate::Page;
+use crate::error::LocatorError;
+use crate::page::Frame;
+
+/// What a [`FrameElementLocator`](super::FrameElementLocator) searches in.
+#[derive(Debug, Clone)]
+pub(crate) enum FrameScope<'a> {
+    /// An iframe located from the page.
+    Locator(FrameLocator<'a>),
+    /// A frame of the page.
+    Frame(&'a Frame),
+}
+
+impl<'a> FrameScope<'a> {
+    /// Resolve the session and document of the frame.
+    ///
+    /// Returns `None` if the frame cannot be reached (yet).
+    pub(crate) async fn resolve(&self) -> Result<Option<ResolvedFrame>, LocatorError> {
+        match self {
+            Self::Locator(frame_locator) => frame_locator.resolve().await,
+            Self::Frame(frame) => {
+                if frame.is_detached() {
+                    return Err(LocatorError::EvaluationError(
+                        "Frame is detached".to_string(),
+                    ));
+                }
+                Ok(Some(ResolvedFrame {
+                    session_id: frame.session_id().to_string(),
+                    selectors: Vec::new(),
+                    offset: frame.viewport_offset().await,
+                    context_id: frame.main_world_context_id(),
+                }))
+            }
+        }
+    }
+
+    /// Get the CDP connection.
+    pub(crate) fn connection(&self) -> &'a Arc<CdpConnection> {
+        match self {
+            Self::Locator(frame_locator) => frame_locator.page().connection(),
+            Self::Frame(frame) => frame.connection(),
+        }
+    }
+
+    /// Get the session input is dispatched to, which is the page's session.
+    pub(crate) fn input_session_id(&self) -> &'a str {
+        match self {
+            Self::Locator(frame_locator) => frame_locator.page().session_id(),
+            Self::Frame(frame) => frame.page_session_id(),
+        }
+    }
+}
+
+/// A frame located through a [`FrameScope`].
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedFrame {
+    /// Session owning the document the selectors start from.
+    pub session_id: String,
+    /// Same-process iframe selectors leading to the frame's document.
+    pub selectors: Vec<String>,
+    /// Position of the session's viewport within the page's viewport.
+    pub offset: (f64, f64),
+    /// Execution context to evaluate in, or `None` for the session's main frame.
+    pub context_id: Option<ExecutionContextId>,
+}
+
+impl ResolvedFrame {
+    /// Build the JavaScript expression evaluating to the frame's document,
+    /// or `null` if it cannot be reached.
+    pub(crate) fn to_js_frame_access(&self) -> String {
+        frame_access_js(&self.selectors)
+    }
+}
+
+/// Where an iframe element's content lives.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum IframeLookup {
+    /// No such iframe.
+    Missing,
+    /// The content document is accessible from the embedding document.
+    Local,
+    /// The content is in another process; `x`/`y` locate its content box.
+    Remote { x: f64, y: f64 },
+}
+
+impl FrameLocator<'_> {
+    /// Resolve the session and document path of this frame.
+    ///
+    /// Returns `None` if an iframe on the way is not found (yet).
+    pub(crate) async fn resolve(&self) -> Result<Option<ResolvedFrame>, LocatorError> {
+        let page = self.page();
+        if page.is_closed() {
+            return Err(LocatorError::PageClosed);
+        }
+
+        let mut frame = ResolvedFrame {
+            session_id: page.session_id().to_string(),
+            selectors: Vec::new(),
+            offset: (0.0, 0.0),
+            context_id: None,
+        };
+
+        for selector in self
+            .parent_selectors()
+            .iter()
+            .chain(std::iter::once(&self.frame_selector))
+        {
+            let frame_access = frame.to_js_frame_access();
+            let content_position = frame_content_position_js();
+            let lookup_js = js! {
+                (function() {
+                    const doc = @{frame_access};
+                    const frame = doc && doc.querySelector(#{selector});
+                    if (!frame) return { kind: "missing" };
+                    if (frame.contentDocument) return { kind: "local" };
+
+                    const position = (@{content_position})(frame);
+                    return { kind: "remote", x: position.x, y: position.y };
+                })()
+            };
+
+            let value =
+                evaluate_in_session(page.connection(), &frame.session_id, None, &lookup_js, true)
+                    .await?
+                    .value
+                    .unwrap_or_default();
+            let lookup: IframeLookup = serde_json::from_value(value)
+                .map_err(|e| LocatorError::EvaluationError(e.to_string()))?;
+
+            match lookup {
+                IframeLookup::Missing => return Ok(None),
+                IframeLookup::Local => frame.selectors.push(selector.clone()),
+                IframeLookup::Remote { x, y } => {
+                    let element_js = js! {
+                        (function() {
+                            const doc = @{frame_access};
+                            return doc && doc.querySelector(#{selector});
+                        })()
+                    };
+                    let Some(session_id) =
+                        oopif_session_of(page, &frame.session_id, &element_js).await?
+                    else {
+                        return Ok(None);
+                    };
+
+                    frame = ResolvedFrame {
+                        session_id,
+                        selectors: Vec::new(),
+                        offset: (frame.offset.0 + x, frame.offset.1 + y),
+                        context_id: None,
+                    };
+                }
+            }
+        }
+
+        Ok(Some(frame))
+    }
+}
+
+/// Find the session of the out-of-process iframe `element_js` evaluates to.
+///
+/// Returns `None` if the iframe's content is neither accessible nor served
+/// by an attached session, e.g. while the iframe is still loading.
+async fn oopif_session_of(
+    page: &Page,
+    session_id: &str,
+    element_js: &str,
+) -> Result<Option<String>, LocatorError> {
+    let Some(object_id) =
+        evaluate_in_session(page.connection(), session_id, None, element_js, false)
+            .await?
+            .object_id
+    else {
+        return Ok(None);
+    };
+
+    let described: Result<DescribeNodeResult, _> = page
+        .connection()
+        .send_command(
+            "DOM.describeNode",
+            Some(DescribeNodeParams {
+                node_id: None,
+                backend_node_id: None,
+                object_id: Some(object_id.clone()),
+                depth: None,
+                pierce: None,
+            }),
+            Some(session_id),
+        )
+        .await;
+
+    let _ = page
+        .connection()
+        .send_command::<_, serde_json::Value>(
+            "Runtime.releaseObject",
+            Some(serde_json::json!({ "objectId": object_id })),
+            Some(session_id),
+        )
+        .await;
+
+    Ok(described?
+        .node
+        .frame_id
+        .and_then(|frame_id| page.context_registry.oopif_session(&frame_id)))
+}
+
+/// Evaluate an expression in a session, in `context_id` or the session's main frame.
+pub(crate) async fn evaluate_in_session(
+    connection: &CdpConnection,
+    session_id: &str,
+    context_id: Option<ExecutionContextId>,
+    expression: &str,
+    return_by_value: bool,
+) -> Result<RemoteObject, LocatorError> {
+    let result: EvaluateResult = connection
+        .send_command(
+            "Runtime.evaluate",
+            Some(EvaluateParams {
+                expression: expression.to_string(),
+                object_group: None,
+                include_command_line_api: None,
+                silent: Some(true),
+                context_id,
+                return_by_value: Some(return_by_value),
+                await_promise: Some(false),
+            }),
+            Some(session_id),
+        )
+        .await?;
+
+    if let Some(exception) = result.exception_details {
+        return Err(LocatorError::EvaluationError(exception.text));
+    }
+
+    Ok(result.result)
+}
+
+/// JavaScript function returning the `{ x, y }` position of an iframe
+/// element's content box within the viewport of its session's main frame.
+///
+/// Same-process ancestor iframes are accounted for; the position is relative
+/// to the viewport of the session the function is evaluated in.
+pub(crate) fn frame_content_position_js() -> &'static str {
+    js! {
+        (function(frame) {
+            const rect = frame.getBoundingClientRect();
+            const style = frame.ownerDocument.defaultView.getComputedStyle(frame);
+            let x = rect.x + frame.clientLeft + parseFloat(style.paddingLeft);
+            let y = rect.y + frame.clientTop + parseFloat(style.paddingTop);
+            let current = frame.ownerDocument.defaultView.frameElement;
+            while (current) {
+                const currentRect = current.getBoundingClientRect();
+                x += currentRect.x + current.clientLeft;
+                y += currentRect.y + current.clientTop;
+                current = current.ownerDocument.defaultView.frameElement;
+            }
+            return { x: x, y: y };
+        })
+    }
+}
+
+/// Build the JavaScript expression following `selectors` through
+/// same-process iframes from the top-level document.
+///
+/// Note: This function builds JavaScript dynamically at runtime because it processes
+/// a variable number of frame selectors.
+pub(crate) fn frame_access_js(selectors: &[String]) -> String {
+    let selectors = serde_json::to_string(selectors).unwrap_or_else(|_| "[]".to_string());
+    js! {
+        (function() {
+            let doc = document;
+            for (const selector of @{selectors}) {
+                const frame = doc.querySelector(selector);
+                if (!frame || !frame.contentDocument) return null;
+                doc = frame.contentDocument;
+            }
+            return doc;
+        })()
+    }
+}
@@ -0,0 +1,15 @@
+use super::resolve::frame_access_js;
+
+#[test]
+fn test_frame_access_js_escapes_selectors() {
+    let js = frame_access_js(&["#outer".to_string(), r#"iframe[name="pay"]"#.to_string()]);
+    assert!(js.contains(r##"["#outer","iframe[name=\"pay\"]"]"##));
+    assert!(js.contains("contentDocument"));
+}
+
+#[test]
+fn test_frame_access_js_without_selectors_is_document() {
+    let js = frame_access_js(&[]);
+    assert!(js.contains("document"));
+    assert!(js.contains("[]"));
+}
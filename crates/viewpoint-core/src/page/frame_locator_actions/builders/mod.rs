@@ -0,0 +1,217 @@
+//! Builder types for frame element locator actions.
+//!
+//! These mirror the click and tap builders of [`Locator`](crate::page::Locator),
+//! dispatching input to the page at page coordinates.
+
+use tracing::{debug, instrument};
+use viewpoint_cdp::protocol::input::{
+    DispatchMouseEventParams, DispatchTouchEventParams, MouseButton, TouchEventType, TouchPoint,
+};
+
+use super::FrameElementInfo;
+use crate::error::LocatorError;
+use crate::page::frame_locator::FrameElementLocator;
+
+/// Builder for click operations on frame elements.
+///
+/// Created via [`FrameElementLocator::click`]; await it directly for a
+/// plain left click.
+#[derive(Debug)]
+pub struct FrameClickBuilder<'l, 'a> {
+    locator: &'l FrameElementLocator<'a>,
+    /// Position offset from element's top-left corner.
+    position: Option<(f64, f64)>,
+    /// Mouse button to use.
+    button: MouseButton,
+    /// Modifier keys to hold during the click.
+    modifiers: i32,
+    /// Whether to bypass actionability checks.
+    force: bool,
+}
+
+impl<'l, 'a> FrameClickBuilder<'l, 'a> {
+    pub(crate) fn new(locator: &'l FrameElementLocator<'a>) -> Self {
+        Self {
+            locator,
+            position: None,
+            button: MouseButton::Left,
+            modifiers: 0,
+            force: false,
+        }
+    }
+
+    /// Set the position offset from the element's top-left corner.
+    ///
+    /// By default, clicks the center of the element.
+    #[must_use]
+    pub fn position(mut self, x: f64, y: f64) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Set the mouse button to use.
+    #[must_use]
+    pub fn button(mut self, button: MouseButton) -> Self {
+        self.button = button;
+        self
+    }
+
+    /// Set modifier keys to hold during the click.
+    ///
+    /// Use the `modifiers` constants from `viewpoint_cdp::protocol::input::modifiers`.
+    #[must_use]
+    pub fn modifiers(mut self, modifiers: i32) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Whether to bypass actionability checks.
+    #[must_use]
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Execute the click operation.
+    #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector()))]
+    pub async fn send(self) -> Result<(), LocatorError> {
+        let info = self.locator.element_info_for_action(self.force).await?;
+        let (x, y) = self
+            .position
+            .map_or_else(|| info.center(), |p| info.offset(p));
+
+        debug!(x, y, button = ?self.button, "Clicking element in frame");
+
+        let modifiers = (self.modifiers != 0).then_some(self.modifiers);
+
+        let mut mouse_move = DispatchMouseEventParams::mouse_move(x, y);
+        mouse_move.modifiers = modifiers;
+        self.locator.dispatch_mouse_event(mouse_move).await?;
+
+        let mut down = DispatchMouseEventParams::mouse_down(x, y, self.button);
+        down.modifiers = modifiers;
+        self.locator.dispatch_mouse_event(down).await?;
+
+        let mut up = DispatchMouseEventParams::mouse_up(x, y, self.button);
+        up.modifiers = modifiers;
+        self.locator.dispatch_mouse_event(up).await
+    }
+}
+
+impl<'l> std::future::IntoFuture for FrameClickBuilder<'l, '_> {
+    type Output = Result<(), LocatorError>;
+    type IntoFuture =
+        std::pin::Pin<Box<dyn std::future::Future<Output = Self::Output> + Send + 'l>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+/// Builder for tap operations on frame elements.
+///
+/// Created via [`FrameElementLocator::tap`]. Touch emulation must be enabled
+/// on the page, e.g. with a context created with `has_touch(true)`.
+#[derive(Debug)]
+pub struct FrameTapBuilder<'l, 'a> {
+    locator: &'l FrameElementLocator<'a>,
+    position: Option<(f64, f64)>,
+    force: bool,
+    modifiers: i32,
+}
+
+impl<'l, 'a> FrameTapBuilder<'l, 'a> {
+    pub(crate) fn new(locator: &'l FrameElementLocator<'a>) -> Self {
+        Self {
+            locator,
+            position: None,
+            force: false,
+            modifiers: 0,
+        }
+    }
+
+    /// Set the position offset from the element's top-left corner.
+    #[must_use]
+    pub fn position(mut self, x: f64, y: f64) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Whether to bypass actionability checks.
+    #[must_use]
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Set modifier keys to hold during the tap.
+    #[must_use]
+    pub fn modifiers(mut self, modifiers: i32) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Execute the tap operation.
+    #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector()))]
+    pub async fn send(self) -> Result<(), LocatorError> {
+        let info = self.locator.element_info_for_action(self.force).await?;
+        let (x, y) = self
+            .position
+            .map_or_else(|| info.center(), |p| info.offset(p));
+
+        debug!(x, y, modifiers = self.modifiers, "Tapping element in frame");
+
+        let modifiers = (self.modifiers != 0).then_some(self.modifiers);
+
+        self.locator
+            .dispatch_touch_event(DispatchTouchEventParams {
+                event_type: TouchEventType::TouchStart,
+                touch_points: vec![TouchPoint::new(x, y)],
+                modifiers,
+                timestamp: None,
+            })
+            .await?;
+        self.locator
+            .dispatch_touch_event(DispatchTouchEventParams {
+                event_type: TouchEventType::TouchEnd,
+                touch_points: vec![],
+                modifiers,
+                timestamp: None,
+            })
+            .await
+    }
+}
+
+impl<'l> std::future::IntoFuture for FrameTapBuilder<'l, '_> {
+    type Output = Result<(), LocatorError>;
+    type IntoFuture =
+        std::pin::Pin<Box<dyn std::future::Future<Output = Self::Output> + Send + 'l>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+impl FrameElementLocator<'_> {
+    /// Get the element to act on, waiting for it to be actionable unless forced.
+    ///
+    /// Forced actions only require the element to exist; missing coordinates
+    /// are taken as zero.
+    async fn element_info_for_action(&self, force: bool) -> Result<FrameElementInfo, LocatorError> {
+        if !force {
+            return self.wait_for_actionable().await;
+        }
+
+        let info = self.query_element_info().await?;
+        if !info.found {
+            return Err(LocatorError::NotFound(format!("{:?}", self.selector())));
+        }
+        Ok(FrameElementInfo {
+            x: Some(info.x.unwrap_or(0.0)),
+            y: Some(info.y.unwrap_or(0.0)),
+            width: Some(info.width.unwrap_or(0.0)),
+            height: Some(info.height.unwrap_or(0.0)),
+            ..info
+        })
+    }
+}
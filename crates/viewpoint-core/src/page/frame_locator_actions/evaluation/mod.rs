@@ -0,0 +1,126 @@
+//! JavaScript evaluation on frame element locators.
+
+use tracing::{debug, instrument};
+use viewpoint_js::js;
+
+use crate::error::LocatorError;
+use crate::page::frame_locator::FrameElementLocator;
+
+impl FrameElementLocator<'_> {
+    /// Evaluate a JavaScript expression with the first matching element.
+    ///
+    /// The element is available as `element` in the expression.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// let tag: String = page.frame_locator("#editor")
+    ///     .locator("textarea")
+    ///     .evaluate("element.tagName")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element is not found, the expression throws,
+    /// or the result cannot be deserialized.
+    #[instrument(level = "debug", skip(self), fields(selector = ?self.selector()))]
+    pub async fn evaluate<T: serde::de::DeserializeOwned>(
+        &self,
+        expression: &str,
+    ) -> Result<T, LocatorError> {
+        self.wait_for_actionable().await?;
+
+        debug!(expression, "Evaluating expression on frame element");
+
+        let function = js! {
+            (function(elements) {
+                if (elements.length === 0) return { __viewpoint_error: "Element not found" };
+
+                const element = elements[0];
+                try {
+                    const result = (function(element) { return @{expression}; })(element);
+                    return { __viewpoint_result: result };
+                } catch (e) {
+                    return { __viewpoint_error: e.toString() };
+                }
+            })
+        };
+
+        let result = self.evaluate_on_elements(&function).await?;
+        evaluation_result(&result)
+    }
+
+    /// Evaluate a JavaScript expression with all matching elements.
+    ///
+    /// The elements are available as `elements` in the expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression throws or the result cannot be
+    /// deserialized.
+    #[instrument(level = "debug", skip(self), fields(selector = ?self.selector()))]
+    pub async fn evaluate_all<T: serde::de::DeserializeOwned>(
+        &self,
+        expression: &str,
+    ) -> Result<T, LocatorError> {
+        debug!(expression, "Evaluating expression on all frame elements");
+
+        let function = js! {
+            (function(elements) {
+                try {
+                    const result = (function(elements) { return @{expression}; })(elements);
+                    return { __viewpoint_result: result };
+                } catch (e) {
+                    return { __viewpoint_error: e.toString() };
+                }
+            })
+        };
+
+        let result = self.evaluate_on_elements(&function).await?;
+        evaluation_result(&result)
+    }
+
+    /// Scroll the element into view within its frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be found.
+    #[instrument(level = "debug", skip(self), fields(selector = ?self.selector()))]
+    pub async fn scroll_into_view_if_needed(&self) -> Result<(), LocatorError> {
+        let function = js! {
+            (function(elements) {
+                if (elements.length === 0) return false;
+                elements[0].scrollIntoView({ behavior: "instant", block: "center", inline: "center" });
+                return true;
+            })
+        };
+
+        let found = self.evaluate_on_elements(function).await?;
+        if found.as_bool() != Some(true) {
+            return Err(LocatorError::NotFound(format!("{:?}", self.selector())));
+        }
+        Ok(())
+    }
+}
+
+/// Unwrap a `{ __viewpoint_result }` / `{ __viewpoint_error }` evaluation result.
+fn evaluation_result<T: serde::de::DeserializeOwned>(
+    result: &serde_json::Value,
+) -> Result<T, LocatorError> {
+    if let Some(error) = result.get("__viewpoint_error").and_then(|v| v.as_str()) {
+        return Err(LocatorError::EvaluationError(error.to_string()));
+    }
+
+    let value = result
+        .get("__viewpoint_result")
+        .cloned()
+        .unwrap_or(serde_json::Value::Null);
+    serde_json::from_value(value)
+        .map_err(|e| LocatorError::EvaluationError(format!("Failed to deserialize result: {e}")))
+}
@@ -0,0 +1,175 @@
+//! File input actions for frame element locators.
+
+use tracing::debug;
+use viewpoint_cdp::protocol::dom::SetFileInputFilesParams;
+use viewpoint_js::js;
+
+use crate::error::LocatorError;
+use crate::page::FilePayload;
+use crate::page::frame_locator::FrameElementLocator;
+use crate::page::locator::file_payloads_json;
+
+impl FrameElementLocator<'_> {
+    /// Set the files of a file input.
+    ///
+    /// Pass an empty slice to clear the selection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element is not a file input, or several
+    /// files are set on an input without `multiple`.
+    #[tracing::instrument(level = "debug", skip(self, files), fields(selector = ?self.selector(), file_count = files.len()))]
+    pub async fn set_input_files<P: AsRef<std::path::Path>>(
+        &self,
+        files: &[P],
+    ) -> Result<(), LocatorError> {
+        self.wait_for_actionable().await?;
+
+        let file_paths: Vec<String> = files
+            .iter()
+            .map(|p| p.as_ref().to_string_lossy().into_owned())
+            .collect();
+
+        debug!("Setting {} files on file input in frame", file_paths.len());
+
+        let check_js = js! {
+            (function(elements) {
+                if (elements.length === 0) return { found: false, error: "Element not found" };
+
+                const el = elements[0];
+                if (el.tagName.toLowerCase() !== "input" || el.type !== "file") {
+                    return { found: false, error: "Element is not a file input" };
+                }
+
+                return { found: true, isMultiple: el.multiple };
+            })
+        };
+        let result = self.evaluate_on_elements(check_js).await?;
+
+        if !result
+            .get("found")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+        {
+            let error = result
+                .get("error")
+                .and_then(|v| v.as_str())
+                .unwrap_or("Unknown error");
+            return Err(LocatorError::EvaluationError(error.to_string()));
+        }
+
+        let is_multiple = result
+            .get("isMultiple")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        if !is_multiple && file_paths.len() > 1 {
+            return Err(LocatorError::EvaluationError(
+                "Cannot set multiple files on a single file input".to_string(),
+            ));
+        }
+
+        let frame = self.resolve_frame().await?;
+        let object_id = self.first_element_object_id(&frame).await?;
+
+        self.scope()
+            .connection()
+            .send_command::<_, serde_json::Value>(
+                "DOM.setFileInputFiles",
+                Some(SetFileInputFilesParams {
+                    files: file_paths,
+                    node_id: None,
+                    backend_node_id: None,
+                    object_id: Some(object_id),
+                }),
+                Some(&frame.session_id),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Set the files of a file input from memory buffers.
+    ///
+    /// Pass an empty slice to clear the selection.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::{FilePayload, Page};
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// let payload = FilePayload::from_text("notes.txt", "Hello");
+    /// page.frame_locator("#upload-frame")
+    ///     .locator("input[type=file]")
+    ///     .set_input_files_from_buffer(&[payload])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element is not a file input, or several
+    /// files are set on an input without `multiple`.
+    #[tracing::instrument(level = "debug", skip(self, files), fields(selector = ?self.selector(), file_count = files.len()))]
+    pub async fn set_input_files_from_buffer(
+        &self,
+        files: &[FilePayload],
+    ) -> Result<(), LocatorError> {
+        self.wait_for_actionable().await?;
+
+        debug!(
+            "Setting {} files from buffer on file input in frame",
+            files.len()
+        );
+
+        let file_data_json = file_payloads_json(files)?;
+        let set_files_js = js! {
+            (function(elements) {
+                if (elements.length === 0) return { success: false, error: "Element not found" };
+
+                const input = elements[0];
+                if (input.tagName.toLowerCase() !== "input" || input.type !== "file") {
+                    return { success: false, error: "Element is not a file input" };
+                }
+
+                const fileData = @{file_data_json};
+                if (!input.multiple && fileData.length > 1) {
+                    return { success: false, error: "Cannot set multiple files on a single file input" };
+                }
+
+                // Create a DataTransfer holding File objects decoded from base64
+                const dataTransfer = new DataTransfer();
+                for (const fd of fileData) {
+                    const binaryString = atob(fd.data);
+                    const bytes = new Uint8Array(binaryString.length);
+                    for (let i = 0; i < binaryString.length; i++) {
+                        bytes[i] = binaryString.charCodeAt(i);
+                    }
+                    dataTransfer.items.add(new File([bytes], fd.name, { type: fd.mimeType }));
+                }
+
+                input.files = dataTransfer.files;
+                input.dispatchEvent(new Event("change", { bubbles: true }));
+                input.dispatchEvent(new Event("input", { bubbles: true }));
+
+                return { success: true };
+            })
+        };
+        let result = self.evaluate_on_elements(&set_files_js).await?;
+
+        if !result
+            .get("success")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+        {
+            let error = result
+                .get("error")
+                .and_then(|v| v.as_str())
+                .unwrap_or("Unknown error");
+            return Err(LocatorError::EvaluationError(error.to_string()));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+//! Internal helpers for frame element locators.
+//!
+//! Elements are queried in the session owning the frame; input is dispatched
+//! to the page session at page coordinates.
+
+use viewpoint_cdp::protocol::input::{
+    DispatchKeyEventParams, DispatchMouseEventParams, DispatchTouchEventParams, InsertTextParams,
+};
+use viewpoint_js::js;
+
+use super::{FRAME_NOT_FOUND, FrameElementInfo};
+use crate::error::LocatorError;
+use crate::page::frame_locator::{
+    FrameElementLocator, ResolvedFrame, evaluate_in_session, frame_content_position_js,
+};
+
+impl FrameElementLocator<'_> {
+    /// Wait for element to be actionable (visible, enabled, stable).
+    pub(crate) async fn wait_for_actionable(&self) -> Result<FrameElementInfo, LocatorError> {
+        let start = std::time::Instant::now();
+        let timeout = self.options().timeout;
+
+        loop {
+            let info = self.query_element_info().await?;
+
+            if let Some(error) = &info.error {
+                if start.elapsed() >= timeout {
+                    return Err(LocatorError::NotFound(error.clone()));
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            }
+
+            if !info.found {
+                if start.elapsed() >= timeout {
+                    return Err(LocatorError::NotFound(format!("{:?}", self.selector())));
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            }
+
+            if !info.visible.unwrap_or(false) {
+                if start.elapsed() >= timeout {
+                    return Err(LocatorError::NotVisible);
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            }
+
+            return Ok(info);
+        }
+    }
+
+    /// Query element information within the frame.
+    ///
+    /// Coordinates are relative to the page's viewport.
+    pub(crate) async fn query_element_info(&self) -> Result<FrameElementInfo, LocatorError> {
+        let Some(frame) = self.scope().resolve().await? else {
+            return Ok(FrameElementInfo {
+                error: Some(FRAME_NOT_FOUND.to_string()),
+                ..FrameElementInfo::default()
+            });
+        };
+        let frame_access = frame.to_js_frame_access();
+        let elements = self.elements_js(&frame);
+        let content_position = frame_content_position_js();
+
+        let js_code = js! {
+            (function() {
+                const frameDoc = @{frame_access};
+                if (!frameDoc) {
+                    return { found: false, count: 0, error: #{FRAME_NOT_FOUND} };
+                }
+
+                let elements;
+                try {
+                    elements = @{elements};
+                } catch (e) {
+                    return { found: false, count: 0, error: e.message };
+                }
+
+                if (elements.length === 0) {
+                    return { found: false, count: 0 };
+                }
+
+                const el = elements[0];
+                const rect = el.getBoundingClientRect();
+
+                // Get frame position to calculate absolute coordinates
+                const frameElement = frameDoc.defaultView?.frameElement;
+                const frameRect = frameElement
+                    ? (@{content_position})(frameElement)
+                    : { x: 0, y: 0 };
+
+                const style = frameDoc.defaultView?.getComputedStyle(el) || window.getComputedStyle(el);
+                const visible = rect.width > 0 && rect.height > 0 &&
+                    style.visibility !== "hidden" &&
+                    style.display !== "none" &&
+                    parseFloat(style.opacity) > 0;
+
+                return {
+                    found: true,
+                    count: elements.length,
+                    visible: visible,
+                    enabled: !el.disabled,
+                    x: frameRect.x + rect.x,
+                    y: frameRect.y + rect.y,
+                    width: rect.width,
+                    height: rect.height,
+                    text: el.textContent
+                };
+            })()
+        };
+
+        let result = self.evaluate_js(&frame, &js_code).await?;
+        let mut info: FrameElementInfo = serde_json::from_value(result)
+            .map_err(|e| LocatorError::EvaluationError(e.to_string()))?;
+
+        // Translate from the frame session's viewport to the page's
+        let (offset_x, offset_y) = frame.offset;
+        info.x = info.x.map(|x| x + offset_x);
+        info.y = info.y.map(|y| y + offset_y);
+        Ok(info)
+    }
+
+    /// Focus the element via JavaScript.
+    pub(crate) async fn focus_element(&self) -> Result<(), LocatorError> {
+        let function = js! {
+            (function(elements) {
+                if (elements.length > 0) {
+                    elements[0].focus();
+                    return true;
+                }
+                return false;
+            })
+        };
+
+        self.evaluate_on_elements(function).await?;
+        Ok(())
+    }
+
+    /// Resolve the frame, failing if it cannot be reached.
+    pub(crate) async fn resolve_frame(&self) -> Result<ResolvedFrame, LocatorError> {
+        self.scope()
+            .resolve()
+            .await?
+            .ok_or_else(|| LocatorError::NotFound(FRAME_NOT_FOUND.to_string()))
+    }
+
+    /// Call a JavaScript function with the array of matching elements and
+    /// return its result.
+    pub(crate) async fn evaluate_on_elements(
+        &self,
+        function: &str,
+    ) -> Result<serde_json::Value, LocatorError> {
+        let frame = self.resolve_frame().await?;
+        let elements = self.elements_js(&frame);
+        let js_code = js! { (@{function})(@{elements}) };
+
+        self.evaluate_js(&frame, &js_code).await
+    }
+
+    /// Get a reference to the first matching element.
+    pub(crate) async fn first_element_object_id(
+        &self,
+        frame: &ResolvedFrame,
+    ) -> Result<String, LocatorError> {
+        let elements = self.elements_js(frame);
+        let js_code = js! { (@{elements})[0] };
+
+        evaluate_in_session(
+            self.scope().connection(),
+            &frame.session_id,
+            frame.context_id,
+            &js_code,
+            false,
+        )
+        .await?
+        .object_id
+        .ok_or_else(|| LocatorError::NotFound(format!("{:?}", self.selector())))
+    }
+
+    /// Evaluate JavaScript in the frame and return the result.
+    pub(crate) async fn evaluate_js(
+        &self,
+        frame: &ResolvedFrame,
+        expression: &str,
+    ) -> Result<serde_json::Value, LocatorError> {
+        evaluate_in_session(
+            self.scope().connection(),
+            &frame.session_id,
+            frame.context_id,
+            expression,
+            true,
+        )
+        .await?
+        .value
+        .ok_or_else(|| LocatorError::EvaluationError("No result value".to_string()))
+    }
+
+    /// Dispatch a mouse event.
+    pub(crate) async fn dispatch_mouse_event(
+        &self,
+        params: DispatchMouseEventParams,
+    ) -> Result<(), LocatorError> {
+        self.scope()
+            .connection()
+            .send_command::<_, serde_json::Value>(
+                "Input.dispatchMouseEvent",
+                Some(params),
+                Some(self.scope().input_session_id()),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Dispatch a key event.
+    pub(crate) async fn dispatch_key_event(
+        &self,
+        params: DispatchKeyEventParams,
+    ) -> Result<(), LocatorError> {
+        self.scope()
+            .connection()
+            .send_command::<_, serde_json::Value>(
+                "Input.dispatchKeyEvent",
+                Some(params),
+                Some(self.scope().input_session_id()),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Dispatch a touch event.
+    pub(crate) async fn dispatch_touch_event(
+        &self,
+        params: DispatchTouchEventParams,
+    ) -> Result<(), LocatorError> {
+        self.scope()
+            .connection()
+            .send_command::<_, serde_json::Value>(
+                "Input.dispatchTouchEvent",
+                Some(params),
+                Some(self.scope().input_session_id()),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Insert text directly.
+    pub(crate) async fn insert_text(&self, text: &str) -> Result<(), LocatorError> {
+        self.scope()
+            .connection()
+            .send_command::<_, serde_json::Value>(
+                "Input.insertText",
+                Some(InsertTextParams {
+                    text: text.to_string(),
+                }),
+                Some(self.scope().input_session_id()),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -2,19 +2,37 @@
 //!
 //! This module provides the action methods (click, fill, etc.) for
 //! `FrameElementLocator`.
+//!
+//! Elements are queried in the session owning the frame, so out-of-process
+//! iframes work like same-process ones. Input is dispatched to the page at
+//! page coordinates and routed to the frame by the browser.
+
+mod builders;
+mod evaluation;
+mod files;
+mod helpers;
+mod queries;
 
 use serde::Deserialize;
 use tracing::debug;
 use viewpoint_cdp::protocol::input::{
-    DispatchKeyEventParams, DispatchMouseEventParams, InsertTextParams, MouseButton,
+    DispatchKeyEventParams, DispatchMouseEventParams, MouseButton,
+};
+use viewpoint_cdp::protocol::page::{
+    CaptureScreenshotParams, CaptureScreenshotResult, ScreenshotFormat, Viewport,
 };
-use viewpoint_js::js;
 
 use super::frame_locator::FrameElementLocator;
+use super::locator::{build_select_option_js, build_select_options_js, check_select_result};
+use super::screenshot::base64_decode;
 use crate::error::LocatorError;
+pub use builders::{FrameClickBuilder, FrameTapBuilder};
+
+/// Error reported while the frame cannot be reached.
+const FRAME_NOT_FOUND: &str = "Frame not found or not accessible";
 
 /// Result of querying element information in a frame.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct FrameElementInfo {
     /// Whether the element exists.
@@ -36,40 +54,73 @@ pub(crate) struct FrameElementInfo {
     pub error: Option<String>,
 }
 
-impl FrameElementLocator<'_> {
+impl FrameElementInfo {
+    /// Get the center of an actionable element, in page coordinates.
+    fn center(&self) -> (f64, f64) {
+        (
+            self.x.expect("visible element has x")
+                + self.width.expect("visible element has width") / 2.0,
+            self.y.expect("visible element has y")
+                + self.height.expect("visible element has height") / 2.0,
+        )
+    }
+
+    /// Get a point relative to the top-left corner of an actionable element.
+    fn offset(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (
+            self.x.expect("visible element has x") + x,
+            self.y.expect("visible element has y") + y,
+        )
+    }
+}
+
+impl<'a> FrameElementLocator<'a> {
     /// Click the element within the frame.
     ///
     /// Waits for the element to be visible and enabled, then clicks its center.
+    /// Returns a builder for setting the position, button, modifiers or
+    /// `force`; await it directly for a plain click.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// let frame = page.frame_locator("#editor");
+    /// frame.locator("button").click().await?;
+    /// frame.locator("canvas").click().position(10.0, 20.0).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn click(&self) -> FrameClickBuilder<'_, 'a> {
+        FrameClickBuilder::new(self)
+    }
+
+    /// Double-click the element within the frame.
     ///
     /// # Errors
     ///
-    /// Returns an error if the frame or element is not found, not visible, or the click fails.
+    /// Returns an error if the frame or element is not found, not visible, or the clicks fail.
     #[tracing::instrument(level = "debug", skip(self), fields(selector = ?self.selector()))]
-    pub async fn click(&self) -> Result<(), LocatorError> {
+    pub async fn dblclick(&self) -> Result<(), LocatorError> {
         let info = self.wait_for_actionable().await?;
+        let (x, y) = info.center();
 
-        let x = info.x.expect("visible element has x")
-            + info.width.expect("visible element has width") / 2.0;
-        let y = info.y.expect("visible element has y")
-            + info.height.expect("visible element has height") / 2.0;
-
-        debug!(x, y, "Clicking element in frame");
+        debug!(x, y, "Double-clicking element in frame");
 
-        // Move to element
         self.dispatch_mouse_event(DispatchMouseEventParams::mouse_move(x, y))
             .await?;
 
-        // Mouse down
-        self.dispatch_mouse_event(DispatchMouseEventParams::mouse_down(
-            x,
-            y,
-            MouseButton::Left,
-        ))
-        .await?;
+        for click_count in 1..=2 {
+            let mut down = DispatchMouseEventParams::mouse_down(x, y, MouseButton::Left);
+            down.click_count = Some(click_count);
+            self.dispatch_mouse_event(down).await?;
 
-        // Mouse up
-        self.dispatch_mouse_event(DispatchMouseEventParams::mouse_up(x, y, MouseButton::Left))
-            .await?;
+            let mut up = DispatchMouseEventParams::mouse_up(x, y, MouseButton::Left);
+            up.click_count = Some(click_count);
+            self.dispatch_mouse_event(up).await?;
+        }
 
         Ok(())
     }
@@ -89,13 +140,7 @@ impl FrameElementLocator<'_> {
         self.focus_element().await?;
 
         // Select all and delete (clear)
-        let mut select_all = DispatchKeyEventParams::key_down("a");
-        select_all.modifiers = Some(viewpoint_cdp::protocol::input::modifiers::CTRL);
-        self.dispatch_key_event(select_all).await?;
-
-        // Delete selected text
-        self.dispatch_key_event(DispatchKeyEventParams::key_down("Backspace"))
-            .await?;
+        self.select_all_and_delete().await?;
 
         // Insert the new text
         self.insert_text(text).await?;
@@ -103,6 +148,21 @@ impl FrameElementLocator<'_> {
         Ok(())
     }
 
+    /// Clear the element's content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be focused or keys cannot be dispatched.
+    #[tracing::instrument(level = "debug", skip(self), fields(selector = ?self.selector()))]
+    pub async fn clear(&self) -> Result<(), LocatorError> {
+        self.wait_for_actionable().await?;
+
+        debug!("Clearing element in frame");
+
+        self.focus_element().await?;
+        self.select_all_and_delete().await
+    }
+
     /// Type text character by character.
     ///
     /// # Errors
@@ -127,6 +187,50 @@ impl FrameElementLocator<'_> {
         Ok(())
     }
 
+    /// Press a key or key combination on the element.
+    ///
+    /// Combinations join modifiers and the key with `+`, e.g. `"Control+a"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be focused or keys cannot be dispatched.
+    #[tracing::instrument(level = "debug", skip(self), fields(selector = ?self.selector()))]
+    pub async fn press(&self, key: &str) -> Result<(), LocatorError> {
+        self.wait_for_actionable().await?;
+
+        debug!(key, "Pressing key in frame element");
+
+        self.focus_element().await?;
+
+        // Parse modifiers and key
+        let parts: Vec<&str> = key.split('+').collect();
+        let actual_key = *parts.last().unwrap_or(&key);
+
+        let mut modifiers = 0;
+        for part in &parts[..parts.len().saturating_sub(1)] {
+            match part.to_lowercase().as_str() {
+                "control" | "ctrl" => {
+                    modifiers |= viewpoint_cdp::protocol::input::modifiers::CTRL;
+                }
+                "alt" => modifiers |= viewpoint_cdp::protocol::input::modifiers::ALT,
+                "shift" => modifiers |= viewpoint_cdp::protocol::input::modifiers::SHIFT,
+                "meta" | "cmd" => modifiers |= viewpoint_cdp::protocol::input::modifiers::META,
+                _ => {}
+            }
+        }
+
+        let mut key_down = DispatchKeyEventParams::key_down(actual_key);
+        let mut key_up = DispatchKeyEventParams::key_up(actual_key);
+        if modifiers != 0 {
+            key_down.modifiers = Some(modifiers);
+            key_up.modifiers = Some(modifiers);
+        }
+        self.dispatch_key_event(key_down).await?;
+        self.dispatch_key_event(key_up).await?;
+
+        Ok(())
+    }
+
     /// Hover over the element.
     ///
     /// # Errors
@@ -135,11 +239,7 @@ impl FrameElementLocator<'_> {
     #[tracing::instrument(level = "debug", skip(self), fields(selector = ?self.selector()))]
     pub async fn hover(&self) -> Result<(), LocatorError> {
         let info = self.wait_for_actionable().await?;
-
-        let x = info.x.expect("visible element has x")
-            + info.width.expect("visible element has width") / 2.0;
-        let y = info.y.expect("visible element has y")
-            + info.height.expect("visible element has height") / 2.0;
+        let (x, y) = info.center();
 
         debug!(x, y, "Hovering over element in frame");
 
@@ -149,234 +249,207 @@ impl FrameElementLocator<'_> {
         Ok(())
     }
 
-    /// Get the text content of the element.
+    /// Tap the element within the frame.
+    ///
+    /// Touch emulation must be enabled on the page. Returns a builder for
+    /// setting the position, modifiers or `force`; await it directly for a
+    /// plain tap.
+    pub fn tap(&self) -> FrameTapBuilder<'_, 'a> {
+        FrameTapBuilder::new(self)
+    }
+
+    /// Focus the element.
     ///
     /// # Errors
     ///
-    /// Returns an error if the element cannot be queried.
-    pub async fn text_content(&self) -> Result<Option<String>, LocatorError> {
-        let info = self.query_element_info().await?;
-        Ok(info.text)
+    /// Returns an error if the element cannot be found.
+    pub async fn focus(&self) -> Result<(), LocatorError> {
+        self.wait_for_actionable().await?;
+        self.focus_element().await
     }
 
-    /// Check if the element is visible.
+    /// Check a checkbox or radio button.
+    ///
+    /// Does nothing if the element is already checked.
     ///
     /// # Errors
     ///
-    /// Returns an error if the element cannot be queried.
-    pub async fn is_visible(&self) -> Result<bool, LocatorError> {
-        let info = self.query_element_info().await?;
-        Ok(info.visible.unwrap_or(false))
+    /// Returns an error if the element cannot be found or clicked.
+    pub async fn check(&self) -> Result<(), LocatorError> {
+        self.set_checked(true).await
     }
 
-    /// Count matching elements.
+    /// Uncheck a checkbox.
+    ///
+    /// Does nothing if the element is already unchecked.
     ///
     /// # Errors
     ///
-    /// Returns an error if the elements cannot be queried.
-    pub async fn count(&self) -> Result<usize, LocatorError> {
-        let info = self.query_element_info().await?;
-        Ok(info.count)
+    /// Returns an error if the element cannot be found or clicked.
+    pub async fn uncheck(&self) -> Result<(), LocatorError> {
+        self.set_checked(false).await
     }
 
-    // =========================================================================
-    // Internal helpers
-    // =========================================================================
-
-    /// Wait for element to be actionable (visible, enabled, stable).
-    pub(crate) async fn wait_for_actionable(&self) -> Result<FrameElementInfo, LocatorError> {
-        let start = std::time::Instant::now();
-        let timeout = self.options().timeout;
-
-        loop {
-            let info = self.query_element_info().await?;
-
-            if let Some(error) = &info.error {
-                if start.elapsed() >= timeout {
-                    return Err(LocatorError::NotFound(error.clone()));
-                }
-                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-                continue;
-            }
-
-            if !info.found {
-                if start.elapsed() >= timeout {
-                    return Err(LocatorError::NotFound(format!("{:?}", self.selector())));
-                }
-                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-                continue;
-            }
+    /// Select an option in a `<select>` element by value or label.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element is not a select or the option is not found.
+    #[tracing::instrument(level = "debug", skip(self), fields(selector = ?self.selector()))]
+    pub async fn select_option(&self, option: &str) -> Result<(), LocatorError> {
+        self.wait_for_actionable().await?;
 
-            if !info.visible.unwrap_or(false) {
-                if start.elapsed() >= timeout {
-                    return Err(LocatorError::NotVisible);
-                }
-                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-                continue;
-            }
+        debug!(option, "Selecting option in frame element");
 
-            return Ok(info);
-        }
+        let frame = self.resolve_frame().await?;
+        let js = build_select_option_js(&self.elements_js(&frame), option);
+        let result = self.evaluate_js(&frame, &js).await?;
+        check_select_result(&result)
     }
 
-    /// Query element information within the frame.
-    pub(crate) async fn query_element_info(&self) -> Result<FrameElementInfo, LocatorError> {
-        let frame_access = self.frame_locator().to_js_frame_access();
-        let element_selector = self.selector().to_js_expression();
-
-        let js_code = js! {
-            (function() {
-                const frameDoc = @{frame_access};
-                if (!frameDoc) {
-                    return { found: false, count: 0, error: "Frame not found or not accessible" };
-                }
-
-                // Create a modified expression that uses frameDoc instead of document
-                let elements;
-                try {
-                    elements = (function() {
-                        const document = frameDoc;
-                        return Array.from(@{element_selector});
-                    })();
-                } catch (e) {
-                    return { found: false, count: 0, error: e.message };
-                }
+    /// Select several options in a `<select multiple>` element by value or label.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element is not a multiple select or an option is not found.
+    #[tracing::instrument(level = "debug", skip(self), fields(selector = ?self.selector()))]
+    pub async fn select_options(&self, options: &[&str]) -> Result<(), LocatorError> {
+        self.wait_for_actionable().await?;
 
-                if (elements.length === 0) {
-                    return { found: false, count: 0 };
-                }
+        debug!(?options, "Selecting options in frame element");
 
-                const el = elements[0];
-                const rect = el.getBoundingClientRect();
-
-                // Get frame position to calculate absolute coordinates
-                let frameRect = { x: 0, y: 0 };
-                let current = frameDoc.defaultView?.frameElement;
-                while (current) {
-                    const currentRect = current.getBoundingClientRect();
-                    frameRect.x += currentRect.x;
-                    frameRect.y += currentRect.y;
-                    current = current.ownerDocument?.defaultView?.frameElement;
-                }
-
-                const style = frameDoc.defaultView?.getComputedStyle(el) || window.getComputedStyle(el);
-                const visible = rect.width > 0 && rect.height > 0 &&
-                    style.visibility !== "hidden" &&
-                    style.display !== "none" &&
-                    parseFloat(style.opacity) > 0;
-
-                return {
-                    found: true,
-                    count: elements.length,
-                    visible: visible,
-                    enabled: !el.disabled,
-                    x: frameRect.x + rect.x,
-                    y: frameRect.y + rect.y,
-                    width: rect.width,
-                    height: rect.height,
-                    text: el.textContent
-                };
-            })()
-        };
-
-        let result = self.evaluate_js(&js_code).await?;
-        let info: FrameElementInfo = serde_json::from_value(result)
-            .map_err(|e| LocatorError::EvaluationError(e.to_string()))?;
-        Ok(info)
+        let frame = self.resolve_frame().await?;
+        let js = build_select_options_js(&self.elements_js(&frame), options);
+        let result = self.evaluate_js(&frame, &js).await?;
+        check_select_result(&result)
     }
 
-    /// Focus the element via JavaScript.
-    pub(crate) async fn focus_element(&self) -> Result<(), LocatorError> {
-        let frame_access = self.frame_locator().to_js_frame_access();
-        let element_selector = self.selector().to_js_expression();
-
-        let js_code = js! {
-            (function() {
-                const frameDoc = @{frame_access};
-                if (!frameDoc) return false;
-
-                const elements = (function() {
-                    const document = frameDoc;
-                    return Array.from(@{element_selector});
-                })();
-
-                if (elements.length > 0) {
-                    elements[0].focus();
-                    return true;
-                }
-                return false;
-            })()
-        };
-
-        self.evaluate_js(&js_code).await?;
-        Ok(())
+    /// Drag this element to another element.
+    ///
+    /// The target may be in another frame; both are located in page coordinates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either element is not actionable or the drag fails.
+    pub async fn drag_to(&self, target: &FrameElementLocator<'_>) -> Result<(), LocatorError> {
+        self.drag_to_with_options(target, None, None, 1).await
     }
 
-    /// Evaluate JavaScript and return the result.
+    /// Drag this element to another element with options.
+    ///
+    /// # Arguments
     ///
-    /// Delegates to `Page::evaluate_js_raw` for the actual evaluation.
-    pub(crate) async fn evaluate_js(
+    /// * `target` - The target element.
+    /// * `source_position` - Point to grab, relative to the source's top-left corner.
+    /// * `target_position` - Point to drop on, relative to the target's top-left corner.
+    /// * `steps` - Number of intermediate mouse moves.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either element is not actionable or the drag fails.
+    #[tracing::instrument(level = "debug", skip(self, target))]
+    pub async fn drag_to_with_options(
         &self,
-        expression: &str,
-    ) -> Result<serde_json::Value, LocatorError> {
-        let page = self.frame_locator().page();
+        target: &FrameElementLocator<'_>,
+        source_position: Option<(f64, f64)>,
+        target_position: Option<(f64, f64)>,
+        steps: u32,
+    ) -> Result<(), LocatorError> {
+        let source_info = self.wait_for_actionable().await?;
+        let (source_x, source_y) =
+            source_position.map_or_else(|| source_info.center(), |p| source_info.offset(p));
 
-        if page.is_closed() {
-            return Err(LocatorError::PageClosed);
-        }
+        let target_info = target.wait_for_actionable().await?;
+        let (target_x, target_y) =
+            target_position.map_or_else(|| target_info.center(), |p| target_info.offset(p));
 
-        page.evaluate_js_raw(expression)
-            .await
-            .map_err(|e| LocatorError::EvaluationError(e.to_string()))
-    }
+        debug!(
+            "Dragging from ({}, {}) to ({}, {})",
+            source_x, source_y, target_x, target_y
+        );
 
-    /// Dispatch a mouse event.
-    pub(crate) async fn dispatch_mouse_event(
-        &self,
-        params: DispatchMouseEventParams,
-    ) -> Result<(), LocatorError> {
-        let page = self.frame_locator().page();
+        self.dispatch_mouse_event(DispatchMouseEventParams::mouse_move(source_x, source_y))
+            .await?;
+        self.dispatch_mouse_event(DispatchMouseEventParams::mouse_down(
+            source_x,
+            source_y,
+            MouseButton::Left,
+        ))
+        .await?;
 
-        page.connection()
-            .send_command::<_, serde_json::Value>(
-                "Input.dispatchMouseEvent",
-                Some(params),
-                Some(page.session_id()),
-            )
+        let steps = steps.max(1);
+        for i in 1..=steps {
+            let progress = f64::from(i) / f64::from(steps);
+            self.dispatch_mouse_event(DispatchMouseEventParams::mouse_move(
+                source_x + (target_x - source_x) * progress,
+                source_y + (target_y - source_y) * progress,
+            ))
             .await?;
-        Ok(())
-    }
+        }
 
-    /// Dispatch a key event.
-    pub(crate) async fn dispatch_key_event(
-        &self,
-        params: DispatchKeyEventParams,
-    ) -> Result<(), LocatorError> {
-        let page = self.frame_locator().page();
+        self.dispatch_mouse_event(DispatchMouseEventParams::mouse_up(
+            target_x,
+            target_y,
+            MouseButton::Left,
+        ))
+        .await?;
 
-        page.connection()
-            .send_command::<_, serde_json::Value>(
-                "Input.dispatchKeyEvent",
-                Some(params),
-                Some(page.session_id()),
-            )
-            .await?;
         Ok(())
     }
 
-    /// Insert text directly.
-    pub(crate) async fn insert_text(&self, text: &str) -> Result<(), LocatorError> {
-        let page = self.frame_locator().page();
+    /// Take a PNG screenshot of the element.
+    ///
+    /// The element is captured from the composited page, so elements of
+    /// out-of-process iframes are captured like any other.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element is not visible or the capture fails.
+    pub async fn screenshot(&self) -> Result<Vec<u8>, LocatorError> {
+        let info = self.wait_for_actionable().await?;
 
-        page.connection()
-            .send_command::<_, serde_json::Value>(
-                "Input.insertText",
-                Some(InsertTextParams {
-                    text: text.to_string(),
+        let result: CaptureScreenshotResult = self
+            .scope()
+            .connection()
+            .send_command(
+                "Page.captureScreenshot",
+                Some(CaptureScreenshotParams {
+                    format: Some(ScreenshotFormat::Png),
+                    quality: None,
+                    clip: Some(Viewport {
+                        x: info.x.expect("visible element has x"),
+                        y: info.y.expect("visible element has y"),
+                        width: info.width.expect("visible element has width"),
+                        height: info.height.expect("visible element has height"),
+                        scale: 1.0,
+                    }),
+                    from_surface: Some(true),
+                    capture_beyond_viewport: Some(false),
+                    optimize_for_speed: None,
                 }),
-                Some(page.session_id()),
+                Some(self.scope().input_session_id()),
             )
             .await?;
-        Ok(())
+
+        base64_decode(&result.data).map_err(|e| LocatorError::EvaluationError(e.to_string()))
+    }
+
+    /// Click the element unless it is already in the wanted checked state.
+    async fn set_checked(&self, checked: bool) -> Result<(), LocatorError> {
+        if self.is_checked().await? == checked {
+            debug!(checked, "Element already in wanted state");
+            return Ok(());
+        }
+        self.click().await
+    }
+
+    /// Select all content of the focused element and delete it.
+    async fn select_all_and_delete(&self) -> Result<(), LocatorError> {
+        let mut select_all = DispatchKeyEventParams::key_down("a");
+        select_all.modifiers = Some(viewpoint_cdp::protocol::input::modifiers::CTRL);
+        self.dispatch_key_event(select_all).await?;
+
+        self.dispatch_key_event(DispatchKeyEventParams::key_down("Backspace"))
+            .await
     }
 }
@@ -0,0 +1,274 @@
+//! State, text and attribute queries for frame element locators.
+
+use viewpoint_js::js;
+
+use crate::error::LocatorError;
+use crate::page::frame_locator::FrameElementLocator;
+use crate::page::locator::{AriaSnapshot, BoundingBox, aria_snapshot_js};
+
+impl<'a> FrameElementLocator<'a> {
+    /// Get the text content of the element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be queried.
+    pub async fn text_content(&self) -> Result<Option<String>, LocatorError> {
+        let info = self.query_element_info().await?;
+        Ok(info.text)
+    }
+
+    /// Check if the element is visible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be queried.
+    pub async fn is_visible(&self) -> Result<bool, LocatorError> {
+        let info = self.query_element_info().await?;
+        Ok(info.visible.unwrap_or(false))
+    }
+
+    /// Check if the element is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be queried.
+    pub async fn is_enabled(&self) -> Result<bool, LocatorError> {
+        let info = self.query_element_info().await?;
+        Ok(info.enabled.unwrap_or(false))
+    }
+
+    /// Check if a matching element is attached to the frame's document.
+    ///
+    /// Returns `false` while the frame cannot be reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be queried.
+    pub async fn is_attached(&self) -> Result<bool, LocatorError> {
+        let info = self.query_element_info().await?;
+        Ok(info.found)
+    }
+
+    /// Check if the element is checked (for checkboxes/radios).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be queried.
+    pub async fn is_checked(&self) -> Result<bool, LocatorError> {
+        let function = js! {
+            (function(elements) {
+                if (elements.length === 0) return { found: false, checked: false };
+                return { found: true, checked: elements[0].checked || false };
+            })
+        };
+
+        let result = self.evaluate_on_elements(function).await?;
+        Ok(result
+            .get("checked")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false))
+    }
+
+    /// Count matching elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the elements cannot be queried.
+    pub async fn count(&self) -> Result<usize, LocatorError> {
+        let info = self.query_element_info().await?;
+        Ok(info.count)
+    }
+
+    /// Return all matching elements as individual locators.
+    ///
+    /// Each returned locator points to a single element (via nth index).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the elements cannot be queried.
+    pub async fn all(&self) -> Result<Vec<FrameElementLocator<'a>>, LocatorError> {
+        let count = self.count().await?;
+        let mut locators = Vec::with_capacity(count);
+        for i in 0..count {
+            locators.push(self.nth(i as i32));
+        }
+        Ok(locators)
+    }
+
+    /// Get the inner text of the first matching element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be found.
+    pub async fn inner_text(&self) -> Result<String, LocatorError> {
+        let function = js! {
+            (function(elements) {
+                if (elements.length === 0) return { found: false };
+                return { found: true, value: elements[0].innerText || "" };
+            })
+        };
+
+        let value = self.query_first(function).await?;
+        Ok(value.as_str().unwrap_or("").to_string())
+    }
+
+    /// Get the inner text of all matching elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the elements cannot be queried.
+    pub async fn all_inner_texts(&self) -> Result<Vec<String>, LocatorError> {
+        let function = js! {
+            (function(elements) {
+                return elements.map(el => el.innerText || "");
+            })
+        };
+
+        let result = self.evaluate_on_elements(function).await?;
+        string_array(&result)
+    }
+
+    /// Get the text content of all matching elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the elements cannot be queried.
+    pub async fn all_text_contents(&self) -> Result<Vec<String>, LocatorError> {
+        let function = js! {
+            (function(elements) {
+                return elements.map(el => el.textContent || "");
+            })
+        };
+
+        let result = self.evaluate_on_elements(function).await?;
+        string_array(&result)
+    }
+
+    /// Get an attribute value from the first matching element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be found.
+    pub async fn get_attribute(&self, name: &str) -> Result<Option<String>, LocatorError> {
+        let function = js! {
+            (function(elements) {
+                if (elements.length === 0) return { found: false };
+                return { found: true, value: elements[0].getAttribute(#{name}) };
+            })
+        };
+
+        let value = self.query_first(&function).await?;
+        Ok(value.as_str().map(ToString::to_string))
+    }
+
+    /// Get the value of an input, textarea or select element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be found.
+    pub async fn input_value(&self) -> Result<String, LocatorError> {
+        let function = js! {
+            (function(elements) {
+                if (elements.length === 0) return { found: false };
+                return { found: true, value: elements[0].value || "" };
+            })
+        };
+
+        let value = self.query_first(function).await?;
+        Ok(value.as_str().unwrap_or("").to_string())
+    }
+
+    /// Get the bounding box of the element in page coordinates.
+    ///
+    /// Returns `None` if the element has no size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element cannot be found.
+    pub async fn bounding_box(&self) -> Result<Option<BoundingBox>, LocatorError> {
+        let info = self.query_element_info().await?;
+
+        if !info.found {
+            return Err(LocatorError::NotFound(format!("{:?}", self.selector())));
+        }
+
+        match (info.x, info.y, info.width, info.height) {
+            (Some(x), Some(y), Some(width), Some(height)) if width > 0.0 && height > 0.0 => {
+                Ok(Some(BoundingBox {
+                    x,
+                    y,
+                    width,
+                    height,
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Run a `{ found, value }` query on the matching elements and return the value.
+    async fn query_first(&self, function: &str) -> Result<serde_json::Value, LocatorError> {
+        let mut result = self.evaluate_on_elements(function).await?;
+
+        if !result
+            .get("found")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+        {
+            return Err(LocatorError::NotFound(format!("{:?}", self.selector())));
+        }
+
+        Ok(result
+            .get_mut("value")
+            .map(serde_json::Value::take)
+            .unwrap_or_default())
+    }
+
+    /// Get an ARIA accessibility snapshot of the first matching element.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// let snapshot = page.frame_locator("#checkout").locator("form").aria_snapshot().await?;
+    /// println!("{}", snapshot);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the element is not found or snapshot capture fails.
+    pub async fn aria_snapshot(&self) -> Result<AriaSnapshot, LocatorError> {
+        let snapshot_fn = aria_snapshot_js();
+        let function = js! {
+            (function(elements) {
+                if (elements.length === 0) return { error: "Element not found" };
+                const getSnapshot = @{snapshot_fn};
+                return getSnapshot(elements[0]);
+            })
+        };
+
+        let value = self.evaluate_on_elements(&function).await?;
+        if let Some(error) = value.get("error").and_then(|e| e.as_str()) {
+            return Err(LocatorError::NotFound(error.to_string()));
+        }
+
+        serde_json::from_value(value).map_err(|e| {
+            LocatorError::EvaluationError(format!("Failed to parse aria snapshot: {e}"))
+        })
+    }
+}
+
+/// Convert an array result to strings.
+fn string_array(result: &serde_json::Value) -> Result<Vec<String>, LocatorError> {
+    result
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|v| v.as_str().unwrap_or("").to_string())
+                .collect()
+        })
+        .ok_or_else(|| LocatorError::EvaluationError("Expected array result".to_string()))
+}
@@ -2,7 +2,7 @@
 //!
 //! This module contains the frame-related methods on the `Page` struct.
 
-use tracing::instrument;
+use tracing::{debug, instrument};
 
 use super::Page;
 use super::frame::Frame;
@@ -94,6 +94,10 @@ impl Page {
 
     /// Get all frames in the page, including the main frame and all iframes.
     ///
+    /// Out-of-process iframes (cross-origin iframes isolated in their own
+    /// renderer process) are included and bound to their own session, so
+    /// evaluation, navigation and snapshots on them work like on any frame.
+    ///
     /// # Errors
     ///
     /// Returns an error if the frame tree cannot be retrieved.
@@ -124,25 +128,63 @@ impl Page {
 
         let mut frames = Vec::new();
         let mut frame_index_counter = 0usize;
-        self.collect_frames(&result.frame_tree, &mut frames, &mut frame_index_counter);
+        self.collect_frames(
+            &result.frame_tree,
+            &self.session_id,
+            &mut frames,
+            &mut frame_index_counter,
+        );
+
+        // Out-of-process iframes are served by sessions of their own
+        for (frame_id, session_id) in self.context_registry.oopif_frames() {
+            let result: viewpoint_cdp::protocol::page::GetFrameTreeResult = match self
+                .connection
+                .send_command("Page.getFrameTree", None::<()>, Some(&session_id))
+                .await
+            {
+                Ok(result) => result,
+                Err(e) => {
+                    debug!(frame_id = %frame_id, error = %e, "Skipping out-of-process frame");
+                    continue;
+                }
+            };
+
+            let mut tree = result.frame_tree;
+            if tree.frame.parent_id.is_none() {
+                tree.frame.parent_id = self.context_registry.parent_frame_id(&frame_id);
+            }
+            self.collect_frames(&tree, &session_id, &mut frames, &mut frame_index_counter);
+        }
 
         Ok(frames)
     }
 
-    /// Collect frames recursively from a frame tree.
+    /// Collect frames recursively from the frame tree of `session_id`.
+    ///
+    /// Out-of-process iframes reported by another session are skipped; they
+    /// are collected from their own session.
     fn collect_frames(
         &self,
         tree: &viewpoint_cdp::protocol::page::FrameTree,
+        session_id: &str,
         frames: &mut Vec<Frame>,
         frame_index_counter: &mut usize,
     ) {
         let frame_info = &tree.frame;
+        if self
+            .context_registry
+            .oopif_session(&frame_info.id)
+            .is_some_and(|oopif_session| oopif_session != session_id)
+        {
+            return;
+        }
+
         let frame_index = *frame_index_counter;
         *frame_index_counter += 1;
 
         frames.push(Frame::with_context_registry_and_indices(
             self.connection.clone(),
-            self.session_id.clone(),
+            session_id.to_string(),
             frame_info.id.clone(),
             frame_info.parent_id.clone(),
             frame_info.loader_id.clone(),
@@ -156,7 +198,7 @@ impl Page {
 
         if let Some(children) = &tree.child_frames {
             for child in children {
-                self.collect_frames(child, frames, frame_index_counter);
+                self.collect_frames(child, session_id, frames, frame_index_counter);
             }
         }
     }
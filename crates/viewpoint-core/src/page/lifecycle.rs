@@ -23,6 +23,11 @@ impl Page {
 
         info!("Closing page");
 
+        // Finalize the video while the page can still be reached
+        if let Err(e) = self.stop_video_recording().await {
+            debug!("Failed to finalize video: {}", e);
+        }
+
         // Clean up route handlers
         self.route_registry.unroute_all().await;
         debug!("Route handlers cleaned up");
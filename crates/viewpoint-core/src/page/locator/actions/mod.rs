@@ -195,12 +195,13 @@ impl<'a> Locator<'a> {
     /// Returns an error if the element cannot be found or focused.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.selector))]
     pub async fn focus(&self) -> Result<(), LocatorError> {
-        self.wait_for_actionable().await?;
-
-        debug!("Focusing element");
-        self.focus_element().await?;
+        self.traced("focus", serde_json::json!({}), async {
+            self.wait_for_actionable().await?;
 
-        Ok(())
+            debug!("Focusing element");
+            self.focus_element().await
+        })
+        .await
     }
 
     /// Clear the element's content.
@@ -210,6 +211,12 @@ impl<'a> Locator<'a> {
     /// Returns an error if the element cannot be cleared.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.selector))]
     pub async fn clear(&self) -> Result<(), LocatorError> {
+        self.traced("clear", serde_json::json!({}), self.clear_untraced())
+            .await
+    }
+
+    /// Clear the element's content without recording it in the trace.
+    async fn clear_untraced(&self) -> Result<(), LocatorError> {
         self.wait_for_actionable().await?;
 
         debug!("Clearing element");
@@ -342,6 +349,30 @@ impl<'a> Locator<'a> {
         source_position: Option<(f64, f64)>,
         target_position: Option<(f64, f64)>,
         steps: u32,
+    ) -> Result<(), LocatorError> {
+        let position =
+            |p: Option<(f64, f64)>| p.map(|(x, y)| serde_json::json!({ "x": x, "y": y }));
+        let params = serde_json::json!({
+            "target": target.selector.to_string(),
+            "sourcePosition": position(source_position),
+            "targetPosition": position(target_position),
+            "steps": steps,
+        });
+        self.traced(
+            "dragTo",
+            params,
+            self.drag_to_untraced(target, source_position, target_position, steps),
+        )
+        .await
+    }
+
+    /// Drag this element to another locator without recording it in the trace.
+    async fn drag_to_untraced(
+        &self,
+        target: &Locator<'_>,
+        source_position: Option<(f64, f64)>,
+        target_position: Option<(f64, f64)>,
+        steps: u32,
     ) -> Result<(), LocatorError> {
         // Get source element info
         let source_info = self.wait_for_actionable().await?;
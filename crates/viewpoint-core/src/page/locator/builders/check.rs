@@ -57,6 +57,17 @@ impl<'l, 'a> CheckBuilder<'l, 'a> {
     /// Execute the check/uncheck operation.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector, check = self.check))]
     pub async fn send(self) -> Result<(), LocatorError> {
+        let action_type = if self.check { "check" } else { "uncheck" };
+        let params = serde_json::json!({
+            "force": self.force,
+            "noWaitAfter": self.no_wait_after,
+        });
+        let locator = self.locator;
+        locator.traced(action_type, params, self.execute()).await
+    }
+
+    /// Execute the check/uncheck operation without recording it in the trace.
+    async fn execute(self) -> Result<(), LocatorError> {
         // Set up navigation waiter before the action if needed
         let navigation_waiter = if self.no_wait_after {
             None
@@ -96,7 +107,7 @@ impl<'l, 'a> CheckBuilder<'l, 'a> {
                 ClickBuilder::new(self.locator)
                     .force(self.force)
                     .no_wait_after(true)
-                    .send()
+                    .execute()
                     .await?;
             }
         } else {
@@ -106,7 +117,7 @@ impl<'l, 'a> CheckBuilder<'l, 'a> {
                 ClickBuilder::new(self.locator)
                     .force(self.force)
                     .no_wait_after(true)
-                    .send()
+                    .execute()
                     .await?;
             } else {
                 debug!("Element already unchecked");
@@ -129,6 +129,21 @@ impl<'l, 'a> ClickBuilder<'l, 'a> {
     /// Execute the click operation.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector))]
     pub async fn send(self) -> Result<(), LocatorError> {
+        let action_type = "click";
+        let params = serde_json::json!({
+            "button": self.button,
+            "modifiers": self.modifiers,
+            "clickCount": self.click_count,
+            "force": self.force,
+            "position": self.position.map(|(x, y)| serde_json::json!({ "x": x, "y": y })),
+            "noWaitAfter": self.no_wait_after,
+        });
+        let locator = self.locator;
+        locator.traced(action_type, params, self.execute()).await
+    }
+
+    /// Execute the click operation without recording it in the trace.
+    pub(super) async fn execute(self) -> Result<(), LocatorError> {
         // Set up navigation waiter before the action if needed
         let navigation_waiter = if self.no_wait_after {
             None
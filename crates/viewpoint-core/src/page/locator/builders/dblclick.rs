@@ -65,6 +65,19 @@ impl<'l, 'a> DblclickBuilder<'l, 'a> {
     /// Execute the double-click operation.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector))]
     pub async fn send(self) -> Result<(), LocatorError> {
+        let action_type = "dblclick";
+        let params = serde_json::json!({
+            "modifiers": self.modifiers,
+            "force": self.force,
+            "position": self.position.map(|(x, y)| serde_json::json!({ "x": x, "y": y })),
+            "noWaitAfter": self.no_wait_after,
+        });
+        let locator = self.locator;
+        locator.traced(action_type, params, self.execute()).await
+    }
+
+    /// Execute the double-click operation without recording it in the trace.
+    async fn execute(self) -> Result<(), LocatorError> {
         // Set up navigation waiter before the action if needed
         let navigation_waiter = if self.no_wait_after {
             None
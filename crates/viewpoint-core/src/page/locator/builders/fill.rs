@@ -40,6 +40,17 @@ impl<'l, 'a> FillBuilder<'l, 'a> {
     /// Execute the fill operation.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector))]
     pub async fn send(self) -> Result<(), LocatorError> {
+        let action_type = "fill";
+        let params = serde_json::json!({
+            "value": self.text,
+            "noWaitAfter": self.no_wait_after,
+        });
+        let locator = self.locator;
+        locator.traced(action_type, params, self.execute()).await
+    }
+
+    /// Execute the fill operation without recording it in the trace.
+    async fn execute(self) -> Result<(), LocatorError> {
         // Set up navigation waiter before the action if needed
         let navigation_waiter = if self.no_wait_after {
             None
@@ -51,6 +51,18 @@ impl<'l, 'a> HoverBuilder<'l, 'a> {
     /// Execute the hover operation.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector))]
     pub async fn send(self) -> Result<(), LocatorError> {
+        let action_type = "hover";
+        let params = serde_json::json!({
+            "modifiers": self.modifiers,
+            "force": self.force,
+            "position": self.position.map(|(x, y)| serde_json::json!({ "x": x, "y": y })),
+        });
+        let locator = self.locator;
+        locator.traced(action_type, params, self.execute()).await
+    }
+
+    /// Execute the hover operation without recording it in the trace.
+    async fn execute(self) -> Result<(), LocatorError> {
         let (x, y) = if self.force {
             let info = self.locator.query_element_info().await?;
             if !info.found {
@@ -40,6 +40,17 @@ impl<'l, 'a> PressBuilder<'l, 'a> {
     /// Execute the press operation.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector, key = %self.key))]
     pub async fn send(self) -> Result<(), LocatorError> {
+        let action_type = "press";
+        let params = serde_json::json!({
+            "key": self.key,
+            "noWaitAfter": self.no_wait_after,
+        });
+        let locator = self.locator;
+        locator.traced(action_type, params, self.execute()).await
+    }
+
+    /// Execute the press operation without recording it in the trace.
+    async fn execute(self) -> Result<(), LocatorError> {
         // Set up navigation waiter before the action if needed
         let navigation_waiter = if self.no_wait_after {
             None
@@ -61,6 +61,17 @@ impl<'l, 'a> SelectOptionBuilder<'l, 'a> {
     /// Execute the select operation.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector))]
     pub async fn send(self) -> Result<(), LocatorError> {
+        let action_type = "selectOption";
+        let params = serde_json::json!({
+            "values": self.options.clone().or_else(|| self.option.clone().map(|o| vec![o])),
+            "noWaitAfter": self.no_wait_after,
+        });
+        let locator = self.locator;
+        locator.traced(action_type, params, self.execute()).await
+    }
+
+    /// Execute the select operation without recording it in the trace.
+    async fn execute(self) -> Result<(), LocatorError> {
         // Set up navigation waiter before the action if needed
         let navigation_waiter = if self.no_wait_after {
             None
@@ -50,6 +50,18 @@ impl<'l, 'a> TapBuilder<'l, 'a> {
     /// Execute the tap operation.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector))]
     pub async fn send(self) -> Result<(), LocatorError> {
+        let action_type = "tap";
+        let params = serde_json::json!({
+            "modifiers": self.modifiers,
+            "force": self.force,
+            "position": self.position.map(|(x, y)| serde_json::json!({ "x": x, "y": y })),
+        });
+        let locator = self.locator;
+        locator.traced(action_type, params, self.execute()).await
+    }
+
+    /// Execute the tap operation without recording it in the trace.
+    async fn execute(self) -> Result<(), LocatorError> {
         let (x, y) = if self.force {
             let info = self.locator.query_element_info().await?;
             if !info.found {
@@ -37,6 +37,17 @@ impl<'l, 'a> TypeBuilder<'l, 'a> {
     /// Execute the type operation.
     #[instrument(level = "debug", skip(self), fields(selector = ?self.locator.selector))]
     pub async fn send(self) -> Result<(), LocatorError> {
+        let action_type = "type";
+        let params = serde_json::json!({
+            "text": self.text,
+            "delay": self.delay.map(|d| d.as_millis() as u64),
+        });
+        let locator = self.locator;
+        locator.traced(action_type, params, self.execute()).await
+    }
+
+    /// Execute the type operation without recording it in the trace.
+    async fn execute(self) -> Result<(), LocatorError> {
         self.locator.wait_for_actionable().await?;
 
         debug!(text = %self.text, delay = ?self.delay, "Typing text");
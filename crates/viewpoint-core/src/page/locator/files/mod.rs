@@ -4,3 +4,5 @@
 
 mod set_input_files;
 mod set_input_files_buffer;
+
+pub(crate) use set_input_files_buffer::file_payloads_json;
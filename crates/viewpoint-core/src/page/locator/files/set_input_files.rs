@@ -40,6 +40,25 @@ impl Locator<'_> {
     pub async fn set_input_files<P: AsRef<std::path::Path>>(
         &self,
         files: &[P],
+    ) -> Result<(), LocatorError> {
+        let params = serde_json::json!({
+            "files": files
+                .iter()
+                .map(|p| p.as_ref().to_string_lossy().into_owned())
+                .collect::<Vec<_>>(),
+        });
+        self.traced(
+            "setInputFiles",
+            params,
+            self.set_input_files_untraced(files),
+        )
+        .await
+    }
+
+    /// Set the files without recording it in the trace.
+    async fn set_input_files_untraced<P: AsRef<std::path::Path>>(
+        &self,
+        files: &[P],
     ) -> Result<(), LocatorError> {
         self.wait_for_actionable().await?;
 
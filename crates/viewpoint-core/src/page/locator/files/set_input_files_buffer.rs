@@ -40,8 +40,22 @@ impl Locator<'_> {
         &self,
         files: &[crate::page::FilePayload],
     ) -> Result<(), LocatorError> {
-        use base64::{Engine, engine::general_purpose::STANDARD};
+        let params = serde_json::json!({
+            "files": files.iter().map(|f| &f.name).collect::<Vec<_>>(),
+        });
+        self.traced(
+            "setInputFiles",
+            params,
+            self.set_input_files_from_buffer_untraced(files),
+        )
+        .await
+    }
 
+    /// Set the file buffers without recording it in the trace.
+    async fn set_input_files_from_buffer_untraced(
+        &self,
+        files: &[crate::page::FilePayload],
+    ) -> Result<(), LocatorError> {
         self.wait_for_actionable().await?;
 
         debug!("Setting {} files from buffer on file input", files.len());
@@ -103,19 +117,7 @@ impl Locator<'_> {
         }
 
         // Build the file data array for JavaScript
-        let file_data: Vec<serde_json::Value> = files
-            .iter()
-            .map(|f| {
-                serde_json::json!({
-                    "name": f.name,
-                    "mimeType": f.mime_type,
-                    "data": STANDARD.encode(&f.buffer),
-                })
-            })
-            .collect();
-
-        let file_data_json = serde_json::to_string(&file_data)
-            .map_err(|e| LocatorError::EvaluationError(e.to_string()))?;
+        let file_data_json = file_payloads_json(files)?;
 
         // Use JavaScript to create File objects and set them on the input
         let set_files_js = js! {
@@ -205,8 +207,6 @@ impl Locator<'_> {
         backend_node_id: BackendNodeId,
         files: &[crate::page::FilePayload],
     ) -> Result<(), LocatorError> {
-        use base64::{Engine, engine::general_purpose::STANDARD};
-
         // Resolve the backend node ID to a RemoteObject
         let result: ResolveNodeResult = self
             .page
@@ -326,19 +326,7 @@ impl Locator<'_> {
         }
 
         // Build the file data array for JavaScript
-        let file_data: Vec<serde_json::Value> = files
-            .iter()
-            .map(|f| {
-                serde_json::json!({
-                    "name": f.name,
-                    "mimeType": f.mime_type,
-                    "data": STANDARD.encode(&f.buffer),
-                })
-            })
-            .collect();
-
-        let file_data_json = serde_json::to_string(&file_data)
-            .map_err(|e| LocatorError::EvaluationError(e.to_string()))?;
+        let file_data_json = file_payloads_json(files)?;
 
         // Use callFunctionOn to create File objects and set them on the input
         // Build function declaration for CDP callFunctionOn
@@ -429,3 +417,24 @@ impl Locator<'_> {
         Ok(())
     }
 }
+
+/// Build the JSON array of `{ name, mimeType, data }` file payloads, with
+/// base64 `data`, read by the file setting JavaScript.
+pub(crate) fn file_payloads_json(
+    files: &[crate::page::FilePayload],
+) -> Result<String, LocatorError> {
+    use base64::{Engine, engine::general_purpose::STANDARD};
+
+    let file_data: Vec<serde_json::Value> = files
+        .iter()
+        .map(|f| {
+            serde_json::json!({
+                "name": f.name,
+                "mimeType": f.mime_type,
+                "data": STANDARD.encode(&f.buffer),
+            })
+        })
+        .collect();
+
+    serde_json::to_string(&file_data).map_err(|e| LocatorError::EvaluationError(e.to_string()))
+}
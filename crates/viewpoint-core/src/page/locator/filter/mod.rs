@@ -4,7 +4,7 @@
 
 use crate::Page;
 
-use super::{AriaRole, Locator, LocatorOptions, Selector};
+use super::{AriaRole, Locator, LocatorOptions, Selector, TextMatcher};
 
 /// Builder for filtering locators by various criteria.
 ///
@@ -27,6 +27,8 @@ impl<'a> FilterBuilder<'a> {
 
     /// Filter to elements that contain the specified text.
     ///
+    /// Accepts a [`TextMatcher`] for exact, case-insensitive or regex matching.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -37,13 +39,12 @@ impl<'a> FilterBuilder<'a> {
     /// # }
     /// ```
     #[must_use]
-    pub fn has_text(self, text: impl Into<String>) -> Locator<'a> {
+    pub fn has_text(self, text: impl Into<TextMatcher>) -> Locator<'a> {
         Locator {
             page: self.page,
             selector: Selector::FilterText {
                 base: Box::new(self.base_selector),
-                text: text.into(),
-                exact: false,
+                text: text.into().resolve(false),
                 has_not: false,
             },
             options: self.options,
@@ -67,8 +68,7 @@ impl<'a> FilterBuilder<'a> {
             page: self.page,
             selector: Selector::FilterText {
                 base: Box::new(self.base_selector),
-                text: text.into(),
-                exact: true,
+                text: TextMatcher::Exact(text.into()),
                 has_not: false,
             },
             options: self.options,
@@ -77,6 +77,8 @@ impl<'a> FilterBuilder<'a> {
 
     /// Filter to elements that do NOT contain the specified text.
     ///
+    /// Accepts a [`TextMatcher`] for exact, case-insensitive or regex matching.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -87,13 +89,12 @@ impl<'a> FilterBuilder<'a> {
     /// # }
     /// ```
     #[must_use]
-    pub fn has_not_text(self, text: impl Into<String>) -> Locator<'a> {
+    pub fn has_not_text(self, text: impl Into<TextMatcher>) -> Locator<'a> {
         Locator {
             page: self.page,
             selector: Selector::FilterText {
                 base: Box::new(self.base_selector),
-                text: text.into(),
-                exact: false,
+                text: text.into().resolve(false),
                 has_not: true,
             },
             options: self.options,
@@ -117,8 +118,7 @@ impl<'a> FilterBuilder<'a> {
             page: self.page,
             selector: Selector::FilterText {
                 base: Box::new(self.base_selector),
-                text: text.into(),
-                exact: true,
+                text: TextMatcher::Exact(text.into()),
                 has_not: true,
             },
             options: self.options,
@@ -198,7 +198,7 @@ impl<'a> FilterBuilder<'a> {
 pub struct RoleLocatorBuilder<'a> {
     page: &'a Page,
     role: AriaRole,
-    name: Option<String>,
+    name: Option<TextMatcher>,
 }
 
 impl<'a> RoleLocatorBuilder<'a> {
@@ -212,9 +212,12 @@ impl<'a> RoleLocatorBuilder<'a> {
     }
 
     /// Filter by accessible name.
+    ///
+    /// Plain strings match the whole name; pass a [`TextMatcher`] to match
+    /// otherwise, e.g. `TextMatcher::regex(r"Order #\d+")`.
     #[must_use]
-    pub fn with_name(mut self, name: impl Into<String>) -> Self {
-        self.name = Some(name.into());
+    pub fn with_name(mut self, name: impl Into<TextMatcher>) -> Self {
+        self.name = Some(name.into().resolve(true));
         self
     }
 
@@ -287,6 +287,23 @@ impl Locator<'_> {
         Ok(())
     }
 
+    /// Run a locator action, recording it in the context trace if tracing is active.
+    pub(super) async fn traced<T>(
+        &self,
+        action_type: &str,
+        params: serde_json::Value,
+        action: impl std::future::Future<Output = Result<T, LocatorError>>,
+    ) -> Result<T, LocatorError> {
+        self.page
+            .trace_action(
+                action_type,
+                Some(self.selector.to_string()),
+                Some(params),
+                action,
+            )
+            .await
+    }
+
     /// Evaluate JavaScript and return the result.
     ///
     /// Delegates to `Page::evaluate_js_raw` for the actual evaluation.
@@ -202,17 +202,22 @@ mod helpers;
 mod queries;
 mod select;
 pub(crate) mod selector;
+mod text_matcher;
 
 use std::time::Duration;
 
+pub(crate) use aria::aria_snapshot_js;
 pub use aria::{AriaCheckedState, AriaSnapshot};
 pub use builders::{
     CheckBuilder, ClickBuilder, DblclickBuilder, FillBuilder, HoverBuilder, PressBuilder,
     SelectOptionBuilder, TapBuilder, TypeBuilder,
 };
 pub use element::{BoundingBox, BoxModel, ElementHandle};
+pub(crate) use files::file_payloads_json;
 pub use filter::{FilterBuilder, RoleLocatorBuilder};
+pub(crate) use select::{build_select_option_js, build_select_options_js, check_select_result};
 pub use selector::{AriaRole, Selector, TextOptions};
+pub use text_matcher::TextMatcher;
 
 use crate::Page;
 
@@ -295,6 +300,8 @@ impl<'a> Locator<'a> {
 
     /// Create a child locator that further filters elements.
     ///
+    /// Accepts the same selector syntax as [`Page::locator`].
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -310,7 +317,7 @@ impl<'a> Locator<'a> {
             page: self.page,
             selector: Selector::Chained(
                 Box::new(self.selector.clone()),
-                Box::new(Selector::Css(selector.into())),
+                Box::new(Selector::parse(&selector.into())),
             ),
             options: self.options.clone(),
         }
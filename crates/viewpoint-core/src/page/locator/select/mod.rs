@@ -347,7 +347,7 @@ impl Locator<'_> {
 }
 
 /// Build JavaScript for selecting a single option.
-fn build_select_option_js(selector_expr: &str, option: &str) -> String {
+pub(crate) fn build_select_option_js(selector_expr: &str, option: &str) -> String {
     format!(
         r"(function() {{
             const elements = {selector};
@@ -387,7 +387,7 @@ fn build_select_option_js(selector_expr: &str, option: &str) -> String {
 }
 
 /// Build JavaScript for selecting multiple options.
-fn build_select_options_js(selector_expr: &str, options: &[&str]) -> String {
+pub(crate) fn build_select_options_js(selector_expr: &str, options: &[&str]) -> String {
     let options_js: Vec<String> = options.iter().map(|o| js_string_literal(o)).collect();
     let options_array = format!("[{}]", options_js.join(", "));
 
@@ -453,7 +453,7 @@ fn build_select_options_js(selector_expr: &str, options: &[&str]) -> String {
 }
 
 /// Check the result of a select operation.
-fn check_select_result(result: &serde_json::Value) -> Result<(), LocatorError> {
+pub(crate) fn check_select_result(result: &serde_json::Value) -> Result<(), LocatorError> {
     let success = result
         .get("success")
         .and_then(serde_json::Value::as_bool)
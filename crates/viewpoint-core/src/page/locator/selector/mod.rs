@@ -1,10 +1,18 @@
 //! Selector types for element location strategies.
+//!
+//! CSS and the semantic engines (text, role, label, ...) pierce open shadow
+//! roots, so elements inside web components are found like any other. XPath
+//! follows the DOM specification and does not. On the right of `>>`, XPath
+//! is evaluated with each parent match as the context node, so relative
+//! expressions such as `./span` work.
 
 // Re-export AriaRole from aria_role module
 pub use super::aria_role::{AriaRole, implicit_role_selector};
 
 use viewpoint_cdp::protocol::dom::BackendNodeId;
 
+use super::TextMatcher;
+
 /// Options for text-based locators.
 #[derive(Debug, Clone, Default)]
 pub struct TextOptions {
@@ -17,29 +25,36 @@ pub struct TextOptions {
 pub enum Selector {
     /// CSS selector.
     Css(String),
+    /// XPath expression.
+    XPath(String),
+    /// Selector evaluated by a registered custom engine.
+    ///
+    /// Engines are registered with [`Selectors::register`](crate::Selectors::register)
+    /// or [`BrowserContext::register_selector_engine`](crate::BrowserContext::register_selector_engine).
+    Custom { engine: String, body: String },
     /// Text content selector.
-    Text { text: String, exact: bool },
+    Text(TextMatcher),
     /// ARIA role selector with optional accessible name.
     Role {
         role: AriaRole,
-        name: Option<String>,
+        name: Option<TextMatcher>,
     },
     /// Test ID selector (data-testid attribute).
     TestId(String),
     /// Test ID with custom attribute.
     TestIdCustom { id: String, attribute: String },
     /// Label selector (finds form elements by label text).
-    Label(String),
+    Label(TextMatcher),
     /// Placeholder selector.
-    Placeholder(String),
+    Placeholder(TextMatcher),
     /// Chained selector (parent >> child).
     Chained(Box<Selector>, Box<Selector>),
     /// Nth element in a collection (0-based index, negative from end).
     Nth { base: Box<Selector>, index: i32 },
     /// Alt text selector for images.
-    AltText { text: String, exact: bool },
+    AltText(TextMatcher),
     /// Title attribute selector.
-    Title { text: String, exact: bool },
+    Title(TextMatcher),
     /// AND combinator - matches elements that match both selectors.
     And(Box<Selector>, Box<Selector>),
     /// OR combinator - matches elements that match either selector.
@@ -47,8 +62,7 @@ pub enum Selector {
     /// Filter by text content.
     FilterText {
         base: Box<Selector>,
-        text: String,
-        exact: bool,
+        text: TextMatcher,
         has_not: bool,
     },
     /// Filter by having a child that matches another selector.
@@ -74,48 +88,34 @@ impl std::fmt::Display for Selector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Selector::Css(css) => write!(f, "css={css}"),
-            Selector::Text { text, exact } => {
-                if *exact {
-                    write!(f, "text={text}")
-                } else {
-                    write!(f, "text*={text}")
-                }
-            }
+            Selector::XPath(xpath) => write!(f, "xpath={xpath}"),
+            Selector::Custom { engine, body } => write!(f, "{engine}={body}"),
+            Selector::Text(text) => write_matcher(f, "text", text),
             Selector::Role { role, name } => match name {
-                Some(n) => write!(f, "role={}[name={}]", role.as_str(), n),
+                Some(n) => {
+                    let (op, value) = n.selector_parts();
+                    write!(f, "role={}[name{op}{value}]", role.as_str())
+                }
                 None => write!(f, "role={}", role.as_str()),
             },
             Selector::TestId(id) => write!(f, "testid={id}"),
             Selector::TestIdCustom { id, attribute } => write!(f, "testid[{attribute}]={id}"),
-            Selector::Label(label) => write!(f, "label={label}"),
-            Selector::Placeholder(placeholder) => write!(f, "placeholder={placeholder}"),
+            Selector::Label(label) => write_matcher(f, "label", label),
+            Selector::Placeholder(placeholder) => write_matcher(f, "placeholder", placeholder),
             Selector::Chained(parent, child) => write!(f, "{parent} >> {child}"),
             Selector::Nth { base, index } => write!(f, "{base}.nth({index})"),
-            Selector::AltText { text, exact } => {
-                if *exact {
-                    write!(f, "alt={text}")
-                } else {
-                    write!(f, "alt*={text}")
-                }
-            }
-            Selector::Title { text, exact } => {
-                if *exact {
-                    write!(f, "title={text}")
-                } else {
-                    write!(f, "title*={text}")
-                }
-            }
+            Selector::AltText(text) => write_matcher(f, "alt", text),
+            Selector::Title(text) => write_matcher(f, "title", text),
             Selector::And(a, b) => write!(f, "({a}).and({b})"),
             Selector::Or(a, b) => write!(f, "({a}).or({b})"),
             Selector::FilterText {
                 base,
                 text,
-                exact,
                 has_not,
             } => {
-                let op = if *has_not { "hasNotText" } else { "hasText" };
-                let eq = if *exact { "" } else { "*" };
-                write!(f, "{base}.filter({op}{eq}={text})")
+                let name = if *has_not { "hasNotText" } else { "hasText" };
+                let (op, value) = text.selector_parts();
+                write!(f, "{base}.filter({name}{op}{value})")
             }
             Selector::FilterHas {
                 base,
@@ -137,101 +137,121 @@ impl std::fmt::Display for Selector {
 impl Selector {
     /// Convert selector to a JavaScript expression that returns element(s).
     pub fn to_js_expression(&self) -> String {
+        self.to_js_expression_in("document")
+    }
+
+    /// Like [`to_js_expression`](Self::to_js_expression), with `root` as the
+    /// JS expression of the node that relative engines search from.
+    ///
+    /// XPath uses `root` as its context node and custom engines receive it as
+    /// the root to query. The other engines search the whole document; chains
+    /// then keep the matches inside each parent.
+    fn to_js_expression_in(&self, root: &str) -> String {
         match self {
-            Selector::Css(css) => {
-                format!("document.querySelectorAll({})", js_string_literal(css))
+            Selector::Css(css) => deep_query(css),
+
+            Selector::XPath(xpath) => {
+                format!(
+                    r"(function(root) {{
+                        const result = document.evaluate({}, root, null, XPathResult.ORDERED_NODE_SNAPSHOT_TYPE, null);
+                        const elements = [];
+                        for (let i = 0; i < result.snapshotLength; i++) {{
+                            const node = result.snapshotItem(i);
+                            if (node.nodeType === Node.ELEMENT_NODE) elements.push(node);
+                        }}
+                        return elements;
+                    }})({root})",
+                    js_string_literal(xpath)
+                )
             }
 
-            Selector::Text { text, exact } => {
-                if *exact {
-                    format!(
-                        r"Array.from(document.querySelectorAll('*')).filter(el => el.textContent?.trim() === {})",
-                        js_string_literal(text)
-                    )
-                } else {
-                    format!(
-                        r"Array.from(document.querySelectorAll('*')).filter(el => el.textContent?.includes({}))",
-                        js_string_literal(text)
-                    )
-                }
+            Selector::Custom { engine, body } => {
+                let engine = js_string_literal(engine);
+                format!(
+                    r"(function(root) {{
+                        const engine = (globalThis.{SELECTOR_ENGINES} || {{}})[{engine}];
+                        if (!engine) throw new Error('Unknown selector engine ' + {engine});
+                        const body = {};
+                        if (engine.queryAll) return Array.from(engine.queryAll(root, body));
+                        const element = engine.query(root, body);
+                        return element ? [element] : [];
+                    }})({root})",
+                    js_string_literal(body)
+                )
+            }
+
+            Selector::Text(text) => {
+                format!(
+                    r"{}.filter(el => {})",
+                    deep_query("*"),
+                    text.to_js_predicate(TEXT_CONTENT)
+                )
             }
 
             Selector::Role { role, name } => {
-                let role_str = role.as_str();
-                // Escape the CSS selector for embedding in a single-quoted JS string
-                let implicit_css =
-                    viewpoint_js_core::escape_js_contents_single(implicit_role_selector(*role));
+                let explicit = deep_query(&format!(r#"[role="{}"]"#, role.as_str()));
+                let implicit = deep_query(implicit_role_selector(*role));
                 match name {
                     Some(n) => format!(
-                        r#"Array.from(document.querySelectorAll('[role="{}"]')).concat(Array.from(document.querySelectorAll('{}'))).filter(el => (el.getAttribute('aria-label') || el.textContent?.trim()) === {})"#,
-                        role_str,
-                        implicit_css,
-                        js_string_literal(n)
-                    ),
-                    None => format!(
-                        r#"Array.from(document.querySelectorAll('[role="{role_str}"]')).concat(Array.from(document.querySelectorAll('{implicit_css}')))"#
+                        r"{explicit}.concat({implicit}).filter(el => {})",
+                        n.to_js_predicate(
+                            "el.getAttribute('aria-label') || el.textContent?.trim() || ''"
+                        )
                     ),
+                    None => format!(r"{explicit}.concat({implicit})"),
                 }
             }
 
-            Selector::TestId(id) => {
-                format!(
-                    "document.querySelectorAll('[data-testid={}]')",
-                    css_attr_value(id)
-                )
-            }
+            Selector::TestId(id) => deep_query_attr("data-testid", id),
 
-            Selector::TestIdCustom { id, attribute } => {
-                format!(
-                    "document.querySelectorAll('[{}={}]')",
-                    attribute,
-                    css_attr_value(id)
-                )
-            }
+            Selector::TestIdCustom { id, attribute } => deep_query_attr(attribute, id),
 
             Selector::Label(label) => {
                 format!(
                     r"(function() {{
-                        const labels = Array.from(document.querySelectorAll('label'));
-                        const matching = labels.filter(l => l.textContent?.trim() === {});
+                        const labels = {};
+                        const matching = labels.filter(l => {});
                         return matching.flatMap(l => {{
-                            if (l.htmlFor) return Array.from(document.querySelectorAll('#' + l.htmlFor));
+                            if (l.htmlFor) return Array.from(l.getRootNode().querySelectorAll('#' + CSS.escape(l.htmlFor)));
                             return Array.from(l.querySelectorAll('input, textarea, select'));
                         }});
                     }})()",
-                    js_string_literal(label)
+                    deep_query("label"),
+                    label.to_js_predicate("l.textContent?.trim() || ''")
                 )
             }
 
-            Selector::Placeholder(placeholder) => {
-                format!(
-                    "document.querySelectorAll('[placeholder={}]')",
-                    css_attr_value(placeholder)
-                )
-            }
+            Selector::Placeholder(placeholder) => match placeholder {
+                TextMatcher::Exact(text) => deep_query_attr("placeholder", text),
+                matcher => format!(
+                    r"{}.filter(el => {})",
+                    deep_query("[placeholder]"),
+                    matcher.to_js_predicate("el.placeholder || ''")
+                ),
+            },
 
             Selector::Chained(parent, child) => {
+                // The child side is evaluated once per parent, from that parent
                 format!(
-                    r"(function() {{
+                    r"(function(root) {{
                         const parents = {};
                         const results = [];
+                        const contains = {DEEP_CONTAINS};
                         for (const parent of parents) {{
-                            const children = parent.querySelectorAll ? 
-                                Array.from(parent.querySelectorAll('*')) : [];
                             const childSelector = {};
                             for (const child of childSelector) {{
-                                if (parent.contains(child)) results.push(child);
+                                if (contains(parent, child)) results.push(child);
                             }}
                         }}
                         return results;
-                    }})()",
-                    parent.to_js_expression(),
-                    child.to_js_expression()
+                    }})({root})",
+                    parent.to_js_expression_in("root"),
+                    child.to_js_expression_in("parent")
                 )
             }
 
             Selector::Nth { base, index } => {
-                let base_expr = base.to_js_expression();
+                let base_expr = base.to_js_expression_in(root);
                 if *index >= 0 {
                     format!(
                         r"(function() {{
@@ -251,37 +271,25 @@ impl Selector {
                 }
             }
 
-            Selector::AltText { text, exact } => {
-                if *exact {
-                    format!(
-                        r"Array.from(document.querySelectorAll('[alt]')).filter(el => el.alt === {})",
-                        js_string_literal(text)
-                    )
-                } else {
-                    format!(
-                        r"Array.from(document.querySelectorAll('[alt]')).filter(el => (el.alt || '').includes({}))",
-                        js_string_literal(text)
-                    )
-                }
+            Selector::AltText(text) => {
+                format!(
+                    r"{}.filter(el => {})",
+                    deep_query("[alt]"),
+                    text.to_js_predicate("el.alt || ''")
+                )
             }
 
-            Selector::Title { text, exact } => {
-                if *exact {
-                    format!(
-                        r"Array.from(document.querySelectorAll('[title]')).filter(el => el.title === {})",
-                        js_string_literal(text)
-                    )
-                } else {
-                    format!(
-                        r"Array.from(document.querySelectorAll('[title]')).filter(el => (el.title || '').includes({}))",
-                        js_string_literal(text)
-                    )
-                }
+            Selector::Title(text) => {
+                format!(
+                    r"{}.filter(el => {})",
+                    deep_query("[title]"),
+                    text.to_js_predicate("el.title || ''")
+                )
             }
 
             Selector::And(a, b) => {
-                let a_expr = a.to_js_expression();
-                let b_expr = b.to_js_expression();
+                let a_expr = a.to_js_expression_in(root);
+                let b_expr = b.to_js_expression_in(root);
                 format!(
                     r"(function() {{
                         const setA = new Set({a_expr});
@@ -292,8 +300,8 @@ impl Selector {
             }
 
             Selector::Or(a, b) => {
-                let a_expr = a.to_js_expression();
-                let b_expr = b.to_js_expression();
+                let a_expr = a.to_js_expression_in(root);
+                let b_expr = b.to_js_expression_in(root);
                 format!(
                     r"(function() {{
                         const results = new Set({a_expr});
@@ -306,29 +314,14 @@ impl Selector {
             Selector::FilterText {
                 base,
                 text,
-                exact,
                 has_not,
             } => {
-                let base_expr = base.to_js_expression();
-                let text_lit = js_string_literal(text);
+                let base_expr = base.to_js_expression_in(root);
+                let predicate = text.to_js_predicate(TEXT_CONTENT);
                 if *has_not {
-                    if *exact {
-                        format!(
-                            r"Array.from({base_expr}).filter(el => el.textContent?.trim() !== {text_lit})"
-                        )
-                    } else {
-                        format!(
-                            r"Array.from({base_expr}).filter(el => !el.textContent?.includes({text_lit}))"
-                        )
-                    }
-                } else if *exact {
-                    format!(
-                        r"Array.from({base_expr}).filter(el => el.textContent?.trim() === {text_lit})"
-                    )
+                    format!(r"Array.from({base_expr}).filter(el => !({predicate}))")
                 } else {
-                    format!(
-                        r"Array.from({base_expr}).filter(el => el.textContent?.includes({text_lit}))"
-                    )
+                    format!(r"Array.from({base_expr}).filter(el => {predicate})")
                 }
             }
 
@@ -337,15 +330,16 @@ impl Selector {
                 child,
                 has_not,
             } => {
-                let base_expr = base.to_js_expression();
+                let base_expr = base.to_js_expression_in(root);
                 let child_expr = child.to_js_expression();
                 if *has_not {
                     format!(
                         r"(function() {{
                             const childSet = new Set({child_expr});
+                            const contains = {DEEP_CONTAINS};
                             return Array.from({base_expr}).filter(el => {{
                                 for (const c of childSet) {{
-                                    if (el.contains(c)) return false;
+                                    if (contains(el, c)) return false;
                                 }}
                                 return true;
                             }});
@@ -355,9 +349,10 @@ impl Selector {
                     format!(
                         r"(function() {{
                             const childSet = new Set({child_expr});
+                            const contains = {DEEP_CONTAINS};
                             return Array.from({base_expr}).filter(el => {{
                                 for (const c of childSet) {{
-                                    if (el.contains(c)) return true;
+                                    if (contains(el, c)) return true;
                                 }}
                                 return false;
                             }});
@@ -395,6 +390,63 @@ impl Selector {
     }
 }
 
+/// Global object in the page holding registered custom selector engines.
+pub(crate) const SELECTOR_ENGINES: &str = "__viewpointSelectorEngines";
+
+/// Trimmed text content of `el`, as compared by text matchers.
+const TEXT_CONTENT: &str = "el.textContent?.trim() || ''";
+
+/// JS function returning the elements under `root` that match a CSS
+/// selector, descending into open shadow roots.
+///
+/// Each root is queried natively; matches in a root come before matches in
+/// the shadow roots nested inside it.
+const DEEP_QUERY: &str = r"((root, selector) => {
+    const found = [];
+    const walk = (scope) => {
+        for (const el of scope.querySelectorAll(selector)) found.push(el);
+        const walker = document.createTreeWalker(scope, NodeFilter.SHOW_ELEMENT);
+        for (let el = walker.nextNode(); el; el = walker.nextNode()) {
+            if (el.shadowRoot) walk(el.shadowRoot);
+        }
+    };
+    walk(root);
+    return found;
+})";
+
+/// JS function checking whether `child` is `parent` or one of its
+/// descendants, crossing shadow root boundaries.
+const DEEP_CONTAINS: &str = r"((parent, child) => {
+    for (let node = child; node; node = node.parentNode || node.host) {
+        if (node === parent) return true;
+    }
+    return false;
+})";
+
+/// JS expression for all elements in `document` matching a CSS selector,
+/// including elements inside open shadow roots.
+fn deep_query(css: &str) -> String {
+    format!("{DEEP_QUERY}(document, {})", js_string_literal(css))
+}
+
+/// Like [`deep_query`], for elements whose `attribute` equals `value`.
+fn deep_query_attr(attribute: &str, value: &str) -> String {
+    format!(
+        "{DEEP_QUERY}(document, '[{attribute}={}]')",
+        css_attr_value(value)
+    )
+}
+
+/// Write a text-matching selector such as `text*=Save` or `alt=/logo/i`.
+fn write_matcher(
+    f: &mut std::fmt::Formatter<'_>,
+    prefix: &str,
+    matcher: &TextMatcher,
+) -> std::fmt::Result {
+    let (op, value) = matcher.selector_parts();
+    write!(f, "{prefix}{op}{value}")
+}
+
 // Re-export escape functions from viewpoint-js-core for use in selectors
 use viewpoint_js_core::{escape_for_css_attr, escape_js_string_single};
 
@@ -410,5 +462,9 @@ pub(crate) fn css_attr_value(s: &str) -> String {
     escape_for_css_attr(s)
 }
 
+mod parse;
+
+pub(crate) use parse::{BUILTIN_ENGINES, is_engine_name};
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,125 @@
+//! Parsing of selector strings passed to `locator()`.
+
+use super::{Selector, TextMatcher};
+
+impl Selector {
+    /// Parse a selector string.
+    ///
+    /// A selector is one or more parts joined by `>>`; each part is matched
+    /// inside the elements matched by the part before it, crossing shadow
+    /// root boundaries. A part may start with an engine prefix:
+    ///
+    /// - `css=` - CSS selector (the default for unprefixed parts)
+    /// - `xpath=` - XPath expression; parts starting with `//` or `..` are
+    ///   XPath too
+    /// - `text=` - text content: `text="Save"` matches the whole text,
+    ///   `text=/save/i` a regular expression, and `text=save` a substring
+    ///   ignoring case
+    /// - `<name>=` - any other engine name selects a custom engine, see
+    ///   [`Selectors::register`](crate::Selectors::register)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use viewpoint_core::Selector;
+    ///
+    /// let selector = Selector::parse("my-dialog >> xpath=//button >> text=\"Save\"");
+    /// assert_eq!(selector.to_string(), "css=my-dialog >> xpath=//button >> text=Save");
+    /// ```
+    pub fn parse(selector: &str) -> Self {
+        split_chain(selector)
+            .into_iter()
+            .map(parse_part)
+            .reduce(|parent, child| Selector::Chained(Box::new(parent), Box::new(child)))
+            .unwrap_or_else(|| Selector::Css(selector.to_string()))
+    }
+}
+
+/// Split a selector on top-level `>>`, ignoring `>>` inside quotes,
+/// brackets and parentheses.
+fn split_chain(selector: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut chars = selector.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match (quote, c) {
+            (Some(_), '\\') => {
+                chars.next();
+            }
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '[' | '(') => depth += 1,
+            (None, ']' | ')') => depth = depth.saturating_sub(1),
+            (None, '>') if depth == 0 && matches!(chars.peek(), Some((_, '>'))) => {
+                parts.push(selector[start..i].trim());
+                chars.next();
+                start = i + 2;
+            }
+            (None, _) => {}
+        }
+    }
+    parts.push(selector[start..].trim());
+
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// Parse one part of a selector chain.
+fn parse_part(part: &str) -> Selector {
+    if let Some(xpath) = part.strip_prefix("xpath=") {
+        Selector::XPath(xpath.trim().to_string())
+    } else if let Some(css) = part.strip_prefix("css=") {
+        Selector::Css(css.trim().to_string())
+    } else if let Some(text) = part.strip_prefix("text=") {
+        Selector::Text(parse_text(text.trim()))
+    } else if part.starts_with("//") || part.starts_with("..") || part.starts_with("(//") {
+        Selector::XPath(part.to_string())
+    } else if let Some((engine, body)) = part.split_once('=').filter(|(e, _)| is_engine_name(e)) {
+        Selector::Custom {
+            engine: engine.to_string(),
+            body: body.trim().to_string(),
+        }
+    } else {
+        Selector::Css(part.to_string())
+    }
+}
+
+/// Engine prefixes handled by the built-in selector engines.
+pub(crate) const BUILTIN_ENGINES: &[&str] = &["css", "xpath", "text"];
+
+/// Whether `name` can be used as a selector engine prefix: a letter or
+/// underscore followed by letters, digits, `_` or `-`.
+pub(crate) fn is_engine_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Parse the value of a `text=` part.
+fn parse_text(text: &str) -> TextMatcher {
+    for q in ['"', '\''] {
+        if let Some(inner) = text.strip_prefix(q).and_then(|rest| rest.strip_suffix(q)) {
+            return TextMatcher::Exact(inner.replace(&format!("\\{q}"), &q.to_string()));
+        }
+    }
+
+    if let Some((pattern, flags)) = text
+        .strip_prefix('/')
+        .and_then(|rest| rest.rsplit_once('/'))
+    {
+        if flags.chars().all(|c| c == 'i') {
+            return TextMatcher::Regex {
+                pattern: pattern.to_string(),
+                ignore_case: !flags.is_empty(),
+            };
+        }
+    }
+
+    TextMatcher::IgnoreCase(text.to_string())
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::page::locator::TextMatcher;
 
 // =========================================================================
 // Selector Display Tests
@@ -12,19 +13,13 @@ fn test_selector_display_css() {
 
 #[test]
 fn test_selector_display_text_exact() {
-    let selector = Selector::Text {
-        text: "Click me".to_string(),
-        exact: true,
-    };
+    let selector = Selector::Text(TextMatcher::exact("Click me"));
     assert_eq!(format!("{selector}"), "text=Click me");
 }
 
 #[test]
 fn test_selector_display_text_partial() {
-    let selector = Selector::Text {
-        text: "Click".to_string(),
-        exact: false,
-    };
+    let selector = Selector::Text(TextMatcher::substring("Click"));
     assert_eq!(format!("{selector}"), "text*=Click");
 }
 
@@ -41,7 +36,7 @@ fn test_selector_display_role() {
 fn test_selector_display_role_with_name() {
     let selector = Selector::Role {
         role: AriaRole::Button,
-        name: Some("Submit".to_string()),
+        name: Some(TextMatcher::exact("Submit")),
     };
     assert_eq!(format!("{selector}"), "role=button[name=Submit]");
 }
@@ -74,15 +69,60 @@ fn test_css_selector_js() {
 
 #[test]
 fn test_text_selector_exact_js() {
-    let selector = Selector::Text {
-        text: "Hello".to_string(),
-        exact: true,
-    };
+    let selector = Selector::Text(TextMatcher::exact("Hello"));
     let js = selector.to_js_expression();
     assert!(js.contains("textContent"));
     assert!(js.contains("=== 'Hello'"));
 }
 
+#[test]
+fn test_selector_display_text_regex() {
+    let selector = Selector::Text(TextMatcher::regex_ignore_case(r"order #\d+"));
+    assert_eq!(format!("{selector}"), r"text=/order #\d+/i");
+}
+
+#[test]
+fn test_selector_display_filter_ignore_case() {
+    let selector = Selector::FilterText {
+        base: Box::new(Selector::Css("li".to_string())),
+        text: TextMatcher::ignore_case("sold out"),
+        has_not: true,
+    };
+    assert_eq!(
+        format!("{selector}"),
+        r#"css=li.filter(hasNotText*="sold out"i)"#
+    );
+}
+
+#[test]
+fn test_text_selector_regex_js() {
+    let selector = Selector::Text(TextMatcher::regex(r"Order #\d+"));
+    let js = selector.to_js_expression();
+    assert!(js.contains(r"new RegExp('Order #\\d+', '')"));
+}
+
+#[test]
+fn test_role_selector_regex_name_js() {
+    let selector = Selector::Role {
+        role: AriaRole::Button,
+        name: Some(TextMatcher::regex_ignore_case("^save")),
+    };
+    let js = selector.to_js_expression();
+    assert!(js.contains("new RegExp('^save', 'i')"));
+    assert!(js.contains("aria-label"));
+}
+
+#[test]
+fn test_placeholder_selector_js() {
+    let exact = Selector::Placeholder(TextMatcher::exact("Search"));
+    assert!(exact.to_js_expression().contains("[placeholder="));
+
+    let regex = Selector::Placeholder(TextMatcher::regex("^Sea"));
+    let js = regex.to_js_expression();
+    assert!(js.contains("el.placeholder"));
+    assert!(js.contains("new RegExp('^Sea', '')"));
+}
+
 #[test]
 fn test_testid_selector_js() {
     let selector = Selector::TestId("my-button".to_string());
@@ -126,3 +166,135 @@ fn test_deeply_nested_selector() {
     let display = format!("{ul_li_a}");
     assert_eq!(display, "css=ul >> css=li >> css=a");
 }
+
+// =========================================================================
+// Selector Engine Tests
+// =========================================================================
+
+#[test]
+fn test_xpath_selector_js() {
+    let selector = Selector::XPath("//button[@type='submit']".to_string());
+    assert_eq!(format!("{selector}"), "xpath=//button[@type='submit']");
+    let js = selector.to_js_expression();
+    assert!(js.contains("document.evaluate('//button[@type=\\'submit\\']'"));
+    assert!(js.contains("ORDERED_NODE_SNAPSHOT_TYPE"));
+}
+
+#[test]
+fn test_chained_xpath_uses_parent_as_context_node() {
+    let selector = Selector::parse("css=.card >> xpath=./span");
+    let js = selector.to_js_expression();
+    assert!(js.contains("document.evaluate('./span', root,"));
+    assert!(js.contains("})(parent)"));
+    assert!(js.contains("})(document)"));
+}
+
+#[test]
+fn test_css_selector_pierces_shadow_roots() {
+    let js = Selector::Css("button".to_string()).to_js_expression();
+    assert!(js.contains("el.shadowRoot"));
+    assert!(js.contains("(document, 'button')"));
+
+    let js = Selector::TestId("it's".to_string()).to_js_expression();
+    assert!(js.contains(r#"(document, '[data-testid=\"it\'s\"]')"#));
+}
+
+#[test]
+fn test_chained_selector_crosses_shadow_boundaries() {
+    let selector = Selector::Chained(
+        Box::new(Selector::Css("my-card".to_string())),
+        Box::new(Selector::Css("button".to_string())),
+    );
+    let js = selector.to_js_expression();
+    assert!(js.contains("node.parentNode || node.host"));
+    assert!(!js.contains("parent.contains(child)"));
+}
+
+#[test]
+fn test_parse_css_and_xpath() {
+    assert!(matches!(Selector::parse("div > .item"), Selector::Css(css) if css == "div > .item"));
+    assert!(matches!(Selector::parse("css=a[href]"), Selector::Css(css) if css == "a[href]"));
+    assert!(matches!(Selector::parse("xpath=//li"), Selector::XPath(x) if x == "//li"));
+    assert!(matches!(Selector::parse("//div/span"), Selector::XPath(x) if x == "//div/span"));
+    assert!(matches!(Selector::parse("(//li)[2]"), Selector::XPath(x) if x == "(//li)[2]"));
+    assert!(matches!(Selector::parse(".."), Selector::XPath(_)));
+}
+
+#[test]
+fn test_parse_text() {
+    assert!(matches!(
+        Selector::parse(r#"text="Save""#),
+        Selector::Text(TextMatcher::Exact(t)) if t == "Save"
+    ));
+    assert!(matches!(
+        Selector::parse("text=save"),
+        Selector::Text(TextMatcher::IgnoreCase(t)) if t == "save"
+    ));
+    assert!(matches!(
+        Selector::parse(r"text=/order #\d+/i"),
+        Selector::Text(TextMatcher::Regex { pattern, ignore_case: true }) if pattern == r"order #\d+"
+    ));
+}
+
+#[test]
+fn test_parse_chain() {
+    let selector = Selector::parse("my-app >> xpath=//form >> button.primary");
+    assert_eq!(
+        format!("{selector}"),
+        "css=my-app >> xpath=//form >> css=button.primary"
+    );
+
+    // `>>` inside quotes, brackets and parentheses does not split
+    let selector = Selector::parse(r#"[title=">>"] >> text=">> next""#);
+    assert_eq!(format!("{selector}"), r#"css=[title=">>"] >> text=>> next"#);
+    let selector = Selector::parse("xpath=//a[contains(., '>>')]");
+    assert!(matches!(selector, Selector::XPath(_)));
+}
+
+#[test]
+fn test_parse_custom_engine() {
+    let selector = Selector::parse("data-qa=checkout >> button");
+    assert_eq!(format!("{selector}"), "data-qa=checkout >> css=button");
+    let Selector::Chained(parent, _) = selector else {
+        panic!("expected a chained selector");
+    };
+    assert!(matches!(
+        *parent,
+        Selector::Custom { ref engine, ref body } if engine == "data-qa" && body == "checkout"
+    ));
+
+    // Attribute selectors are still CSS
+    assert!(matches!(
+        Selector::parse("[data-qa=checkout]"),
+        Selector::Css(_)
+    ));
+    assert!(is_engine_name("_my-engine2"));
+    assert!(!is_engine_name("my engine"));
+}
+
+#[test]
+fn test_custom_selector_js() {
+    let selector = Selector::Custom {
+        engine: "data-qa".to_string(),
+        body: "it's".to_string(),
+    };
+    let js = selector.to_js_expression();
+    assert!(js.contains("globalThis.__viewpointSelectorEngines || {})['data-qa']"));
+    assert!(js.contains(r"const body = 'it\'s';"));
+    assert!(js.contains("engine.queryAll(root, body)"));
+    assert!(js.contains("engine.query(root, body)"));
+    assert!(js.ends_with("})(document)"));
+}
+
+#[test]
+fn test_chained_custom_selector_queries_each_parent() {
+    let selector = Selector::parse("css=.card >> myengine=x");
+    let js = selector.to_js_expression();
+    // The chain iterates the parents and hands each one to the engine
+    let (_, per_parent) = js
+        .split_once("for (const parent of parents)")
+        .expect("chain iterates parents");
+    assert!(per_parent.contains("engine.queryAll(root, body)"));
+    assert!(per_parent.contains("})(parent)"));
+    assert!(!js.contains("queryAll(document"));
+}
@@ -0,0 +1,198 @@
+//! Text matching for locators and assertions.
+//!
+//! A [`TextMatcher`] describes how a string is compared: exactly, as a
+//! substring, case-insensitively, or with a regular expression. Plain strings
+//! convert into [`TextMatcher::Text`], which each API resolves to its usual
+//! behavior, so `get_by_text("Save")` keeps matching substrings while
+//! `to_have_text("Save")` keeps matching the whole text.
+
+use std::fmt;
+
+use super::selector::js_string_literal;
+
+/// How text is matched by locators and assertions.
+///
+/// # Example
+///
+/// ```no_run
+/// use viewpoint_core::{Page, TextMatcher};
+///
+/// # fn example(page: &Page) {
+/// // Order numbers change on every run
+/// let order = page.get_by_text(TextMatcher::regex(r"Order #\d+"));
+///
+/// let save = page.get_by_text(TextMatcher::ignore_case("save"));
+/// let exact = page.locator("li").filter().has_text(TextMatcher::exact("Item 1"));
+/// # }
+/// ```
+///
+/// Regular expressions are evaluated by the browser as JavaScript `RegExp`s
+/// and, for assertions on values read into Rust, by the `regex` crate. Stick
+/// to the syntax both support; lookaround and backreferences are not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextMatcher {
+    /// Plain text, matched the way the receiving API matches strings by
+    /// default: as a substring for locators and `to_contain_*` assertions,
+    /// and as the whole text for role names and `to_have_*` assertions.
+    Text(String),
+    /// The whole text must equal this string.
+    Exact(String),
+    /// The text must contain this string.
+    Substring(String),
+    /// The text must contain this string, ignoring case.
+    IgnoreCase(String),
+    /// The text must match this regular expression.
+    Regex {
+        /// The pattern, without delimiters.
+        pattern: String,
+        /// Whether matching ignores case.
+        ignore_case: bool,
+    },
+}
+
+impl TextMatcher {
+    /// Match the whole text exactly.
+    pub fn exact(text: impl Into<String>) -> Self {
+        Self::Exact(text.into())
+    }
+
+    /// Match text containing the given string.
+    pub fn substring(text: impl Into<String>) -> Self {
+        Self::Substring(text.into())
+    }
+
+    /// Match text containing the given string, ignoring case.
+    pub fn ignore_case(text: impl Into<String>) -> Self {
+        Self::IgnoreCase(text.into())
+    }
+
+    /// Match text against a regular expression.
+    pub fn regex(pattern: impl Into<String>) -> Self {
+        Self::Regex {
+            pattern: pattern.into(),
+            ignore_case: false,
+        }
+    }
+
+    /// Match text against a case-insensitive regular expression.
+    pub fn regex_ignore_case(pattern: impl Into<String>) -> Self {
+        Self::Regex {
+            pattern: pattern.into(),
+            ignore_case: true,
+        }
+    }
+
+    /// Resolve plain [`Text`](Self::Text) to [`Exact`](Self::Exact) or
+    /// [`Substring`](Self::Substring). Other matchers are returned unchanged.
+    #[must_use]
+    pub fn resolve(self, exact: bool) -> Self {
+        match self {
+            Self::Text(text) if exact => Self::Exact(text),
+            Self::Text(text) => Self::Substring(text),
+            other => other,
+        }
+    }
+
+    /// Check whether `text` matches.
+    ///
+    /// Plain [`Text`](Self::Text) is matched as a substring; call
+    /// [`resolve`](Self::resolve) first to choose otherwise. An invalid
+    /// regular expression matches nothing.
+    pub fn matches(&self, text: &str) -> bool {
+        match self {
+            Self::Exact(expected) => text == expected,
+            Self::Text(expected) | Self::Substring(expected) => text.contains(expected.as_str()),
+            Self::IgnoreCase(expected) => text.to_lowercase().contains(&expected.to_lowercase()),
+            Self::Regex {
+                pattern,
+                ignore_case,
+            } => regex::RegexBuilder::new(pattern)
+                .case_insensitive(*ignore_case)
+                .build()
+                .is_ok_and(|re| re.is_match(text)),
+        }
+    }
+
+    /// Build a JavaScript boolean expression testing the string expression
+    /// `value`, which callers normalize (e.g. trim) as needed.
+    pub(crate) fn to_js_predicate(&self, value: &str) -> String {
+        match self {
+            Self::Exact(expected) => format!("({value}) === {}", js_string_literal(expected)),
+            Self::Text(expected) | Self::Substring(expected) => {
+                format!("({value}).includes({})", js_string_literal(expected))
+            }
+            Self::IgnoreCase(expected) => format!(
+                "({value}).toLowerCase().includes({})",
+                js_string_literal(&expected.to_lowercase())
+            ),
+            Self::Regex {
+                pattern,
+                ignore_case,
+            } => format!(
+                "new RegExp({}, '{}').test({value})",
+                js_string_literal(pattern),
+                if *ignore_case { "i" } else { "" }
+            ),
+        }
+    }
+
+    /// Operator and value used when displaying selectors, e.g. `("*=", "Save")`.
+    ///
+    /// Case-insensitive text is quoted with the flag outside the quotes, e.g.
+    /// `("*=", "\"sold out\"i")`, so the flag cannot be read as part of the text.
+    pub(crate) fn selector_parts(&self) -> (&'static str, String) {
+        match self {
+            Self::Exact(text) => ("=", text.clone()),
+            Self::Text(text) | Self::Substring(text) => ("*=", text.clone()),
+            Self::IgnoreCase(text) => ("*=", format!("\"{text}\"i")),
+            Self::Regex { .. } => ("=", self.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for TextMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exact(text) => write!(f, "\"{text}\""),
+            Self::Text(text) | Self::Substring(text) => write!(f, "containing \"{text}\""),
+            Self::IgnoreCase(text) => write!(f, "containing \"{text}\" (ignoring case)"),
+            Self::Regex {
+                pattern,
+                ignore_case,
+            } => write!(f, "/{pattern}/{}", if *ignore_case { "i" } else { "" }),
+        }
+    }
+}
+
+impl From<&str> for TextMatcher {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for TextMatcher {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&String> for TextMatcher {
+    fn from(text: &String) -> Self {
+        Self::Text(text.clone())
+    }
+}
+
+impl From<regex::Regex> for TextMatcher {
+    fn from(re: regex::Regex) -> Self {
+        Self::regex(re.as_str())
+    }
+}
+
+impl From<&regex::Regex> for TextMatcher {
+    fn from(re: &regex::Regex) -> Self {
+        Self::regex(re.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests;
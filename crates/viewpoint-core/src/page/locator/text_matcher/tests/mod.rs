@@ -0,0 +1,54 @@
+use super::*;
+
+#[test]
+fn test_resolve_plain_text() {
+    let text = TextMatcher::from("Save");
+    assert_eq!(text.clone().resolve(true), TextMatcher::exact("Save"));
+    assert_eq!(text.resolve(false), TextMatcher::substring("Save"));
+
+    let regex = TextMatcher::regex("^Save$");
+    assert_eq!(regex.clone().resolve(true), regex);
+}
+
+#[test]
+fn test_matches() {
+    assert!(TextMatcher::exact("Save").matches("Save"));
+    assert!(!TextMatcher::exact("Save").matches("  Save \n"));
+    assert!(!TextMatcher::exact("Save").matches("Save all"));
+    assert!(TextMatcher::substring("Save").matches("Save all"));
+    assert!(!TextMatcher::substring("save").matches("Save all"));
+    assert!(TextMatcher::ignore_case("save").matches("SAVE ALL"));
+    assert!(TextMatcher::regex(r"Order #\d+").matches("Your Order #1234 shipped"));
+    assert!(!TextMatcher::regex(r"^Order #\d+$").matches("Order #abc"));
+    assert!(TextMatcher::regex_ignore_case("^order").matches("Order #1"));
+    assert!(!TextMatcher::regex("(unclosed").matches("(unclosed"));
+}
+
+#[test]
+fn test_from_regex() {
+    let re = regex::Regex::new(r"\d+").unwrap();
+    assert_eq!(TextMatcher::from(&re), TextMatcher::regex(r"\d+"));
+}
+
+#[test]
+fn test_js_predicate() {
+    assert_eq!(
+        TextMatcher::exact("it's").to_js_predicate("el.title"),
+        r"(el.title) === 'it\'s'"
+    );
+    assert_eq!(
+        TextMatcher::ignore_case("SAVE").to_js_predicate("el.title"),
+        "(el.title).toLowerCase().includes('save')"
+    );
+    assert_eq!(
+        TextMatcher::regex_ignore_case(r"Order #\d+").to_js_predicate("el.title"),
+        r"new RegExp('Order #\\d+', 'i').test(el.title)"
+    );
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(TextMatcher::exact("Save").to_string(), "\"Save\"");
+    assert_eq!(TextMatcher::from("Save").to_string(), "containing \"Save\"");
+    assert_eq!(TextMatcher::regex_ignore_case("^a").to_string(), "/^a/i");
+}
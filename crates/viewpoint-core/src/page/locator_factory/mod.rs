@@ -3,11 +3,15 @@
 //! This module contains all the `get_by_*` and `locator` methods that create
 //! locator instances for finding elements on the page.
 
-use crate::page::locator::{AriaRole, Locator, RoleLocatorBuilder, Selector};
+use crate::page::locator::{AriaRole, Locator, RoleLocatorBuilder, Selector, TextMatcher};
 use crate::page::{DEFAULT_TEST_ID_ATTRIBUTE, Page};
 
 impl Page {
-    /// Create a locator for elements matching a CSS selector.
+    /// Create a locator for elements matching a selector.
+    ///
+    /// Selectors are CSS by default and pierce open shadow roots. Use the
+    /// `xpath=` prefix (or start with `//`) for XPath and `>>` to chain
+    /// selectors; see [`Selector::parse`] for the full syntax.
     ///
     /// # Example
     ///
@@ -17,10 +21,12 @@ impl Page {
     /// # fn example(page: &Page) {
     /// let button = page.locator("button.submit");
     /// let items = page.locator(".list > .item");
+    /// let cell = page.locator("xpath=//table//td[2]");
+    /// let save = page.locator("settings-panel >> text=\"Save\"");
     /// # }
     /// ```
     pub fn locator(&self, selector: impl Into<String>) -> Locator<'_> {
-        Locator::new(self, Selector::Css(selector.into()))
+        Locator::new(self, Selector::parse(&selector.into()))
     }
 
     /// Create a locator for elements containing the specified text.
@@ -28,32 +34,21 @@ impl Page {
     /// # Example
     ///
     /// ```no_run
-    /// use viewpoint_core::Page;
+    /// use viewpoint_core::{Page, TextMatcher};
     ///
     /// # fn example(page: &Page) {
     /// let heading = page.get_by_text("Welcome");
     /// let exact = page.get_by_text_exact("Welcome to our site");
+    /// let order = page.get_by_text(TextMatcher::regex(r"Order #\d+"));
     /// # }
     /// ```
-    pub fn get_by_text(&self, text: impl Into<String>) -> Locator<'_> {
-        Locator::new(
-            self,
-            Selector::Text {
-                text: text.into(),
-                exact: false,
-            },
-        )
+    pub fn get_by_text(&self, text: impl Into<TextMatcher>) -> Locator<'_> {
+        Locator::new(self, Selector::Text(text.into().resolve(false)))
     }
 
     /// Create a locator for elements with exact text content.
     pub fn get_by_text_exact(&self, text: impl Into<String>) -> Locator<'_> {
-        Locator::new(
-            self,
-            Selector::Text {
-                text: text.into(),
-                exact: true,
-            },
-        )
+        Locator::new(self, Selector::Text(TextMatcher::Exact(text.into())))
     }
 
     /// Create a locator for elements with the specified ARIA role.
@@ -127,8 +122,8 @@ impl Page {
     /// let email = page.get_by_label("Email address");
     /// # }
     /// ```
-    pub fn get_by_label(&self, label: impl Into<String>) -> Locator<'_> {
-        Locator::new(self, Selector::Label(label.into()))
+    pub fn get_by_label(&self, label: impl Into<TextMatcher>) -> Locator<'_> {
+        Locator::new(self, Selector::Label(label.into().resolve(true)))
     }
 
     /// Create a locator for inputs by their placeholder text.
@@ -142,8 +137,11 @@ impl Page {
     /// let search = page.get_by_placeholder("Search...");
     /// # }
     /// ```
-    pub fn get_by_placeholder(&self, placeholder: impl Into<String>) -> Locator<'_> {
-        Locator::new(self, Selector::Placeholder(placeholder.into()))
+    pub fn get_by_placeholder(&self, placeholder: impl Into<TextMatcher>) -> Locator<'_> {
+        Locator::new(
+            self,
+            Selector::Placeholder(placeholder.into().resolve(true)),
+        )
     }
 
     /// Create a locator for images by their alt text.
@@ -157,25 +155,13 @@ impl Page {
     /// let logo = page.get_by_alt_text("Company Logo");
     /// # }
     /// ```
-    pub fn get_by_alt_text(&self, alt: impl Into<String>) -> Locator<'_> {
-        Locator::new(
-            self,
-            Selector::AltText {
-                text: alt.into(),
-                exact: false,
-            },
-        )
+    pub fn get_by_alt_text(&self, alt: impl Into<TextMatcher>) -> Locator<'_> {
+        Locator::new(self, Selector::AltText(alt.into().resolve(false)))
     }
 
     /// Create a locator for images with exact alt text.
     pub fn get_by_alt_text_exact(&self, alt: impl Into<String>) -> Locator<'_> {
-        Locator::new(
-            self,
-            Selector::AltText {
-                text: alt.into(),
-                exact: true,
-            },
-        )
+        Locator::new(self, Selector::AltText(TextMatcher::Exact(alt.into())))
     }
 
     /// Create a locator for elements by their title attribute.
@@ -189,24 +175,12 @@ impl Page {
     /// let tooltip = page.get_by_title("Click to expand");
     /// # }
     /// ```
-    pub fn get_by_title(&self, title: impl Into<String>) -> Locator<'_> {
-        Locator::new(
-            self,
-            Selector::Title {
-                text: title.into(),
-                exact: false,
-            },
-        )
+    pub fn get_by_title(&self, title: impl Into<TextMatcher>) -> Locator<'_> {
+        Locator::new(self, Selector::Title(title.into().resolve(false)))
     }
 
     /// Create a locator for elements with exact title attribute.
     pub fn get_by_title_exact(&self, title: impl Into<String>) -> Locator<'_> {
-        Locator::new(
-            self,
-            Selector::Title {
-                text: title.into(),
-                exact: true,
-            },
-        )
+        Locator::new(self, Selector::Title(TextMatcher::Exact(title.into())))
     }
 }
@@ -16,6 +16,7 @@
 //! - **Clock Mocking**: Control time in the page with [`Clock`]
 //! - **Frames**: Access and interact with iframes via [`Frame`] and [`FrameLocator`]
 //! - **Video Recording**: Record page interactions
+//! - **Code Coverage**: Collect JavaScript and CSS coverage via [`Coverage`]
 //!
 //! ## Quick Start
 //!
@@ -206,15 +207,18 @@
 //! # }
 //! ```
 
+pub mod accessibility;
 mod accessors;
+mod action_tracing;
 mod aria_snapshot;
 pub use aria_snapshot::SnapshotOptions;
 pub mod binding;
 pub mod clock;
-mod clock_script;
+pub(crate) mod clock_script;
 pub mod console;
 mod constructors;
 mod content;
+pub mod coverage;
 pub mod dialog;
 pub mod download;
 pub mod emulation;
@@ -234,9 +238,11 @@ pub mod locator_handler;
 mod mouse;
 mod mouse_drag;
 mod navigation;
+mod network_events;
 pub mod page_error;
 mod page_info;
 mod pdf;
+pub mod performance;
 pub mod popup;
 mod ref_resolution;
 mod routing_impl;
@@ -245,7 +251,10 @@ mod screenshot_element;
 mod scripts;
 mod touchscreen;
 pub mod video;
+mod video_encoding;
 mod video_io;
+pub mod virtual_time;
+pub mod worker;
 
 use std::sync::Arc;
 use std::time::Duration;
@@ -254,11 +263,16 @@ use tokio::sync::RwLock;
 use viewpoint_cdp::CdpConnection;
 
 use crate::error::NavigationError;
-use crate::network::{RouteHandlerRegistry, WebSocketManager};
+use crate::network::{RouteHandlerRegistry, WebSocketManager, WebSocketRouteManager};
 
+pub use accessibility::{
+    Accessibility, AccessibilityAuditBuilder, AccessibilityAuditReport, AccessibilityNode,
+    AccessibilityQueryBuilder, AccessibilitySnapshotBuilder, AuditRule, AuditViolation,
+};
 pub use clock::{Clock, TimeValue};
 pub use console::{ConsoleMessage, ConsoleMessageLocation, ConsoleMessageType, JsArg};
 pub use content::{ScriptTagBuilder, ScriptType, SetContentBuilder, StyleTagBuilder};
+pub use coverage::{Coverage, CssCoverageEntry, CssCoverageRange, JsCoverageEntry};
 pub use dialog::Dialog;
 pub use download::{Download, DownloadState};
 pub use emulation::{EmulateMediaBuilder, MediaType, VisionDeficiency};
@@ -267,11 +281,14 @@ pub use events::PageEventManager;
 pub use file_chooser::{FileChooser, FilePayload};
 pub(crate) use frame::ExecutionContextRegistry;
 pub use frame::Frame;
-pub use frame_locator::{FrameElementLocator, FrameLocator, FrameRoleLocatorBuilder};
+pub use frame_locator::{
+    FrameElementLocator, FrameFilterBuilder, FrameLocator, FrameRoleLocatorBuilder,
+};
+pub use frame_locator_actions::{FrameClickBuilder, FrameTapBuilder};
 pub use keyboard::Keyboard;
 pub use locator::{
     AriaCheckedState, AriaRole, AriaSnapshot, BoundingBox, BoxModel, ElementHandle, FilterBuilder,
-    Locator, LocatorOptions, RoleLocatorBuilder, Selector, TapBuilder, TextOptions,
+    Locator, LocatorOptions, RoleLocatorBuilder, Selector, TapBuilder, TextMatcher, TextOptions,
 };
 pub use locator_handler::{LocatorHandlerHandle, LocatorHandlerManager, LocatorHandlerOptions};
 pub use mouse::Mouse;
@@ -279,12 +296,17 @@ pub use mouse_drag::DragAndDropBuilder;
 pub use navigation::{GotoBuilder, NavigationResponse};
 pub use page_error::{PageError as PageErrorInfo, WebError};
 pub use pdf::{Margins, PaperFormat, PdfBuilder};
+pub use performance::{PerformanceMetrics, WebVitals, WebVitalsReport};
+pub use ref_resolution::{ParsedRef, format_ref, parse_ref};
 pub use screenshot::{Animations, ClipRegion, ScreenshotBuilder, ScreenshotFormat};
 pub use touchscreen::Touchscreen;
 pub use video::{Video, VideoOptions};
+pub use video_encoding::VideoFormat;
 pub use viewpoint_cdp::protocol::DialogType;
-pub use viewpoint_cdp::protocol::emulation::ViewportSize;
+pub use viewpoint_cdp::protocol::emulation::{ViewportSize, VirtualTimePolicy};
 pub use viewpoint_cdp::protocol::input::MouseButton;
+pub use virtual_time::{VirtualTime, VirtualTimeBudget, VirtualTimePolicyBuilder};
+pub use worker::Worker;
 
 /// Default navigation timeout.
 const DEFAULT_NAVIGATION_TIMEOUT: Duration = Duration::from_secs(30);
@@ -322,6 +344,8 @@ pub struct Page {
     event_manager: Arc<PageEventManager>,
     /// Locator handler manager.
     locator_handler_manager: Arc<LocatorHandlerManager>,
+    /// Code coverage controller.
+    coverage: Arc<coverage::Coverage>,
     /// Video recording controller (if recording is enabled).
     video_controller: Option<Arc<Video>>,
     /// Opener target ID (for popup pages).
@@ -330,8 +354,14 @@ pub struct Page {
     popup_manager: Arc<popup::PopupManager>,
     /// WebSocket event manager.
     websocket_manager: Arc<WebSocketManager>,
+    /// Request and response event listener.
+    network_events: Arc<crate::network::NetworkEventListener>,
+    /// WebSocket route manager.
+    websocket_route_manager: Arc<WebSocketRouteManager>,
     /// Exposed function binding manager.
     binding_manager: Arc<binding::BindingManager>,
+    /// Dedicated worker manager.
+    worker_manager: Arc<worker::WorkerManager>,
     /// Custom test ID attribute (defaults to "data-testid").
     test_id_attribute: String,
     /// Execution context registry for tracking frame contexts.
@@ -349,6 +379,14 @@ pub struct Page {
     /// preventing stale sessions from accumulating.
     /// Stores a `Vec<Page>` to enable returning functional Page objects from context.pages().
     context_pages: Option<Arc<RwLock<Vec<Page>>>>,
+    /// Shared tracing state of the owning context, used to record actions.
+    tracing_state: Option<Arc<RwLock<crate::context::trace::TracingState>>>,
+    /// Shared network emulation state of the owning context.
+    network_emulation: Option<Arc<RwLock<crate::network::conditions::NetworkEmulation>>>,
+    /// Network conditions overriding the context's for this page.
+    network_override: Arc<RwLock<Option<crate::network::NetworkConditions>>>,
+    /// Base URL of the owning context for resolving relative URLs.
+    base_url: Option<String>,
 }
 
 // Manual Debug implementation since some fields don't implement Debug
@@ -368,7 +406,8 @@ impl std::fmt::Debug for Page {
 impl Page {
     /// Navigate to a URL.
     ///
-    /// Returns a builder for configuring navigation options.
+    /// Returns a builder for configuring navigation options. Relative URLs
+    /// are resolved against the context's base URL, if one is set.
     ///
     /// # Example
     ///
@@ -391,7 +430,8 @@ impl Page {
     /// # }
     /// ```
     pub fn goto(&self, url: impl Into<String>) -> GotoBuilder<'_> {
-        GotoBuilder::new(self, url.into())
+        let url = crate::network::resolve_url(self.base_url.as_deref(), &url.into());
+        GotoBuilder::new(self, url)
     }
 
     /// Navigate to a URL and wait for the specified load state.
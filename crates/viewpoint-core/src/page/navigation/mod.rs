@@ -185,12 +185,23 @@ impl<'a> GotoBuilder<'a> {
     #[instrument(level = "debug", skip(self), fields(url = %self.url, wait_until = ?self.wait_until, timeout_ms = self.timeout.as_millis(), has_referer = self.referer.is_some()))]
     pub async fn goto(self) -> Result<NavigationResponse, NavigationError> {
         debug!("Executing navigation via GotoBuilder");
+        let params = serde_json::json!({
+            "url": self.url,
+            "waitUntil": format!("{:?}", self.wait_until),
+            "timeout": self.timeout.as_millis() as u64,
+            "referer": self.referer,
+        });
         self.page
-            .navigate_internal(
-                &self.url,
-                self.wait_until,
-                self.timeout,
-                self.referer.as_deref(),
+            .trace_action(
+                "goto",
+                None,
+                Some(params),
+                self.page.navigate_internal(
+                    &self.url,
+                    self.wait_until,
+                    self.timeout,
+                    self.referer.as_deref(),
+                ),
             )
             .await
     }
@@ -310,6 +321,12 @@ impl Page {
     /// ```
     #[instrument(level = "info", skip(self))]
     pub async fn go_back(&self) -> Result<Option<NavigationResponse>, NavigationError> {
+        self.trace_action("goBack", None, None, self.go_back_untraced())
+            .await
+    }
+
+    /// Navigate back in history without recording it in the trace.
+    async fn go_back_untraced(&self) -> Result<Option<NavigationResponse>, NavigationError> {
         if self.closed {
             return Err(NavigationError::Cancelled);
         }
@@ -355,6 +372,12 @@ impl Page {
     /// Returns `None` if there is no next page in history.
     #[instrument(level = "info", skip(self))]
     pub async fn go_forward(&self) -> Result<Option<NavigationResponse>, NavigationError> {
+        self.trace_action("goForward", None, None, self.go_forward_untraced())
+            .await
+    }
+
+    /// Navigate forward in history without recording it in the trace.
+    async fn go_forward_untraced(&self) -> Result<Option<NavigationResponse>, NavigationError> {
         if self.closed {
             return Err(NavigationError::Cancelled);
         }
@@ -408,6 +431,12 @@ impl Page {
     /// ```
     #[instrument(level = "info", skip(self))]
     pub async fn reload(&self) -> Result<NavigationResponse, NavigationError> {
+        self.trace_action("reload", None, None, self.reload_untraced())
+            .await
+    }
+
+    /// Reload the current page without recording it in the trace.
+    async fn reload_untraced(&self) -> Result<NavigationResponse, NavigationError> {
         if self.closed {
             return Err(NavigationError::Cancelled);
         }
@@ -0,0 +1,213 @@
+//! Network event listeners for Page.
+//!
+//! This module provides handlers and streams observing every request the page
+//! makes, as opposed to the one-shot `wait_for_request`/`wait_for_response`.
+
+use std::future::Future;
+
+use futures::Stream;
+
+use super::Page;
+use crate::context::HandlerId;
+use crate::network::{NetworkEvent, NetworkEventKind, NetworkEventListener, Request, Response};
+
+impl Page {
+    /// Relay this page's network events to its context.
+    pub(crate) fn with_context_network_events(self, context_events: &NetworkEventListener) -> Self {
+        self.network_events.forward_to(context_events);
+        self
+    }
+
+    /// Register a handler for requests issued by the page.
+    ///
+    /// Each hop of a redirect chain is reported as its own request; see
+    /// [`Request::redirected_from`].
+    /// Returns a handler ID that can be used to remove the handler with `off_request`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::{Page, Request};
+    ///
+    /// # async fn example(page: Page) -> Result<(), viewpoint_core::CoreError> {
+    /// let handler_id = page.on_request(|request: Request| async move {
+    ///     println!("{} {}", request.method(), request.url());
+    /// }).await;
+    ///
+    /// // Later, remove the handler
+    /// page.off_request(handler_id).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_request<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Request) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.network_events
+            .add_handler(
+                NetworkEventKind::Request,
+                NetworkEvent::into_request,
+                handler,
+            )
+            .await
+    }
+
+    /// Remove a request handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed, `false` if the ID was not found
+    /// or belongs to a handler of another kind.
+    pub async fn off_request(&self, handler_id: HandlerId) -> bool {
+        self.network_events
+            .remove_handler(NetworkEventKind::Request, handler_id)
+            .await
+    }
+
+    /// Register a handler for responses received by the page.
+    ///
+    /// Redirect responses are reported too, before the request they redirect to.
+    /// Returns a handler ID that can be used to remove the handler with `off_response`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use viewpoint_core::{Page, Response};
+    ///
+    /// # async fn example(page: Page) -> Result<(), viewpoint_core::CoreError> {
+    /// // Handlers run on a task of their own, so use them for side effects
+    /// // such as logging
+    /// page.on_response(|response: Response| async move {
+    ///     if response.status() >= 500 {
+    ///         eprintln!("{} {}", response.status(), response.url());
+    ///     }
+    /// }).await;
+    ///
+    /// // To check responses in the test itself, await them from a stream
+    /// let mut responses = Box::pin(page.response_stream());
+    /// page.goto("https://example.com").goto().await?;
+    /// let document = responses.next().await.expect("page received no response");
+    /// assert!(document.status() < 500);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_response<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Response) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.network_events
+            .add_handler(
+                NetworkEventKind::Response,
+                NetworkEvent::into_response,
+                handler,
+            )
+            .await
+    }
+
+    /// Remove a response handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed, `false` if the ID was not found
+    /// or belongs to a handler of another kind.
+    pub async fn off_response(&self, handler_id: HandlerId) -> bool {
+        self.network_events
+            .remove_handler(NetworkEventKind::Response, handler_id)
+            .await
+    }
+
+    /// Register a handler for requests that finished loading.
+    ///
+    /// Returns a handler ID that can be used to remove the handler with
+    /// `off_request_finished`.
+    pub async fn on_request_finished<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Request) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.network_events
+            .add_handler(
+                NetworkEventKind::RequestFinished,
+                NetworkEvent::into_finished_request,
+                handler,
+            )
+            .await
+    }
+
+    /// Remove a request finished handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed, `false` if the ID was not found
+    /// or belongs to a handler of another kind.
+    pub async fn off_request_finished(&self, handler_id: HandlerId) -> bool {
+        self.network_events
+            .remove_handler(NetworkEventKind::RequestFinished, handler_id)
+            .await
+    }
+
+    /// Register a handler for requests that failed.
+    ///
+    /// The error text is available from [`Request::failure`]. HTTP error
+    /// statuses are not failures; they are reported as responses.
+    /// Returns a handler ID that can be used to remove the handler with
+    /// `off_request_failed`.
+    pub async fn on_request_failed<F, Fut>(&self, handler: F) -> HandlerId
+    where
+        F: Fn(Request) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.network_events
+            .add_handler(
+                NetworkEventKind::RequestFailed,
+                NetworkEvent::into_failed_request,
+                handler,
+            )
+            .await
+    }
+
+    /// Remove a request failed handler by its ID.
+    ///
+    /// Returns `true` if a handler was removed, `false` if the ID was not found
+    /// or belongs to a handler of another kind.
+    pub async fn off_request_failed(&self, handler_id: HandlerId) -> bool {
+        self.network_events
+            .remove_handler(NetworkEventKind::RequestFailed, handler_id)
+            .await
+    }
+
+    /// Stream the requests issued by the page from now on.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use viewpoint_core::Page;
+    ///
+    /// # async fn example(page: Page) -> Result<(), viewpoint_core::CoreError> {
+    /// let mut requests = Box::pin(page.request_stream());
+    /// page.goto("https://example.com").goto().await?;
+    ///
+    /// let first = requests.next().await.expect("page made no request");
+    /// assert_eq!(first.url(), "https://example.com/");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn request_stream(&self) -> impl Stream<Item = Request> + Send + use<> {
+        self.network_events.stream(NetworkEvent::into_request)
+    }
+
+    /// Stream the responses received by the page from now on.
+    pub fn response_stream(&self) -> impl Stream<Item = Response> + Send + use<> {
+        self.network_events.stream(NetworkEvent::into_response)
+    }
+
+    /// Stream the requests that finish loading from now on.
+    pub fn request_finished_stream(&self) -> impl Stream<Item = Request> + Send + use<> {
+        self.network_events
+            .stream(NetworkEvent::into_finished_request)
+    }
+
+    /// Stream the requests that fail from now on.
+    pub fn request_failed_stream(&self) -> impl Stream<Item = Request> + Send + use<> {
+        self.network_events
+            .stream(NetworkEvent::into_failed_request)
+    }
+}
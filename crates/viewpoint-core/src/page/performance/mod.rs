@@ -0,0 +1,283 @@
+//! Performance metrics and Web Vitals.
+//!
+//! [`Page::metrics`] reads Chromium's run-time counters through the CDP
+//! `Performance` domain. [`Page::web_vitals`] collects the Core Web Vitals
+//! (LCP, CLS, INP) and TTFB with `PerformanceObserver`s injected into the
+//! page, so tests can assert performance budgets.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "integration")]
+//! # tokio_test::block_on(async {
+//! # use viewpoint_core::Browser;
+//! use std::time::Duration;
+//! # let browser = Browser::launch().headless(true).launch().await.unwrap();
+//! # let context = browser.new_context().await.unwrap();
+//! # let page = context.new_page().await.unwrap();
+//!
+//! page.web_vitals().start().await.unwrap();
+//! page.goto("https://example.com").goto().await.unwrap();
+//!
+//! let vitals = page.web_vitals().collect().await.unwrap();
+//! assert!(vitals.lcp.is_none_or(|lcp| lcp < Duration::from_millis(2500)));
+//! assert!(vitals.cls.is_none_or(|cls| cls < 0.1));
+//!
+//! let metrics = page.metrics().await.unwrap();
+//! println!("heap: {:?} bytes", metrics.js_heap_used_size());
+//! # });
+//! ```
+
+mod web_vitals_js;
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::Deserialize;
+use tracing::{debug, instrument};
+use viewpoint_cdp::protocol::performance::{EnableParams, GetMetricsResult, Metric};
+
+use super::Page;
+use crate::error::PageError;
+use web_vitals_js::web_vitals_js;
+
+/// Run-time performance counters reported by the browser.
+///
+/// Counts are returned as integers and durations as [`Duration`]s. Use
+/// [`get`](Self::get) for metrics without a dedicated accessor.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PerformanceMetrics {
+    metrics: BTreeMap<String, f64>,
+}
+
+impl PerformanceMetrics {
+    /// Get a raw metric value by its CDP name, e.g. `"JSHeapUsedSize"`.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.metrics.get(name).copied()
+    }
+
+    /// Iterate over all metrics by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.metrics
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+    }
+
+    /// Number of documents in the page.
+    pub fn documents(&self) -> Option<u64> {
+        self.count("Documents")
+    }
+
+    /// Number of frames in the page.
+    pub fn frames(&self) -> Option<u64> {
+        self.count("Frames")
+    }
+
+    /// Number of event listeners in the page.
+    pub fn js_event_listeners(&self) -> Option<u64> {
+        self.count("JSEventListeners")
+    }
+
+    /// Number of DOM nodes in the page.
+    pub fn nodes(&self) -> Option<u64> {
+        self.count("Nodes")
+    }
+
+    /// Number of full or partial page layouts.
+    pub fn layout_count(&self) -> Option<u64> {
+        self.count("LayoutCount")
+    }
+
+    /// Number of page style recalculations.
+    pub fn recalc_style_count(&self) -> Option<u64> {
+        self.count("RecalcStyleCount")
+    }
+
+    /// Combined duration of all page layouts.
+    pub fn layout_duration(&self) -> Option<Duration> {
+        self.seconds("LayoutDuration")
+    }
+
+    /// Combined duration of all page style recalculations.
+    pub fn recalc_style_duration(&self) -> Option<Duration> {
+        self.seconds("RecalcStyleDuration")
+    }
+
+    /// Combined duration of JavaScript execution.
+    pub fn script_duration(&self) -> Option<Duration> {
+        self.seconds("ScriptDuration")
+    }
+
+    /// Combined duration of all tasks performed by the browser.
+    pub fn task_duration(&self) -> Option<Duration> {
+        self.seconds("TaskDuration")
+    }
+
+    /// Used JavaScript heap size in bytes.
+    pub fn js_heap_used_size(&self) -> Option<u64> {
+        self.count("JSHeapUsedSize")
+    }
+
+    /// Total JavaScript heap size in bytes.
+    pub fn js_heap_total_size(&self) -> Option<u64> {
+        self.count("JSHeapTotalSize")
+    }
+
+    fn count(&self, name: &str) -> Option<u64> {
+        self.get(name).map(|v| v.max(0.0).round() as u64)
+    }
+
+    fn seconds(&self, name: &str) -> Option<Duration> {
+        self.get(name).map(|v| Duration::from_secs_f64(v.max(0.0)))
+    }
+}
+
+impl From<Vec<Metric>> for PerformanceMetrics {
+    fn from(metrics: Vec<Metric>) -> Self {
+        Self {
+            metrics: metrics.into_iter().map(|m| (m.name, m.value)).collect(),
+        }
+    }
+}
+
+/// Core Web Vitals collected from a page.
+///
+/// Metrics are `None` until the browser has reported them: LCP needs a
+/// contentful paint, INP needs a user interaction, and TTFB needs a
+/// navigation over the network.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WebVitalsReport {
+    /// Largest Contentful Paint.
+    pub lcp: Option<Duration>,
+    /// Cumulative Layout Shift score.
+    pub cls: Option<f64>,
+    /// Interaction to Next Paint.
+    pub inp: Option<Duration>,
+    /// Time to First Byte.
+    pub ttfb: Option<Duration>,
+}
+
+/// Web Vitals as reported by the collector script, in milliseconds.
+#[derive(Debug, Deserialize)]
+struct RawWebVitals {
+    lcp: Option<f64>,
+    cls: Option<f64>,
+    inp: Option<f64>,
+    ttfb: Option<f64>,
+}
+
+impl From<RawWebVitals> for WebVitalsReport {
+    fn from(raw: RawWebVitals) -> Self {
+        let millis = |ms: Option<f64>| ms.map(|ms| Duration::from_secs_f64(ms.max(0.0) / 1000.0));
+        Self {
+            lcp: millis(raw.lcp),
+            cls: raw.cls,
+            inp: millis(raw.inp),
+            ttfb: millis(raw.ttfb),
+        }
+    }
+}
+
+/// Web Vitals collector for a page.
+///
+/// Obtain it with [`Page::web_vitals`].
+#[derive(Debug)]
+pub struct WebVitals<'a> {
+    page: &'a Page,
+}
+
+impl WebVitals<'_> {
+    /// Start collecting Web Vitals in the current document and every
+    /// document loaded after it.
+    ///
+    /// Paints and layout shifts are observed with buffering, so they are
+    /// reported even if they happened before collection started. Start before
+    /// interacting with the page so INP sees every interaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page is closed or the collector cannot be
+    /// installed.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn start(&self) -> Result<(), PageError> {
+        self.page.add_init_script(web_vitals_js()).await?;
+        let _: serde_json::Value = self.page.evaluate(web_vitals_js()).await?;
+        debug!("Web Vitals collector installed");
+        Ok(())
+    }
+
+    /// Collect the Web Vitals observed so far in the current document.
+    ///
+    /// Installs the collector first if [`start`](Self::start) was not called.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page is closed or evaluation fails.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn collect(&self) -> Result<WebVitalsReport, PageError> {
+        let raw: RawWebVitals = self
+            .page
+            .evaluate(&format!(
+                "({}, window.__viewpointWebVitals.report())",
+                web_vitals_js()
+            ))
+            .await?;
+        let report = WebVitalsReport::from(raw);
+        debug!(?report, "Web Vitals collected");
+        Ok(report)
+    }
+}
+
+impl Page {
+    /// Get the browser's run-time performance metrics for this page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::Page;
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// let metrics = page.metrics().await?;
+    /// println!("layouts: {:?}", metrics.layout_count());
+    /// println!("script time: {:?}", metrics.script_duration());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the page is closed or the CDP command fails.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn metrics(&self) -> Result<PerformanceMetrics, PageError> {
+        if self.is_closed() {
+            return Err(PageError::Closed);
+        }
+
+        self.connection()
+            .send_command::<_, serde_json::Value>(
+                "Performance.enable",
+                Some(EnableParams::default()),
+                Some(self.session_id()),
+            )
+            .await?;
+
+        let result: GetMetricsResult = self
+            .connection()
+            .send_command(
+                "Performance.getMetrics",
+                None::<()>,
+                Some(self.session_id()),
+            )
+            .await?;
+
+        Ok(PerformanceMetrics::from(result.metrics))
+    }
+
+    /// Get the Web Vitals collector for this page.
+    pub fn web_vitals(&self) -> WebVitals<'_> {
+        WebVitals { page: self }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use viewpoint_cdp::protocol::performance::Metric;
+
+use super::{PerformanceMetrics, RawWebVitals, WebVitalsReport};
+
+fn metric(name: &str, value: f64) -> Metric {
+    Metric {
+        name: name.to_string(),
+        value,
+    }
+}
+
+#[test]
+fn test_performance_metrics_accessors() {
+    let metrics = PerformanceMetrics::from(vec![
+        metric("Nodes", 42.0),
+        metric("LayoutCount", 3.0),
+        metric("ScriptDuration", 0.25),
+        metric("JSHeapUsedSize", 1_048_576.0),
+    ]);
+
+    assert_eq!(metrics.nodes(), Some(42));
+    assert_eq!(metrics.layout_count(), Some(3));
+    assert_eq!(metrics.script_duration(), Some(Duration::from_millis(250)));
+    assert_eq!(metrics.js_heap_used_size(), Some(1_048_576));
+    assert_eq!(metrics.frames(), None);
+    assert_eq!(metrics.get("Nodes"), Some(42.0));
+    assert_eq!(metrics.iter().count(), 4);
+}
+
+#[test]
+fn test_web_vitals_report_from_raw() {
+    let raw: RawWebVitals = serde_json::from_value(serde_json::json!({
+        "lcp": 1234.5,
+        "cls": 0.05,
+        "inp": null,
+        "ttfb": 80.0
+    }))
+    .unwrap();
+
+    let report = WebVitalsReport::from(raw);
+    assert_eq!(report.lcp, Some(Duration::from_micros(1_234_500)));
+    assert_eq!(report.cls, Some(0.05));
+    assert_eq!(report.inp, None);
+    assert_eq!(report.ttfb, Some(Duration::from_millis(80)));
+}
@@ -0,0 +1,99 @@
+//! JavaScript for the Web Vitals collector.
+
+use viewpoint_js::js;
+
+/// Script installing the Web Vitals collector as `window.__viewpointWebVitals`.
+///
+/// The collector observes buffered `largest-contentful-paint`, `layout-shift`
+/// and `event` entries, so installing it after load still picks up earlier
+/// paints and shifts. Its `report()` method returns
+/// `{ lcp, cls, inp, ttfb }`, with times in milliseconds and `null` for
+/// metrics that have not been observed.
+pub(super) fn web_vitals_js() -> &'static str {
+    js! {
+        (function() {
+            if (window.__viewpointWebVitals) {
+                return;
+            }
+
+            const state = {
+                lcp: null,
+                cls: null,
+                sessionValue: 0,
+                sessionEntries: [],
+                interactions: new Map()
+            };
+            const observers = [];
+
+            function observe(type, handler, options) {
+                try {
+                    const observer = new PerformanceObserver(function(list) {
+                        list.getEntries().forEach(handler);
+                    });
+                    observer.observe(Object.assign({ type: type, buffered: true }, options || {}));
+                    observers.push({ observer: observer, handler: handler });
+                } catch (e) {
+                    // Entry type not supported by this browser
+                }
+            }
+
+            observe("largest-contentful-paint", function(entry) {
+                state.lcp = entry.startTime;
+            });
+
+            // CLS is the largest burst of shifts: a session window ends after
+            // a 1s gap or once it spans 5s.
+            observe("layout-shift", function(entry) {
+                if (entry.hadRecentInput) {
+                    return;
+                }
+                const first = state.sessionEntries[0];
+                const last = state.sessionEntries[state.sessionEntries.length - 1];
+                if (last && entry.startTime - last.startTime < 1000 && entry.startTime - first.startTime < 5000) {
+                    state.sessionValue += entry.value;
+                    state.sessionEntries.push(entry);
+                } else {
+                    state.sessionValue = entry.value;
+                    state.sessionEntries = [entry];
+                }
+                state.cls = Math.max(state.cls || 0, state.sessionValue);
+            });
+            if (state.cls === null && PerformanceObserver.supportedEntryTypes.includes("layout-shift")) {
+                state.cls = 0;
+            }
+
+            function recordInteraction(entry) {
+                if (!entry.interactionId) {
+                    return;
+                }
+                const previous = state.interactions.get(entry.interactionId) || 0;
+                state.interactions.set(entry.interactionId, Math.max(previous, entry.duration));
+            }
+            observe("event", recordInteraction, { durationThreshold: 16 });
+            observe("first-input", recordInteraction);
+
+            window.__viewpointWebVitals = {
+                report: function() {
+                    observers.forEach(function(o) {
+                        o.observer.takeRecords().forEach(o.handler);
+                    });
+
+                    // INP ignores one outlier per 50 interactions
+                    let inp = null;
+                    const durations = Array.from(state.interactions.values()).sort(function(a, b) { return b - a; });
+                    if (durations.length > 0) {
+                        inp = durations[Math.min(Math.floor(durations.length / 50), durations.length - 1)];
+                    }
+
+                    let ttfb = null;
+                    const navigation = performance.getEntriesByType("navigation")[0];
+                    if (navigation && navigation.responseStart > 0) {
+                        ttfb = Math.max(navigation.responseStart - (navigation.activationStart || 0), 0);
+                    }
+
+                    return { lcp: state.lcp, cls: state.cls, inp: inp, ttfb: ttfb };
+                }
+            };
+        })()
+    }
+}
@@ -6,8 +6,10 @@
 use std::future::Future;
 
 use crate::error::NetworkError;
+use crate::network::resolve_glob;
 use crate::network::{
     Route, UrlMatcher, UrlPattern, WaitForRequestBuilder, WaitForResponseBuilder, WebSocket,
+    WebSocketRoute,
 };
 
 use super::Page;
@@ -64,6 +66,7 @@ impl Page {
         if self.closed {
             return Err(NetworkError::Aborted);
         }
+        let pattern = pattern.into().with_base_url(self.base_url.as_deref());
         self.route_registry.route(pattern, handler).await
     }
 
@@ -126,7 +129,8 @@ impl Page {
     /// # }
     /// ```
     pub async fn unroute(&self, pattern: &str) {
-        self.route_registry.unroute(pattern).await;
+        let pattern = resolve_glob(self.base_url.as_deref(), pattern);
+        self.route_registry.unroute(&pattern).await;
     }
 
     /// Unregister all route handlers.
@@ -240,6 +244,76 @@ impl Page {
         self.route_registry.route("**/*", route_handler).await
     }
 
+    /// Register a route handler for WebSocket connections matching the given pattern.
+    ///
+    /// Matching WebSockets opened by the page never reach the network on their own.
+    /// The handler can act as the server by sending messages and closing the
+    /// connection, or call `connect_to_server()` to proxy the real server and
+    /// intercept frames in either direction.
+    ///
+    /// The mock is installed for documents created after the route is registered
+    /// and for the current document, but sockets that are already open are not
+    /// affected. Page routes take precedence over context routes, and the last
+    /// registered matching route handles the connection.
+    ///
+    /// The handler is called before the page's socket opens and should return
+    /// promptly: messages for the socket are delivered after it returns.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::page::Page;
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// // Echo every message back to the page
+    /// page.route_web_socket("**/echo", |ws| async move {
+    ///     let reply = ws.clone();
+    ///     ws.on_message(move |message| {
+    ///         let reply = reply.clone();
+    ///         async move {
+    ///             let _ = reply.send(message).await;
+    ///         }
+    ///     }).await;
+    ///     Ok(())
+    /// }).await?;
+    ///
+    /// // Proxy to the real server, closing it when the page closes
+    /// page.route_web_socket("wss://example.com/feed", |ws| async move {
+    ///     ws.connect_to_server().await?;
+    ///     Ok(())
+    /// }).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn route_web_socket<M, H, Fut>(
+        &self,
+        pattern: M,
+        handler: H,
+    ) -> Result<(), NetworkError>
+    where
+        M: Into<UrlPattern>,
+        H: Fn(WebSocketRoute) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), NetworkError>> + Send + 'static,
+    {
+        if self.closed {
+            return Err(NetworkError::Aborted);
+        }
+        self.websocket_route_manager.route(pattern, handler).await
+    }
+
+    /// Unregister WebSocket route handlers matching the given pattern.
+    ///
+    /// New connections that no longer match a route connect to the server
+    /// directly. Connections that were already routed are not affected.
+    pub async fn unroute_web_socket(&self, pattern: &str) {
+        self.websocket_route_manager.unroute(pattern).await;
+    }
+
+    /// Unregister all WebSocket route handlers.
+    pub async fn unroute_all_web_sockets(&self) {
+        self.websocket_route_manager.unroute_all().await;
+    }
+
     // =========================================================================
     // Network Event Methods
     // =========================================================================
@@ -275,6 +349,7 @@ impl Page {
         pattern: M,
     ) -> WaitForRequestBuilder<'_, M> {
         WaitForRequestBuilder::new(&self.connection, &self.session_id, pattern)
+            .with_base_url(self.base_url.clone())
     }
 
     /// Wait for a response matching the given pattern.
@@ -306,6 +381,7 @@ impl Page {
         pattern: M,
     ) -> WaitForResponseBuilder<'_, M> {
         WaitForResponseBuilder::new(&self.connection, &self.session_id, pattern)
+            .with_base_url(self.base_url.clone())
     }
 
     // =========================================================================
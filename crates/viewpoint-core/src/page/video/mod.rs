@@ -1,7 +1,8 @@
 //! Video recording for pages.
 //!
 //! This module provides video recording functionality using CDP's screencast feature.
-//! Videos are recorded as a sequence of JPEG frames and can be saved as `WebM` files.
+//! Videos are recorded as a sequence of JPEG frames and saved as MJPEG video in an
+//! AVI or Matroska container.
 
 // Allow dead code for video recording scaffolding (spec: video-recording)
 
@@ -19,6 +20,8 @@ use viewpoint_cdp::protocol::{
 
 use crate::error::PageError;
 
+use super::video_encoding::{self, VideoFormat};
+
 /// Options for video recording.
 #[derive(Debug, Clone)]
 pub struct VideoOptions {
@@ -28,6 +31,8 @@ pub struct VideoOptions {
     pub width: Option<i32>,
     /// Video height (max).
     pub height: Option<i32>,
+    /// Container format of the recorded video file.
+    pub format: VideoFormat,
 }
 
 impl VideoOptions {
@@ -37,6 +42,7 @@ impl VideoOptions {
             dir: dir.into(),
             width: None,
             height: None,
+            format: VideoFormat::default(),
         }
     }
 
@@ -61,6 +67,13 @@ impl VideoOptions {
         self.height = Some(height);
         self
     }
+
+    /// Set the container format of the recorded video file.
+    #[must_use]
+    pub fn format(mut self, format: VideoFormat) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 impl Default for VideoOptions {
@@ -69,6 +82,7 @@ impl Default for VideoOptions {
             dir: std::env::temp_dir().join("viewpoint-videos"),
             width: None,
             height: None,
+            format: VideoFormat::default(),
         }
     }
 }
@@ -77,9 +91,9 @@ impl Default for VideoOptions {
 #[derive(Debug, Clone)]
 pub(super) struct RecordedFrame {
     /// JPEG image data.
-    data: Vec<u8>,
-    /// Timestamp when the frame was captured.
-    timestamp: f64,
+    pub(super) data: Vec<u8>,
+    /// Timestamp when the frame was captured (seconds since epoch).
+    pub(super) timestamp: f64,
 }
 
 /// Internal state for video recording.
@@ -95,6 +109,24 @@ pub(super) struct VideoState {
     pub(super) options: VideoOptions,
     /// Generated video path (set when recording stops).
     pub(super) video_path: Option<PathBuf>,
+    /// When recording stopped (seconds since epoch).
+    pub(super) stopped_at: Option<f64>,
+}
+
+impl VideoState {
+    /// Encode the recorded frames into a video file in `format`.
+    pub(super) fn encode(&self, format: VideoFormat) -> Result<Vec<u8>, PageError> {
+        let size = self.options.width.zip(self.options.height);
+        video_encoding::encode_video(format, &self.frames, self.stopped_at, size)
+    }
+}
+
+/// Current wall-clock time in seconds since the Unix epoch.
+fn now_secs() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
 }
 
 /// Video recording controller for a page.
@@ -247,7 +279,8 @@ impl Video {
                                 &base64::engine::general_purpose::STANDARD,
                                 &frame_event.data,
                             ) {
-                                let timestamp = frame_event.metadata.timestamp.unwrap_or(0.0);
+                                let timestamp =
+                                    frame_event.metadata.timestamp.unwrap_or_else(now_secs);
 
                                 // Store the frame
                                 {
@@ -297,6 +330,7 @@ impl Video {
             .await?;
 
         state.recording = false;
+        state.stopped_at = Some(now_secs());
 
         // Generate video file
         let video_path = self.generate_video(&state).await?;
@@ -309,64 +343,29 @@ impl Video {
 
     /// Generate the video file from recorded frames.
     async fn generate_video(&self, state: &VideoState) -> Result<PathBuf, PageError> {
-        if state.frames.is_empty() {
-            return Err(PageError::EvaluationFailed(
-                "No frames recorded".to_string(),
-            ));
-        }
+        let data = state.encode(state.options.format)?;
 
         // Generate a unique filename
         let filename = format!(
-            "video-{}.webm",
+            "video-{}.{}",
             uuid::Uuid::new_v4()
                 .to_string()
                 .split('-')
                 .next()
-                .unwrap_or("unknown")
+                .unwrap_or("unknown"),
+            state.options.format.extension()
         );
         let video_path = state.options.dir.join(&filename);
 
-        // For now, we save frames as individual images and create a simple container
-        // A full WebM encoder would require additional dependencies (ffmpeg, vpx, etc.)
-        // This is a simplified implementation that saves frames to a directory
-
-        // Create a frames directory
-        let frames_dir = state.options.dir.join(format!(
-            "frames-{}",
-            uuid::Uuid::new_v4()
-                .to_string()
-                .split('-')
-                .next()
-                .unwrap_or("unknown")
-        ));
-        tokio::fs::create_dir_all(&frames_dir).await.map_err(|e| {
-            PageError::EvaluationFailed(format!("Failed to create frames directory: {e}"))
-        })?;
-
-        // Save each frame
-        for (i, frame) in state.frames.iter().enumerate() {
-            let frame_path = frames_dir.join(format!("frame-{i:05}.jpg"));
-            tokio::fs::write(&frame_path, &frame.data)
-                .await
-                .map_err(|e| PageError::EvaluationFailed(format!("Failed to write frame: {e}")))?;
-        }
-
-        // Create a simple metadata file indicating this is a video
-        let metadata = serde_json::json!({
-            "type": "viewpoint-video",
-            "format": "jpeg-sequence",
-            "frame_count": state.frames.len(),
-            "frames_dir": frames_dir.to_string_lossy(),
-        });
-
-        tokio::fs::write(
-            &video_path,
-            serde_json::to_string_pretty(&metadata).unwrap(),
-        )
-        .await
-        .map_err(|e| PageError::EvaluationFailed(format!("Failed to write video metadata: {e}")))?;
+        tokio::fs::write(&video_path, &data)
+            .await
+            .map_err(|e| PageError::EvaluationFailed(format!("Failed to write video: {e}")))?;
 
-        debug!("Saved {} frames to {:?}", state.frames.len(), frames_dir);
+        debug!(
+            "Encoded {} frames into {:?}",
+            state.frames.len(),
+            video_path
+        );
 
         Ok(video_path)
     }
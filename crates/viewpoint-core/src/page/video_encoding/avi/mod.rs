@@ -0,0 +1,139 @@
+//! AVI (RIFF) container writer for MJPEG video.
+//!
+//! AVI streams have a constant frame rate, so the timeline is sampled at
+//! [`FPS`]. A slot that shows the same frame as the previous slot is written
+//! as an empty chunk, which players treat as "repeat the previous frame".
+
+use super::Timeline;
+
+/// Output frame rate.
+const FPS: u32 = 25;
+
+/// `AVIF_HASINDEX`: the file has an `idx1` index.
+const AVIF_HASINDEX: u32 = 0x10;
+
+/// `AVIIF_KEYFRAME`: the indexed chunk is a key frame.
+const AVIIF_KEYFRAME: u32 = 0x10;
+
+/// Chunk ID for compressed video frames of stream 0.
+const VIDEO_CHUNK: [u8; 4] = *b"00dc";
+
+/// Write the timeline as an AVI file.
+pub(super) fn write(timeline: &Timeline<'_>) -> Vec<u8> {
+    let slot_ms = 1000.0 / f64::from(FPS);
+    let total_frames = ((timeline.duration_ms / slot_ms).ceil() as u32).max(1);
+
+    let mut movi = b"movi".to_vec();
+    let mut index = Vec::new();
+    let mut max_chunk = 0u32;
+    let mut current = 0usize;
+    let mut shown = None;
+
+    for slot in 0..total_frames {
+        let time = f64::from(slot) * slot_ms;
+        while current + 1 < timeline.frames.len() && timeline.frames[current + 1].offset_ms <= time
+        {
+            current += 1;
+        }
+
+        let offset = chunk_len(&movi);
+        let (data, flags): (&[u8], u32) = if shown == Some(current) {
+            (&[], 0)
+        } else {
+            shown = Some(current);
+            (timeline.frames[current].data, AVIIF_KEYFRAME)
+        };
+        let size = chunk_len(data);
+        max_chunk = max_chunk.max(size);
+
+        movi.extend_from_slice(&chunk(VIDEO_CHUNK, data));
+        index.extend_from_slice(&VIDEO_CHUNK);
+        index.extend_from_slice(&flags.to_le_bytes());
+        index.extend_from_slice(&offset.to_le_bytes());
+        index.extend_from_slice(&size.to_le_bytes());
+    }
+
+    let width = timeline.width;
+    let height = timeline.height;
+
+    let mut avih = Vec::with_capacity(56);
+    for value in [
+        1_000_000 / FPS,
+        max_chunk.saturating_mul(FPS),
+        0,
+        AVIF_HASINDEX,
+        total_frames,
+        0,
+        1,
+        max_chunk,
+        width,
+        height,
+        0,
+        0,
+        0,
+        0,
+    ] {
+        avih.extend_from_slice(&value.to_le_bytes());
+    }
+
+    let mut strh = Vec::with_capacity(56);
+    strh.extend_from_slice(b"vids");
+    strh.extend_from_slice(b"MJPG");
+    for value in [0, 0, 0, 1, FPS, 0, total_frames, max_chunk, u32::MAX, 0] {
+        strh.extend_from_slice(&value.to_le_bytes());
+    }
+    for value in [0u16, 0, clamp_u16(width), clamp_u16(height)] {
+        strh.extend_from_slice(&value.to_le_bytes());
+    }
+
+    let mut strf = Vec::with_capacity(40);
+    strf.extend_from_slice(&40u32.to_le_bytes());
+    strf.extend_from_slice(&width.to_le_bytes());
+    strf.extend_from_slice(&height.to_le_bytes());
+    strf.extend_from_slice(&1u16.to_le_bytes());
+    strf.extend_from_slice(&24u16.to_le_bytes());
+    strf.extend_from_slice(b"MJPG");
+    for value in [width.saturating_mul(height).saturating_mul(3), 0, 0, 0, 0] {
+        strf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    let strl = list(
+        *b"strl",
+        &[chunk(*b"strh", &strh), chunk(*b"strf", &strf)].concat(),
+    );
+    let hdrl = list(*b"hdrl", &[chunk(*b"avih", &avih), strl].concat());
+
+    let mut body = b"AVI ".to_vec();
+    body.extend_from_slice(&hdrl);
+    body.extend_from_slice(&chunk(*b"LIST", &movi));
+    body.extend_from_slice(&chunk(*b"idx1", &index));
+    chunk(*b"RIFF", &body)
+}
+
+/// Build a RIFF chunk, padded to an even length.
+fn chunk(id: [u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + 9);
+    out.extend_from_slice(&id);
+    out.extend_from_slice(&chunk_len(data).to_le_bytes());
+    out.extend_from_slice(data);
+    if data.len() % 2 == 1 {
+        out.push(0);
+    }
+    out
+}
+
+/// Build a RIFF `LIST` chunk of the given type.
+fn list(kind: [u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut payload = kind.to_vec();
+    payload.extend_from_slice(data);
+    chunk(*b"LIST", &payload)
+}
+
+/// Length of chunk data as stored in the 32-bit size field.
+fn chunk_len(data: &[u8]) -> u32 {
+    u32::try_from(data.len()).unwrap_or(u32::MAX)
+}
+
+fn clamp_u16(value: u32) -> u16 {
+    u16::try_from(value).unwrap_or(u16::MAX)
+}
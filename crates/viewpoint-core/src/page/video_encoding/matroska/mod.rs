@@ -0,0 +1,177 @@
+//! Matroska container writer for MJPEG video.
+//!
+//! Every frame is written as a key-frame `SimpleBlock` carrying its real
+//! capture time, so the video plays with the page's actual timing.
+
+use super::Timeline;
+
+/// Timestamps are in milliseconds (1,000,000 ns per tick).
+const TIMESTAMP_SCALE: u64 = 1_000_000;
+
+/// Start a new cluster before block offsets overflow their signed 16-bit field.
+const MAX_CLUSTER_SPAN_MS: u64 = 30_000;
+
+// Element IDs (including their length-marker bits).
+const EBML: u32 = 0x1A45_DFA3;
+const EBML_VERSION: u32 = 0x4286;
+const EBML_READ_VERSION: u32 = 0x42F7;
+const EBML_MAX_ID_LENGTH: u32 = 0x42F2;
+const EBML_MAX_SIZE_LENGTH: u32 = 0x42F3;
+const DOC_TYPE: u32 = 0x4282;
+const DOC_TYPE_VERSION: u32 = 0x4287;
+const DOC_TYPE_READ_VERSION: u32 = 0x4285;
+const SEGMENT: u32 = 0x1853_8067;
+const INFO: u32 = 0x1549_A966;
+const TIMESTAMP_SCALE_ID: u32 = 0x2A_D7B1;
+const MUXING_APP: u32 = 0x4D80;
+const WRITING_APP: u32 = 0x5741;
+const DURATION: u32 = 0x4489;
+const TRACKS: u32 = 0x1654_AE6B;
+const TRACK_ENTRY: u32 = 0xAE;
+const TRACK_NUMBER: u32 = 0xD7;
+const TRACK_UID: u32 = 0x73C5;
+const TRACK_TYPE: u32 = 0x83;
+const FLAG_LACING: u32 = 0x9C;
+const CODEC_ID: u32 = 0x86;
+const VIDEO: u32 = 0xE0;
+const PIXEL_WIDTH: u32 = 0xB0;
+const PIXEL_HEIGHT: u32 = 0xBA;
+const DISPLAY_WIDTH: u32 = 0x54B0;
+const DISPLAY_HEIGHT: u32 = 0x54BA;
+const CLUSTER: u32 = 0x1F43_B675;
+const CLUSTER_TIMESTAMP: u32 = 0xE7;
+const SIMPLE_BLOCK: u32 = 0xA3;
+
+/// Write the timeline as a Matroska file.
+pub(super) fn write(timeline: &Timeline<'_>) -> Vec<u8> {
+    let header = master(
+        EBML,
+        &[
+            uint(EBML_VERSION, 1),
+            uint(EBML_READ_VERSION, 1),
+            uint(EBML_MAX_ID_LENGTH, 4),
+            uint(EBML_MAX_SIZE_LENGTH, 8),
+            string(DOC_TYPE, "matroska"),
+            uint(DOC_TYPE_VERSION, 4),
+            uint(DOC_TYPE_READ_VERSION, 2),
+        ],
+    );
+
+    let app = concat!("viewpoint ", env!("CARGO_PKG_VERSION"));
+    let info = master(
+        INFO,
+        &[
+            uint(TIMESTAMP_SCALE_ID, TIMESTAMP_SCALE),
+            string(MUXING_APP, app),
+            string(WRITING_APP, app),
+            float(DURATION, timeline.duration_ms),
+        ],
+    );
+
+    let mut video = vec![
+        uint(PIXEL_WIDTH, u64::from(timeline.width)),
+        uint(PIXEL_HEIGHT, u64::from(timeline.height)),
+    ];
+    if let Some((width, height)) = timeline.display_size {
+        video.push(uint(DISPLAY_WIDTH, u64::from(width)));
+        video.push(uint(DISPLAY_HEIGHT, u64::from(height)));
+    }
+    let tracks = master(
+        TRACKS,
+        &[master(
+            TRACK_ENTRY,
+            &[
+                uint(TRACK_NUMBER, 1),
+                uint(TRACK_UID, 1),
+                uint(TRACK_TYPE, 1),
+                uint(FLAG_LACING, 0),
+                string(CODEC_ID, "V_MJPEG"),
+                master(VIDEO, &video),
+            ],
+        )],
+    );
+
+    let mut segment = vec![info, tracks];
+    segment.extend(clusters(timeline));
+
+    let mut out = header;
+    out.extend_from_slice(&master(SEGMENT, &segment));
+    out
+}
+
+/// Group frames into clusters of at most [`MAX_CLUSTER_SPAN_MS`].
+fn clusters(timeline: &Timeline<'_>) -> Vec<Vec<u8>> {
+    let mut clusters = Vec::new();
+    let mut cluster_start = 0u64;
+    let mut blocks = Vec::new();
+
+    for frame in &timeline.frames {
+        let timestamp = frame.offset_ms.round() as u64;
+        if !blocks.is_empty() && timestamp - cluster_start > MAX_CLUSTER_SPAN_MS {
+            clusters.push(cluster(cluster_start, &blocks));
+            blocks.clear();
+        }
+        if blocks.is_empty() {
+            cluster_start = timestamp;
+        }
+
+        let relative = i16::try_from(timestamp - cluster_start).unwrap_or(i16::MAX);
+        let mut block = Vec::with_capacity(frame.data.len() + 4);
+        block.push(0x81); // track number 1 as a 1-byte vint
+        block.extend_from_slice(&relative.to_be_bytes());
+        block.push(0x80); // key frame
+        block.extend_from_slice(frame.data);
+        blocks.push(element(SIMPLE_BLOCK, &block));
+    }
+
+    if !blocks.is_empty() {
+        clusters.push(cluster(cluster_start, &blocks));
+    }
+    clusters
+}
+
+fn cluster(timestamp: u64, blocks: &[Vec<u8>]) -> Vec<u8> {
+    let mut children = vec![uint(CLUSTER_TIMESTAMP, timestamp)];
+    children.extend_from_slice(blocks);
+    master(CLUSTER, &children)
+}
+
+/// Encode an element with its ID, size and payload.
+fn element(id: u32, payload: &[u8]) -> Vec<u8> {
+    let id_bytes = id.to_be_bytes();
+    let skip = id_bytes.iter().take_while(|&&b| b == 0).count();
+
+    let mut out = Vec::with_capacity(payload.len() + 12);
+    out.extend_from_slice(&id_bytes[skip..]);
+    out.extend_from_slice(&size_vint(payload.len() as u64));
+    out.extend_from_slice(payload);
+    out
+}
+
+fn master(id: u32, children: &[Vec<u8>]) -> Vec<u8> {
+    element(id, &children.concat())
+}
+
+fn uint(id: u32, value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let skip = bytes.iter().take_while(|&&b| b == 0).count().min(7);
+    element(id, &bytes[skip..])
+}
+
+fn float(id: u32, value: f64) -> Vec<u8> {
+    element(id, &value.to_be_bytes())
+}
+
+fn string(id: u32, value: &str) -> Vec<u8> {
+    element(id, value.as_bytes())
+}
+
+/// Encode an element size as an EBML variable-length integer.
+pub(super) fn size_vint(size: u64) -> Vec<u8> {
+    // A value of all ones is reserved for "unknown size", hence `<` rather than `<=`.
+    let length = (1..=8u32)
+        .find(|&len| size < (1u64 << (7 * len)) - 1)
+        .unwrap_or(8);
+    let marked = size | (1u64 << (7 * length));
+    marked.to_be_bytes()[(8 - length as usize)..].to_vec()
+}
@@ -0,0 +1,186 @@
+//! Video container encoding for recorded screencast frames.
+//!
+//! Screencast frames are already JPEG-compressed, so they are muxed as-is
+//! into an MJPEG video stream without re-encoding. Both containers are
+//! written in-process with no external tools.
+
+mod avi;
+mod matroska;
+
+#[cfg(test)]
+mod tests;
+
+use std::path::Path;
+
+use crate::error::PageError;
+
+use super::video::RecordedFrame;
+
+/// Frame duration assumed for the last frame when no stop time is known (25 fps).
+const DEFAULT_FRAME_MS: f64 = 40.0;
+
+/// Container format for recorded videos.
+///
+/// Both formats store the browser's JPEG screencast frames as an MJPEG video
+/// stream and play in common desktop players (VLC, mpv, ffmpeg-based tools).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VideoFormat {
+    /// AVI container at a constant 25 fps (`.avi`).
+    ///
+    /// Frames are repeated to keep their real on-screen duration.
+    #[default]
+    Avi,
+    /// Matroska container with the exact capture timestamp of every frame (`.mkv`).
+    Matroska,
+}
+
+impl VideoFormat {
+    /// File extension used for videos in this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Avi => "avi",
+            Self::Matroska => "mkv",
+        }
+    }
+
+    /// Pick a format from a file extension.
+    ///
+    /// `.avi` maps to [`VideoFormat::Avi`] and `.mkv` to
+    /// [`VideoFormat::Matroska`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PageError::InvalidArgument`] for any other extension, or for
+    /// a path without one: recordings are MJPEG streams and can only be
+    /// written into these two containers.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, PageError> {
+        let extension = path
+            .as_ref()
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("avi") => Ok(Self::Avi),
+            Some("mkv") => Ok(Self::Matroska),
+            _ => Err(PageError::InvalidArgument(format!(
+                "Unsupported video file {}; save recordings as .avi or .mkv",
+                path.as_ref().display()
+            ))),
+        }
+    }
+}
+
+/// A frame positioned on the video timeline.
+#[derive(Debug, Clone, Copy)]
+struct TimedFrame<'a> {
+    /// JPEG image data.
+    data: &'a [u8],
+    /// Offset from the first frame in milliseconds.
+    offset_ms: f64,
+}
+
+/// Frames with their timing and the dimensions of the video stream.
+#[derive(Debug)]
+struct Timeline<'a> {
+    frames: Vec<TimedFrame<'a>>,
+    /// Total video duration in milliseconds.
+    duration_ms: f64,
+    /// Pixel size of the frames.
+    width: u32,
+    height: u32,
+    /// Requested display size, if it differs from the frame size.
+    display_size: Option<(u32, u32)>,
+}
+
+/// Encode recorded frames into a video file in `format`.
+///
+/// `stopped_at` is the time recording stopped (seconds since epoch, like frame
+/// timestamps) and determines how long the last frame is shown. `size` is the
+/// requested video size from [`VideoOptions`](super::VideoOptions).
+pub(super) fn encode_video(
+    format: VideoFormat,
+    frames: &[RecordedFrame],
+    stopped_at: Option<f64>,
+    size: Option<(i32, i32)>,
+) -> Result<Vec<u8>, PageError> {
+    let timeline = build_timeline(frames, stopped_at, size)?;
+    Ok(match format {
+        VideoFormat::Avi => avi::write(&timeline),
+        VideoFormat::Matroska => matroska::write(&timeline),
+    })
+}
+
+/// Convert recorded frames into a monotonic timeline starting at zero.
+fn build_timeline(
+    frames: &[RecordedFrame],
+    stopped_at: Option<f64>,
+    size: Option<(i32, i32)>,
+) -> Result<Timeline<'_>, PageError> {
+    let first = frames
+        .first()
+        .ok_or_else(|| PageError::EvaluationFailed("No frames recorded".to_string()))?;
+    let (width, height) = jpeg_dimensions(&first.data).ok_or_else(|| {
+        PageError::EvaluationFailed("Recorded frame is not a valid JPEG image".to_string())
+    })?;
+
+    let mut last_offset = 0.0_f64;
+    let timed: Vec<TimedFrame<'_>> = frames
+        .iter()
+        .map(|frame| {
+            // Clamp so out-of-order timestamps never move backwards.
+            last_offset = ((frame.timestamp - first.timestamp) * 1000.0).max(last_offset);
+            TimedFrame {
+                data: &frame.data,
+                offset_ms: last_offset,
+            }
+        })
+        .collect();
+
+    let end = stopped_at.map_or(0.0, |stop| (stop - first.timestamp) * 1000.0);
+    let duration_ms = end.max(last_offset + DEFAULT_FRAME_MS);
+
+    let display_size = size
+        .and_then(|(w, h)| Some((u32::try_from(w).ok()?, u32::try_from(h).ok()?)))
+        .filter(|&requested| requested != (width, height));
+
+    Ok(Timeline {
+        frames: timed,
+        duration_ms,
+        width,
+        height,
+        display_size,
+    })
+}
+
+/// Read the width and height from a JPEG's start-of-frame segment.
+pub(super) fn jpeg_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    if data.get(..2)? != [0xFF, 0xD8] {
+        return None;
+    }
+
+    let mut i = 2;
+    while i + 4 <= data.len() {
+        if data[i] != 0xFF {
+            return None;
+        }
+        let marker = data[i + 1];
+        if marker == 0xFF {
+            // Fill byte before a marker
+            i += 1;
+            continue;
+        }
+
+        let length = usize::from(u16::from_be_bytes([data[i + 2], data[i + 3]]));
+        let is_start_of_frame =
+            matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+        if is_start_of_frame {
+            let segment = data.get(i + 5..i + 9)?;
+            let height = u16::from_be_bytes([segment[0], segment[1]]);
+            let width = u16::from_be_bytes([segment[2], segment[3]]);
+            return Some((u32::from(width), u32::from(height)));
+        }
+        i += 2 + length;
+    }
+
+    None
+}
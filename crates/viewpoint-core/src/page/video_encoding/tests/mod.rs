@@ -0,0 +1,143 @@
+use super::*;
+
+/// A minimal JPEG with a baseline start-of-frame segment.
+fn jpeg(width: u16, height: u16) -> Vec<u8> {
+    let mut data = vec![0xFF, 0xD8];
+    // APP0 segment with an empty JFIF payload
+    data.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00]);
+    data.extend_from_slice(&[0xFF, 0xC0, 0x00, 0x11, 0x08]);
+    data.extend_from_slice(&height.to_be_bytes());
+    data.extend_from_slice(&width.to_be_bytes());
+    data.extend_from_slice(&[0x03, 1, 0x22, 0, 2, 0x11, 1, 3, 0x11, 1]);
+    data.extend_from_slice(&[0xFF, 0xD9]);
+    data
+}
+
+fn frame(timestamp: f64) -> RecordedFrame {
+    RecordedFrame {
+        data: jpeg(320, 240),
+        timestamp,
+    }
+}
+
+fn read_u32(data: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes(data[at..at + 4].try_into().unwrap())
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+#[test]
+fn test_jpeg_dimensions() {
+    assert_eq!(jpeg_dimensions(&jpeg(1280, 720)), Some((1280, 720)));
+    assert_eq!(jpeg_dimensions(b"not a jpeg"), None);
+    assert_eq!(jpeg_dimensions(&[0xFF, 0xD8, 0xFF, 0xD9]), None);
+}
+
+#[test]
+fn test_format_from_path() {
+    assert_eq!(VideoFormat::from_path("out.avi").unwrap(), VideoFormat::Avi);
+    assert_eq!(
+        VideoFormat::from_path("out.MKV").unwrap(),
+        VideoFormat::Matroska
+    );
+    assert_eq!(VideoFormat::default().extension(), "avi");
+
+    for path in ["out.webm", "out.mp4", "out"] {
+        let err = VideoFormat::from_path(path).unwrap_err();
+        assert!(matches!(err, PageError::InvalidArgument(_)), "{path}");
+        assert!(err.to_string().contains(".avi or .mkv"), "{path}");
+    }
+}
+
+#[test]
+fn test_container_codec_for_each_extension() {
+    let frames = [frame(10.0), frame(10.1)];
+
+    let avi = VideoFormat::from_path("out.avi").unwrap();
+    let data = encode_video(avi, &frames, None, None).unwrap();
+    assert_eq!(&data[8..12], b"AVI ");
+    // Stream header handler and bitmap compression are both MJPEG
+    let strh = find(&data, b"strh").unwrap() + 8;
+    assert_eq!(&data[strh..strh + 8], b"vidsMJPG");
+    let strf = find(&data, b"strf").unwrap() + 8;
+    assert_eq!(&data[strf + 16..strf + 20], b"MJPG");
+
+    let mkv = VideoFormat::from_path("out.mkv").unwrap();
+    let data = encode_video(mkv, &frames, None, None).unwrap();
+    // DocType element (0x4282), size 8, "matroska"
+    assert!(find(&data, &[&[0x42, 0x82, 0x88][..], b"matroska"].concat()).is_some());
+    // CodecID element (0x86), size 7, "V_MJPEG"
+    assert!(find(&data, &[&[0x86, 0x87][..], b"V_MJPEG"].concat()).is_some());
+    assert!(find(&data, b"webm").is_none());
+}
+
+#[test]
+fn test_timeline_uses_real_timestamps() {
+    let frames = [frame(100.0), frame(100.5), frame(100.25)];
+    let timeline = build_timeline(&frames, Some(102.0), Some((640, 480))).unwrap();
+
+    let offsets: Vec<f64> = timeline.frames.iter().map(|f| f.offset_ms).collect();
+    // Out-of-order frames never move the timeline backwards
+    assert_eq!(offsets, vec![0.0, 500.0, 500.0]);
+    assert!((timeline.duration_ms - 2000.0).abs() < f64::EPSILON);
+    assert_eq!((timeline.width, timeline.height), (320, 240));
+    assert_eq!(timeline.display_size, Some((640, 480)));
+}
+
+#[test]
+fn test_timeline_without_frames_fails() {
+    assert!(build_timeline(&[], None, None).is_err());
+}
+
+#[test]
+fn test_avi_structure() {
+    let frames = [frame(10.0), frame(10.1)];
+    let data = encode_video(VideoFormat::Avi, &frames, Some(10.2), None).unwrap();
+
+    assert_eq!(&data[..4], b"RIFF");
+    assert_eq!(read_u32(&data, 4) as usize, data.len() - 8);
+    assert_eq!(&data[8..12], b"AVI ");
+
+    // 200ms at 25 fps is 5 frame slots
+    let avih = find(&data, b"avih").unwrap() + 8;
+    assert_eq!(read_u32(&data, avih), 40_000);
+    assert_eq!(read_u32(&data, avih + 16), 5);
+    assert_eq!(read_u32(&data, avih + 32), 320);
+    assert_eq!(read_u32(&data, avih + 36), 240);
+    assert!(find(&data, b"MJPG").is_some());
+
+    // Only slots where the frame changes carry image data
+    let idx1 = find(&data, b"idx1").unwrap();
+    assert_eq!(read_u32(&data, idx1 + 4), 5 * 16);
+    let sizes: Vec<u32> = (0..5)
+        .map(|i| read_u32(&data, idx1 + 8 + i * 16 + 12))
+        .collect();
+    let frame_size = jpeg(320, 240).len() as u32;
+    assert_eq!(sizes, vec![frame_size, 0, 0, frame_size, 0]);
+}
+
+#[test]
+fn test_matroska_structure() {
+    let frames = [frame(10.0), frame(10.5)];
+    let data = encode_video(VideoFormat::Matroska, &frames, Some(11.0), None).unwrap();
+
+    assert_eq!(&data[..4], &[0x1A, 0x45, 0xDF, 0xA3]);
+    assert!(find(&data, b"matroska").is_some());
+    assert!(find(&data, b"V_MJPEG").is_some());
+    assert!(find(&data, &1000.0_f64.to_be_bytes()).is_some());
+
+    // Second block is 500ms into the cluster
+    assert!(find(&data, &[0x81, 0x01, 0xF4, 0x80]).is_some());
+}
+
+#[test]
+fn test_ebml_size_encoding() {
+    use super::matroska::size_vint;
+
+    assert_eq!(size_vint(0), vec![0x80]);
+    assert_eq!(size_vint(126), vec![0xFE]);
+    assert_eq!(size_vint(127), vec![0x40, 0x7F]);
+    assert_eq!(size_vint(300), vec![0x41, 0x2C]);
+}
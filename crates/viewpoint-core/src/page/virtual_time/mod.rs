@@ -0,0 +1,307 @@
+//! CDP virtual time control.
+//!
+//! Unlike the JavaScript-level [`Clock`](super::Clock), virtual time is
+//! implemented by the browser itself: timers, animations and rendering only
+//! progress when virtual time advances. Granting a budget of virtual time and
+//! waiting for it to expire renders animation-heavy pages deterministically,
+//! for example before taking a screenshot or generating a PDF.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "integration")]
+//! # tokio_test::block_on(async {
+//! # use viewpoint_core::Browser;
+//! use std::time::Duration;
+//! # let browser = Browser::launch().headless(true).launch().await.unwrap();
+//! # let context = browser.new_context().await.unwrap();
+//! # let page = context.new_page().await.unwrap();
+//!
+//! page.virtual_time().pause().await.unwrap();
+//! page.goto("https://example.com").goto().await.unwrap();
+//!
+//! // Let exactly five seconds of animations run, then capture
+//! page.virtual_time().advance(Duration::from_secs(5)).await.unwrap();
+//! let screenshot = page.screenshot().capture().await.unwrap();
+//! # });
+//! ```
+
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+use tracing::{debug, instrument};
+use viewpoint_cdp::protocol::emulation::{
+    SetVirtualTimePolicyParams, SetVirtualTimePolicyResult, VirtualTimePolicy,
+};
+use viewpoint_cdp::{CdpConnection, CdpEvent};
+
+use crate::error::PageError;
+
+#[cfg(test)]
+mod tests;
+
+/// Default wall-clock time to wait for a budget to expire.
+const DEFAULT_BUDGET_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Virtual time controller for a page.
+///
+/// Obtain it with [`Page::virtual_time`](super::Page::virtual_time).
+#[derive(Debug)]
+pub struct VirtualTime<'a> {
+    connection: &'a Arc<CdpConnection>,
+    session_id: &'a str,
+}
+
+impl<'a> VirtualTime<'a> {
+    /// Create a new virtual time controller for a page.
+    pub(crate) fn new(connection: &'a Arc<CdpConnection>, session_id: &'a str) -> Self {
+        Self {
+            connection,
+            session_id,
+        }
+    }
+
+    /// Start building a virtual time policy.
+    pub fn policy(&self, policy: VirtualTimePolicy) -> VirtualTimePolicyBuilder<'a> {
+        VirtualTimePolicyBuilder {
+            connection: self.connection,
+            session_id: self.session_id,
+            params: SetVirtualTimePolicyParams::new(policy),
+            timeout: DEFAULT_BUDGET_TIMEOUT,
+        }
+    }
+
+    /// Pause virtual time.
+    ///
+    /// Timers, animations and other time-dependent work stop until virtual
+    /// time is advanced again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the CDP command fails.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn pause(&self) -> Result<(), PageError> {
+        self.policy(VirtualTimePolicy::Pause).apply().await?;
+        Ok(())
+    }
+
+    /// Let virtual time advance freely whenever the page is idle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the CDP command fails.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn resume(&self) -> Result<(), PageError> {
+        self.policy(VirtualTimePolicy::Advance).apply().await?;
+        Ok(())
+    }
+
+    /// Advance virtual time by `budget` and wait until it has elapsed.
+    ///
+    /// Virtual time is paused again once the budget has expired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the CDP command fails, the page is closed while
+    /// waiting, or the budget does not expire within 30 seconds of real time.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn advance(&self, budget: Duration) -> Result<(), PageError> {
+        self.policy(VirtualTimePolicy::Advance)
+            .budget(budget)
+            .apply()
+            .await?
+            .await
+    }
+
+    /// Advance virtual time by `budget`, pausing while network fetches are
+    /// pending, and wait until the budget has elapsed.
+    ///
+    /// This lets a page finish loading resources before its timers run, which
+    /// is useful for rendering pages that animate in after data arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the CDP command fails, the page is closed while
+    /// waiting, or the budget does not expire within 30 seconds of real time.
+    #[instrument(level = "debug", skip(self))]
+    pub async fn advance_when_network_idle(&self, budget: Duration) -> Result<(), PageError> {
+        self.policy(VirtualTimePolicy::PauseIfNetworkFetchesPending)
+            .budget(budget)
+            .apply()
+            .await?
+            .await
+    }
+}
+
+/// Builder for a virtual time policy.
+#[derive(Debug)]
+pub struct VirtualTimePolicyBuilder<'a> {
+    connection: &'a Arc<CdpConnection>,
+    session_id: &'a str,
+    params: SetVirtualTimePolicyParams,
+    timeout: Duration,
+}
+
+impl VirtualTimePolicyBuilder<'_> {
+    /// Set the amount of virtual time after which virtual time is paused.
+    ///
+    /// The [`VirtualTimeBudget`] returned by [`apply`](Self::apply) resolves
+    /// when the budget has expired.
+    #[must_use]
+    pub fn budget(mut self, budget: Duration) -> Self {
+        self.params = self.params.budget(budget.as_secs_f64() * 1000.0);
+        self
+    }
+
+    /// Set how long to wait in real time for the budget to expire (default:
+    /// 30 seconds).
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the maximum number of tasks that may run before virtual time is
+    /// forced forwards, to prevent deadlock.
+    #[must_use]
+    pub fn max_task_starvation_count(mut self, count: u32) -> Self {
+        self.params = self.params.max_virtual_time_task_starvation_count(count);
+        self
+    }
+
+    /// Set the initial virtual time.
+    #[must_use]
+    pub fn initial_virtual_time(mut self, time: std::time::SystemTime) -> Self {
+        let seconds = time
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0.0, |d| d.as_secs_f64());
+        self.params = self.params.initial_virtual_time(seconds);
+        self
+    }
+
+    /// Apply the policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the CDP command fails.
+    #[instrument(level = "debug", skip(self), fields(policy = ?self.params.policy))]
+    pub async fn apply(self) -> Result<VirtualTimeBudget, PageError> {
+        // Subscribe before applying so an immediately expiring budget is not missed.
+        let events = self
+            .params
+            .budget
+            .map(|_| self.connection.subscribe_events());
+
+        let result: SetVirtualTimePolicyResult = self
+            .connection
+            .send_command(
+                "Emulation.setVirtualTimePolicy",
+                Some(self.params),
+                Some(self.session_id),
+            )
+            .await?;
+
+        debug!(
+            ticks_base = result.virtual_time_ticks_base,
+            "Virtual time policy applied"
+        );
+
+        Ok(VirtualTimeBudget {
+            virtual_time_ticks_base: result.virtual_time_ticks_base,
+            session_id: self.session_id.to_string(),
+            events,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/// A virtual time budget granted by a policy.
+///
+/// Awaiting it waits for the `Emulation.virtualTimeBudgetExpired` event. If
+/// the policy had no budget, it resolves immediately.
+///
+/// Awaiting fails with [`PageError::Timeout`] if the budget does not expire
+/// within the builder's [`timeout`](VirtualTimePolicyBuilder::timeout), and
+/// with [`PageError::EventsMissed`] if browser events were dropped while
+/// waiting, since the expiry event may have been among them.
+#[derive(Debug)]
+pub struct VirtualTimeBudget {
+    virtual_time_ticks_base: f64,
+    session_id: String,
+    events: Option<broadcast::Receiver<CdpEvent>>,
+    timeout: Duration,
+}
+
+impl VirtualTimeBudget {
+    /// Absolute timer value of the virtual time base, in milliseconds.
+    pub fn virtual_time_ticks_base(&self) -> f64 {
+        self.virtual_time_ticks_base
+    }
+}
+
+impl IntoFuture for VirtualTimeBudget {
+    type Output = Result<(), PageError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let Some(mut events) = self.events else {
+                return Ok(());
+            };
+            let expired = async {
+                loop {
+                    match events.recv().await {
+                        Ok(event)
+                            if event.method == "Emulation.virtualTimeBudgetExpired"
+                                && event.session_id.as_deref() == Some(&self.session_id) =>
+                        {
+                            debug!("Virtual time budget expired");
+                            return Ok(());
+                        }
+                        Ok(_) => {}
+                        Err(broadcast::error::RecvError::Lagged(missed)) => {
+                            return Err(PageError::EventsMissed(missed));
+                        }
+                        Err(broadcast::error::RecvError::Closed) => return Err(PageError::Closed),
+                    }
+                }
+            };
+            tokio::time::timeout(self.timeout, expired)
+                .await
+                .map_err(|_| PageError::Timeout(self.timeout))?
+        })
+    }
+}
+
+// Page impl for virtual time methods
+impl super::Page {
+    /// Get the virtual time controller for this page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use viewpoint_core::page::Page;
+    /// use viewpoint_core::page::VirtualTimePolicy;
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// // Grant 10 virtual seconds, pausing while resources load
+    /// let budget = page
+    ///     .virtual_time()
+    ///     .policy(VirtualTimePolicy::PauseIfNetworkFetchesPending)
+    ///     .budget(Duration::from_secs(10))
+    ///     .apply()
+    ///     .await?;
+    ///
+    /// page.goto("https://example.com").goto().await?;
+    /// budget.await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn virtual_time(&self) -> VirtualTime<'_> {
+        VirtualTime::new(&self.connection, &self.session_id)
+    }
+}
@@ -0,0 +1,65 @@
+use super::*;
+
+/// A browser that answers `Emulation.setVirtualTimePolicy` but never reports
+/// the budget as expired.
+fn fake_browser() -> Arc<CdpConnection> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let (to_browser, browser_in) = tokio::io::duplex(4096);
+    let (mut browser_out, from_browser) = tokio::io::duplex(4096);
+
+    tokio::spawn(async move {
+        let mut reader = BufReader::new(browser_in);
+        loop {
+            let mut buf = Vec::new();
+            if reader.read_until(0, &mut buf).await.unwrap_or(0) == 0 {
+                break;
+            }
+            buf.pop();
+            let request: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+            let response = serde_json::json!({
+                "id": request["id"],
+                "result": { "virtualTimeTicksBase": 1000.0 }
+            });
+            browser_out
+                .write_all(response.to_string().as_bytes())
+                .await
+                .unwrap();
+            browser_out.write_all(&[0]).await.unwrap();
+        }
+    });
+
+    Arc::new(CdpConnection::connect_pipe(from_browser, to_browser))
+}
+
+#[tokio::test]
+async fn test_budget_wait_times_out() {
+    let connection = fake_browser();
+    let budget = VirtualTime::new(&connection, "session")
+        .policy(VirtualTimePolicy::Advance)
+        .budget(Duration::from_secs(5))
+        .timeout(Duration::from_millis(50))
+        .apply()
+        .await
+        .unwrap();
+
+    assert!((budget.virtual_time_ticks_base() - 1000.0).abs() < f64::EPSILON);
+    let err = budget.await.unwrap_err();
+    assert!(
+        matches!(err, PageError::Timeout(timeout) if timeout == Duration::from_millis(50)),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn test_no_budget_resolves_immediately() {
+    let connection = fake_browser();
+    let budget = VirtualTime::new(&connection, "session")
+        .policy(VirtualTimePolicy::Pause)
+        .timeout(Duration::ZERO)
+        .apply()
+        .await
+        .unwrap();
+
+    budget.await.unwrap();
+}
@@ -0,0 +1,168 @@
+//! Auto-attach handling for the dedicated workers of a page.
+//!
+//! Auto-attach is enabled on the page session with
+//! `waitForDebuggerOnStart`, so each new worker is paused until it has been
+//! set up and its init script has run.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use tracing::debug;
+use viewpoint_cdp::CdpConnection;
+use viewpoint_cdp::protocol::target_domain::{AttachedToTargetEvent, SetAutoAttachParams};
+
+use super::{Worker, WorkerEventHandler};
+use crate::page::events::PageEventManager;
+
+/// Manager for the dedicated workers of a page.
+pub(crate) struct WorkerManager {
+    /// Live workers.
+    workers: Arc<RwLock<Vec<Worker>>>,
+    /// Worker event handler.
+    handler: Arc<RwLock<Option<WorkerEventHandler>>>,
+    /// Script evaluated in new workers before they start running.
+    init_script: Arc<RwLock<Option<String>>>,
+}
+
+impl WorkerManager {
+    /// Create a worker manager and start auto-attaching to the page's workers.
+    pub(crate) fn new(
+        connection: Arc<CdpConnection>,
+        session_id: String,
+        page_events: Arc<PageEventManager>,
+    ) -> Self {
+        let manager = Self {
+            workers: Arc::new(RwLock::new(Vec::new())),
+            handler: Arc::new(RwLock::new(None)),
+            init_script: Arc::new(RwLock::new(None)),
+        };
+        manager.start_listening(connection, session_id, page_events);
+        manager
+    }
+
+    /// Listen for targets auto-attached to the page session.
+    fn start_listening(
+        &self,
+        connection: Arc<CdpConnection>,
+        session_id: String,
+        page_events: Arc<PageEventManager>,
+    ) {
+        // Subscribe before enabling auto-attach so no target is missed; new
+        // targets are paused until resumed below.
+        let mut events = connection.subscribe_events();
+        let workers = self.workers.clone();
+        let handler = self.handler.clone();
+        let init_script = self.init_script.clone();
+
+        let auto_attach_connection = connection.clone();
+        let auto_attach_session = session_id.clone();
+        tokio::spawn(async move {
+            if let Err(e) = auto_attach_connection
+                .send_command::<_, serde_json::Value>(
+                    "Target.setAutoAttach",
+                    Some(SetAutoAttachParams {
+                        auto_attach: true,
+                        wait_for_debugger_on_start: true,
+                        flatten: Some(true),
+                    }),
+                    Some(&auto_attach_session),
+                )
+                .await
+            {
+                debug!(error = %e, "Failed to enable auto-attach for workers");
+            }
+        });
+
+        tokio::spawn(async move {
+            while let Ok(event) = events.recv().await {
+                if event.method != "Target.attachedToTarget"
+                    || event.session_id.as_deref() != Some(&session_id)
+                {
+                    continue;
+                }
+                let Some(attached) = event
+                    .params
+                    .and_then(|p| serde_json::from_value::<AttachedToTargetEvent>(p).ok())
+                else {
+                    continue;
+                };
+
+                // Out-of-process iframes are resumed by the frame registry
+                if attached.target_info.target_type == "iframe" {
+                    continue;
+                }
+
+                if attached.target_info.target_type != "worker" {
+                    // Other auto-attached targets must not stay paused.
+                    if attached.waiting_for_debugger {
+                        let _ = connection
+                            .send_command::<(), serde_json::Value>(
+                                "Runtime.runIfWaitingForDebugger",
+                                None,
+                                Some(&attached.session_id),
+                            )
+                            .await;
+                    }
+                    continue;
+                }
+
+                let script = init_script.read().await.clone();
+                let worker = match Worker::attach(
+                    connection.clone(),
+                    attached.session_id,
+                    &attached.target_info,
+                    Some(page_events.clone()),
+                    script.as_deref(),
+                )
+                .await
+                {
+                    Ok(worker) => worker,
+                    Err(e) => {
+                        debug!(error = %e, "Failed to attach to worker");
+                        continue;
+                    }
+                };
+
+                {
+                    let mut workers = workers.write().await;
+                    workers.retain(|w| !w.is_closed());
+                    workers.push(worker.clone());
+                }
+
+                if let Some(ref h) = *handler.read().await {
+                    h(worker).await;
+                }
+            }
+        });
+    }
+
+    /// Get the live workers.
+    pub(crate) async fn workers(&self) -> Vec<Worker> {
+        let mut workers = self.workers.write().await;
+        workers.retain(|w| !w.is_closed());
+        workers.clone()
+    }
+
+    /// Set the worker event handler.
+    pub(crate) async fn set_handler<F, Fut>(&self, handler: F)
+    where
+        F: Fn(Worker) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut h = self.handler.write().await;
+        *h = Some(Box::new(move |worker| Box::pin(handler(worker))));
+    }
+
+    /// Remove the worker event handler.
+    pub(crate) async fn remove_handler(&self) {
+        let mut h = self.handler.write().await;
+        *h = None;
+    }
+
+    /// Set the script evaluated in new workers before they start running.
+    pub(crate) async fn set_init_script(&self, script: Option<String>) {
+        let mut s = self.init_script.write().await;
+        *s = script;
+    }
+}
@@ -0,0 +1,371 @@
+//! Web Worker, Shared Worker and Service Worker handles.
+//!
+//! Dedicated workers started by a page are reported through
+//! [`Page::workers`](super::Page::workers) and [`Page::on_worker`](super::Page::on_worker).
+//! Service workers and shared workers belong to the browser context and are
+//! reported through `BrowserContext::service_workers`,
+//! `BrowserContext::on_service_worker`, `BrowserContext::shared_workers` and
+//! `BrowserContext::on_shared_worker`.
+
+mod manager;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::de::DeserializeOwned;
+use tokio::sync::RwLock;
+use tracing::{debug, instrument, trace};
+use viewpoint_cdp::CdpConnection;
+use viewpoint_cdp::protocol::runtime::{ConsoleApiCalledEvent, EvaluateParams, EvaluateResult};
+use viewpoint_cdp::protocol::target_domain::{DetachedFromTargetEvent, TargetInfo};
+
+use crate::error::PageError;
+
+use super::Page;
+use super::console::ConsoleMessage;
+use super::evaluate::{JsHandle, wrap_expression};
+use super::events::{ConsoleHandler, PageEventManager};
+
+pub(crate) use manager::WorkerManager;
+
+/// Type alias for the worker event handler function.
+pub type WorkerEventHandler =
+    Box<dyn Fn(Worker) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
+/// Type alias for the worker close handler function.
+pub type WorkerCloseHandler =
+    Box<dyn Fn() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
+/// Shared state of a worker.
+struct WorkerState {
+    /// Whether the worker has been terminated.
+    closed: AtomicBool,
+    /// Console message handler.
+    console_handler: Arc<RwLock<Option<ConsoleHandler>>>,
+    /// Close handler.
+    close_handler: RwLock<Option<WorkerCloseHandler>>,
+}
+
+/// A dedicated Web Worker, Shared Worker or Service Worker.
+///
+/// Workers run JavaScript in their own global scope, separate from any page.
+/// A `Worker` can evaluate code in that scope and reports console messages
+/// and termination.
+///
+/// # Example
+///
+/// ```no_run
+/// use viewpoint_core::page::Page;
+///
+/// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+/// for worker in page.workers().await {
+///     let title: String = worker.evaluate("self.name").await?;
+///     println!("{}: {}", worker.url(), title);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Worker {
+    /// CDP connection.
+    connection: Arc<CdpConnection>,
+    /// Session ID of the worker target.
+    session_id: String,
+    /// Target ID of the worker.
+    target_id: String,
+    /// Worker script URL.
+    url: String,
+    /// Shared state.
+    state: Arc<WorkerState>,
+}
+
+// Manual Debug implementation since handlers don't implement Debug
+impl std::fmt::Debug for Worker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Worker")
+            .field("target_id", &self.target_id)
+            .field("session_id", &self.session_id)
+            .field("url", &self.url)
+            .field("closed", &self.is_closed())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Worker {
+    /// Set up a worker for an attached target session.
+    ///
+    /// Console messages are delivered to the worker's own handler and, if
+    /// `page_events` is given, to the owning page's console handling. The
+    /// `init_script`, if any, is evaluated before a paused worker resumes.
+    pub(crate) async fn attach(
+        connection: Arc<CdpConnection>,
+        session_id: String,
+        target_info: &TargetInfo,
+        page_events: Option<Arc<PageEventManager>>,
+        init_script: Option<&str>,
+    ) -> Result<Self, PageError> {
+        let worker = Self {
+            connection,
+            session_id,
+            target_id: target_info.target_id.clone(),
+            url: target_info.url.clone(),
+            state: Arc::new(WorkerState {
+                closed: AtomicBool::new(false),
+                console_handler: Arc::new(RwLock::new(None)),
+                close_handler: RwLock::new(None),
+            }),
+        };
+
+        // Listen before enabling Runtime so no console message is missed.
+        worker.start_event_listener(page_events);
+
+        worker
+            .connection
+            .send_command::<(), serde_json::Value>("Runtime.enable", None, Some(&worker.session_id))
+            .await?;
+        if let Some(script) = init_script {
+            worker.evaluate_raw(script, true).await?;
+        }
+        worker
+            .connection
+            .send_command::<(), serde_json::Value>(
+                "Runtime.runIfWaitingForDebugger",
+                None,
+                Some(&worker.session_id),
+            )
+            .await?;
+
+        debug!(
+            target_id = %worker.target_id,
+            url = %worker.url,
+            "Worker attached"
+        );
+        Ok(worker)
+    }
+
+    /// Listen for console messages and termination of this worker.
+    fn start_event_listener(&self, page_events: Option<Arc<PageEventManager>>) {
+        let mut events = self.connection.subscribe_events();
+        let worker = self.clone();
+
+        tokio::spawn(async move {
+            while let Ok(event) = events.recv().await {
+                match event.method.as_str() {
+                    "Runtime.consoleAPICalled"
+                        if event.session_id.as_deref() == Some(&worker.session_id) =>
+                    {
+                        let Some(console_event) = event
+                            .params
+                            .and_then(|p| serde_json::from_value::<ConsoleApiCalledEvent>(p).ok())
+                        else {
+                            continue;
+                        };
+                        let message = ConsoleMessage::from_event(
+                            console_event,
+                            worker.connection.clone(),
+                            worker.session_id.clone(),
+                        );
+                        if let Some(ref h) = *worker.state.console_handler.read().await {
+                            h(message.clone()).await;
+                        }
+                        if let Some(ref page_events) = page_events {
+                            page_events.emit_console(message).await;
+                        }
+                    }
+                    "Target.detachedFromTarget" => {
+                        let detached = event.params.and_then(|p| {
+                            serde_json::from_value::<DetachedFromTargetEvent>(p).ok()
+                        });
+                        if detached.is_some_and(|d| d.session_id == worker.session_id) {
+                            worker.mark_closed().await;
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    /// Mark the worker as terminated and notify the close handler.
+    async fn mark_closed(&self) {
+        if self.state.closed.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        debug!(target_id = %self.target_id, "Worker closed");
+        if let Some(ref h) = *self.state.close_handler.read().await {
+            h().await;
+        }
+    }
+
+    /// Get the URL of the worker script.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Get the target ID of the worker.
+    pub fn target_id(&self) -> &str {
+        &self.target_id
+    }
+
+    /// Check if the worker has been terminated.
+    pub fn is_closed(&self) -> bool {
+        self.state.closed.load(Ordering::SeqCst)
+    }
+
+    /// Evaluate JavaScript in the worker's global scope.
+    ///
+    /// Promises are automatically awaited and the result is deserialized to
+    /// the specified type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The worker has been terminated
+    /// - The JavaScript throws an error
+    /// - The result cannot be deserialized
+    #[instrument(level = "debug", skip(self), fields(expression = %expression))]
+    pub async fn evaluate<T: DeserializeOwned>(&self, expression: &str) -> Result<T, PageError> {
+        let result = self.evaluate_raw(expression, true).await?;
+
+        // Handle undefined return values - use null if no value present
+        let value = result.result.value.unwrap_or(serde_json::Value::Null);
+
+        serde_json::from_value(value)
+            .map_err(|e| PageError::EvaluationFailed(format!("Failed to deserialize: {e}")))
+    }
+
+    /// Evaluate JavaScript in the worker and return a handle to the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the worker has been terminated, the JavaScript
+    /// throws an error, or the result is not an object.
+    #[instrument(level = "debug", skip(self), fields(expression = %expression))]
+    pub async fn evaluate_handle(&self, expression: &str) -> Result<JsHandle, PageError> {
+        let result = self.evaluate_raw(expression, false).await?;
+
+        let object_id = result
+            .result
+            .object_id
+            .ok_or_else(|| PageError::EvaluationFailed("Result is not an object".to_string()))?;
+
+        Ok(JsHandle::new(
+            object_id,
+            self.session_id.clone(),
+            self.connection.clone(),
+        ))
+    }
+
+    /// Send `Runtime.evaluate` to the worker session.
+    async fn evaluate_raw(
+        &self,
+        expression: &str,
+        return_by_value: bool,
+    ) -> Result<EvaluateResult, PageError> {
+        if self.is_closed() {
+            return Err(PageError::Closed);
+        }
+
+        trace!(expression = expression, "Evaluating JavaScript in worker");
+
+        let params = EvaluateParams {
+            expression: wrap_expression(expression),
+            object_group: (!return_by_value).then(|| "viewpoint".to_string()),
+            include_command_line_api: None,
+            silent: Some(false),
+            context_id: None,
+            return_by_value: Some(return_by_value),
+            await_promise: Some(true),
+        };
+
+        let result: EvaluateResult = self
+            .connection
+            .send_command("Runtime.evaluate", Some(params), Some(&self.session_id))
+            .await?;
+
+        if let Some(exception) = result.exception_details {
+            return Err(PageError::EvaluationFailed(exception.text));
+        }
+
+        Ok(result)
+    }
+
+    /// Set a handler for console messages logged by the worker.
+    pub async fn on_console<F, Fut>(&self, handler: F)
+    where
+        F: Fn(ConsoleMessage) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut h = self.state.console_handler.write().await;
+        *h = Some(Box::new(move |message| Box::pin(handler(message))));
+    }
+
+    /// Remove the console message handler.
+    pub async fn off_console(&self) {
+        let mut h = self.state.console_handler.write().await;
+        *h = None;
+    }
+
+    /// Set a handler called when the worker is terminated.
+    pub async fn on_close<F, Fut>(&self, handler: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut h = self.state.close_handler.write().await;
+        *h = Some(Box::new(move || Box::pin(handler())));
+    }
+
+    /// Remove the close handler.
+    pub async fn off_close(&self) {
+        let mut h = self.state.close_handler.write().await;
+        *h = None;
+    }
+}
+
+// Page impl for worker methods
+impl Page {
+    /// Get the dedicated Web Workers started by this page.
+    ///
+    /// Terminated workers are not included.
+    pub async fn workers(&self) -> Vec<Worker> {
+        self.worker_manager.workers().await
+    }
+
+    /// Set a handler for dedicated Web Workers started by this page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use viewpoint_core::page::Page;
+    ///
+    /// # async fn example(page: &Page) -> Result<(), viewpoint_core::CoreError> {
+    /// page.on_worker(|worker| async move {
+    ///     println!("Worker started: {}", worker.url());
+    ///     worker.on_close(|| async { println!("Worker terminated") }).await;
+    /// }).await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_worker<F, Fut>(&self, handler: F)
+    where
+        F: Fn(Worker) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.worker_manager.set_handler(handler).await;
+    }
+
+    /// Remove the worker handler.
+    pub async fn off_worker(&self) {
+        self.worker_manager.remove_handler().await;
+    }
+
+    /// Set the script evaluated in new workers of this page before they start
+    /// running.
+    pub(crate) async fn set_worker_init_script(&self, script: Option<String>) {
+        self.worker_manager.set_init_script(script).await;
+    }
+}
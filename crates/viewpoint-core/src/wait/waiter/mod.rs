@@ -78,7 +78,8 @@ impl LoadStateWaiter {
     ///
     /// # Errors
     ///
-    /// Returns an error if the wait times out or is cancelled.
+    /// Returns an error if the wait times out or is cancelled, or if the page
+    /// crashes or its connection closes.
     pub async fn wait_for_load_state(
         &mut self,
         target_state: DocumentLoadState,
@@ -170,6 +171,9 @@ impl LoadStateWaiter {
 
             // Process the event
             match event.method.as_str() {
+                "Inspector.targetCrashed" => {
+                    return Err(WaitError::PageCrashed);
+                }
                 "Page.domContentEventFired" => {
                     let mut current = self.current_state.lock().await;
                     if *current < DocumentLoadState::DomContentLoaded {
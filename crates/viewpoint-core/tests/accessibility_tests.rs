@@ -0,0 +1,202 @@
+#![cfg(feature = "integration")]
+
+//! Accessibility tests for viewpoint-core.
+//!
+//! These tests verify the browser-computed accessibility tree and the
+//! accessibility audit rules.
+
+mod common;
+
+use viewpoint_core::AuditRule;
+
+use common::launch_with_page;
+
+/// Test capturing the browser's accessibility tree.
+#[tokio::test]
+async fn test_accessibility_snapshot() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(
+        r#"<html><body>
+            <h1>Title</h1>
+            <div><button>Save</button></div>
+            <input type="checkbox" aria-label="Agree" checked>
+        </body></html>"#,
+    )
+    .set()
+    .await
+    .expect("Failed to set content");
+
+    let tree = page
+        .accessibility()
+        .snapshot()
+        .capture()
+        .await
+        .expect("Failed to capture snapshot")
+        .expect("Page should have an accessibility tree");
+
+    assert_eq!(tree.role, "RootWebArea");
+    assert!(tree.find("heading", Some("Title")).is_some());
+    let button = tree
+        .find("button", Some("Save"))
+        .expect("Button should be in tree");
+    assert_eq!(button.property("focusable"), Some(&serde_json::json!(true)));
+    let checkbox = tree
+        .find("checkbox", Some("Agree"))
+        .expect("Checkbox should be in tree");
+    assert_eq!(
+        checkbox.property("checked"),
+        Some(&serde_json::json!("true"))
+    );
+
+    // Generic containers are pruned unless all nodes are requested
+    assert!(tree.find("generic", None).is_none());
+    let full = page
+        .accessibility()
+        .snapshot()
+        .interesting_only(false)
+        .capture()
+        .await
+        .expect("Failed to capture snapshot")
+        .expect("Page should have an accessibility tree");
+    assert!(full.descendants().count() > tree.descendants().count());
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test snapshots rooted at an element, queries and single-node lookup.
+#[tokio::test]
+async fn test_accessibility_root_query_and_node() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(
+        r#"<html><body>
+            <nav><a href="/a">First</a><a href="/b">Second</a></nav>
+            <button>Outside</button>
+        </body></html>"#,
+    )
+    .set()
+    .await
+    .expect("Failed to set content");
+
+    let nav = page.locator("nav");
+    let subtree = page
+        .accessibility()
+        .snapshot()
+        .root(&nav)
+        .capture()
+        .await
+        .expect("Failed to capture snapshot")
+        .expect("Nav should be in tree");
+    assert_eq!(subtree.role, "navigation");
+    assert_eq!(
+        subtree.descendants().filter(|n| n.role == "link").count(),
+        2
+    );
+    assert!(subtree.find("button", None).is_none());
+
+    let links = page
+        .accessibility()
+        .query()
+        .role("link")
+        .all()
+        .await
+        .expect("Failed to query tree");
+    assert_eq!(links.len(), 2);
+
+    let node = page
+        .accessibility()
+        .node(&page.locator("button"))
+        .await
+        .expect("Failed to get node")
+        .expect("Button should be exposed");
+    assert_eq!(node.role, "button");
+    assert_eq!(node.name, "Outside");
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that each audit rule reports its violations.
+#[tokio::test]
+async fn test_accessibility_audit_rules() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(
+        r#"<html><body>
+            <button id="empty"></button>
+            <div role="bogus">Unknown role</div>
+            <span aria-labelledby="missing">Dangling reference</span>
+            <p style="color: #ccc; background: #fff">Low contrast</p>
+            <a tabindex="3" href="/x">Jumps the queue</a>
+        </body></html>"#,
+    )
+    .set()
+    .await
+    .expect("Failed to set content");
+
+    let report = page
+        .accessibility()
+        .audit()
+        .run()
+        .await
+        .expect("Failed to run audit");
+
+    assert!(!report.passed());
+    for rule in AuditRule::ALL {
+        assert!(
+            report.violations_of(rule).next().is_some(),
+            "Expected a {rule} violation in:\n{report}"
+        );
+    }
+    let missing = report
+        .violations_of(AuditRule::MissingName)
+        .next()
+        .expect("Expected missing name violation");
+    assert!(missing.element.contains("id=\"empty\""));
+
+    let report = page
+        .accessibility()
+        .audit()
+        .rules([AuditRule::ColorContrast])
+        .run()
+        .await
+        .expect("Failed to run audit");
+    assert!(
+        report
+            .violations()
+            .iter()
+            .all(|v| v.rule == AuditRule::ColorContrast)
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that an accessible page passes the audit.
+#[tokio::test]
+async fn test_accessibility_audit_passes() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(
+        r#"<html lang="en"><body>
+            <main>
+                <h1>Sign in</h1>
+                <label for="email">Email</label><input id="email" type="email">
+                <img src="data:image/gif;base64,R0lGODlhAQABAAAAACw=" alt="Logo">
+                <button>Submit</button>
+            </main>
+        </body></html>"#,
+    )
+    .set()
+    .await
+    .expect("Failed to set content");
+
+    let report = page
+        .accessibility()
+        .audit()
+        .run()
+        .await
+        .expect("Failed to run audit");
+    assert!(report.passed(), "{report}");
+
+    browser.close().await.expect("Failed to close browser");
+}
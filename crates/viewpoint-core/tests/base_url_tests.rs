@@ -0,0 +1,87 @@
+#![cfg(feature = "integration")]
+
+//! Base URL tests for viewpoint-core.
+//!
+//! These tests verify that navigation, routing and request waiting resolve
+//! relative URLs against the context's base URL.
+
+mod common;
+
+use viewpoint_core::network::Route;
+
+use common::{http_ok, launch_browser, serve};
+
+/// Serve a page at every path, echoing the requested path in its title.
+async fn serve_site() -> String {
+    let origin = serve(|path, _| {
+        Some(http_ok(
+            "text/html",
+            &format!("<html><head><title>{path}</title></head></html>"),
+        ))
+    })
+    .await;
+    format!("{origin}/")
+}
+
+/// Test that relative navigation resolves against the base URL.
+#[tokio::test]
+async fn test_goto_relative_url() {
+    let browser = launch_browser().await;
+    let base_url = serve_site().await;
+
+    let context = browser
+        .new_context_builder()
+        .base_url(&base_url)
+        .build()
+        .await
+        .expect("Failed to create context");
+    assert_eq!(context.base_url(), Some(base_url.as_str()));
+
+    let page = context.new_page().await.expect("Failed to create page");
+    page.goto("/login")
+        .goto()
+        .await
+        .expect("Failed to navigate");
+
+    assert_eq!(
+        page.url().await.expect("Failed to get URL"),
+        format!("{base_url}login")
+    );
+    assert_eq!(page.title().await.expect("Failed to get title"), "/login");
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that relative route and wait patterns resolve against the base URL.
+#[tokio::test]
+async fn test_relative_patterns() {
+    let browser = launch_browser().await;
+    let base_url = serve_site().await;
+
+    let context = browser
+        .new_context_builder()
+        .base_url(&base_url)
+        .build()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    page.route("/api/**", |route: Route| async move {
+        route.fulfill().status(200).body("mocked").send().await
+    })
+    .await
+    .expect("Failed to register route");
+
+    page.goto("/").goto().await.expect("Failed to navigate");
+
+    let waiter = page.wait_for_response("/api/*".to_string());
+    let (response, text) = tokio::join!(
+        waiter.wait(),
+        page.evaluate::<String>("fetch('/api/users').then(r => r.text())")
+    );
+    let response = response.expect("Failed to wait for response");
+    assert_eq!(response.url(), format!("{base_url}api/users"));
+    assert_eq!(text.expect("Failed to fetch"), "mocked");
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -41,6 +41,31 @@ async fn test_browser_launch_and_close() {
     browser.close().await.expect("Failed to close browser");
 }
 
+/// Test launching a browser over the pipe transport.
+#[cfg(unix)]
+#[tokio::test]
+async fn test_browser_launch_over_pipe() {
+    init_tracing();
+
+    let browser = Browser::launch()
+        .headless(true)
+        .pipe(true)
+        .launch()
+        .await
+        .expect("Failed to launch browser over pipe");
+
+    assert!(browser.is_owned());
+
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+    assert!(!page.target_id().is_empty());
+
+    browser.close().await.expect("Failed to close browser");
+}
+
 /// Test creating a browser context.
 #[tokio::test]
 async fn test_browser_context_creation() {
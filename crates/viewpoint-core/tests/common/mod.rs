@@ -3,6 +3,8 @@
 use std::sync::Once;
 use std::time::Duration;
 
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
 use viewpoint_core::{AriaSnapshot, Browser};
 
 static TRACING_INIT: Once = Once::new();
@@ -48,6 +50,48 @@ pub async fn launch_with_page() -> (
     (browser, context, page)
 }
 
+/// Serve HTTP on a local port.
+///
+/// `handler` receives the request path and the server's port, and returns the
+/// raw HTTP response, or `None` to close the connection without responding.
+///
+/// Returns the server's origin, e.g. `http://127.0.0.1:12345`.
+pub async fn serve<F>(handler: F) -> String
+where
+    F: Fn(&str, u16) -> Option<String> + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind");
+    let port = listener.local_addr().expect("Failed to get address").port();
+    let handler = std::sync::Arc::new(handler);
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let handler = handler.clone();
+            tokio::spawn(async move {
+                let mut buf = [0; 4096];
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or("/");
+                if let Some(response) = handler(path, port) {
+                    let _ = stream.write_all(response.as_bytes()).await;
+                }
+            });
+        }
+    });
+
+    format!("http://127.0.0.1:{port}")
+}
+
+/// Build a `200 OK` HTTP response.
+pub fn http_ok(content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
 /// Helper to find a ref for an element by role and optional name.
 pub fn find_ref_by_role(snapshot: &AriaSnapshot, role: &str, name: Option<&str>) -> Option<String> {
     if snapshot.role.as_deref() == Some(role) {
@@ -0,0 +1,152 @@
+#![cfg(feature = "integration")]
+
+//! Context-wide clock tests for viewpoint-core.
+//!
+//! These tests verify that the context clock applies to every page of a
+//! context, survives navigation, and keeps pages on one timeline.
+
+mod common;
+
+use std::time::Duration;
+
+use viewpoint_js::js;
+
+use common::launch_with_page;
+
+const FIXED_TIME: f64 = 1_704_067_200_000.0; // 2024-01-01T00:00:00Z
+
+/// Test that the context clock applies to existing and new pages and survives navigation.
+#[tokio::test]
+async fn test_context_clock_applies_to_all_pages() {
+    let (browser, context, existing) = launch_with_page().await;
+
+    context
+        .clock()
+        .install()
+        .await
+        .expect("Failed to install clock");
+    context
+        .clock()
+        .set_fixed_time("2024-01-01T00:00:00Z")
+        .await
+        .expect("Failed to set fixed time");
+
+    let now: f64 = existing
+        .evaluate(js! { Date.now() })
+        .await
+        .expect("Failed to evaluate");
+    assert!((now - FIXED_TIME).abs() < f64::EPSILON);
+
+    let page = context.new_page().await.expect("Failed to create page");
+    let now: f64 = page
+        .evaluate(js! { Date.now() })
+        .await
+        .expect("Failed to evaluate");
+    assert!((now - FIXED_TIME).abs() < f64::EPSILON);
+
+    page.goto("data:text/html,<h1>Navigated</h1>")
+        .goto()
+        .await
+        .expect("Failed to navigate");
+    let now: f64 = page
+        .evaluate(js! { Date.now() })
+        .await
+        .expect("Failed to evaluate");
+    assert!((now - FIXED_TIME).abs() < f64::EPSILON);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that run_for fires timers in every page and advances one shared timeline.
+#[tokio::test]
+async fn test_context_clock_shared_timeline() {
+    let (browser, context, first) = launch_with_page().await;
+    let second = context.new_page().await.expect("Failed to create page");
+
+    context
+        .clock()
+        .install()
+        .await
+        .expect("Failed to install clock");
+    context
+        .clock()
+        .pause_at(FIXED_TIME as i64)
+        .await
+        .expect("Failed to pause clock");
+
+    for page in [&first, &second] {
+        let _: serde_json::Value = page
+            .evaluate(js! { (() => { window.expired = false; setTimeout(() => { window.expired = true; }, 60000); })() })
+            .await
+            .expect("Failed to schedule timer");
+    }
+    assert_eq!(
+        context
+            .clock()
+            .pending_timer_count()
+            .await
+            .expect("Failed to count timers"),
+        2
+    );
+
+    let fired = context
+        .clock()
+        .run_for(Duration::from_secs(60))
+        .await
+        .expect("Failed to run clock");
+    assert_eq!(fired, 2);
+
+    for page in [&first, &second] {
+        let expired: bool = page
+            .evaluate(js! { window.expired })
+            .await
+            .expect("Failed to evaluate");
+        assert!(expired);
+        let now: f64 = page
+            .evaluate(js! { Date.now() })
+            .await
+            .expect("Failed to evaluate");
+        assert!((now - (FIXED_TIME + 60_000.0)).abs() < f64::EPSILON);
+    }
+
+    // Pages opened later join the timeline where it is now
+    let third = context.new_page().await.expect("Failed to create page");
+    let now: f64 = third
+        .evaluate(js! { Date.now() })
+        .await
+        .expect("Failed to evaluate");
+    assert!((now - (FIXED_TIME + 60_000.0)).abs() < f64::EPSILON);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that operations require the clock to be installed.
+#[tokio::test]
+async fn test_context_clock_requires_install() {
+    let (browser, context, _page) = launch_with_page().await;
+
+    assert!(!context.clock().is_installed().await);
+    assert!(
+        context
+            .clock()
+            .run_for(Duration::from_secs(1))
+            .await
+            .is_err()
+    );
+
+    context
+        .clock()
+        .install()
+        .await
+        .expect("Failed to install clock");
+    assert!(context.clock().is_installed().await);
+
+    context
+        .clock()
+        .uninstall()
+        .await
+        .expect("Failed to uninstall clock");
+    assert!(!context.clock().is_installed().await);
+
+    browser.close().await.expect("Failed to close browser");
+}
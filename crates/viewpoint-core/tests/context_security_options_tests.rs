@@ -0,0 +1,101 @@
+#![cfg(feature = "integration")]
+
+//! Context security option tests for viewpoint-core.
+//!
+//! These tests verify the ignore_https_errors, bypass_csp and
+//! java_script_enabled context options.
+
+mod common;
+
+use viewpoint_js::js;
+
+use common::launch_browser;
+
+const CSP_PAGE: &str = "data:text/html,<meta http-equiv=\"Content-Security-Policy\" content=\"script-src 'none'\"><body></body>";
+
+/// Check whether an injected inline script ran on a CSP-protected page.
+async fn injected_script_runs(page: &viewpoint_core::Page) -> bool {
+    page.goto(CSP_PAGE)
+        .goto()
+        .await
+        .expect("Failed to navigate");
+    page.add_script_tag()
+        .content("window.injected = true;")
+        .inject()
+        .await
+        .expect("Failed to add script tag");
+    page.evaluate(js! { window.injected === true })
+        .await
+        .expect("Failed to evaluate")
+}
+
+/// Test that bypass_csp lets injected scripts run on pages with a strict CSP.
+#[tokio::test]
+async fn test_bypass_csp() {
+    let browser = launch_browser().await;
+
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+    assert!(!injected_script_runs(&page).await);
+
+    let context = browser
+        .new_context_builder()
+        .bypass_csp(true)
+        .build()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+    assert!(injected_script_runs(&page).await);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that disabling JavaScript blocks page scripts.
+#[tokio::test]
+async fn test_java_script_disabled() {
+    let browser = launch_browser().await;
+
+    let context = browser
+        .new_context_builder()
+        .java_script_enabled(false)
+        .build()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    page.goto("data:text/html,<script>window.ran = true;</script>")
+        .goto()
+        .await
+        .expect("Failed to navigate");
+    let ran: bool = page
+        .evaluate(js! { window.ran === true })
+        .await
+        .expect("Failed to evaluate");
+    assert!(!ran);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that contexts ignoring HTTPS errors still create working pages.
+#[tokio::test]
+async fn test_ignore_https_errors() {
+    let browser = launch_browser().await;
+
+    let context = browser
+        .new_context_builder()
+        .ignore_https_errors(true)
+        .build()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    page.goto("data:text/html,<h1>ok</h1>")
+        .goto()
+        .await
+        .expect("Failed to navigate");
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -165,3 +165,98 @@ async fn test_storage_state_with_multiple_pages_some_closed() {
 
     browser.close().await.expect("Failed to close browser");
 }
+
+/// Test that sessionStorage is captured on request and restored in a new context.
+#[tokio::test]
+async fn test_session_storage_capture_and_restore() {
+    common::init_tracing();
+
+    let (browser, context, page) = common::launch_with_page().await;
+
+    page.goto("https://example.com")
+        .wait_until(DocumentLoadState::DomContentLoaded)
+        .goto()
+        .await
+        .expect("Failed to navigate");
+
+    let _: serde_json::Value = page
+        .evaluate("sessionStorage.setItem('sso_token', 'abc123')")
+        .await
+        .expect("Failed to set sessionStorage");
+
+    // Not collected by default
+    let state = context
+        .storage_state()
+        .await
+        .expect("Failed to get storage state");
+    assert!(state.origins.iter().all(|o| o.session_storage.is_empty()));
+
+    let state = context
+        .storage_state_builder()
+        .session_storage(true)
+        .collect()
+        .await
+        .expect("Failed to get storage state");
+    let origin = state
+        .origins
+        .iter()
+        .find(|o| o.origin == "https://example.com")
+        .expect("Origin should be captured");
+    assert_eq!(origin.session_storage.len(), 1);
+    assert_eq!(origin.session_storage[0].name, "sso_token");
+    assert_eq!(origin.session_storage[0].value, "abc123");
+
+    // Restore into a fresh context
+    let restored = browser
+        .new_context_builder()
+        .storage_state(state)
+        .build()
+        .await
+        .expect("Failed to create context");
+    let restored_page = restored.new_page().await.expect("Failed to create page");
+    restored_page
+        .goto("https://example.com")
+        .wait_until(DocumentLoadState::DomContentLoaded)
+        .goto()
+        .await
+        .expect("Failed to navigate");
+
+    let token: Option<String> = restored_page
+        .evaluate("sessionStorage.getItem('sso_token')")
+        .await
+        .expect("Failed to read sessionStorage");
+    assert_eq!(token.as_deref(), Some("abc123"));
+
+    // Changes made by the page survive a reload
+    let _: serde_json::Value = restored_page
+        .evaluate("sessionStorage.setItem('sso_token', 'refreshed')")
+        .await
+        .expect("Failed to update sessionStorage item");
+    restored_page.reload().await.expect("Failed to reload");
+    let token: Option<String> = restored_page
+        .evaluate("sessionStorage.getItem('sso_token')")
+        .await
+        .expect("Failed to read sessionStorage");
+    assert_eq!(token.as_deref(), Some("refreshed"));
+
+    // Only the restored entries are written to the page's storage
+    let keys: Vec<String> = restored_page
+        .evaluate("Object.keys(sessionStorage)")
+        .await
+        .expect("Failed to read sessionStorage keys");
+    assert_eq!(keys, vec!["sso_token"]);
+
+    // Keys removed by the page are not restored again
+    let _: serde_json::Value = restored_page
+        .evaluate("sessionStorage.removeItem('sso_token')")
+        .await
+        .expect("Failed to remove sessionStorage item");
+    restored_page.reload().await.expect("Failed to reload");
+    let token: Option<String> = restored_page
+        .evaluate("sessionStorage.getItem('sso_token')")
+        .await
+        .expect("Failed to read sessionStorage");
+    assert_eq!(token, None);
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "integration")]
+
+//! Code coverage tests for viewpoint-core.
+//!
+//! These tests verify JavaScript and CSS coverage collection and export.
+
+mod common;
+
+use common::launch_with_page;
+use viewpoint_core::page::coverage;
+
+const PAGE: &str = "data:text/html,\
+    <style>h1 { color: red; } .missing { color: blue; }</style>\
+    <h1>Coverage</h1>\
+    <script>\
+    function used() { return 1; }\
+    function unused() { return 2; }\
+    used();\
+    </script>";
+
+/// Test collecting JavaScript coverage and exporting it.
+#[tokio::test]
+async fn test_js_coverage() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.coverage()
+        .start_js_coverage()
+        .await
+        .expect("Failed to start JS coverage");
+    page.goto(PAGE).goto().await.expect("Failed to navigate");
+
+    let entries = page
+        .coverage()
+        .stop_js_coverage()
+        .await
+        .expect("Failed to stop JS coverage");
+
+    let entry = entries
+        .iter()
+        .find(|e| e.source.as_deref().is_some_and(|s| s.contains("unused")))
+        .expect("Inline script should be reported");
+    let count = |name: &str| {
+        entry
+            .functions
+            .iter()
+            .find(|f| f.function_name == name)
+            .map(|f| f.ranges[0].count)
+    };
+    assert_eq!(count("used"), Some(1));
+    assert_eq!(count("unused"), Some(0));
+
+    let lcov = coverage::to_lcov(&entries);
+    assert!(lcov.contains("FNDA:1,used"));
+    assert!(lcov.contains("FNDA:0,unused"));
+
+    // Stopping twice is an error
+    assert!(page.coverage().stop_js_coverage().await.is_err());
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test collecting CSS coverage.
+#[tokio::test]
+async fn test_css_coverage() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.coverage()
+        .start_css_coverage()
+        .await
+        .expect("Failed to start CSS coverage");
+    page.goto(PAGE).goto().await.expect("Failed to navigate");
+
+    let entries = page
+        .coverage()
+        .stop_css_coverage()
+        .await
+        .expect("Failed to stop CSS coverage");
+
+    let entry = entries
+        .iter()
+        .find(|e| e.text.as_deref().is_some_and(|t| t.contains(".missing")))
+        .expect("Inline stylesheet should be reported");
+    let text: Vec<u16> = entry.text.as_deref().unwrap().encode_utf16().collect();
+    let used: Vec<String> = entry
+        .ranges
+        .iter()
+        .map(|r| String::from_utf16_lossy(&text[r.start..r.end]))
+        .collect();
+    assert!(used.iter().any(|rule| rule.contains("h1")));
+    assert!(!used.iter().any(|rule| rule.contains(".missing")));
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "integration")]
+
+//! Crash and disconnect detection tests for viewpoint-core.
+//!
+//! These tests verify that renderer crashes and browser disconnects are
+//! reported through handlers and fail operations fast with typed errors.
+
+mod common;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use tokio::sync::oneshot;
+use viewpoint_core::error::{BrowserError, LocatorError, NavigationError, PageError};
+use viewpoint_core::{DisconnectReason, Page};
+
+use common::{launch_browser, launch_with_page};
+
+/// Crash the page's renderer.
+async fn crash(page: &Page) {
+    // The navigation never completes, it fails once the renderer is gone
+    let result = page
+        .goto("chrome://crash")
+        .timeout(Duration::from_secs(10))
+        .goto()
+        .await;
+    assert!(result.is_err(), "navigation to chrome://crash succeeded");
+}
+
+/// Test that a renderer crash is reported and fails operations fast.
+#[tokio::test]
+async fn test_page_crash() {
+    let (browser, _context, page) = launch_with_page().await;
+    page.set_content("<button>Click</button>")
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    let crashed = Arc::new(AtomicBool::new(false));
+    let handler_crashed = crashed.clone();
+    page.on_crash(move || {
+        let crashed = handler_crashed.clone();
+        async move {
+            crashed.store(true, Ordering::SeqCst);
+        }
+    })
+    .await;
+
+    let start = Instant::now();
+    crash(&page).await;
+    assert!(start.elapsed() < Duration::from_secs(10));
+
+    for _ in 0..50 {
+        if crashed.load(Ordering::SeqCst) {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(
+        crashed.load(Ordering::SeqCst),
+        "crash handler was not called"
+    );
+    assert!(page.is_crashed());
+
+    let start = Instant::now();
+    let result = page.locator("button").click().await;
+    assert!(
+        matches!(result, Err(LocatorError::PageCrashed)),
+        "{result:?}"
+    );
+
+    let result = page.evaluate::<i32>("1 + 1").await;
+    assert!(matches!(result, Err(PageError::Crashed)), "{result:?}");
+
+    let result = page.goto("about:blank").goto().await;
+    assert!(
+        matches!(result, Err(NavigationError::PageCrashed)),
+        "{result:?}"
+    );
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that closing the browser reports a disconnect with the exit status.
+#[tokio::test]
+async fn test_browser_disconnected_on_close() {
+    let browser = launch_browser().await;
+    assert!(browser.is_connected());
+    assert!(browser.disconnect_reason().is_none());
+
+    let (tx, rx) = oneshot::channel::<DisconnectReason>();
+    let tx = Arc::new(std::sync::Mutex::new(Some(tx)));
+    browser
+        .on_disconnected(move |reason| {
+            let tx = tx.clone();
+            async move {
+                if let Some(tx) = tx.lock().unwrap().take() {
+                    let _ = tx.send(reason);
+                }
+            }
+        })
+        .await;
+
+    browser.close().await.expect("Failed to close browser");
+
+    let reason = tokio::time::timeout(Duration::from_secs(5), rx)
+        .await
+        .expect("Disconnect was not reported")
+        .expect("Handler dropped");
+    assert!(reason.exit_status().is_some(), "{reason}");
+
+    // Wait for the connection to notice the closed transport
+    for _ in 0..50 {
+        if !browser.is_connected() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(!browser.is_connected());
+
+    let result = browser.new_context().await;
+    assert!(
+        matches!(result, Err(BrowserError::Disconnected(_))),
+        "{result:?}"
+    );
+}
@@ -0,0 +1,370 @@
+#![cfg(feature = "integration")]
+
+//! Frame evaluation and locator tests for viewpoint-core.
+//!
+//! These tests verify `Frame` evaluation, frame-scoped locators and the
+//! actions and queries of frame element locators.
+
+mod common;
+
+use std::time::Duration;
+
+use viewpoint_core::{AriaRole, FilePayload, Frame, Page};
+
+use common::launch_with_page;
+
+const FORM: &str = r#"<html><head><title>Form</title></head><body>
+    <label for="name">Name</label><input id="name" placeholder="Your name">
+    <label><input type="checkbox" id="terms"> Accept terms</label>
+    <select id="size"><option value="s">Small</option><option value="l">Large</option></select>
+    <input type="file" id="upload">
+    <ul><li>One</li><li>Two</li></ul>
+    <button data-testid="submit" title="Send it" ondblclick="this.textContent = 'Twice'" onclick="this.dataset.clicked = 'yes'">Submit</button>
+    <input id="keys" onkeydown="this.dataset.key = event.key">
+    <div id="touch" ontouchstart="this.dataset.touched = 'yes'">Touch</div>
+</body></html>"#;
+
+/// Load a page embedding `FORM` in an iframe named `form`.
+async fn load_form(page: &Page) -> Frame {
+    let srcdoc = FORM.replace('"', "&quot;");
+    page.set_content(format!(
+        r#"<html><body><h1>Host</h1><iframe name="form" id="form" width="600" height="400" srcdoc="{srcdoc}"></iframe></body></html>"#
+    ))
+    .set()
+    .await
+    .expect("Failed to set content");
+
+    for _ in 0..50 {
+        if let Some(frame) = page.frame("form").await.expect("Failed to get frames") {
+            if frame.title().await.is_ok_and(|title| title == "Form") {
+                return frame;
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("Form frame never loaded");
+}
+
+/// Test evaluating JavaScript in a frame.
+#[tokio::test]
+async fn test_frame_evaluate() {
+    let (browser, _context, page) = launch_with_page().await;
+    let frame = load_form(&page).await;
+
+    let title: String = frame
+        .evaluate("document.title")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(title, "Form");
+
+    let doubled: i32 = frame
+        .evaluate_with_arg("x => x * 2", 21)
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(doubled, 42);
+
+    let handle = frame
+        .evaluate_handle("document.body")
+        .await
+        .expect("Failed to get handle");
+    handle.dispose().await.expect("Failed to dispose handle");
+
+    frame
+        .add_script_tag()
+        .content("window.frameReady = true;")
+        .inject()
+        .await
+        .expect("Failed to add script");
+    frame
+        .wait_for_function("() => window.frameReady")
+        .timeout(Duration::from_secs(5))
+        .wait()
+        .await
+        .expect("Failed to wait for function");
+
+    // The main frame is untouched
+    let host_ready: Option<bool> = page
+        .evaluate("window.frameReady")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(host_ready, None);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test frame locators and their factories.
+#[tokio::test]
+async fn test_frame_locators() {
+    let (browser, _context, page) = launch_with_page().await;
+    let frame = load_form(&page).await;
+
+    frame
+        .get_by_label("Name")
+        .fill("Ada")
+        .await
+        .expect("Failed to fill");
+    assert_eq!(
+        frame
+            .get_by_placeholder("Your name")
+            .input_value()
+            .await
+            .expect("Failed to get value"),
+        "Ada"
+    );
+
+    frame
+        .get_by_role(AriaRole::Checkbox)
+        .build()
+        .check()
+        .await
+        .expect("Failed to check");
+    assert!(
+        frame
+            .locator("#terms")
+            .is_checked()
+            .await
+            .expect("Failed to query")
+    );
+    frame
+        .locator("#terms")
+        .uncheck()
+        .await
+        .expect("Failed to uncheck");
+    assert!(
+        !frame
+            .locator("#terms")
+            .is_checked()
+            .await
+            .expect("Failed to query")
+    );
+
+    frame
+        .locator("#size")
+        .select_option("Large")
+        .await
+        .expect("Failed to select");
+    assert_eq!(
+        frame
+            .locator("#size")
+            .input_value()
+            .await
+            .expect("Failed to get value"),
+        "l"
+    );
+
+    assert_eq!(
+        frame
+            .locator("li")
+            .all_text_contents()
+            .await
+            .expect("Failed to get texts"),
+        vec!["One", "Two"]
+    );
+    assert_eq!(
+        frame
+            .locator("li")
+            .all()
+            .await
+            .expect("Failed to list")
+            .len(),
+        2
+    );
+
+    let submit = frame.get_by_test_id("submit");
+    assert_eq!(
+        submit
+            .get_attribute("title")
+            .await
+            .expect("Failed to get attribute"),
+        Some("Send it".to_string())
+    );
+    assert!(
+        frame
+            .get_by_title("Send it")
+            .is_visible()
+            .await
+            .expect("Failed to query")
+    );
+    submit.click().await.expect("Failed to click");
+    let clicked: String = submit
+        .evaluate("element.dataset.clicked")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(clicked, "yes");
+
+    // The frame's bounding box is offset by the iframe's position
+    let host_box = page
+        .locator("#form")
+        .bounding_box()
+        .await
+        .expect("Failed to get box")
+        .expect("Iframe has no box");
+    let submit_box = submit
+        .bounding_box()
+        .await
+        .expect("Failed to get box")
+        .expect("Button has no box");
+    assert!(submit_box.y > host_box.y);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test frame element locator actions through a frame locator.
+#[tokio::test]
+async fn test_frame_element_locator_actions() {
+    let (browser, _context, page) = launch_with_page().await;
+    load_form(&page).await;
+    let form = page.frame_locator("#form");
+
+    form.get_by_role(AriaRole::Button)
+        .with_name("Submit")
+        .build()
+        .dblclick()
+        .await
+        .expect("Failed to double-click");
+    assert_eq!(
+        form.locator("button")
+            .inner_text()
+            .await
+            .expect("Failed to get text"),
+        "Twice"
+    );
+
+    form.locator("#keys")
+        .press("Shift+A")
+        .await
+        .expect("Failed to press");
+    let key: String = form
+        .locator("#keys")
+        .evaluate("element.dataset.key")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(key, "A");
+
+    let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+    form.locator("#upload")
+        .set_input_files(&[manifest])
+        .await
+        .expect("Failed to set files");
+    let names: Vec<String> = form
+        .locator("#upload")
+        .evaluate("Array.from(element.files).map(f => f.name)")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(names, vec!["Cargo.toml"]);
+
+    let count: usize = form
+        .locator("li")
+        .evaluate_all("elements.length")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(count, 2);
+
+    let screenshot = form
+        .locator("button")
+        .screenshot()
+        .await
+        .expect("Failed to take screenshot");
+    assert!(screenshot.starts_with(&[0x89, b'P', b'N', b'G']));
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test frame element locator filters, option builders and buffers.
+#[tokio::test]
+async fn test_frame_element_locator_parity() {
+    let (browser, _context, page) = launch_with_page().await;
+    load_form(&page).await;
+    let form = page.frame_locator("#form");
+
+    let two = form.locator("li").filter().has_text("Two");
+    assert_eq!(two.count().await.expect("Failed to count"), 1);
+    assert_eq!(
+        form.locator("li")
+            .filter()
+            .has_not_text("Two")
+            .inner_text()
+            .await
+            .expect("Failed to get text"),
+        "One"
+    );
+    assert_eq!(
+        form.locator("ul")
+            .filter()
+            .has(form.locator("li"))
+            .count()
+            .await
+            .expect("Failed to count"),
+        1
+    );
+    assert_eq!(
+        form.locator("li")
+            .or(form.locator("button"))
+            .count()
+            .await
+            .expect("Failed to count"),
+        3
+    );
+    assert_eq!(
+        form.locator("li")
+            .and(form.get_by_text("One"))
+            .count()
+            .await
+            .expect("Failed to count"),
+        1
+    );
+
+    assert!(two.is_attached().await.expect("Failed to query"));
+    assert!(
+        !form
+            .locator("#missing")
+            .is_attached()
+            .await
+            .expect("Failed to query")
+    );
+
+    let submit = form.get_by_test_id("submit");
+    submit
+        .click()
+        .position(2.0, 2.0)
+        .await
+        .expect("Failed to click");
+    let clicked: String = submit
+        .evaluate("element.dataset.clicked")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(clicked, "yes");
+
+    page.touchscreen()
+        .enable()
+        .await
+        .expect("Failed to enable touch");
+    form.locator("#touch").tap().await.expect("Failed to tap");
+    let touched: String = form
+        .locator("#touch")
+        .evaluate("element.dataset.touched")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(touched, "yes");
+
+    form.locator("#upload")
+        .set_input_files_from_buffer(&[FilePayload::from_text("notes.txt", "Hello")])
+        .await
+        .expect("Failed to set files");
+    let names: Vec<String> = form
+        .locator("#upload")
+        .evaluate("Array.from(element.files).map(f => f.name)")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(names, vec!["notes.txt"]);
+
+    let snapshot = form
+        .locator("ul")
+        .aria_snapshot()
+        .await
+        .expect("Failed to capture snapshot");
+    let yaml = snapshot.to_yaml();
+    assert!(yaml.contains("One"), "missing list item: {yaml}");
+
+    browser.close().await.expect("Failed to close browser");
+}
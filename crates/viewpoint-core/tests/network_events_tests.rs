@@ -0,0 +1,244 @@
+#![cfg(feature = "integration")]
+
+//! Network event tests for viewpoint-core.
+//!
+//! These tests verify request, response, finished and failed handlers and
+//! streams on pages and contexts, including redirect chains.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::StreamExt;
+use viewpoint_core::{Request, Response};
+
+use common::{http_ok, launch_browser, launch_with_page, serve};
+
+/// Serve a small site:
+/// - `/redirect` redirects to `/ok`
+/// - `/error` responds with a 500
+/// - `/drop` closes the connection without responding
+/// - every other path responds with a 200 page
+async fn serve_site() -> String {
+    serve(|path, _| match path {
+        "/drop" => None,
+        "/redirect" => Some(
+            "HTTP/1.1 302 Found\r\nLocation: /ok\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                .to_string(),
+        ),
+        "/error" => Some(
+            "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                .to_string(),
+        ),
+        _ => Some(http_ok("text/html", "<html><body>ok</body></html>")),
+    })
+    .await
+}
+
+/// Test detecting server errors with a response handler.
+#[tokio::test]
+async fn test_on_response_collects_server_errors() {
+    let (browser, _context, page) = launch_with_page().await;
+    let site = serve_site().await;
+
+    let server_errors = Arc::new(Mutex::new(Vec::new()));
+    let errors = server_errors.clone();
+    page.on_response(move |response: Response| {
+        let errors = errors.clone();
+        async move {
+            if response.status() >= 500 {
+                errors.lock().unwrap().push(response.url().to_string());
+            }
+        }
+    })
+    .await;
+
+    page.goto(format!("{site}/ok"))
+        .goto()
+        .await
+        .expect("Failed to navigate");
+    let _: i32 = page
+        .evaluate("fetch('/error').then(r => r.status)")
+        .await
+        .expect("Failed to fetch");
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    assert_eq!(
+        server_errors.lock().unwrap().clone(),
+        vec![format!("{site}/error")]
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that removed handlers stop receiving events.
+#[tokio::test]
+async fn test_off_request() {
+    let (browser, _context, page) = launch_with_page().await;
+    let site = serve_site().await;
+
+    let count = Arc::new(Mutex::new(0));
+    let counter = count.clone();
+    let handler_id = page
+        .on_request(move |_request: Request| {
+            let counter = counter.clone();
+            async move {
+                *counter.lock().unwrap() += 1;
+            }
+        })
+        .await;
+
+    page.goto(format!("{site}/ok"))
+        .goto()
+        .await
+        .expect("Failed to navigate");
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let seen = *count.lock().unwrap();
+    assert!(seen >= 1);
+
+    assert!(page.off_request(handler_id).await);
+    assert!(!page.off_request(handler_id).await);
+
+    page.goto(format!("{site}/again"))
+        .goto()
+        .await
+        .expect("Failed to navigate");
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(*count.lock().unwrap(), seen);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that redirect chains are reported hop by hop.
+#[tokio::test]
+async fn test_request_stream_follows_redirects() {
+    let (browser, _context, page) = launch_with_page().await;
+    let site = serve_site().await;
+
+    let mut requests = Box::pin(page.request_stream());
+    let mut responses = Box::pin(page.response_stream());
+
+    page.goto(format!("{site}/redirect"))
+        .goto()
+        .await
+        .expect("Failed to navigate");
+
+    let first = requests.next().await.expect("No request");
+    assert_eq!(first.url(), format!("{site}/redirect"));
+    let second = requests.next().await.expect("No request");
+    assert_eq!(second.url(), format!("{site}/ok"));
+    assert_eq!(
+        second.redirected_from().map(Request::url),
+        Some(format!("{site}/redirect").as_str())
+    );
+
+    let redirect = responses.next().await.expect("No response");
+    assert_eq!(redirect.status(), 302);
+    let ok = responses.next().await.expect("No response");
+    assert_eq!(ok.status(), 200);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test failed request handlers and streams.
+#[tokio::test]
+async fn test_request_failed() {
+    let (browser, _context, page) = launch_with_page().await;
+    let site = serve_site().await;
+
+    page.goto(format!("{site}/ok"))
+        .goto()
+        .await
+        .expect("Failed to navigate");
+
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let seen = failures.clone();
+    page.on_request_failed(move |request: Request| {
+        let seen = seen.clone();
+        async move {
+            seen.lock()
+                .unwrap()
+                .push(request.failure().map(str::to_string));
+        }
+    })
+    .await;
+    let mut failed = Box::pin(page.request_failed_stream());
+
+    let _: bool = page
+        .evaluate("fetch('/drop').then(() => true, () => false)")
+        .await
+        .expect("Failed to fetch");
+
+    let request = tokio::time::timeout(Duration::from_secs(5), failed.next())
+        .await
+        .expect("Timed out waiting for failure")
+        .expect("No failed request");
+    assert_eq!(request.url(), format!("{site}/drop"));
+    assert!(request.failure().is_some());
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    {
+        let failures = failures.lock().unwrap();
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].is_some());
+    }
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test context-level handlers observing pages created later.
+#[tokio::test]
+async fn test_context_network_events() {
+    let browser = launch_browser().await;
+    let site = serve_site().await;
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+
+    let finished = Arc::new(Mutex::new(Vec::new()));
+    let seen = finished.clone();
+    context
+        .on_request_finished(move |request: Request| {
+            let seen = seen.clone();
+            async move {
+                seen.lock().unwrap().push(request.url().to_string());
+            }
+        })
+        .await;
+    let mut responses = Box::pin(context.response_stream());
+
+    let first = context.new_page().await.expect("Failed to create page");
+    let second = context.new_page().await.expect("Failed to create page");
+    first
+        .goto(format!("{site}/one"))
+        .goto()
+        .await
+        .expect("Failed to navigate");
+    second
+        .goto(format!("{site}/two"))
+        .goto()
+        .await
+        .expect("Failed to navigate");
+
+    let mut urls = Vec::new();
+    while urls.len() < 2 {
+        let response = tokio::time::timeout(Duration::from_secs(5), responses.next())
+            .await
+            .expect("Timed out waiting for response")
+            .expect("No response");
+        urls.push(response.url().to_string());
+    }
+    assert!(urls.contains(&format!("{site}/one")));
+    assert!(urls.contains(&format!("{site}/two")));
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    {
+        let finished = finished.lock().unwrap();
+        assert!(finished.contains(&format!("{site}/one")));
+        assert!(finished.contains(&format!("{site}/two")));
+    }
+
+    browser.close().await.expect("Failed to close browser");
+}
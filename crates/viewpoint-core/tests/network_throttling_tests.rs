@@ -0,0 +1,123 @@
+#![cfg(feature = "integration")]
+
+//! Network and CPU throttling tests for viewpoint-core.
+//!
+//! These tests verify context-wide network conditions, per-page overrides,
+//! and CPU throttling.
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use viewpoint_core::NetworkConditions;
+use viewpoint_core::error::PageError;
+use viewpoint_js::js;
+
+use common::{http_ok, launch_with_page, serve};
+
+const LATENCY: Duration = Duration::from_millis(500);
+
+/// Serve a minimal page over HTTP.
+async fn serve_page() -> String {
+    let origin = serve(|_, _| Some(http_ok("text/html", "<html><body>ok</body></html>"))).await;
+    format!("{origin}/")
+}
+
+/// Measure how long navigating a page to `url` takes.
+async fn navigation_duration(page: &viewpoint_core::Page, url: &str) -> Duration {
+    let start = Instant::now();
+    page.goto(url).goto().await.expect("Failed to navigate");
+    start.elapsed()
+}
+
+/// Test that context network conditions apply to new pages and can be overridden per page.
+#[tokio::test]
+async fn test_context_network_conditions() {
+    let (browser, context, _page) = launch_with_page().await;
+    let url = serve_page().await;
+
+    context
+        .emulate_network(NetworkConditions {
+            latency: LATENCY,
+            ..NetworkConditions::NO_THROTTLING
+        })
+        .await
+        .expect("Failed to emulate network");
+
+    // Pages opened after the call inherit the conditions
+    let page = context.new_page().await.expect("Failed to create page");
+    assert!(navigation_duration(&page, &url).await >= LATENCY);
+
+    // A page override replaces the context conditions
+    page.emulate_network(NetworkConditions::NO_THROTTLING)
+        .await
+        .expect("Failed to override network");
+    assert!(navigation_duration(&page, &url).await < LATENCY);
+
+    // Clearing the override restores them
+    page.clear_network_emulation()
+        .await
+        .expect("Failed to clear override");
+    assert!(navigation_duration(&page, &url).await >= LATENCY);
+
+    context
+        .clear_network_emulation()
+        .await
+        .expect("Failed to clear network emulation");
+    assert!(navigation_duration(&page, &url).await < LATENCY);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that offline mode applies to pages opened after it was enabled.
+#[tokio::test]
+async fn test_offline_applies_to_new_pages() {
+    let (browser, context, _page) = launch_with_page().await;
+
+    context
+        .set_offline(true)
+        .await
+        .expect("Failed to go offline");
+    context
+        .emulate_network(NetworkConditions::FAST_3G)
+        .await
+        .expect("Failed to emulate network");
+
+    let page = context.new_page().await.expect("Failed to create page");
+    let online: bool = page
+        .evaluate(js! { navigator.onLine })
+        .await
+        .expect("Failed to evaluate");
+    assert!(!online);
+
+    context
+        .set_offline(false)
+        .await
+        .expect("Failed to go online");
+    let online: bool = page
+        .evaluate(js! { navigator.onLine })
+        .await
+        .expect("Failed to evaluate");
+    assert!(online);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test enabling and disabling CPU throttling.
+#[tokio::test]
+async fn test_cpu_throttling() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.emulate_cpu_throttling(4.0)
+        .await
+        .expect("Failed to throttle CPU");
+    page.emulate_cpu_throttling(1.0)
+        .await
+        .expect("Failed to disable CPU throttling");
+    assert!(matches!(
+        page.emulate_cpu_throttling(0.5).await,
+        Err(PageError::InvalidArgument(_))
+    ));
+
+    browser.close().await.expect("Failed to close browser");
+}
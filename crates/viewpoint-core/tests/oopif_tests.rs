@@ -0,0 +1,169 @@
+#![cfg(feature = "integration")]
+
+//! Out-of-process iframe tests for viewpoint-core.
+//!
+//! These tests embed a cross-site iframe (`localhost` inside `127.0.0.1`),
+//! which site isolation renders in its own process, and verify frames, frame
+//! locators, snapshots and screenshots reach into it.
+
+mod common;
+
+use std::time::Duration;
+
+use viewpoint_core::{AriaRole, Frame, Page};
+
+use common::{http_ok, launch_with_page, serve};
+
+const PAYMENT: &str = r#"<html><head><title>Payment</title></head><body>
+    <label for="card">Card number</label><input id="card">
+    <button onclick="this.textContent = 'Paid ' + document.getElementById('card').value">Pay</button>
+</body></html>"#;
+
+/// Serve `/` embedding `/payment` from the other site, and `/payment`.
+///
+/// Returns the URL of the embedding page.
+async fn serve_sites() -> String {
+    let origin = serve(|path, port| {
+        Some(if path == "/payment" {
+            http_ok("text/html", PAYMENT)
+        } else {
+            http_ok(
+                "text/html",
+                &format!(
+                    r#"<html><body><h1>Checkout</h1><iframe id="pay" width="400" height="200" src="http://localhost:{port}/payment"></iframe></body></html>"#
+                ),
+            )
+        })
+    })
+    .await;
+    format!("{origin}/")
+}
+
+/// Wait for the payment frame to show up in the page's frames.
+async fn payment_frame(page: &Page) -> Frame {
+    for _ in 0..50 {
+        let frames = page.frames().await.expect("Failed to get frames");
+        if let Some(frame) = frames.into_iter().find(|f| f.url().ends_with("/payment")) {
+            return frame;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("Payment frame never attached");
+}
+
+/// Test that out-of-process frames are listed and usable.
+#[tokio::test]
+async fn test_oopif_frames() {
+    let (browser, _context, page) = launch_with_page().await;
+    let url = serve_sites().await;
+
+    page.goto(&url).goto().await.expect("Failed to navigate");
+
+    let frame = payment_frame(&page).await;
+    assert!(!frame.is_main());
+    assert_eq!(frame.title().await.expect("Failed to get title"), "Payment");
+    let content = frame.content().await.expect("Failed to get content");
+    assert!(content.contains("Card number"));
+
+    let main = page.main_frame().await.expect("Failed to get main frame");
+    assert_eq!(frame.parent_id(), Some(main.id()));
+    let children = main.child_frames().await.expect("Failed to get children");
+    assert!(children.iter().any(|child| child.id() == frame.id()));
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test frame locator input into an out-of-process frame.
+#[tokio::test]
+async fn test_oopif_frame_locator() {
+    let (browser, _context, page) = launch_with_page().await;
+    let url = serve_sites().await;
+
+    page.goto(&url).goto().await.expect("Failed to navigate");
+
+    let pay = page.frame_locator("#pay");
+    pay.locator("#card")
+        .fill("4242")
+        .await
+        .expect("Failed to fill");
+    pay.get_by_role(AriaRole::Button)
+        .with_name("Pay")
+        .build()
+        .click()
+        .await
+        .expect("Failed to click");
+
+    let text = pay
+        .locator("button")
+        .text_content()
+        .await
+        .expect("Failed to get text");
+    assert_eq!(text.as_deref(), Some("Paid 4242"));
+
+    let screenshot = pay
+        .locator("button")
+        .screenshot()
+        .await
+        .expect("Failed to take screenshot");
+    assert!(screenshot.starts_with(&[0x89, b'P', b'N', b'G']));
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test snapshots stitching out-of-process frame content.
+#[tokio::test]
+async fn test_oopif_aria_snapshot() {
+    let (browser, _context, page) = launch_with_page().await;
+    let url = serve_sites().await;
+
+    page.goto(&url).goto().await.expect("Failed to navigate");
+    payment_frame(&page).await;
+
+    let snapshot = page
+        .aria_snapshot_with_frames()
+        .await
+        .expect("Failed to capture snapshot");
+    let yaml = snapshot.to_yaml();
+    assert!(yaml.contains("Checkout"));
+    assert!(yaml.contains("Pay"), "missing frame content: {yaml}");
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test frame evaluation and frame-scoped locators in an out-of-process frame.
+#[tokio::test]
+async fn test_oopif_frame_locators() {
+    let (browser, _context, page) = launch_with_page().await;
+    let url = serve_sites().await;
+
+    page.goto(&url).goto().await.expect("Failed to navigate");
+
+    let frame = payment_frame(&page).await;
+    let title: String = frame
+        .evaluate("document.title")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(title, "Payment");
+
+    frame
+        .get_by_label("Card number")
+        .fill("4242")
+        .await
+        .expect("Failed to fill");
+    frame
+        .get_by_role(AriaRole::Button)
+        .with_name("Pay")
+        .build()
+        .click()
+        .await
+        .expect("Failed to click");
+
+    let text = frame
+        .locator("button")
+        .inner_text()
+        .await
+        .expect("Failed to get text");
+    assert_eq!(text, "Paid 4242");
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "integration")]
+
+//! Performance tests for viewpoint-core.
+//!
+//! These tests verify performance metrics, Web Vitals collection and
+//! browser-wide Chrome trace capture.
+
+mod common;
+
+use viewpoint_js::js;
+
+use common::launch_with_page;
+
+/// Test reading run-time performance metrics.
+#[tokio::test]
+async fn test_page_metrics() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content("<html><body><div>One</div><div>Two</div></body></html>")
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    let metrics = page.metrics().await.expect("Failed to get metrics");
+    assert!(metrics.nodes().expect("Nodes metric missing") > 0);
+    assert!(metrics.js_heap_used_size().expect("Heap metric missing") > 0);
+    assert!(metrics.get("Timestamp").is_some());
+
+    // Forcing layouts increases the layout count
+    let before = metrics.layout_count().expect("LayoutCount metric missing");
+    let _: serde_json::Value = page
+        .evaluate(js! {
+            (() => {
+                for (let i = 0; i < 5; i++) {
+                    document.body.style.width = (100 + i) + "px";
+                    document.body.offsetWidth;
+                }
+            })()
+        })
+        .await
+        .expect("Failed to force layout");
+    let after = page
+        .metrics()
+        .await
+        .expect("Failed to get metrics")
+        .layout_count()
+        .expect("LayoutCount metric missing");
+    assert!(after > before);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test collecting Web Vitals including layout shifts and interactions.
+#[tokio::test]
+async fn test_web_vitals() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.web_vitals()
+        .start()
+        .await
+        .expect("Failed to start Web Vitals");
+    page.set_content(
+        r#"<html><body>
+            <h1 style="font-size: 48px">Largest content</h1>
+            <div id="spacer"></div>
+            <button id="shift" onclick="slowShift()">Shift</button>
+            <script>
+                // Busy-wait so the interaction exceeds the event timing threshold
+                function slowShift() {
+                    const end = performance.now() + 50;
+                    while (performance.now() < end) {}
+                    document.getElementById('spacer').style.height = '200px';
+                }
+            </script>
+        </body></html>"#,
+    )
+    .set()
+    .await
+    .expect("Failed to set content");
+
+    let vitals = page
+        .web_vitals()
+        .collect()
+        .await
+        .expect("Failed to collect Web Vitals");
+    assert!(vitals.lcp.is_some(), "LCP not reported: {vitals:?}");
+    assert_eq!(vitals.inp, None);
+
+    page.locator("#shift")
+        .click()
+        .await
+        .expect("Failed to click");
+    let vitals = page
+        .web_vitals()
+        .collect()
+        .await
+        .expect("Failed to collect Web Vitals");
+    assert!(vitals.cls.is_some());
+    assert!(vitals.inp.is_some(), "INP not reported: {vitals:?}");
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test recording a browser-wide Chrome trace.
+#[tokio::test]
+async fn test_browser_chrome_trace() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    browser
+        .start_tracing(Vec::<String>::new())
+        .await
+        .expect("Failed to start tracing");
+    assert!(browser.start_tracing(["toplevel"]).await.is_err());
+
+    page.set_content("<html><body><h1>Traced</h1></body></html>")
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    let trace = browser
+        .stop_tracing()
+        .await
+        .expect("Failed to stop tracing");
+    assert!(!trace.events().is_empty());
+    assert!(browser.stop_tracing().await.is_err());
+
+    let dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let path = dir.path().join("trace.json");
+    trace.save(&path).await.expect("Failed to save trace");
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).expect("Failed to read trace"))
+            .expect("Trace is not valid JSON");
+    assert!(
+        saved["traceEvents"]
+            .as_array()
+            .is_some_and(|e| !e.is_empty())
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "integration")]
+
+//! Custom selector engine tests for viewpoint-core.
+//!
+//! These tests verify registering selector engines on the browser and on
+//! a context.
+
+mod common;
+
+use viewpoint_core::error::{BrowserError, ContextError};
+
+const CONTENT: &str = r#"<html><body>
+    <section data-qa="cart"><button>Remove</button></section>
+    <section data-qa="checkout"><button>Pay</button><button>Cancel</button></section>
+</body></html>"#;
+
+const DATA_QA_ENGINE: &str = r#"({
+    queryAll(root, body) {
+        return root.querySelectorAll(`[data-qa="${body}"]`);
+    }
+})"#;
+
+/// Engine matching the direct children of `root` by tag name, which only
+/// finds anything when `root` is the parent element.
+const CHILD_ENGINE: &str = r"({
+    queryAll(root, body) {
+        return Array.from(root.children).filter(el => el.localName === body);
+    }
+})";
+
+/// Test an engine registered on the browser.
+#[tokio::test]
+async fn test_browser_selector_engine() {
+    let browser = common::launch_browser().await;
+
+    browser
+        .selectors()
+        .register("data-qa", DATA_QA_ENGINE)
+        .expect("Failed to register engine");
+    assert!(matches!(
+        browser.selectors().register("data-qa", DATA_QA_ENGINE),
+        Err(BrowserError::InvalidSelectorEngine(_))
+    ));
+    browser
+        .selectors()
+        .register("child", CHILD_ENGINE)
+        .expect("Failed to register engine");
+
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+    page.set_content(CONTENT)
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    let buttons = page.locator("data-qa=checkout >> button");
+    assert_eq!(buttons.count().await.expect("Failed to count"), 2);
+    let text = buttons
+        .first()
+        .text_content()
+        .await
+        .expect("Failed to get text");
+    assert_eq!(text.as_deref(), Some("Pay"));
+
+    let buttons = page.locator("css=section[data-qa=checkout] >> child=button");
+    assert_eq!(buttons.count().await.expect("Failed to count"), 2);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test an engine registered on a context with an open page.
+#[tokio::test]
+async fn test_context_selector_engine() {
+    let (browser, context, page) = common::launch_with_page().await;
+
+    page.set_content(CONTENT)
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    // Unknown engines fail instead of matching nothing
+    assert!(page.locator("qa=cart").count().await.is_err());
+
+    // A `query`-only engine returns at most one element
+    context
+        .register_selector_engine(
+            "qa",
+            "({ query: (root, body) => root.querySelector(`[data-qa=\"${body}\"]`) })",
+        )
+        .await
+        .expect("Failed to register engine");
+    assert_eq!(
+        page.locator("qa=cart >> button")
+            .count()
+            .await
+            .expect("Failed to count"),
+        1
+    );
+
+    assert!(matches!(
+        context
+            .register_selector_engine("css", DATA_QA_ENGINE)
+            .await,
+        Err(ContextError::InvalidSelectorEngine(_))
+    ));
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "integration")]
+
+//! Selector engine tests for viewpoint-core.
+//!
+//! These tests verify XPath selectors, `>>` chains, and locators piercing
+//! open shadow roots.
+
+mod common;
+
+use viewpoint_core::AriaRole;
+
+use common::launch_with_page;
+
+const CONTENT: &str = r#"<html><body>
+    <ul id="list"><li>One</li><li>Two</li><li>Three</li></ul>
+    <user-card id="alice"></user-card>
+    <user-card id="bob"></user-card>
+    <script>
+        customElements.define('user-card', class extends HTMLElement {
+            connectedCallback() {
+                const root = this.attachShadow({ mode: 'open' });
+                root.innerHTML = `
+                    <label for="note">Note</label><input id="note">
+                    <span class="name" title="${this.id}">${this.id}</span>
+                    <button data-testid="follow">Follow ${this.id}</button>`;
+            }
+        });
+    </script>
+</body></html>"#;
+
+/// Test XPath selectors.
+#[tokio::test]
+async fn test_xpath_selector() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(CONTENT)
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    let items = page.locator("xpath=//ul[@id='list']/li");
+    assert_eq!(items.count().await.expect("Failed to count"), 3);
+
+    let second = page.locator("(//li)[2]");
+    let text = second.text_content().await.expect("Failed to get text");
+    assert_eq!(text.as_deref(), Some("Two"));
+
+    // Relative XPath is evaluated from each parent of a chain
+    let relative = page.locator("#list >> xpath=./li");
+    assert_eq!(relative.count().await.expect("Failed to count"), 3);
+    let second = page.locator("css=ul >> xpath=./li[2]");
+    let text = second.text_content().await.expect("Failed to get text");
+    assert_eq!(text.as_deref(), Some("Two"));
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test CSS selectors and `>>` chains crossing shadow roots.
+#[tokio::test]
+async fn test_css_pierces_shadow_dom() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(CONTENT)
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    assert_eq!(
+        page.locator("span.name")
+            .count()
+            .await
+            .expect("Failed to count"),
+        2
+    );
+
+    let bob = page.locator("#bob >> span.name");
+    let text = bob.text_content().await.expect("Failed to get text");
+    assert_eq!(text.as_deref(), Some("bob"));
+
+    let follow = page.locator("user-card").nth(1).locator("button");
+    let text = follow.text_content().await.expect("Failed to get text");
+    assert_eq!(text.as_deref(), Some("Follow bob"));
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test semantic locators inside shadow roots.
+#[tokio::test]
+async fn test_semantic_locators_pierce_shadow_dom() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(CONTENT)
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    let follow = page
+        .get_by_role(AriaRole::Button)
+        .with_name("Follow alice")
+        .build();
+    assert_eq!(follow.count().await.expect("Failed to count"), 1);
+
+    assert_eq!(
+        page.get_by_text("Follow")
+            .count()
+            .await
+            .expect("Failed to count"),
+        2
+    );
+    assert_eq!(
+        page.get_by_test_id("follow")
+            .count()
+            .await
+            .expect("Failed to count"),
+        2
+    );
+    assert_eq!(
+        page.get_by_title("alice")
+            .count()
+            .await
+            .expect("Failed to count"),
+        1
+    );
+
+    // Labels resolve `for` within their own shadow root
+    let notes = page.get_by_label("Note");
+    assert_eq!(notes.count().await.expect("Failed to count"), 2);
+    notes.first().fill("hello").await.expect("Failed to fill");
+    let value = page
+        .locator("#alice >> input")
+        .input_value()
+        .await
+        .expect("Failed to get value");
+    assert_eq!(value, "hello");
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "integration")]
+
+//! Text matcher tests for viewpoint-core.
+//!
+//! These tests verify regex and case-insensitive matching in locators.
+
+mod common;
+
+use viewpoint_core::{AriaRole, TextMatcher};
+
+use common::launch_with_page;
+
+const CONTENT: &str = r#"<html><body>
+    <p>Your Order #1234 has shipped</p>
+    <p>Order #abc is pending</p>
+    <button>SAVE changes</button>
+    <button>Save draft</button>
+    <ul><li>Apple</li><li>apple pie</li><li>Banana</li></ul>
+    <img alt="Logo 2024" src="data:,">
+    <span title="Created 5 minutes ago">created</span>
+</body></html>"#;
+
+/// Test regex matching in get_by_text.
+#[tokio::test]
+async fn test_get_by_text_regex() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(CONTENT)
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    let order = page.get_by_text(TextMatcher::regex(r"Order #\d+"));
+    assert_eq!(order.count().await.expect("Failed to count"), 1);
+    let text = order.text_content().await.expect("Failed to get text");
+    assert_eq!(text.as_deref(), Some("Your Order #1234 has shipped"));
+
+    let anchored = page.get_by_text(TextMatcher::regex(r"^Order #\d+"));
+    assert_eq!(anchored.count().await.expect("Failed to count"), 0);
+
+    let regex = regex::Regex::new(r"#\w+ is pending$").unwrap();
+    assert_eq!(
+        page.get_by_text(&regex)
+            .count()
+            .await
+            .expect("Failed to count"),
+        1
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test case-insensitive matching in get_by_text and filters.
+#[tokio::test]
+async fn test_ignore_case_matching() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(CONTENT)
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    let save = page.get_by_text(TextMatcher::ignore_case("save"));
+    assert_eq!(save.count().await.expect("Failed to count"), 2);
+
+    let apples = page
+        .locator("li")
+        .filter()
+        .has_text(TextMatcher::ignore_case("APPLE"));
+    assert_eq!(apples.count().await.expect("Failed to count"), 2);
+
+    let not_apples = page
+        .locator("li")
+        .filter()
+        .has_not_text(TextMatcher::regex_ignore_case("^apple"));
+    assert_eq!(not_apples.count().await.expect("Failed to count"), 1);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test regex matching of accessible names.
+#[tokio::test]
+async fn test_get_by_role_name_regex() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(CONTENT)
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    // Plain strings still match the whole name
+    let exact = page
+        .get_by_role(AriaRole::Button)
+        .with_name("Save draft")
+        .build();
+    assert_eq!(exact.count().await.expect("Failed to count"), 1);
+
+    let buttons = page
+        .get_by_role(AriaRole::Button)
+        .with_name(TextMatcher::regex_ignore_case("^save"))
+        .build();
+    assert_eq!(buttons.count().await.expect("Failed to count"), 2);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test regex matching of alt text and titles.
+#[tokio::test]
+async fn test_alt_text_and_title_regex() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content(CONTENT)
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    let logo = page.get_by_alt_text(TextMatcher::regex(r"^Logo \d{4}$"));
+    assert_eq!(logo.count().await.expect("Failed to count"), 1);
+
+    let created = page.get_by_title(TextMatcher::regex(r"\d+ minutes ago"));
+    assert_eq!(created.count().await.expect("Failed to count"), 1);
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -8,7 +8,7 @@ mod common;
 
 use std::path::PathBuf;
 use tempfile::TempDir;
-use viewpoint_core::context::TracingOptions;
+use viewpoint_core::context::{TraceArchive, TraceFormat, TracingOptions};
 
 /// Helper to get a temp directory for trace files.
 fn temp_trace_dir() -> TempDir {
@@ -351,3 +351,242 @@ async fn test_tracing_creates_valid_zip() {
 
     browser.close().await.expect("Failed to close browser");
 }
+
+/// Read `trace.json` from a saved trace archive.
+fn read_trace_json(path: &std::path::Path) -> serde_json::Value {
+    use std::io::Read;
+
+    let file = std::fs::File::open(path).expect("Failed to open trace file");
+    let mut archive = zip::ZipArchive::new(file).expect("Trace should be a valid zip file");
+    let mut entry = archive
+        .by_name("trace.json")
+        .expect("Trace should contain trace.json");
+    let mut contents = String::new();
+    entry
+        .read_to_string(&mut contents)
+        .expect("Failed to read trace.json");
+    serde_json::from_str(&contents).expect("trace.json should be valid JSON")
+}
+
+/// Test that locator and page actions are recorded automatically.
+#[tokio::test]
+async fn test_tracing_records_actions() {
+    common::init_tracing();
+
+    let (browser, context, page) = common::launch_with_page().await;
+    let temp_dir = temp_trace_dir();
+    let trace_path = temp_trace_path(&temp_dir, "trace-actions.zip");
+
+    context
+        .tracing()
+        .start(TracingOptions::new().screenshots(true))
+        .await
+        .expect("Failed to start tracing");
+
+    page.set_content(r#"<input id="name"><button id="go">Go</button>"#)
+        .set()
+        .await
+        .expect("Failed to set content");
+    page.locator("#name")
+        .fill("Ada")
+        .await
+        .expect("Failed to fill");
+    page.locator("#go").click().await.expect("Failed to click");
+
+    // A failing action should be recorded with its error
+    let missing = page
+        .locator("#missing")
+        .timeout(std::time::Duration::from_millis(200));
+    assert!(missing.click().await.is_err());
+
+    context
+        .tracing()
+        .stop(&trace_path)
+        .await
+        .expect("Failed to stop tracing");
+
+    let trace = read_trace_json(&trace_path);
+    let actions = trace["actions"].as_array().expect("actions array");
+    let types: Vec<&str> = actions
+        .iter()
+        .filter_map(|a| a["actionType"].as_str())
+        .collect();
+    assert_eq!(types, vec!["setContent", "fill", "click", "click"]);
+
+    let fill = &actions[1];
+    assert_eq!(fill["value"], "Ada");
+    assert_eq!(fill["params"]["value"], "Ada");
+    assert!(fill["selector"].as_str().unwrap().contains("#name"));
+    assert!(fill["endTime"].as_f64().unwrap() >= fill["startTime"].as_f64().unwrap());
+    assert!(fill.get("beforeScreenshot").is_some());
+    assert!(fill.get("afterScreenshot").is_some());
+
+    let failed = &actions[3];
+    assert!(failed["error"].as_str().unwrap().contains("not found"));
+
+    let events = trace["events"].as_array().expect("events array");
+    let before_count = events.iter().filter(|e| e["type"] == "before").count();
+    let after_count = events.iter().filter(|e| e["type"] == "after").count();
+    assert_eq!(before_count, 4);
+    assert_eq!(after_count, 4);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that actions are not recorded when tracing is inactive.
+#[tokio::test]
+async fn test_tracing_ignores_actions_when_inactive() {
+    common::init_tracing();
+
+    let (browser, context, page) = common::launch_with_page().await;
+    let temp_dir = temp_trace_dir();
+    let trace_path = temp_trace_path(&temp_dir, "trace-inactive.zip");
+
+    // Action before tracing starts
+    page.set_content("<button>Before</button>")
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    context
+        .tracing()
+        .start(TracingOptions::new())
+        .await
+        .expect("Failed to start tracing");
+
+    page.locator("button")
+        .click()
+        .await
+        .expect("Failed to click");
+
+    context
+        .tracing()
+        .stop(&trace_path)
+        .await
+        .expect("Failed to stop tracing");
+
+    let trace = read_trace_json(&trace_path);
+    let actions = trace["actions"].as_array().expect("actions array");
+    assert_eq!(actions.len(), 1);
+    assert_eq!(actions[0]["actionType"], "click");
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that traces can be written in Playwright's trace viewer format.
+#[tokio::test]
+async fn test_tracing_playwright_format() {
+    use std::io::Read;
+
+    common::init_tracing();
+
+    let (browser, context, page) = common::launch_with_page().await;
+    let temp_dir = temp_trace_dir();
+    let trace_path = temp_trace_path(&temp_dir, "trace-playwright.zip");
+
+    context
+        .tracing()
+        .start(
+            TracingOptions::new()
+                .screenshots(true)
+                .snapshots(true)
+                .format(TraceFormat::Playwright),
+        )
+        .await
+        .expect("Failed to start tracing");
+
+    page.set_content(r#"<button id="go">Go</button>"#)
+        .set()
+        .await
+        .expect("Failed to set content");
+    page.locator("#go").click().await.expect("Failed to click");
+
+    context
+        .tracing()
+        .stop(&trace_path)
+        .await
+        .expect("Failed to stop tracing");
+
+    let file = std::fs::File::open(&trace_path).expect("Failed to open trace file");
+    let mut archive = zip::ZipArchive::new(file).expect("Trace should be a valid zip file");
+    assert!(archive.by_name("trace.json").is_err());
+    assert!(archive.by_name("trace.network").is_ok());
+
+    let mut contents = String::new();
+    archive
+        .by_name("trace.trace")
+        .expect("Trace should contain trace.trace")
+        .read_to_string(&mut contents)
+        .expect("Failed to read trace.trace");
+    let events: Vec<serde_json::Value> = contents
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Each line should be valid JSON"))
+        .collect();
+
+    assert_eq!(events[0]["type"], "context-options");
+    let api_names: Vec<&str> = events
+        .iter()
+        .filter(|e| e["type"] == "before")
+        .filter_map(|e| e["apiName"].as_str())
+        .collect();
+    assert_eq!(api_names, vec!["page.setContent", "locator.click"]);
+    assert!(events.iter().any(|e| e["type"] == "frame-snapshot"));
+
+    let frame = events
+        .iter()
+        .find(|e| e["type"] == "screencast-frame")
+        .expect("Trace should contain screencast frames");
+    let resource = format!("resources/{}", frame["sha1"].as_str().unwrap());
+    assert!(archive.by_name(&resource).is_ok());
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that a saved trace can be loaded and queried.
+#[tokio::test]
+async fn test_trace_archive_reads_recorded_trace() {
+    common::init_tracing();
+
+    let (browser, context, page) = common::launch_with_page().await;
+    let temp_dir = temp_trace_dir();
+
+    for format in [TraceFormat::Viewpoint, TraceFormat::Playwright] {
+        let trace_path = temp_trace_path(&temp_dir, &format!("trace-{format:?}.zip"));
+
+        context
+            .tracing()
+            .start(TracingOptions::new().screenshots(true).format(format))
+            .await
+            .expect("Failed to start tracing");
+
+        page.set_content(r#"<button id="go">Go</button>"#)
+            .set()
+            .await
+            .expect("Failed to set content");
+        let missing = page
+            .locator("#missing")
+            .timeout(std::time::Duration::from_millis(200));
+        assert!(missing.click().await.is_err());
+
+        context
+            .tracing()
+            .stop(&trace_path)
+            .await
+            .expect("Failed to stop tracing");
+
+        let trace = TraceArchive::open(&trace_path).expect("Failed to open trace");
+        assert_eq!(trace.format(), format);
+        assert_eq!(trace.actions().len(), 2);
+
+        let failed = trace.failed_actions();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].action_type, "click");
+        assert!(
+            trace
+                .screenshot_nearest(failed[0].start_time)
+                .is_some_and(|s| !s.data.is_empty())
+        );
+    }
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "integration")]
+
+//! Video recording integration tests.
+//!
+//! Tests that recorded videos are written as playable MJPEG containers.
+
+mod common;
+
+use std::time::Duration;
+
+use viewpoint_core::page::{VideoFormat, VideoOptions};
+
+/// Test that closing a page produces an AVI video with the recorded frames.
+#[tokio::test]
+async fn test_video_recorded_as_avi_on_close() {
+    common::init_tracing();
+
+    let browser = common::launch_browser().await;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let context = browser
+        .new_context_builder()
+        .record_video(VideoOptions::new(temp_dir.path()).size(640, 480))
+        .build()
+        .await
+        .expect("Failed to create context");
+
+    let mut page = context.new_page().await.expect("Failed to create page");
+    page.set_content("<h1 id='title'>Recording</h1>")
+        .set()
+        .await
+        .expect("Failed to set content");
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    page.close().await.expect("Failed to close page");
+
+    let video = page.video().expect("Video should be enabled");
+    let path = video.path().await.expect("Video should be finalized");
+    assert_eq!(path.extension().and_then(|e| e.to_str()), Some("avi"));
+
+    let data = std::fs::read(&path).expect("Failed to read video");
+    assert_eq!(&data[..4], b"RIFF");
+    assert_eq!(&data[8..12], b"AVI ");
+
+    // Saving with a different extension re-muxes into that container
+    let mkv_path = temp_dir.path().join("saved.mkv");
+    video
+        .save_as(&mkv_path)
+        .await
+        .expect("Failed to save video");
+    let mkv = std::fs::read(&mkv_path).expect("Failed to read saved video");
+    assert_eq!(&mkv[..4], &[0x1A, 0x45, 0xDF, 0xA3]);
+
+    video.delete().await.expect("Failed to delete video");
+    assert!(!path.exists());
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that the Matroska format can be chosen for recording.
+#[tokio::test]
+async fn test_video_recorded_as_matroska() {
+    common::init_tracing();
+
+    let browser = common::launch_browser().await;
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let context = browser
+        .new_context_builder()
+        .record_video(VideoOptions::new(temp_dir.path()).format(VideoFormat::Matroska))
+        .build()
+        .await
+        .expect("Failed to create context");
+
+    let mut page = context.new_page().await.expect("Failed to create page");
+    page.set_content("<p>Matroska</p>")
+        .set()
+        .await
+        .expect("Failed to set content");
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    page.close().await.expect("Failed to close page");
+
+    let path = page
+        .video()
+        .expect("Video should be enabled")
+        .path()
+        .await
+        .expect("Video should be finalized");
+    assert_eq!(path.extension().and_then(|e| e.to_str()), Some("mkv"));
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "integration")]
+
+//! Virtual time tests for viewpoint-core.
+//!
+//! These tests verify CDP virtual time policies and budget expiry.
+
+mod common;
+
+use std::time::Duration;
+
+use viewpoint_core::VirtualTimePolicy;
+use viewpoint_js::js;
+
+use common::launch_with_page;
+
+/// Test that timers only fire once enough virtual time has been granted.
+#[tokio::test]
+async fn test_virtual_time_advance() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    page.set_content("<html><body></body></html>")
+        .set()
+        .await
+        .expect("Failed to set content");
+    page.virtual_time()
+        .pause()
+        .await
+        .expect("Failed to pause virtual time");
+
+    let _: serde_json::Value = page
+        .evaluate(js! { (() => { window.fired = false; setTimeout(() => { window.fired = true; }, 1000); })() })
+        .await
+        .expect("Failed to schedule timer");
+
+    page.virtual_time()
+        .advance(Duration::from_millis(500))
+        .await
+        .expect("Failed to advance virtual time");
+    let fired: bool = page
+        .evaluate(js! { window.fired })
+        .await
+        .expect("Failed to evaluate");
+    assert!(!fired);
+
+    page.virtual_time()
+        .advance(Duration::from_millis(600))
+        .await
+        .expect("Failed to advance virtual time");
+    let fired: bool = page
+        .evaluate(js! { window.fired })
+        .await
+        .expect("Failed to evaluate");
+    assert!(fired);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test applying a policy and awaiting its budget separately.
+#[tokio::test]
+async fn test_virtual_time_budget_future() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    let budget = page
+        .virtual_time()
+        .policy(VirtualTimePolicy::PauseIfNetworkFetchesPending)
+        .budget(Duration::from_secs(1))
+        .apply()
+        .await
+        .expect("Failed to apply policy");
+    assert!(budget.virtual_time_ticks_base() > 0.0);
+
+    tokio::time::timeout(Duration::from_secs(10), budget)
+        .await
+        .expect("Budget did not expire")
+        .expect("Failed waiting for budget");
+
+    // Without a budget the future resolves immediately
+    page.virtual_time()
+        .policy(VirtualTimePolicy::Advance)
+        .apply()
+        .await
+        .expect("Failed to apply policy")
+        .await
+        .expect("Budget future failed");
+
+    browser.close().await.expect("Failed to close browser");
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "integration")]
+
+//! WebSocket routing tests.
+//!
+//! These tests verify that routed WebSockets can be fully mocked without a
+//! server, at both the page and the context level.
+
+mod common;
+
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+use viewpoint_core::WebSocketMessage;
+use viewpoint_js::js;
+
+/// Test a page route acting as an echo server.
+#[tokio::test]
+async fn test_page_route_web_socket_mocks_server() {
+    common::init_tracing();
+
+    let browser = common::launch_browser().await;
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    let received: Arc<Mutex<Vec<WebSocketMessage>>> = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+
+    // Nothing listens on port 9, so only the route can answer
+    page.route_web_socket("**/echo", move |ws| {
+        let received = received_clone.clone();
+        async move {
+            let reply = ws.clone();
+            ws.on_message(move |message| {
+                let received = received.clone();
+                let reply = reply.clone();
+                async move {
+                    received.lock().await.push(message.clone());
+                    let _ = reply.send(message).await;
+                }
+            })
+            .await;
+            ws.send("welcome").await
+        }
+    })
+    .await
+    .expect("Failed to route WebSocket");
+
+    let messages: Vec<String> = page
+        .evaluate(js! {
+            new Promise((resolve, reject) => {
+                const messages = [];
+                const ws = new WebSocket("ws://127.0.0.1:9/echo");
+                ws.onopen = () => ws.send("ping");
+                ws.onmessage = (event) => {
+                    messages.push(event.data);
+                    if (messages.length === 2) resolve(messages);
+                };
+                ws.onerror = () => reject(new Error("socket failed"));
+            })
+        })
+        .await
+        .expect("Failed to exchange messages");
+
+    assert_eq!(messages, vec!["welcome", "ping"]);
+    assert_eq!(
+        *received.lock().await,
+        vec![WebSocketMessage::Text("ping".to_string())]
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test a context route closing connections from an existing page.
+#[tokio::test]
+async fn test_context_route_web_socket_closes_connection() {
+    common::init_tracing();
+
+    let browser = common::launch_browser().await;
+    let context = browser
+        .new_context()
+        .await
+        .expect("Failed to create context");
+    let page = context.new_page().await.expect("Failed to create page");
+
+    context
+        .route_web_socket("**/chat", |ws| async move {
+            ws.close_with(4003, "chat disabled").await
+        })
+        .await
+        .expect("Failed to route WebSocket");
+
+    let close: serde_json::Value = page
+        .evaluate(js! {
+            new Promise((resolve) => {
+                const ws = new WebSocket("ws://127.0.0.1:9/chat");
+                ws.onclose = (event) => resolve({ code: event.code, reason: event.reason });
+            })
+        })
+        .await
+        .expect("Failed to observe close");
+
+    assert_eq!(
+        close,
+        serde_json::json!({ "code": 4003, "reason": "chat disabled" })
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
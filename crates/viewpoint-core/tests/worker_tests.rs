@@ -0,0 +1,263 @@
+#![cfg(feature = "integration")]
+
+//! Worker tests for viewpoint-core.
+//!
+//! These tests verify dedicated Web Worker, Shared Worker and Service Worker
+//! handles.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use viewpoint_core::Worker;
+
+use common::{http_ok, launch_with_page, serve};
+
+const WORKER_PAGE: &str = r"
+    <html><body><script>
+        const source = 'self.name = \'worker\'; console.log(\'hello from worker\');\
+            self.onmessage = () => self.close();';
+        window.worker = new Worker(URL.createObjectURL(new Blob([source])));
+    </script></body></html>
+";
+
+/// Wait for a worker to be reported by the page.
+async fn wait_for_worker(page: &viewpoint_core::Page) -> Worker {
+    for _ in 0..50 {
+        if let Some(worker) = page.workers().await.into_iter().next() {
+            return worker;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("Worker was not reported");
+}
+
+/// Test evaluating in a dedicated worker and observing its termination.
+#[tokio::test]
+async fn test_dedicated_worker() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    let started = Arc::new(Mutex::new(None));
+    let started_clone = started.clone();
+    page.on_worker(move |worker| {
+        let started = started_clone.clone();
+        async move {
+            *started.lock().await = Some(worker.url().to_string());
+        }
+    })
+    .await;
+
+    page.set_content(WORKER_PAGE)
+        .set()
+        .await
+        .expect("Failed to set content");
+
+    let worker = wait_for_worker(&page).await;
+    assert!(worker.url().starts_with("blob:"));
+    assert_eq!(
+        started.lock().await.as_deref(),
+        Some(worker.url()),
+        "on_worker should report the worker"
+    );
+
+    let sum: i32 = worker.evaluate("1 + 2").await.expect("Failed to evaluate");
+    assert_eq!(sum, 3);
+
+    let handle = worker
+        .evaluate_handle("self")
+        .await
+        .expect("Failed to evaluate handle");
+    assert!(!handle.object_id().is_empty());
+
+    let closed = Arc::new(Mutex::new(false));
+    let closed_clone = closed.clone();
+    worker
+        .on_close(move || {
+            let closed = closed_clone.clone();
+            async move {
+                *closed.lock().await = true;
+            }
+        })
+        .await;
+
+    let _: serde_json::Value = page
+        .evaluate("window.worker.postMessage('close')")
+        .await
+        .expect("Failed to post message");
+
+    for _ in 0..50 {
+        if worker.is_closed() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(worker.is_closed());
+    assert!(*closed.lock().await);
+    assert!(page.workers().await.is_empty());
+    assert!(worker.evaluate::<i32>("1").await.is_err());
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that worker console messages reach the page console handler.
+#[tokio::test]
+async fn test_worker_console_routed_to_page() {
+    let (browser, _context, page) = launch_with_page().await;
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let messages_clone = messages.clone();
+    page.on_console(move |message| {
+        let messages = messages_clone.clone();
+        async move {
+            messages.lock().await.push(message.text());
+        }
+    })
+    .await;
+
+    page.set_content(WORKER_PAGE)
+        .set()
+        .await
+        .expect("Failed to set content");
+    wait_for_worker(&page).await;
+
+    for _ in 0..50 {
+        if !messages.lock().await.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(
+        messages
+            .lock()
+            .await
+            .iter()
+            .any(|text| text == "hello from worker")
+    );
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Serve a page registering a service worker at `/` and a page starting a
+/// shared worker at `/shared`.
+async fn serve_worker_site() -> String {
+    let origin = serve(|path, _| {
+        Some(match path {
+            "/sw.js" => http_ok("application/javascript", "self.answer = 42;"),
+            "/shared.js" => http_ok("application/javascript", "self.answer = 7;"),
+            "/shared" => http_ok(
+                "text/html",
+                "<script>window.shared = new SharedWorker('/shared.js');</script>",
+            ),
+            _ => http_ok(
+                "text/html",
+                "<script>navigator.serviceWorker.register('/sw.js');</script>",
+            ),
+        })
+    })
+    .await;
+    format!("{origin}/")
+}
+
+/// Test that service workers are reported by the context.
+#[tokio::test]
+async fn test_service_worker() {
+    let (browser, context, page) = launch_with_page().await;
+    let url = serve_worker_site().await;
+
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let reported_clone = reported.clone();
+    context
+        .on_service_worker(move |worker| {
+            let reported = reported_clone.clone();
+            async move {
+                reported.lock().await.push(worker.url().to_string());
+            }
+        })
+        .await;
+
+    page.goto(&url).goto().await.expect("Failed to navigate");
+
+    let mut worker = None;
+    for _ in 0..50 {
+        worker = context
+            .service_workers()
+            .await
+            .expect("Failed to get service workers")
+            .into_iter()
+            .next();
+        if worker.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let worker = worker.expect("Service worker was not reported");
+
+    assert!(worker.url().ends_with("/sw.js"));
+    assert_eq!(*reported.lock().await, vec![worker.url().to_string()]);
+
+    let answer: i32 = worker
+        .evaluate("self.answer")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(answer, 42);
+
+    browser.close().await.expect("Failed to close browser");
+}
+
+/// Test that shared workers are reported by the context.
+#[tokio::test]
+async fn test_shared_worker() {
+    let (browser, context, page) = launch_with_page().await;
+    let url = serve_worker_site().await;
+
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    let reported_clone = reported.clone();
+    context
+        .on_shared_worker(move |worker| {
+            let reported = reported_clone.clone();
+            async move {
+                reported.lock().await.push(worker.url().to_string());
+            }
+        })
+        .await;
+
+    page.goto(format!("{url}shared"))
+        .goto()
+        .await
+        .expect("Failed to navigate");
+
+    let mut worker = None;
+    for _ in 0..50 {
+        worker = context
+            .shared_workers()
+            .await
+            .expect("Failed to get shared workers")
+            .into_iter()
+            .next();
+        if worker.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let worker = worker.expect("Shared worker was not reported");
+
+    assert!(worker.url().ends_with("/shared.js"));
+    assert_eq!(*reported.lock().await, vec![worker.url().to_string()]);
+    assert!(
+        context
+            .service_workers()
+            .await
+            .expect("Failed to get service workers")
+            .is_empty()
+    );
+
+    let answer: i32 = worker
+        .evaluate("self.answer")
+        .await
+        .expect("Failed to evaluate");
+    assert_eq!(answer, 7);
+
+    browser.close().await.expect("Failed to close browser");
+}
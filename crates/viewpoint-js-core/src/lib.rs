@@ -204,6 +204,7 @@ pub fn escape_for_css_attr(s: &str) -> String {
         match c {
             '\\' => result.push_str("\\\\"),
             '"' => result.push_str("\\\""),
+            '\'' => result.push_str("\\'"),
             '\n' => result.push_str("\\n"),
             '\r' => result.push_str("\\r"),
             '\t' => result.push_str("\\t"),
@@ -83,6 +83,12 @@ fn test_css_attr_with_quotes() {
     assert_eq!(escape_for_css_attr(r#"say "hi""#), r#"\"say \"hi\"\""#);
 }
 
+#[test]
+fn test_css_attr_with_single_quote() {
+    // The value is embedded in a single-quoted JS string
+    assert_eq!(escape_for_css_attr("it's"), r#"\"it\'s\""#);
+}
+
 #[test]
 fn test_css_attr_with_backslash() {
     assert_eq!(escape_for_css_attr(r"path\to"), r#"\"path\\to\""#);
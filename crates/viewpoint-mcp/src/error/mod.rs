@@ -0,0 +1,53 @@
+//! Error types for the MCP server.
+
+use thiserror::Error;
+use viewpoint_core::CoreError;
+use viewpoint_core::error::{BrowserError, ContextError, LocatorError, NavigationError, PageError};
+
+/// Errors that can occur while serving MCP requests.
+#[derive(Debug, Error)]
+pub enum McpError {
+    /// Error from the core browser automation library.
+    #[error("{0}")]
+    Core(#[from] CoreError),
+
+    /// A tool was called with missing or malformed arguments.
+    #[error("Invalid params: {0}")]
+    InvalidParams(String),
+
+    /// The requested tool does not exist.
+    #[error("Unknown tool: {0}")]
+    UnknownTool(String),
+
+    /// A tool was called in a state where it cannot run.
+    #[error("{0}")]
+    InvalidState(String),
+
+    /// Reading from or writing to the transport failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A message could not be serialized.
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+macro_rules! impl_from_core {
+    ($($error:ty),* $(,)?) => {
+        $(
+            impl From<$error> for McpError {
+                fn from(error: $error) -> Self {
+                    Self::Core(error.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_core!(
+    BrowserError,
+    ContextError,
+    LocatorError,
+    NavigationError,
+    PageError,
+);
@@ -0,0 +1,60 @@
+//! # Viewpoint MCP - Model Context Protocol Server
+//!
+//! A [Model Context Protocol](https://modelcontextprotocol.io) server that lets
+//! AI agents drive a Chromium browser through `Viewpoint`.
+//!
+//! The server speaks JSON-RPC over stdio. Agents read the page with
+//! `browser_snapshot`, which returns an ARIA snapshot (including iframes) where
+//! every element carries a ref such as `c0p0f0e3`. Element tools take those
+//! refs and resolve them with [`Page::locator_from_ref`](viewpoint_core::Page::locator_from_ref),
+//! so no selectors are needed.
+//!
+//! ## Tools
+//!
+//! | Tool | Description |
+//! |------|-------------|
+//! | `browser_navigate`, `browser_navigate_back` | Navigate the current tab |
+//! | `browser_snapshot` | ARIA snapshot with element refs |
+//! | `browser_click`, `browser_hover`, `browser_fill`, `browser_select_option` | Act on an element by ref |
+//! | `browser_press_key` | Press a key in the current tab |
+//! | `browser_take_screenshot` | PNG of the viewport, full page or one element |
+//! | `browser_evaluate` | Evaluate JavaScript on the page or an element |
+//! | `browser_tabs` | List, open, select and close tabs |
+//! | `browser_network_requests` | Requests made by the current tab |
+//! | `browser_close` | Close the browser |
+//!
+//! ## Running
+//!
+//! ```sh
+//! cargo run -p viewpoint-mcp -- --headed
+//! ```
+//!
+//! Register the binary with an MCP client as a stdio server, e.g.:
+//!
+//! ```json
+//! { "mcpServers": { "viewpoint": { "command": "viewpoint-mcp" } } }
+//! ```
+//!
+//! ## Embedding
+//!
+//! ```no_run
+//! use viewpoint_mcp::{McpServer, SessionOptions};
+//!
+//! # async fn example() -> Result<(), viewpoint_mcp::McpError> {
+//! let mut server = McpServer::new(SessionOptions::new().viewport(1280, 720));
+//! server.serve_stdio().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod error;
+pub mod network_log;
+pub mod protocol;
+pub mod server;
+pub mod session;
+pub mod tools;
+
+pub use error::McpError;
+pub use network_log::{NetworkLog, NetworkLogEntry};
+pub use server::McpServer;
+pub use session::{BrowserSession, SessionOptions};
@@ -0,0 +1,84 @@
+//! `viewpoint-mcp` binary: an MCP server on stdin/stdout.
+//!
+//! Logs go to stderr so they never interleave with protocol messages.
+
+use std::process::ExitCode;
+
+use viewpoint_mcp::{McpServer, SessionOptions};
+
+const USAGE: &str = "\
+Usage: viewpoint-mcp [OPTIONS]
+
+Model Context Protocol server for Viewpoint browser automation (stdio transport).
+
+Options:
+  --headed                  Show the browser window
+  --executable-path <PATH>  Chromium executable (defaults to CHROMIUM_PATH or auto-detection)
+  --viewport <WxH>          Viewport size for new pages, e.g. 1280x720
+  -h, --help                Print help
+  -V, --version             Print version
+
+Set RUST_LOG to control logging (written to stderr).";
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<SessionOptions>, String> {
+    let mut options = SessionOptions::new();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--headed" => options = options.headless(false),
+            "--executable-path" => {
+                let path = args.next().ok_or("--executable-path requires a value")?;
+                options = options.executable_path(path);
+            }
+            "--viewport" => {
+                let value = args.next().ok_or("--viewport requires a value")?;
+                let (width, height) = value
+                    .split_once('x')
+                    .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)))
+                    .ok_or_else(|| format!("Invalid viewport '{value}', expected WIDTHxHEIGHT"))?;
+                options = options.viewport(width, height);
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(None);
+            }
+            "-V" | "--version" => {
+                println!("viewpoint-mcp {}", env!("CARGO_PKG_VERSION"));
+                return Ok(None);
+            }
+            other => return Err(format!("Unknown argument '{other}'")),
+        }
+    }
+
+    Ok(Some(options))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .with_writer(std::io::stderr)
+        .with_ansi(false)
+        .init();
+
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => return ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match McpServer::new(options).serve_stdio().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            tracing::error!("Server failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Network request log for the `browser_network_requests` tool.
+//!
+//! The log listens to `Network.*` CDP events on the browser connection and
+//! keeps a bounded list of requests per page session.
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use tracing::debug;
+use viewpoint_cdp::protocol::network::{
+    LoadingFailedEvent, RequestWillBeSentEvent, ResponseReceivedEvent,
+};
+use viewpoint_cdp::{CdpConnection, CdpEvent};
+
+/// Maximum number of requests kept in the log.
+const MAX_ENTRIES: usize = 1000;
+
+/// A request seen by the network log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkLogEntry {
+    /// CDP session of the page that made the request.
+    pub session_id: String,
+    /// CDP request ID.
+    pub request_id: String,
+    /// HTTP method.
+    pub method: String,
+    /// Request URL.
+    pub url: String,
+    /// Resource type (Document, Script, XHR, ...).
+    pub resource_type: Option<String>,
+    /// Response status, once a response was received.
+    pub status: Option<u32>,
+    /// Response status text.
+    pub status_text: Option<String>,
+    /// Failure reason, if the request failed.
+    pub failure: Option<String>,
+}
+
+impl fmt::Display for NetworkLogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.method, self.url)?;
+        if let Some(ref resource_type) = self.resource_type {
+            write!(f, " ({resource_type})")?;
+        }
+        match (&self.failure, self.status) {
+            (Some(failure), _) => write!(f, " => FAILED {failure}"),
+            (None, Some(status)) => {
+                write!(f, " => [{status}]")?;
+                match self.status_text.as_deref() {
+                    Some(text) if !text.is_empty() => write!(f, " {text}"),
+                    _ => Ok(()),
+                }
+            }
+            (None, None) => write!(f, " => pending"),
+        }
+    }
+}
+
+/// Bounded, shareable log of network requests.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkLog {
+    entries: Arc<Mutex<VecDeque<NetworkLogEntry>>>,
+}
+
+impl NetworkLog {
+    /// Create an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start recording network events from `connection`.
+    ///
+    /// The returned task runs until the connection closes.
+    pub fn attach(&self, connection: &Arc<CdpConnection>) -> JoinHandle<()> {
+        let mut events = connection.subscribe_events();
+        let log = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => log.record(&event),
+                    Err(RecvError::Lagged(skipped)) => {
+                        debug!("Network log skipped {} events", skipped);
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    /// Apply a CDP event to the log. Non-network events are ignored.
+    pub fn record(&self, event: &CdpEvent) {
+        let (Some(session_id), Some(params)) = (&event.session_id, &event.params) else {
+            return;
+        };
+
+        match event.method.as_str() {
+            "Network.requestWillBeSent" => {
+                let Ok(sent) = serde_json::from_value::<RequestWillBeSentEvent>(params.clone())
+                else {
+                    return;
+                };
+                if let Some(ref redirect) = sent.redirect_response {
+                    self.update(session_id, &sent.request_id, |entry| {
+                        entry.status = Some(redirect.status);
+                        entry.status_text = Some(redirect.status_text.clone());
+                    });
+                }
+                let mut entries = self.entries.lock();
+                if entries.len() == MAX_ENTRIES {
+                    entries.pop_front();
+                }
+                entries.push_back(NetworkLogEntry {
+                    session_id: session_id.clone(),
+                    request_id: sent.request_id,
+                    method: sent.request.method,
+                    url: sent.request.url,
+                    resource_type: sent.resource_type,
+                    status: None,
+                    status_text: None,
+                    failure: None,
+                });
+            }
+            "Network.responseReceived" => {
+                let Ok(received) = serde_json::from_value::<ResponseReceivedEvent>(params.clone())
+                else {
+                    return;
+                };
+                self.update(session_id, &received.request_id, |entry| {
+                    entry.status = Some(received.response.status);
+                    entry.status_text = Some(received.response.status_text.clone());
+                });
+            }
+            "Network.loadingFailed" => {
+                let Ok(failed) = serde_json::from_value::<LoadingFailedEvent>(params.clone())
+                else {
+                    return;
+                };
+                self.update(session_id, &failed.request_id, |entry| {
+                    entry.failure = Some(failed.error_text.clone());
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Requests made by the page with the given CDP session, oldest first.
+    pub fn entries_for(&self, session_id: &str) -> Vec<NetworkLogEntry> {
+        self.entries
+            .lock()
+            .iter()
+            .filter(|entry| entry.session_id == session_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Remove all entries.
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+
+    /// Update the most recent entry for a request.
+    ///
+    /// Redirects reuse the request ID, so the latest entry is the live one.
+    fn update(&self, session_id: &str, request_id: &str, apply: impl FnOnce(&mut NetworkLogEntry)) {
+        let mut entries = self.entries.lock();
+        if let Some(entry) = entries
+            .iter_mut()
+            .rev()
+            .find(|entry| entry.session_id == session_id && entry.request_id == request_id)
+        {
+            apply(entry);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use serde_json::{Value, json};
+
+use super::*;
+
+fn event(method: &str, session_id: &str, params: Value) -> CdpEvent {
+    CdpEvent {
+        method: method.to_string(),
+        params: Some(params),
+        session_id: Some(session_id.to_string()),
+    }
+}
+
+fn request_sent(session_id: &str, request_id: &str, url: &str) -> CdpEvent {
+    event(
+        "Network.requestWillBeSent",
+        session_id,
+        json!({
+            "requestId": request_id,
+            "loaderId": "L1",
+            "request": {"url": url, "method": "GET", "headers": {}},
+            "timestamp": 1.0,
+            "wallTime": 1.0,
+            "initiator": {"type": "other"},
+            "type": "Document"
+        }),
+    )
+}
+
+fn response(status: u32, status_text: &str) -> Value {
+    json!({
+        "url": "https://example.com/",
+        "status": status,
+        "statusText": status_text,
+        "headers": {},
+        "mimeType": "text/html"
+    })
+}
+
+#[test]
+fn test_records_request_lifecycle() {
+    let log = NetworkLog::new();
+    log.record(&request_sent("S1", "R1", "https://example.com/"));
+    log.record(&request_sent("S1", "R2", "https://example.com/app.js"));
+    log.record(&event(
+        "Network.responseReceived",
+        "S1",
+        json!({
+            "requestId": "R1",
+            "loaderId": "L1",
+            "timestamp": 2.0,
+            "type": "Document",
+            "response": response(200, "OK")
+        }),
+    ));
+    log.record(&event(
+        "Network.loadingFailed",
+        "S1",
+        json!({
+            "requestId": "R2",
+            "timestamp": 2.0,
+            "type": "Script",
+            "errorText": "net::ERR_FAILED"
+        }),
+    ));
+
+    let entries = log.entries_for("S1");
+    assert_eq!(entries.len(), 2);
+    assert_eq!(
+        entries[0].to_string(),
+        "[GET] https://example.com/ (Document) => [200] OK"
+    );
+    assert_eq!(
+        entries[1].to_string(),
+        "[GET] https://example.com/app.js (Document) => FAILED net::ERR_FAILED"
+    );
+}
+
+#[test]
+fn test_entries_are_per_session() {
+    let log = NetworkLog::new();
+    log.record(&request_sent("S1", "R1", "https://a.test/"));
+    log.record(&request_sent("S2", "R1", "https://b.test/"));
+
+    assert_eq!(log.entries_for("S1")[0].url, "https://a.test/");
+    assert_eq!(log.entries_for("S2")[0].url, "https://b.test/");
+    assert!(log.entries_for("S3").is_empty());
+    assert!(log.entries_for("S2")[0].to_string().ends_with("=> pending"));
+
+    log.clear();
+    assert!(log.entries_for("S1").is_empty());
+}
+
+#[test]
+fn test_redirect_completes_previous_hop() {
+    let log = NetworkLog::new();
+    log.record(&request_sent("S1", "R1", "http://example.com/"));
+
+    let mut redirected = request_sent("S1", "R1", "https://example.com/");
+    redirected.params.as_mut().unwrap()["redirectResponse"] = response(301, "Moved Permanently");
+    log.record(&redirected);
+
+    let entries = log.entries_for("S1");
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].status, Some(301));
+    assert_eq!(entries[1].status, None);
+}
+
+#[test]
+fn test_ignores_unrelated_events() {
+    let log = NetworkLog::new();
+    log.record(&event(
+        "Page.loadEventFired",
+        "S1",
+        json!({"timestamp": 1.0}),
+    ));
+    log.record(&CdpEvent {
+        method: "Network.requestWillBeSent".to_string(),
+        params: None,
+        session_id: Some("S1".to_string()),
+    });
+
+    assert!(log.entries_for("S1").is_empty());
+}
@@ -0,0 +1,186 @@
+//! Model Context Protocol message types.
+//!
+//! MCP uses JSON-RPC 2.0. Over the stdio transport every message is a single
+//! line of JSON; requests carry an `id`, notifications do not.
+
+#[cfg(test)]
+mod tests;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The protocol version this server implements.
+pub const PROTOCOL_VERSION: &str = "2025-06-18";
+
+/// Protocol versions this server can speak, newest first.
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// Invalid JSON was received.
+pub const PARSE_ERROR: i64 = -32700;
+/// The JSON sent is not a valid request object.
+pub const INVALID_REQUEST: i64 = -32600;
+/// The method does not exist.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// Invalid method parameters, including unknown tool names.
+pub const INVALID_PARAMS: i64 = -32602;
+/// Internal server error.
+pub const INTERNAL_ERROR: i64 = -32603;
+
+/// An incoming JSON-RPC message.
+///
+/// Requests have both `id` and `method`, notifications only `method`, and
+/// responses to server-initiated requests only `id`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Message {
+    /// Request ID, absent for notifications.
+    #[serde(default)]
+    pub id: Option<Value>,
+    /// Method name.
+    #[serde(default)]
+    pub method: Option<String>,
+    /// Method parameters.
+    #[serde(default)]
+    pub params: Option<Value>,
+}
+
+impl Message {
+    /// Check if this message is a notification (no response expected).
+    pub fn is_notification(&self) -> bool {
+        self.method.is_some() && self.id.is_none()
+    }
+}
+
+/// An outgoing JSON-RPC response.
+#[derive(Debug, Clone, Serialize)]
+pub struct Response {
+    /// Always `"2.0"`.
+    pub jsonrpc: &'static str,
+    /// ID of the request being answered.
+    pub id: Value,
+    /// Result on success.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// Error on failure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorObject>,
+}
+
+impl Response {
+    /// Create a successful response.
+    pub fn success(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    /// Create an error response.
+    pub fn error(id: Value, code: i64, message: impl Into<String>) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(ErrorObject {
+                code,
+                message: message.into(),
+            }),
+        }
+    }
+}
+
+/// A JSON-RPC error.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorObject {
+    /// Error code.
+    pub code: i64,
+    /// Human-readable message.
+    pub message: String,
+}
+
+/// A tool advertised by `tools/list`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolDefinition {
+    /// Tool name.
+    pub name: &'static str,
+    /// What the tool does, shown to the model.
+    pub description: &'static str,
+    /// JSON Schema for the tool arguments.
+    pub input_schema: Value,
+}
+
+/// A content block in a tool result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Content {
+    /// Plain text.
+    Text {
+        /// The text.
+        text: String,
+    },
+    /// A base64-encoded image.
+    Image {
+        /// Base64 image data.
+        data: String,
+        /// Image MIME type.
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
+}
+
+/// The result of a `tools/call` request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallToolResult {
+    /// Result content.
+    pub content: Vec<Content>,
+    /// Whether the tool failed.
+    pub is_error: bool,
+}
+
+impl CallToolResult {
+    /// Create a successful text result.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            content: vec![Content::Text { text: text.into() }],
+            is_error: false,
+        }
+    }
+
+    /// Create a failed result with an error message.
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            content: vec![Content::Text {
+                text: message.into(),
+            }],
+            is_error: true,
+        }
+    }
+
+    /// Append an image to the result.
+    #[must_use]
+    pub fn with_image(mut self, data: String, mime_type: impl Into<String>) -> Self {
+        self.content.push(Content::Image {
+            data,
+            mime_type: mime_type.into(),
+        });
+        self
+    }
+}
+
+/// Pick the protocol version to answer an `initialize` request with.
+///
+/// The client's version is echoed back when supported; otherwise the latest
+/// version is offered and the client decides whether to continue.
+pub fn negotiate_version(requested: Option<&str>) -> &'static str {
+    requested
+        .and_then(|version| {
+            SUPPORTED_PROTOCOL_VERSIONS
+                .iter()
+                .find(|&&supported| supported == version)
+        })
+        .copied()
+        .unwrap_or(PROTOCOL_VERSION)
+}
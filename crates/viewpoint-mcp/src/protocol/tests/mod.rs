@@ -0,0 +1,55 @@
+use serde_json::json;
+
+use super::*;
+
+#[test]
+fn test_message_kinds() {
+    let request: Message =
+        serde_json::from_value(json!({"jsonrpc": "2.0", "id": 1, "method": "ping"})).unwrap();
+    assert!(!request.is_notification());
+
+    let notification: Message =
+        serde_json::from_value(json!({"jsonrpc": "2.0", "method": "notifications/initialized"}))
+            .unwrap();
+    assert!(notification.is_notification());
+
+    let response: Message =
+        serde_json::from_value(json!({"jsonrpc": "2.0", "id": 7, "result": {}})).unwrap();
+    assert!(response.method.is_none());
+    assert!(!response.is_notification());
+}
+
+#[test]
+fn test_response_serialization() {
+    let ok = serde_json::to_value(Response::success(json!(1), json!({}))).unwrap();
+    assert_eq!(ok, json!({"jsonrpc": "2.0", "id": 1, "result": {}}));
+
+    let err = serde_json::to_value(Response::error(json!("a"), METHOD_NOT_FOUND, "nope")).unwrap();
+    assert_eq!(
+        err,
+        json!({"jsonrpc": "2.0", "id": "a", "error": {"code": -32601, "message": "nope"}})
+    );
+}
+
+#[test]
+fn test_tool_result_serialization() {
+    let result = CallToolResult::text("done").with_image("aGk=".to_string(), "image/png");
+    assert_eq!(
+        serde_json::to_value(result).unwrap(),
+        json!({
+            "content": [
+                {"type": "text", "text": "done"},
+                {"type": "image", "data": "aGk=", "mimeType": "image/png"}
+            ],
+            "isError": false
+        })
+    );
+    assert!(CallToolResult::error("failed").is_error);
+}
+
+#[test]
+fn test_negotiate_version() {
+    assert_eq!(negotiate_version(Some("2024-11-05")), "2024-11-05");
+    assert_eq!(negotiate_version(Some("1999-01-01")), PROTOCOL_VERSION);
+    assert_eq!(negotiate_version(None), PROTOCOL_VERSION);
+}
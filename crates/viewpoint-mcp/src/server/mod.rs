@@ -0,0 +1,198 @@
+//! MCP server loop over newline-delimited JSON-RPC.
+
+#[cfg(test)]
+mod tests;
+
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+use tracing::{debug, info, instrument, warn};
+
+use crate::error::McpError;
+use crate::protocol::{
+    CallToolResult, INTERNAL_ERROR, INVALID_PARAMS, INVALID_REQUEST, METHOD_NOT_FOUND, Message,
+    PARSE_ERROR, Response, negotiate_version,
+};
+use crate::session::{BrowserSession, SessionOptions};
+use crate::tools;
+
+/// Name reported in `serverInfo`.
+pub const SERVER_NAME: &str = "viewpoint-mcp";
+
+/// Instructions returned from `initialize` to guide the model.
+const INSTRUCTIONS: &str = "Call browser_snapshot to see the page as an accessibility tree. \
+    Each element has a ref such as c0p0f0e3; pass it to browser_click, browser_fill and other \
+    element tools. Refs are only valid until the next snapshot of the same tab.";
+
+/// Parameters of a `tools/call` request.
+#[derive(Debug, Deserialize)]
+struct CallToolParams {
+    name: String,
+    #[serde(default)]
+    arguments: Value,
+}
+
+/// A Model Context Protocol server backed by a Viewpoint browser.
+///
+/// # Example
+///
+/// ```no_run
+/// use viewpoint_mcp::{McpServer, SessionOptions};
+///
+/// # async fn example() -> Result<(), viewpoint_mcp::McpError> {
+/// let mut server = McpServer::new(SessionOptions::new().headless(true));
+/// server.serve_stdio().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct McpServer {
+    session: BrowserSession,
+}
+
+impl McpServer {
+    /// Create a server. The browser is launched on the first tool call that needs it.
+    pub fn new(options: SessionOptions) -> Self {
+        Self {
+            session: BrowserSession::new(options),
+        }
+    }
+
+    /// Get the browser session.
+    pub fn session(&mut self) -> &mut BrowserSession {
+        &mut self.session
+    }
+
+    /// Serve requests on stdin/stdout until stdin closes.
+    pub async fn serve_stdio(&mut self) -> Result<(), McpError> {
+        let stdin = tokio::io::BufReader::new(tokio::io::stdin());
+        self.serve(stdin, tokio::io::stdout()).await
+    }
+
+    /// Serve newline-delimited JSON-RPC messages until `reader` reaches EOF.
+    ///
+    /// The browser is closed when the client disconnects.
+    pub async fn serve<R, W>(&mut self, reader: R, mut writer: W) -> Result<(), McpError>
+    where
+        R: AsyncBufRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        info!("MCP server started");
+        let mut lines = reader.lines();
+        let result = async {
+            while let Some(line) = lines.next_line().await? {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                if let Some(response) = self.handle_message(&line).await {
+                    let mut bytes = serde_json::to_vec(&response)?;
+                    bytes.push(b'\n');
+                    writer.write_all(&bytes).await?;
+                    writer.flush().await?;
+                }
+            }
+            Ok(())
+        }
+        .await;
+
+        info!("Client disconnected");
+        self.session.close().await;
+        result
+    }
+
+    /// Handle one JSON-RPC message and return the response, if any.
+    ///
+    /// Notifications and responses from the client produce no output.
+    pub async fn handle_message(&mut self, message: &str) -> Option<Response> {
+        let value: Value = match serde_json::from_str(message) {
+            Ok(value) => value,
+            Err(e) => {
+                return Some(Response::error(
+                    Value::Null,
+                    PARSE_ERROR,
+                    format!("Parse error: {e}"),
+                ));
+            }
+        };
+        let message: Message = match serde_json::from_value(value) {
+            Ok(message) => message,
+            Err(e) => {
+                return Some(Response::error(
+                    Value::Null,
+                    INVALID_REQUEST,
+                    format!("Invalid request: {e}"),
+                ));
+            }
+        };
+
+        let Some(ref method) = message.method else {
+            debug!("Ignoring response from client");
+            return None;
+        };
+        let Some(id) = message.id.clone() else {
+            debug!(method = %method, "Received notification");
+            return None;
+        };
+
+        Some(self.handle_request(id, method, message.params).await)
+    }
+
+    #[instrument(level = "debug", skip(self, id, params))]
+    async fn handle_request(&mut self, id: Value, method: &str, params: Option<Value>) -> Response {
+        match method {
+            "initialize" => {
+                let requested = params
+                    .as_ref()
+                    .and_then(|p| p.get("protocolVersion"))
+                    .and_then(Value::as_str);
+                Response::success(
+                    id,
+                    json!({
+                        "protocolVersion": negotiate_version(requested),
+                        "capabilities": { "tools": { "listChanged": false } },
+                        "serverInfo": {
+                            "name": SERVER_NAME,
+                            "version": env!("CARGO_PKG_VERSION")
+                        },
+                        "instructions": INSTRUCTIONS
+                    }),
+                )
+            }
+            "ping" => Response::success(id, json!({})),
+            "tools/list" => Response::success(id, json!({ "tools": tools::definitions() })),
+            "tools/call" => self.call_tool(id, params).await,
+            _ => Response::error(id, METHOD_NOT_FOUND, format!("Method not found: {method}")),
+        }
+    }
+
+    async fn call_tool(&mut self, id: Value, params: Option<Value>) -> Response {
+        let params: CallToolParams = match serde_json::from_value(params.unwrap_or(Value::Null)) {
+            Ok(params) => params,
+            Err(e) => {
+                return Response::error(id, INVALID_PARAMS, format!("Invalid params: {e}"));
+            }
+        };
+
+        // Boxed because the browser futures behind the tools are deeply nested.
+        let call = Box::pin(tools::call(
+            &mut self.session,
+            &params.name,
+            params.arguments,
+        ));
+        let result = match call.await {
+            Ok(result) => result,
+            Err(e @ (McpError::UnknownTool(_) | McpError::InvalidParams(_))) => {
+                return Response::error(id, INVALID_PARAMS, e.to_string());
+            }
+            Err(e) => {
+                warn!(tool = %params.name, error = %e, "Tool failed");
+                CallToolResult::error(e.to_string())
+            }
+        };
+
+        match serde_json::to_value(result) {
+            Ok(value) => Response::success(id, value),
+            Err(e) => Response::error(id, INTERNAL_ERROR, e.to_string()),
+        }
+    }
+}
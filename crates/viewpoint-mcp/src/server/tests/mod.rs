@@ -0,0 +1,140 @@
+use serde_json::json;
+
+use super::*;
+
+fn server() -> McpServer {
+    McpServer::new(SessionOptions::new())
+}
+
+async fn request(server: &mut McpServer, message: Value) -> Value {
+    let response = server
+        .handle_message(&message.to_string())
+        .await
+        .expect("request should be answered");
+    serde_json::to_value(response).unwrap()
+}
+
+#[tokio::test]
+async fn test_initialize() {
+    let mut server = server();
+    let response = request(
+        &mut server,
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-03-26",
+                "capabilities": {},
+                "clientInfo": { "name": "test", "version": "1.0" }
+            }
+        }),
+    )
+    .await;
+
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"]["protocolVersion"], "2025-03-26");
+    assert_eq!(response["result"]["serverInfo"]["name"], SERVER_NAME);
+    assert!(response["result"]["capabilities"]["tools"].is_object());
+}
+
+#[tokio::test]
+async fn test_tools_list() {
+    let mut server = server();
+    let response = request(
+        &mut server,
+        json!({ "jsonrpc": "2.0", "id": "list", "method": "tools/list" }),
+    )
+    .await;
+
+    let tools = response["result"]["tools"].as_array().unwrap();
+    assert_eq!(tools.len(), tools::definitions().len());
+    let click = tools
+        .iter()
+        .find(|tool| tool["name"] == "browser_click")
+        .unwrap();
+    assert_eq!(click["inputSchema"]["required"], json!(["ref"]));
+}
+
+#[tokio::test]
+async fn test_notifications_and_client_responses_are_silent() {
+    let mut server = server();
+    let notification = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+    let client_response = json!({ "jsonrpc": "2.0", "id": 3, "result": {} });
+
+    assert!(
+        server
+            .handle_message(&notification.to_string())
+            .await
+            .is_none()
+    );
+    assert!(
+        server
+            .handle_message(&client_response.to_string())
+            .await
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_protocol_errors() {
+    let mut server = server();
+
+    let parse = serde_json::to_value(server.handle_message("{not json").await.unwrap()).unwrap();
+    assert_eq!(parse["error"]["code"], PARSE_ERROR);
+    assert_eq!(parse["id"], Value::Null);
+
+    let unknown_method = request(
+        &mut server,
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "resources/list" }),
+    )
+    .await;
+    assert_eq!(unknown_method["error"]["code"], METHOD_NOT_FOUND);
+
+    let unknown_tool = request(
+        &mut server,
+        json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "tools/call",
+            "params": { "name": "browser_teleport", "arguments": {} }
+        }),
+    )
+    .await;
+    assert_eq!(unknown_tool["error"]["code"], INVALID_PARAMS);
+
+    let missing_name = request(
+        &mut server,
+        json!({ "jsonrpc": "2.0", "id": 4, "method": "tools/call", "params": {} }),
+    )
+    .await;
+    assert_eq!(missing_name["error"]["code"], INVALID_PARAMS);
+}
+
+#[tokio::test]
+async fn test_serve_writes_one_line_per_response() {
+    let input = [
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+        json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "ping" }),
+    ]
+    .iter()
+    .map(ToString::to_string)
+    .collect::<Vec<_>>()
+    .join("\n\n");
+
+    let mut output = Vec::new();
+    server().serve(input.as_bytes(), &mut output).await.unwrap();
+
+    let responses: Vec<Value> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0]["id"], 1);
+    assert_eq!(
+        responses[1],
+        json!({ "jsonrpc": "2.0", "id": 2, "result": {} })
+    );
+}
@@ -0,0 +1,276 @@
+//! Browser session shared by all tool calls.
+//!
+//! The browser is launched lazily on the first tool call that needs a page,
+//! so an MCP client can list tools without starting Chromium.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+use viewpoint_core::{Browser, BrowserContext, Page, parse_ref};
+
+use crate::error::McpError;
+use crate::network_log::NetworkLog;
+
+/// Default browser launch timeout.
+const DEFAULT_LAUNCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Options for the browser launched by the server.
+#[derive(Debug, Clone)]
+pub struct SessionOptions {
+    /// Run the browser without a visible window.
+    pub headless: bool,
+    /// Chromium executable to launch instead of auto-detection.
+    pub executable_path: Option<PathBuf>,
+    /// Viewport size for new pages.
+    pub viewport: Option<(i32, i32)>,
+    /// How long to wait for the browser to start.
+    pub launch_timeout: Duration,
+}
+
+impl Default for SessionOptions {
+    fn default() -> Self {
+        Self {
+            headless: true,
+            executable_path: None,
+            viewport: None,
+            launch_timeout: DEFAULT_LAUNCH_TIMEOUT,
+        }
+    }
+}
+
+impl SessionOptions {
+    /// Create default options (headless, auto-detected Chromium).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether the browser runs headless.
+    #[must_use]
+    pub fn headless(mut self, headless: bool) -> Self {
+        self.headless = headless;
+        self
+    }
+
+    /// Set the Chromium executable path.
+    #[must_use]
+    pub fn executable_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.executable_path = Some(path.into());
+        self
+    }
+
+    /// Set the viewport size for new pages.
+    #[must_use]
+    pub fn viewport(mut self, width: i32, height: i32) -> Self {
+        self.viewport = Some((width, height));
+        self
+    }
+
+    /// Set the browser launch timeout.
+    #[must_use]
+    pub fn launch_timeout(mut self, timeout: Duration) -> Self {
+        self.launch_timeout = timeout;
+        self
+    }
+}
+
+/// The browser, context and selected tab used by the server.
+#[derive(Debug)]
+pub struct BrowserSession {
+    options: SessionOptions,
+    browser: Option<Browser>,
+    context: Option<BrowserContext>,
+    /// Target ID of the selected tab.
+    selected: Option<String>,
+    network_log: NetworkLog,
+    network_task: Option<JoinHandle<()>>,
+}
+
+impl BrowserSession {
+    /// Create a session. No browser is launched until it is needed.
+    pub fn new(options: SessionOptions) -> Self {
+        Self {
+            options,
+            browser: None,
+            context: None,
+            selected: None,
+            network_log: NetworkLog::new(),
+            network_task: None,
+        }
+    }
+
+    /// Check if a browser is currently running.
+    pub fn is_running(&self) -> bool {
+        self.context.is_some()
+    }
+
+    /// The network request log for all tabs.
+    pub fn network_log(&self) -> &NetworkLog {
+        &self.network_log
+    }
+
+    /// Get the browser context, launching the browser if necessary.
+    pub async fn context(&mut self) -> Result<&BrowserContext, McpError> {
+        if self.context.is_none() {
+            self.launch().await?;
+        }
+        self.context
+            .as_ref()
+            .ok_or_else(|| McpError::InvalidState("Browser is not running".to_string()))
+    }
+
+    /// All open tabs, opening a blank one if there are none.
+    pub async fn tabs(&mut self) -> Result<Vec<Page>, McpError> {
+        let context = self.context().await?;
+        let mut pages = context.pages().await?;
+        if pages.is_empty() {
+            pages.push(context.new_page().await?);
+        }
+        Ok(pages)
+    }
+
+    /// Position of the selected tab in `pages`.
+    ///
+    /// Falls back to the most recently opened tab if the selected one was closed.
+    pub fn selected_index(&self, pages: &[Page]) -> usize {
+        self.selected
+            .as_deref()
+            .and_then(|target_id| pages.iter().position(|p| p.target_id() == target_id))
+            .unwrap_or_else(|| pages.len().saturating_sub(1))
+    }
+
+    /// The selected tab.
+    pub async fn current_page(&mut self) -> Result<Page, McpError> {
+        let mut pages = self.tabs().await?;
+        let index = self.selected_index(&pages);
+        let page = pages.swap_remove(index);
+        self.selected = Some(page.target_id().to_string());
+        Ok(page)
+    }
+
+    /// The tab an ARIA snapshot ref belongs to.
+    ///
+    /// The ref's context, tab and frame must all still exist, and the ref
+    /// must come from the tab's latest snapshot.
+    pub async fn page_for_ref(&mut self, node_ref: &str) -> Result<Page, McpError> {
+        let parsed = parse_ref(node_ref)?;
+        let stale = |what: &str| {
+            McpError::InvalidState(format!(
+                "Ref '{node_ref}' belongs to {what}; take a new snapshot"
+            ))
+        };
+
+        let context_index = self.context().await?.index();
+        if parsed.context_index != context_index {
+            return Err(stale("a browser context that is no longer open"));
+        }
+
+        let page = self
+            .tabs()
+            .await?
+            .into_iter()
+            .find(|page| page.index() == parsed.page_index)
+            .ok_or_else(|| stale("a tab that is no longer open"))?;
+
+        let frames = page.frames().await?;
+        if !frames
+            .iter()
+            .any(|frame| frame.frame_index() == parsed.frame_index)
+        {
+            return Err(stale("a frame that is no longer attached"));
+        }
+
+        if page.get_backend_node_id_for_ref(node_ref).is_err() {
+            return Err(stale("an older snapshot"));
+        }
+
+        Ok(page)
+    }
+
+    /// Open a new tab and select it.
+    pub async fn new_tab(&mut self) -> Result<Page, McpError> {
+        let page = self.context().await?.new_page().await?;
+        self.selected = Some(page.target_id().to_string());
+        Ok(page)
+    }
+
+    /// Select the tab at `index` and bring it to the front.
+    pub async fn select_tab(&mut self, index: usize) -> Result<Page, McpError> {
+        let mut pages = self.tabs().await?;
+        if index >= pages.len() {
+            return Err(McpError::InvalidState(format!(
+                "Tab {index} does not exist ({} open)",
+                pages.len()
+            )));
+        }
+        let page = pages.swap_remove(index);
+        page.bring_to_front().await?;
+        self.selected = Some(page.target_id().to_string());
+        Ok(page)
+    }
+
+    /// Close the tab at `index`, or the selected tab if `None`.
+    pub async fn close_tab(&mut self, index: Option<usize>) -> Result<(), McpError> {
+        let mut pages = self.tabs().await?;
+        let index = index.unwrap_or_else(|| self.selected_index(&pages));
+        if index >= pages.len() {
+            return Err(McpError::InvalidState(format!(
+                "Tab {index} does not exist ({} open)",
+                pages.len()
+            )));
+        }
+        let mut page = pages.swap_remove(index);
+        page.close().await?;
+        if self.selected.as_deref() == Some(page.target_id()) {
+            self.selected = None;
+        }
+        Ok(())
+    }
+
+    /// Close the browser. The next tool call launches a fresh one.
+    pub async fn close(&mut self) {
+        if let Some(task) = self.network_task.take() {
+            task.abort();
+        }
+        self.network_log.clear();
+        self.selected = None;
+
+        if let Some(mut context) = self.context.take() {
+            if let Err(e) = context.close().await {
+                debug!("Failed to close context: {}", e);
+            }
+        }
+        if let Some(browser) = self.browser.take() {
+            info!("Closing browser");
+            if let Err(e) = browser.close().await {
+                warn!("Failed to close browser: {}", e);
+            }
+        }
+    }
+
+    async fn launch(&mut self) -> Result<(), McpError> {
+        info!(headless = self.options.headless, "Launching browser");
+
+        let mut builder = Browser::launch()
+            .headless(self.options.headless)
+            .timeout(self.options.launch_timeout);
+        if let Some(ref path) = self.options.executable_path {
+            builder = builder.executable_path(path);
+        }
+        let browser = builder.launch().await?;
+
+        // Attach before any page exists so the first navigation is logged.
+        self.network_task = Some(self.network_log.attach(browser.connection()));
+
+        let mut context_builder = browser.new_context_builder();
+        if let Some((width, height)) = self.options.viewport {
+            context_builder = context_builder.viewport(width, height);
+        }
+        let context = context_builder.build().await?;
+
+        self.browser = Some(browser);
+        self.context = Some(context);
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+//! Tools for navigation, snapshots, screenshots, tabs and the network log.
+
+use base64::Engine;
+use serde::Deserialize;
+use tracing::info;
+
+use super::page_state;
+use crate::error::McpError;
+use crate::protocol::CallToolResult;
+use crate::session::BrowserSession;
+
+/// Arguments for `browser_navigate`.
+#[derive(Debug, Deserialize)]
+pub(super) struct NavigateArgs {
+    pub url: String,
+}
+
+/// Arguments for `browser_take_screenshot`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ScreenshotArgs {
+    #[serde(rename = "ref", default)]
+    pub node_ref: Option<String>,
+    #[serde(default)]
+    pub element: Option<String>,
+    #[serde(default)]
+    pub full_page: bool,
+}
+
+/// Operation for `browser_tabs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum TabAction {
+    List,
+    New,
+    Select,
+    Close,
+}
+
+/// Arguments for `browser_tabs`.
+#[derive(Debug, Deserialize)]
+pub(super) struct TabsArgs {
+    pub action: TabAction,
+    #[serde(default)]
+    pub index: Option<usize>,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+pub(super) async fn navigate(
+    session: &mut BrowserSession,
+    args: NavigateArgs,
+) -> Result<CallToolResult, McpError> {
+    info!(url = %args.url, "Navigate");
+    let page = session.current_page().await?;
+    page.goto(&args.url).goto().await?;
+    Ok(CallToolResult::text(page_state(&page, true).await))
+}
+
+pub(super) async fn navigate_back(
+    session: &mut BrowserSession,
+) -> Result<CallToolResult, McpError> {
+    let page = session.current_page().await?;
+    if page.go_back().await?.is_none() {
+        return Ok(CallToolResult::error("No previous page in history"));
+    }
+    Ok(CallToolResult::text(page_state(&page, true).await))
+}
+
+pub(super) async fn snapshot(session: &mut BrowserSession) -> Result<CallToolResult, McpError> {
+    let page = session.current_page().await?;
+    let snapshot = page.aria_snapshot_with_frames().await?;
+    let mut text = page_state(&page, false).await;
+    text.push_str("- Page Snapshot:\n```yaml\n");
+    text.push_str(&snapshot.to_yaml());
+    text.push_str("```\n");
+    Ok(CallToolResult::text(text))
+}
+
+pub(super) async fn screenshot(
+    session: &mut BrowserSession,
+    args: ScreenshotArgs,
+) -> Result<CallToolResult, McpError> {
+    let (data, target) = if let Some(ref node_ref) = args.node_ref {
+        let page = session.page_for_ref(node_ref).await?;
+        let data = page
+            .locator_from_ref(node_ref)
+            .screenshot()
+            .capture()
+            .await?;
+        let target = args
+            .element
+            .unwrap_or_else(|| format!("element {node_ref}"));
+        (data, target)
+    } else {
+        let page = session.current_page().await?;
+        let data = page
+            .screenshot()
+            .full_page(args.full_page)
+            .capture()
+            .await?;
+        let target = if args.full_page {
+            "full page"
+        } else {
+            "viewport"
+        };
+        (data, target.to_string())
+    };
+
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
+    Ok(CallToolResult::text(format!("Screenshot of {target}")).with_image(encoded, "image/png"))
+}
+
+pub(super) async fn tabs(
+    session: &mut BrowserSession,
+    args: TabsArgs,
+) -> Result<CallToolResult, McpError> {
+    match args.action {
+        TabAction::List => {}
+        TabAction::New => {
+            let page = session.new_tab().await?;
+            if let Some(ref url) = args.url {
+                page.goto(url).goto().await?;
+            }
+        }
+        TabAction::Select => {
+            let index = args.index.ok_or_else(|| {
+                McpError::InvalidParams("`index` is required to select a tab".to_string())
+            })?;
+            session.select_tab(index).await?;
+        }
+        TabAction::Close => session.close_tab(args.index).await?,
+    }
+
+    let pages = session.tabs().await?;
+    let selected = session.selected_index(&pages);
+    let mut text = String::from("### Open tabs\n");
+    for (index, page) in pages.iter().enumerate() {
+        let marker = if index == selected { " (current)" } else { "" };
+        let title = page.title().await.unwrap_or_default();
+        let url = page.url().await.unwrap_or_default();
+        text.push_str(&format!("- {index}:{marker} [{title}]({url})\n"));
+    }
+
+    if args.action != TabAction::List {
+        text.push_str("\n### Current tab\n");
+        text.push_str(&page_state(&pages[selected], true).await);
+    }
+    Ok(CallToolResult::text(text))
+}
+
+pub(super) async fn network_requests(
+    session: &mut BrowserSession,
+) -> Result<CallToolResult, McpError> {
+    let page = session.current_page().await?;
+    let entries = session.network_log().entries_for(page.session_id());
+    if entries.is_empty() {
+        return Ok(CallToolResult::text(
+            "No requests recorded for the current tab",
+        ));
+    }
+
+    let lines: Vec<String> = entries.iter().map(ToString::to_string).collect();
+    Ok(CallToolResult::text(lines.join("\n")))
+}
+
+pub(super) async fn close(session: &mut BrowserSession) -> Result<CallToolResult, McpError> {
+    if !session.is_running() {
+        return Ok(CallToolResult::text("Browser is not running"));
+    }
+    session.close().await;
+    Ok(CallToolResult::text("Browser closed"))
+}
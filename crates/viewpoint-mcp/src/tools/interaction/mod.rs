@@ -0,0 +1,154 @@
+//! Tools that act on elements resolved from snapshot refs.
+
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::info;
+
+use super::page_state;
+use crate::error::McpError;
+use crate::protocol::CallToolResult;
+use crate::session::BrowserSession;
+
+/// Arguments for `browser_click`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ClickArgs {
+    #[serde(rename = "ref")]
+    pub node_ref: String,
+    #[serde(default)]
+    pub element: Option<String>,
+    #[serde(default)]
+    pub double_click: bool,
+}
+
+/// Arguments for `browser_hover`.
+#[derive(Debug, Deserialize)]
+pub(super) struct HoverArgs {
+    #[serde(rename = "ref")]
+    pub node_ref: String,
+    #[serde(default)]
+    pub element: Option<String>,
+}
+
+/// Arguments for `browser_fill`.
+#[derive(Debug, Deserialize)]
+pub(super) struct FillArgs {
+    #[serde(rename = "ref")]
+    pub node_ref: String,
+    pub text: String,
+    #[serde(default)]
+    pub element: Option<String>,
+    #[serde(default)]
+    pub submit: bool,
+}
+
+/// Arguments for `browser_select_option`.
+#[derive(Debug, Deserialize)]
+pub(super) struct SelectOptionArgs {
+    #[serde(rename = "ref")]
+    pub node_ref: String,
+    pub values: Vec<String>,
+    #[serde(default)]
+    pub element: Option<String>,
+}
+
+/// Arguments for `browser_press_key`.
+#[derive(Debug, Deserialize)]
+pub(super) struct PressKeyArgs {
+    pub key: String,
+}
+
+/// Arguments for `browser_evaluate`.
+#[derive(Debug, Deserialize)]
+pub(super) struct EvaluateArgs {
+    pub expression: String,
+    #[serde(rename = "ref", default)]
+    pub node_ref: Option<String>,
+    #[serde(default)]
+    pub element: Option<String>,
+}
+
+pub(super) async fn click(
+    session: &mut BrowserSession,
+    args: ClickArgs,
+) -> Result<CallToolResult, McpError> {
+    info!(node_ref = %args.node_ref, element = ?args.element, "Click");
+    let page = session.page_for_ref(&args.node_ref).await?;
+    let locator = page.locator_from_ref(&args.node_ref);
+    if args.double_click {
+        locator.dblclick().await?;
+    } else {
+        locator.click().await?;
+    }
+    Ok(CallToolResult::text(page_state(&page, true).await))
+}
+
+pub(super) async fn hover(
+    session: &mut BrowserSession,
+    args: HoverArgs,
+) -> Result<CallToolResult, McpError> {
+    info!(node_ref = %args.node_ref, element = ?args.element, "Hover");
+    let page = session.page_for_ref(&args.node_ref).await?;
+    page.locator_from_ref(&args.node_ref).hover().await?;
+    Ok(CallToolResult::text(page_state(&page, true).await))
+}
+
+pub(super) async fn fill(
+    session: &mut BrowserSession,
+    args: FillArgs,
+) -> Result<CallToolResult, McpError> {
+    info!(node_ref = %args.node_ref, element = ?args.element, "Fill");
+    let page = session.page_for_ref(&args.node_ref).await?;
+    let locator = page.locator_from_ref(&args.node_ref);
+    locator.fill(&args.text).await?;
+    if args.submit {
+        locator.press("Enter").await?;
+    }
+    Ok(CallToolResult::text(page_state(&page, true).await))
+}
+
+pub(super) async fn select_option(
+    session: &mut BrowserSession,
+    args: SelectOptionArgs,
+) -> Result<CallToolResult, McpError> {
+    info!(node_ref = %args.node_ref, element = ?args.element, "Select option");
+    if args.values.is_empty() {
+        return Err(McpError::InvalidParams(
+            "`values` must contain at least one option".to_string(),
+        ));
+    }
+    let page = session.page_for_ref(&args.node_ref).await?;
+    let values: Vec<&str> = args.values.iter().map(String::as_str).collect();
+    page.locator_from_ref(&args.node_ref)
+        .select_option()
+        .values(&values)
+        .await?;
+    Ok(CallToolResult::text(page_state(&page, true).await))
+}
+
+pub(super) async fn press_key(
+    session: &mut BrowserSession,
+    args: PressKeyArgs,
+) -> Result<CallToolResult, McpError> {
+    info!(key = %args.key, "Press key");
+    let page = session.current_page().await?;
+    page.keyboard().press(&args.key).await?;
+    Ok(CallToolResult::text(page_state(&page, true).await))
+}
+
+pub(super) async fn evaluate(
+    session: &mut BrowserSession,
+    args: EvaluateArgs,
+) -> Result<CallToolResult, McpError> {
+    info!(node_ref = ?args.node_ref, element = ?args.element, "Evaluate");
+    let result: Value = if let Some(ref node_ref) = args.node_ref {
+        let page = session.page_for_ref(node_ref).await?;
+        page.locator_from_ref(node_ref)
+            .evaluate(&args.expression)
+            .await?
+    } else {
+        let page = session.current_page().await?;
+        page.evaluate(&args.expression).await?
+    };
+    Ok(CallToolResult::text(serde_json::to_string_pretty(&result)?))
+}
@@ -0,0 +1,84 @@
+//! MCP tools for driving the browser.
+//!
+//! Element tools take refs from `browser_snapshot` (format
+//! `c{ctx}p{page}f{frame}e{n}`) and resolve them with
+//! [`Page::locator_from_ref`], so agents never have to write selectors.
+
+mod browsing;
+mod interaction;
+mod schema;
+
+#[cfg(test)]
+mod tests;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use viewpoint_core::Page;
+
+use crate::error::McpError;
+use crate::protocol::CallToolResult;
+use crate::session::BrowserSession;
+
+pub use schema::definitions;
+
+/// Run a tool by name.
+///
+/// Argument errors and unknown tools are returned as [`McpError`] before the
+/// browser is touched; failures while running the tool are returned as
+/// [`McpError`] too and reported to the client as tool errors.
+pub async fn call(
+    session: &mut BrowserSession,
+    name: &str,
+    arguments: Value,
+) -> Result<CallToolResult, McpError> {
+    match name {
+        "browser_navigate" => browsing::navigate(session, parse_args(arguments)?).await,
+        "browser_navigate_back" => browsing::navigate_back(session).await,
+        "browser_snapshot" => browsing::snapshot(session).await,
+        "browser_click" => interaction::click(session, parse_args(arguments)?).await,
+        "browser_hover" => interaction::hover(session, parse_args(arguments)?).await,
+        "browser_fill" => interaction::fill(session, parse_args(arguments)?).await,
+        "browser_select_option" => {
+            interaction::select_option(session, parse_args(arguments)?).await
+        }
+        "browser_press_key" => interaction::press_key(session, parse_args(arguments)?).await,
+        "browser_take_screenshot" => browsing::screenshot(session, parse_args(arguments)?).await,
+        "browser_evaluate" => interaction::evaluate(session, parse_args(arguments)?).await,
+        "browser_tabs" => browsing::tabs(session, parse_args(arguments)?).await,
+        "browser_network_requests" => browsing::network_requests(session).await,
+        "browser_close" => browsing::close(session).await,
+        _ => Err(McpError::UnknownTool(name.to_string())),
+    }
+}
+
+/// Deserialize tool arguments, treating missing arguments as an empty object.
+fn parse_args<T: DeserializeOwned>(arguments: Value) -> Result<T, McpError> {
+    let arguments = if arguments.is_null() {
+        Value::Object(serde_json::Map::new())
+    } else {
+        arguments
+    };
+    serde_json::from_value(arguments).map_err(|e| McpError::InvalidParams(e.to_string()))
+}
+
+/// Describe a page for the model: URL, title and optionally a fresh snapshot.
+///
+/// A snapshot that fails (e.g. because the page is mid-navigation) is reported
+/// in the text instead of failing the tool, since the action itself succeeded.
+async fn page_state(page: &Page, include_snapshot: bool) -> String {
+    let url = page.url().await.unwrap_or_default();
+    let title = page.title().await.unwrap_or_default();
+    let mut text = format!("- Page URL: {url}\n- Page Title: {title}\n");
+
+    if include_snapshot {
+        match page.aria_snapshot_with_frames().await {
+            Ok(snapshot) => {
+                text.push_str("- Page Snapshot:\n```yaml\n");
+                text.push_str(&snapshot.to_yaml());
+                text.push_str("```\n");
+            }
+            Err(e) => text.push_str(&format!("- Page Snapshot: unavailable ({e})\n")),
+        }
+    }
+    text
+}
@@ -0,0 +1,169 @@
+//! Tool definitions advertised through `tools/list`.
+
+use serde_json::{Value, json};
+
+use crate::protocol::ToolDefinition;
+
+/// Description shared by every `ref` argument.
+const REF_DESCRIPTION: &str = "Exact element ref from the latest page snapshot, e.g. `c0p0f0e3`";
+
+/// Description shared by every `element` argument.
+const ELEMENT_DESCRIPTION: &str =
+    "Human-readable element description, used only for logging and permission prompts";
+
+/// All tools exposed by the server.
+pub fn definitions() -> Vec<ToolDefinition> {
+    vec![
+        ToolDefinition {
+            name: "browser_navigate",
+            description: "Navigate the current tab to a URL",
+            input_schema: object(
+                json!({ "url": { "type": "string", "description": "The URL to navigate to" } }),
+                &["url"],
+            ),
+        },
+        ToolDefinition {
+            name: "browser_navigate_back",
+            description: "Go back to the previous page in the current tab",
+            input_schema: object(json!({}), &[]),
+        },
+        ToolDefinition {
+            name: "browser_snapshot",
+            description: "Capture an accessibility snapshot of the current tab, including \
+                          iframes. Every element has a ref that other tools accept",
+            input_schema: object(json!({}), &[]),
+        },
+        ToolDefinition {
+            name: "browser_click",
+            description: "Click an element from the page snapshot",
+            input_schema: object(
+                json!({
+                    "element": { "type": "string", "description": ELEMENT_DESCRIPTION },
+                    "ref": { "type": "string", "description": REF_DESCRIPTION },
+                    "doubleClick": { "type": "boolean", "description": "Double-click instead of a single click" }
+                }),
+                &["ref"],
+            ),
+        },
+        ToolDefinition {
+            name: "browser_hover",
+            description: "Hover over an element from the page snapshot",
+            input_schema: object(
+                json!({
+                    "element": { "type": "string", "description": ELEMENT_DESCRIPTION },
+                    "ref": { "type": "string", "description": REF_DESCRIPTION }
+                }),
+                &["ref"],
+            ),
+        },
+        ToolDefinition {
+            name: "browser_fill",
+            description: "Replace the value of an editable element from the page snapshot",
+            input_schema: object(
+                json!({
+                    "element": { "type": "string", "description": ELEMENT_DESCRIPTION },
+                    "ref": { "type": "string", "description": REF_DESCRIPTION },
+                    "text": { "type": "string", "description": "Text to fill in" },
+                    "submit": { "type": "boolean", "description": "Press Enter after filling" }
+                }),
+                &["ref", "text"],
+            ),
+        },
+        ToolDefinition {
+            name: "browser_select_option",
+            description: "Select options in a dropdown from the page snapshot",
+            input_schema: object(
+                json!({
+                    "element": { "type": "string", "description": ELEMENT_DESCRIPTION },
+                    "ref": { "type": "string", "description": REF_DESCRIPTION },
+                    "values": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Option values or labels to select"
+                    }
+                }),
+                &["ref", "values"],
+            ),
+        },
+        ToolDefinition {
+            name: "browser_press_key",
+            description: "Press a key or key combination in the current tab",
+            input_schema: object(
+                json!({
+                    "key": {
+                        "type": "string",
+                        "description": "Key name such as `Enter`, `ArrowLeft`, `a` or `Control+A`"
+                    }
+                }),
+                &["key"],
+            ),
+        },
+        ToolDefinition {
+            name: "browser_take_screenshot",
+            description: "Take a PNG screenshot of the current tab or of one element",
+            input_schema: object(
+                json!({
+                    "element": { "type": "string", "description": ELEMENT_DESCRIPTION },
+                    "ref": { "type": "string", "description": "Ref of the element to capture instead of the viewport" },
+                    "fullPage": { "type": "boolean", "description": "Capture the full scrollable page" }
+                }),
+                &[],
+            ),
+        },
+        ToolDefinition {
+            name: "browser_evaluate",
+            description: "Evaluate JavaScript in the current tab and return the JSON result",
+            input_schema: object(
+                json!({
+                    "expression": {
+                        "type": "string",
+                        "description": "Expression or function, e.g. `() => document.title`. \
+                                        With `ref`, the element is available as `element`"
+                    },
+                    "element": { "type": "string", "description": ELEMENT_DESCRIPTION },
+                    "ref": { "type": "string", "description": "Ref of the element to evaluate against" }
+                }),
+                &["expression"],
+            ),
+        },
+        ToolDefinition {
+            name: "browser_tabs",
+            description: "List, open, select or close browser tabs",
+            input_schema: object(
+                json!({
+                    "action": {
+                        "type": "string",
+                        "enum": ["list", "new", "select", "close"],
+                        "description": "Operation to perform"
+                    },
+                    "index": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "description": "Tab index for `select` and `close`; `close` defaults to the current tab"
+                    },
+                    "url": { "type": "string", "description": "URL to open in the new tab for `new`" }
+                }),
+                &["action"],
+            ),
+        },
+        ToolDefinition {
+            name: "browser_network_requests",
+            description: "List the network requests made by the current tab",
+            input_schema: object(json!({}), &[]),
+        },
+        ToolDefinition {
+            name: "browser_close",
+            description: "Close the browser. The next tool call starts a new one",
+            input_schema: object(json!({}), &[]),
+        },
+    ]
+}
+
+fn object(properties: Value, required: &[&str]) -> Value {
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false
+    })
+}
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use serde_json::json;
+
+use super::*;
+use crate::session::SessionOptions;
+
+#[test]
+fn test_definitions_are_well_formed() {
+    let tools = definitions();
+    let names: HashSet<_> = tools.iter().map(|tool| tool.name).collect();
+    assert_eq!(names.len(), tools.len(), "tool names must be unique");
+
+    for tool in &tools {
+        assert!(tool.name.starts_with("browser_"));
+        let schema = &tool.input_schema;
+        assert_eq!(schema["type"], "object");
+        for required in schema["required"].as_array().unwrap() {
+            let required = required.as_str().unwrap();
+            assert!(
+                schema["properties"].get(required).is_some(),
+                "{} requires undeclared property {required}",
+                tool.name
+            );
+        }
+    }
+
+    for name in [
+        "browser_navigate",
+        "browser_snapshot",
+        "browser_click",
+        "browser_fill",
+        "browser_select_option",
+        "browser_take_screenshot",
+        "browser_evaluate",
+        "browser_tabs",
+        "browser_network_requests",
+    ] {
+        assert!(names.contains(name), "missing {name}");
+    }
+}
+
+#[tokio::test]
+async fn test_unknown_tool() {
+    let mut session = BrowserSession::new(SessionOptions::new());
+    let result = call(&mut session, "browser_teleport", json!({})).await;
+    assert!(matches!(result, Err(McpError::UnknownTool(name)) if name == "browser_teleport"));
+}
+
+#[tokio::test]
+async fn test_invalid_arguments_do_not_launch_browser() {
+    let mut session = BrowserSession::new(SessionOptions::new());
+
+    for (tool, arguments) in [
+        ("browser_click", json!({})),
+        ("browser_fill", json!({ "ref": "c0p0f0e1" })),
+        ("browser_navigate", json!({ "url": 42 })),
+        (
+            "browser_select_option",
+            json!({ "ref": "c0p0f0e1", "values": [] }),
+        ),
+        ("browser_tabs", json!({ "action": "select" })),
+        ("browser_tabs", json!({ "action": "rename" })),
+    ] {
+        let result = call(&mut session, tool, arguments).await;
+        assert!(
+            matches!(result, Err(McpError::InvalidParams(_))),
+            "{tool} should reject its arguments"
+        );
+    }
+
+    assert!(!session.is_running());
+}
+
+#[tokio::test]
+async fn test_close_without_browser() {
+    let mut session = BrowserSession::new(SessionOptions::new());
+    let result = call(&mut session, "browser_close", Value::Null)
+        .await
+        .unwrap();
+    assert_eq!(result, CallToolResult::text("Browser is not running"));
+}
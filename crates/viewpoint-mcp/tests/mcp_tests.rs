@@ -0,0 +1,200 @@
+#![cfg(feature = "integration")]
+//! End-to-end tests driving a real browser through MCP tool calls.
+
+use serde_json::{Value, json};
+use viewpoint_mcp::{McpServer, SessionOptions};
+
+const FORM_PAGE: &str = "data:text/html,<title>Form</title>\
+    <input aria-label='Name'>\
+    <select aria-label='Size'><option value='s'>Small</option><option value='l'>Large</option></select>\
+    <button onclick=\"document.title='Clicked'\">Go</button>";
+
+fn init_tracing() {
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_test_writer()
+        .try_init()
+        .ok();
+}
+
+/// Call a tool and return its text content, asserting it succeeded.
+async fn call_tool(server: &mut McpServer, name: &str, arguments: Value) -> String {
+    let message = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": { "name": name, "arguments": arguments }
+    });
+    let response = server.handle_message(&message.to_string()).await.unwrap();
+    let result = response.result.unwrap_or_else(|| {
+        panic!("{name} returned a protocol error: {:?}", response.error);
+    });
+    assert_eq!(result["isError"], false, "{name} failed: {result}");
+    result["content"][0]["text"].as_str().unwrap().to_string()
+}
+
+/// Find the ref of the first snapshot line containing `needle`.
+fn find_ref(snapshot: &str, needle: &str) -> String {
+    let line = snapshot
+        .lines()
+        .find(|line| line.contains(needle))
+        .unwrap_or_else(|| panic!("no '{needle}' in snapshot:\n{snapshot}"));
+    let start = line.find("[ref=").unwrap() + "[ref=".len();
+    let end = start + line[start..].find(']').unwrap();
+    line[start..end].to_string()
+}
+
+#[tokio::test]
+async fn test_mcp_interacts_by_ref() {
+    init_tracing();
+    let mut server = McpServer::new(SessionOptions::new());
+
+    let navigated = call_tool(&mut server, "browser_navigate", json!({ "url": FORM_PAGE })).await;
+    assert!(navigated.contains("- Page Title: Form"));
+
+    let snapshot = call_tool(&mut server, "browser_snapshot", json!({})).await;
+    let name_ref = find_ref(&snapshot, "textbox \"Name\"");
+    let size_ref = find_ref(&snapshot, "combobox \"Size\"");
+    let button_ref = find_ref(&snapshot, "button \"Go\"");
+
+    call_tool(
+        &mut server,
+        "browser_fill",
+        json!({ "ref": name_ref, "text": "Ada" }),
+    )
+    .await;
+    call_tool(
+        &mut server,
+        "browser_select_option",
+        json!({ "ref": size_ref, "values": ["l"] }),
+    )
+    .await;
+    let clicked = call_tool(&mut server, "browser_click", json!({ "ref": button_ref })).await;
+    assert!(clicked.contains("- Page Title: Clicked"));
+
+    let values = call_tool(
+        &mut server,
+        "browser_evaluate",
+        json!({ "expression": "() => [document.querySelector('input').value, document.querySelector('select').value]" }),
+    )
+    .await;
+    assert_eq!(
+        serde_json::from_str::<Value>(&values).unwrap(),
+        json!(["Ada", "l"])
+    );
+
+    let message = json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": { "name": "browser_take_screenshot", "arguments": {} }
+    });
+    let response = server.handle_message(&message.to_string()).await.unwrap();
+    let content = &response.result.unwrap()["content"];
+    assert_eq!(content[1]["type"], "image");
+    assert_eq!(content[1]["mimeType"], "image/png");
+
+    call_tool(&mut server, "browser_close", json!({})).await;
+}
+
+#[tokio::test]
+async fn test_mcp_tabs_and_network_log() {
+    init_tracing();
+    let mut server = McpServer::new(SessionOptions::new());
+
+    call_tool(
+        &mut server,
+        "browser_navigate",
+        json!({ "url": "https://example.com/" }),
+    )
+    .await;
+    let requests = call_tool(&mut server, "browser_network_requests", json!({})).await;
+    assert!(requests.contains("[GET] https://example.com/"));
+
+    let tabs = call_tool(
+        &mut server,
+        "browser_tabs",
+        json!({ "action": "new", "url": FORM_PAGE }),
+    )
+    .await;
+    assert!(tabs.contains("- 1: (current) [Form]"));
+
+    // Refs from the second tab resolve against that tab even after switching back
+    let snapshot = call_tool(&mut server, "browser_snapshot", json!({})).await;
+    let button_ref = find_ref(&snapshot, "button \"Go\"");
+    call_tool(
+        &mut server,
+        "browser_tabs",
+        json!({ "action": "select", "index": 0 }),
+    )
+    .await;
+    call_tool(&mut server, "browser_click", json!({ "ref": button_ref })).await;
+
+    let tabs = call_tool(&mut server, "browser_tabs", json!({ "action": "list" })).await;
+    assert!(tabs.contains("- 0: (current)"));
+    assert!(tabs.contains("[Clicked]"));
+
+    let tabs = call_tool(
+        &mut server,
+        "browser_tabs",
+        json!({ "action": "close", "index": 1 }),
+    )
+    .await;
+    assert!(!tabs.contains("- 1:"));
+
+    call_tool(&mut server, "browser_close", json!({})).await;
+}
+
+/// Call a tool that is expected to fail and return its error text.
+async fn call_tool_error(server: &mut McpServer, name: &str, arguments: Value) -> String {
+    let message = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": { "name": name, "arguments": arguments }
+    });
+    let response = server.handle_message(&message.to_string()).await.unwrap();
+    let result = response.result.unwrap_or_else(|| {
+        panic!("{name} returned a protocol error: {:?}", response.error);
+    });
+    assert_eq!(result["isError"], true, "{name} should fail: {result}");
+    result["content"][0]["text"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_mcp_resolves_ref_context_and_frame() {
+    init_tracing();
+    let mut server = McpServer::new(SessionOptions::new());
+
+    let page = "data:text/html,<title>Outer</title>\
+        <iframe srcdoc=\"<button onclick='parent.document.title=&quot;Framed&quot;'>Inside</button>\"></iframe>";
+    call_tool(&mut server, "browser_navigate", json!({ "url": page })).await;
+
+    let snapshot = call_tool(&mut server, "browser_snapshot", json!({})).await;
+    let button_ref = find_ref(&snapshot, "button \"Inside\"");
+    assert!(
+        button_ref.contains("f1"),
+        "expected a child frame ref: {button_ref}"
+    );
+
+    let clicked = call_tool(&mut server, "browser_click", json!({ "ref": button_ref })).await;
+    assert!(clicked.contains("- Page Title: Framed"));
+
+    // A ref naming another context is rejected
+    let foreign_ref = button_ref.replacen("c0", "c9", 1);
+    let error = call_tool_error(&mut server, "browser_click", json!({ "ref": foreign_ref })).await;
+    assert!(error.contains("browser context"), "{error}");
+
+    // A ref into a frame that has since gone away is stale
+    call_tool(
+        &mut server,
+        "browser_evaluate",
+        json!({ "expression": "() => document.querySelector('iframe').remove()" }),
+    )
+    .await;
+    call_tool(&mut server, "browser_snapshot", json!({})).await;
+    let error = call_tool_error(&mut server, "browser_click", json!({ "ref": button_ref })).await;
+    assert!(error.contains("take a new snapshot"), "{error}");
+
+    call_tool(&mut server, "browser_close", json!({})).await;
+}
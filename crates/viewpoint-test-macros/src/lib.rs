@@ -159,6 +159,8 @@
 //! | `scope` | string | - | Fixture scope: `"browser"` or `"context"` |
 //! | `browser` | string | - | Function name returning shared browser (required when scope = "browser") |
 //! | `context` | string | - | Function name returning shared context (required when scope = "context") |
+//! | `base_url` | string | - | Base URL for relative navigation and URL patterns |
+//! | `base_url_env` | string | - | Environment variable overriding `base_url` when set |
 //!
 //! ## When to Use TestHarness Instead
 //!
@@ -212,6 +214,8 @@ mod test_attr;
 /// - `scope = "browser"|"context"` - Fixture scoping level
 /// - `browser = "<fn_name>"` - Function returning shared browser (required when scope = "browser")
 /// - `context = "<fn_name>"` - Function returning shared context (required when scope = "context")
+/// - `base_url = "<url>"` - Base URL for relative navigation and URL patterns
+/// - `base_url_env = "<VAR>"` - Environment variable overriding `base_url` when set
 #[proc_macro_attribute]
 pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
     let args = parse_macro_input!(attr as test_attr::TestArgs);
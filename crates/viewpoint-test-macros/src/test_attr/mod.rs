@@ -16,6 +16,8 @@ pub struct TestArgs {
     pub scope: Option<String>,
     pub browser: Option<String>,
     pub context: Option<String>,
+    pub base_url: Option<String>,
+    pub base_url_env: Option<String>,
 }
 
 impl Parse for TestArgs {
@@ -45,6 +47,12 @@ impl Parse for TestArgs {
                 "context" => {
                     args.context = Some(kv.value_string()?);
                 }
+                "base_url" => {
+                    args.base_url = Some(kv.value_string()?);
+                }
+                "base_url_env" => {
+                    args.base_url_env = Some(kv.value_string()?);
+                }
                 other => {
                     return Err(Error::new(
                         kv.key.span(),
@@ -222,22 +230,25 @@ fn extract_type_name(ty: &Type) -> Result<String> {
 
 fn validate_scope_args(args: &TestArgs) -> Result<()> {
     match args.scope.as_deref() {
-        Some("browser") => {
-            if args.browser.is_none() {
-                return Err(Error::new(
-                    proc_macro2::Span::call_site(),
-                    "scope = \"browser\" requires browser = \"<function_name>\" to specify the shared browser source",
-                ));
-            }
+        Some("browser") if args.browser.is_none() => {
+            return Err(Error::new(
+                proc_macro2::Span::call_site(),
+                "scope = \"browser\" requires browser = \"<function_name>\" to specify the shared browser source",
+            ));
         }
-        Some("context") => {
-            if args.context.is_none() {
-                return Err(Error::new(
-                    proc_macro2::Span::call_site(),
-                    "scope = \"context\" requires context = \"<function_name>\" to specify the shared context source",
-                ));
-            }
+        Some("context") if args.context.is_none() => {
+            return Err(Error::new(
+                proc_macro2::Span::call_site(),
+                "scope = \"context\" requires context = \"<function_name>\" to specify the shared context source",
+            ));
+        }
+        Some("context") if args.base_url.is_some() || args.base_url_env.is_some() => {
+            return Err(Error::new(
+                proc_macro2::Span::call_site(),
+                "base_url cannot be set with scope = \"context\"; set it on the shared context instead",
+            ));
         }
+        Some("browser" | "context") => {}
         Some(other) => {
             return Err(Error::new(
                 proc_macro2::Span::call_site(),
@@ -253,12 +264,26 @@ fn validate_scope_args(args: &TestArgs) -> Result<()> {
 fn generate_harness_setup(args: &TestArgs) -> Result<TokenStream> {
     let headless = args.headless.unwrap_or(true);
     let timeout_ms = args.timeout.unwrap_or(30000);
+    let base_url = args.base_url.as_ref().map(|url| quote! { .base_url(#url) });
+    let base_url_env = args
+        .base_url_env
+        .as_ref()
+        .map(|var| quote! { .base_url_from_env(#var) });
 
     match args.scope.as_deref() {
         Some("browser") => {
             let browser_fn: Ident = syn::parse_str(args.browser.as_ref().unwrap())?;
             Ok(quote! {
-                ::viewpoint_test::TestHarness::from_browser(#browser_fn().await).await?
+                ::viewpoint_test::TestHarness::from_browser_with_config(
+                    #browser_fn().await,
+                    ::viewpoint_test::TestConfig::builder()
+                        .headless(#headless)
+                        .timeout(::std::time::Duration::from_millis(#timeout_ms))
+                        #base_url
+                        #base_url_env
+                        .build(),
+                )
+                .await?
             })
         }
         Some("context") => {
@@ -271,6 +296,8 @@ fn generate_harness_setup(args: &TestArgs) -> Result<TokenStream> {
             ::viewpoint_test::TestHarness::builder()
                 .headless(#headless)
                 .timeout(::std::time::Duration::from_millis(#timeout_ms))
+                #base_url
+                #base_url_env
                 .build()
                 .await?
         }),
@@ -0,0 +1,11 @@
+// Test: base_url with scope = "context" should produce compile error
+
+use viewpoint_test_macros::test;
+
+// This should fail to compile: the shared context already has its base URL
+#[test(scope = "context", context = "shared_context", base_url = "http://localhost:3000")]
+async fn test_base_url_on_shared_context(page: viewpoint_core::Page) {
+    let _ = page;
+}
+
+fn main() {}
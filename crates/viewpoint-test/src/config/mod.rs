@@ -9,6 +9,8 @@ pub struct TestConfig {
     pub headless: bool,
     /// Default timeout for operations.
     pub timeout: Duration,
+    /// Base URL that relative navigation and URL patterns resolve against.
+    pub base_url: Option<String>,
 }
 
 impl Default for TestConfig {
@@ -16,6 +18,7 @@ impl Default for TestConfig {
         Self {
             headless: true,
             timeout: Duration::from_secs(30),
+            base_url: None,
         }
     }
 }
@@ -37,6 +40,7 @@ impl TestConfig {
 pub struct TestConfigBuilder {
     headless: Option<bool>,
     timeout: Option<Duration>,
+    base_url: Option<String>,
 }
 
 impl TestConfigBuilder {
@@ -54,11 +58,48 @@ impl TestConfigBuilder {
         self
     }
 
+    /// Set the base URL for relative navigation and URL patterns.
+    #[must_use]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Set the base URL from an environment variable.
+    ///
+    /// If the variable is unset or empty, the current base URL is kept. This
+    /// lets a suite default to a local server and run against staging or
+    /// preview deployments by setting the variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use viewpoint_test::TestConfig;
+    ///
+    /// let config = TestConfig::builder()
+    ///     .base_url("http://localhost:3000")
+    ///     .base_url_from_env("BASE_URL")
+    ///     .build();
+    /// ```
+    #[must_use]
+    pub fn base_url_from_env(mut self, var: &str) -> Self {
+        if let Some(base_url) = base_url_from_env(var) {
+            self.base_url = Some(base_url);
+        }
+        self
+    }
+
     /// Build the configuration.
     pub fn build(self) -> TestConfig {
         TestConfig {
             headless: self.headless.unwrap_or(true),
             timeout: self.timeout.unwrap_or(Duration::from_secs(30)),
+            base_url: self.base_url,
         }
     }
 }
+
+/// Read a non-empty base URL from an environment variable.
+pub(crate) fn base_url_from_env(var: &str) -> Option<String> {
+    std::env::var(var).ok().filter(|value| !value.is_empty())
+}
@@ -0,0 +1,218 @@
+//! Pixel comparison of PNG screenshots.
+//!
+//! Images are decoded to 8-bit RGBA and compared pixel by pixel. A pixel
+//! differs when any channel differs by more than the configured threshold.
+
+use std::io::Cursor;
+
+/// Color used to mark differing pixels in diff images.
+const DIFF_COLOR: [u8; 4] = [255, 0, 0, 255];
+
+/// A decoded 8-bit RGBA image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// Pixel data, 4 bytes per pixel in row-major order.
+    pub pixels: Vec<u8>,
+}
+
+impl RgbaImage {
+    /// Decode a PNG into RGBA pixels.
+    pub fn from_png(data: &[u8]) -> Result<Self, String> {
+        let mut decoder = png::Decoder::new(Cursor::new(data));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder
+            .read_info()
+            .map_err(|e| format!("invalid PNG: {e}"))?;
+        let size = reader
+            .output_buffer_size()
+            .ok_or_else(|| "PNG is too large to decode".to_string())?;
+        let mut buffer = vec![0; size];
+        let info = reader
+            .next_frame(&mut buffer)
+            .map_err(|e| format!("invalid PNG: {e}"))?;
+        buffer.truncate(info.buffer_size());
+
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer
+                .chunks_exact(3)
+                .flat_map(|p| [p[0], p[1], p[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => buffer
+                .chunks_exact(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|&g| [g, g, g, 255]).collect(),
+            png::ColorType::Indexed => {
+                return Err("indexed PNG was not expanded".to_string());
+            }
+        };
+
+        Ok(Self {
+            width: info.width,
+            height: info.height,
+            pixels,
+        })
+    }
+
+    /// Encode the image as a PNG.
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let mut data = Vec::new();
+        let mut encoder = png::Encoder::new(&mut data, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| format!("failed to encode PNG: {e}"))?;
+        writer
+            .write_image_data(&self.pixels)
+            .map_err(|e| format!("failed to encode PNG: {e}"))?;
+        writer
+            .finish()
+            .map_err(|e| format!("failed to encode PNG: {e}"))?;
+        Ok(data)
+    }
+}
+
+/// Tolerances for comparing two images.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiffOptions {
+    /// Maximum per-channel difference, as a fraction of 255, for pixels to be equal.
+    pub threshold: f64,
+    /// Maximum number of differing pixels.
+    pub max_diff_pixels: Option<usize>,
+    /// Maximum fraction of differing pixels.
+    pub max_diff_pixel_ratio: Option<f64>,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 0.2,
+            max_diff_pixels: None,
+            max_diff_pixel_ratio: None,
+        }
+    }
+}
+
+impl DiffOptions {
+    /// Check whether a number of differing pixels is within tolerance.
+    ///
+    /// Without any limit set, no pixel may differ.
+    pub fn allows(&self, diff_pixels: usize, total_pixels: usize) -> bool {
+        if self.max_diff_pixels.is_none() && self.max_diff_pixel_ratio.is_none() {
+            return diff_pixels == 0;
+        }
+        let within_count = self.max_diff_pixels.is_none_or(|max| diff_pixels <= max);
+        let within_ratio = self
+            .max_diff_pixel_ratio
+            .is_none_or(|max| total_pixels == 0 || diff_pixels as f64 / total_pixels as f64 <= max);
+        within_count && within_ratio
+    }
+}
+
+/// The result of comparing two images.
+#[derive(Debug, Clone)]
+pub enum ImageComparison {
+    /// The images have different dimensions.
+    SizeMismatch {
+        /// Size of the expected image.
+        expected: (u32, u32),
+        /// Size of the actual image.
+        actual: (u32, u32),
+    },
+    /// The images have the same dimensions.
+    Compared {
+        /// Number of pixels that differ.
+        diff_pixels: usize,
+        /// Total number of pixels.
+        total_pixels: usize,
+        /// Faded copy of the expected image with differing pixels in red.
+        diff_image: RgbaImage,
+    },
+}
+
+impl ImageComparison {
+    /// Check whether the comparison is within the given tolerances.
+    pub fn passes(&self, options: &DiffOptions) -> bool {
+        match self {
+            Self::SizeMismatch { .. } => false,
+            Self::Compared {
+                diff_pixels,
+                total_pixels,
+                ..
+            } => options.allows(*diff_pixels, *total_pixels),
+        }
+    }
+
+    /// Describe the difference for assertion messages.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::SizeMismatch { expected, actual } => format!(
+                "image size {}x{} differs from expected {}x{}",
+                actual.0, actual.1, expected.0, expected.1
+            ),
+            Self::Compared {
+                diff_pixels,
+                total_pixels,
+                ..
+            } => {
+                let ratio = if *total_pixels == 0 {
+                    0.0
+                } else {
+                    *diff_pixels as f64 / *total_pixels as f64
+                };
+                format!("{diff_pixels} pixels (ratio {ratio:.4}) differ")
+            }
+        }
+    }
+}
+
+/// Compare an actual image against the expected one.
+pub fn compare(expected: &RgbaImage, actual: &RgbaImage, threshold: f64) -> ImageComparison {
+    if expected.width != actual.width || expected.height != actual.height {
+        return ImageComparison::SizeMismatch {
+            expected: (expected.width, expected.height),
+            actual: (actual.width, actual.height),
+        };
+    }
+
+    let tolerance = (threshold.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let mut diff_pixels = 0;
+    let mut diff = Vec::with_capacity(expected.pixels.len());
+
+    for (e, a) in expected
+        .pixels
+        .chunks_exact(4)
+        .zip(actual.pixels.chunks_exact(4))
+    {
+        if e.iter().zip(a).any(|(x, y)| x.abs_diff(*y) > tolerance) {
+            diff_pixels += 1;
+            diff.extend_from_slice(&DIFF_COLOR);
+        } else {
+            diff.extend_from_slice(&faded(e));
+        }
+    }
+
+    ImageComparison::Compared {
+        diff_pixels,
+        total_pixels: (expected.width as usize) * (expected.height as usize),
+        diff_image: RgbaImage {
+            width: expected.width,
+            height: expected.height,
+            pixels: diff,
+        },
+    }
+}
+
+/// Render an unchanged pixel as light grayscale so differences stand out.
+fn faded(pixel: &[u8]) -> [u8; 4] {
+    let luma =
+        (u32::from(pixel[0]) * 299 + u32::from(pixel[1]) * 587 + u32::from(pixel[2]) * 114) / 1000;
+    let light = (255 - (255 - luma) / 4) as u8;
+    [light, light, light, 255]
+}
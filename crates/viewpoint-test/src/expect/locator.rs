@@ -2,7 +2,7 @@
 
 use std::time::Duration;
 
-use viewpoint_core::Locator;
+use viewpoint_core::{Locator, TextMatcher};
 
 use super::count::CountAssertions;
 use super::state::StateAssertions;
@@ -14,9 +14,9 @@ const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Assertions for locators (elements).
 pub struct LocatorAssertions<'a> {
-    locator: &'a Locator<'a>,
-    timeout: Duration,
-    is_negated: bool,
+    pub(super) locator: &'a Locator<'a>,
+    pub(super) timeout: Duration,
+    pub(super) is_negated: bool,
 }
 
 impl<'a> LocatorAssertions<'a> {
@@ -79,10 +79,16 @@ impl<'a> LocatorAssertions<'a> {
 
     /// Assert that the element has the exact text content.
     ///
+    /// Plain strings must match the whole trimmed text; pass a
+    /// [`TextMatcher`] for case-insensitive or regex matching.
+    ///
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the element cannot be queried.
-    pub async fn to_have_text(&self, expected: &str) -> Result<(), AssertionError> {
+    pub async fn to_have_text(
+        &self,
+        expected: impl Into<TextMatcher>,
+    ) -> Result<(), AssertionError> {
         TextAssertions::new(self.locator, self.timeout, self.is_negated)
             .to_have_text(expected)
             .await
@@ -93,7 +99,10 @@ impl<'a> LocatorAssertions<'a> {
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the element cannot be queried.
-    pub async fn to_contain_text(&self, expected: &str) -> Result<(), AssertionError> {
+    pub async fn to_contain_text(
+        &self,
+        expected: impl Into<TextMatcher>,
+    ) -> Result<(), AssertionError> {
         TextAssertions::new(self.locator, self.timeout, self.is_negated)
             .to_contain_text(expected)
             .await
@@ -104,12 +113,17 @@ impl<'a> LocatorAssertions<'a> {
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the element cannot be queried.
-    pub async fn to_have_attribute(&self, name: &str, value: &str) -> Result<(), AssertionError> {
+    pub async fn to_have_attribute(
+        &self,
+        name: &str,
+        value: impl Into<TextMatcher>,
+    ) -> Result<(), AssertionError> {
+        let value = value.into().resolve(true);
         let start = std::time::Instant::now();
 
         loop {
             let actual = self.get_attribute(name).await?;
-            let matches = actual.as_deref() == Some(value);
+            let matches = actual.as_deref().is_some_and(|v| value.matches(v));
             let expected_match = !self.is_negated;
 
             if matches == expected_match {
@@ -119,14 +133,14 @@ impl<'a> LocatorAssertions<'a> {
             if start.elapsed() >= self.timeout {
                 return Err(AssertionError::new(
                     if self.is_negated {
-                        format!("Element should not have attribute {name}=\"{value}\"")
+                        format!("Element should not have attribute {name}={value}")
                     } else {
-                        format!("Element should have attribute {name}=\"{value}\"")
+                        format!("Element should have attribute {name}={value}")
                     },
                     if self.is_negated {
-                        format!("not {name}=\"{value}\"")
+                        format!("not {name}={value}")
                     } else {
-                        format!("{name}=\"{value}\"")
+                        format!("{name}={value}")
                     },
                     match actual {
                         Some(v) => format!("{name}=\"{v}\""),
@@ -215,12 +229,16 @@ impl<'a> LocatorAssertions<'a> {
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the element cannot be queried.
-    pub async fn to_have_value(&self, expected: &str) -> Result<(), AssertionError> {
+    pub async fn to_have_value(
+        &self,
+        expected: impl Into<TextMatcher>,
+    ) -> Result<(), AssertionError> {
+        let expected = expected.into().resolve(true);
         let start = std::time::Instant::now();
 
         loop {
             let actual = self.get_input_value().await?;
-            let matches = actual == expected;
+            let matches = expected.matches(&actual);
             let expected_match = !self.is_negated;
 
             if matches == expected_match {
@@ -235,9 +253,9 @@ impl<'a> LocatorAssertions<'a> {
                         "Element should have value"
                     },
                     if self.is_negated {
-                        format!("not \"{expected}\"")
+                        format!("not {expected}")
                     } else {
-                        format!("\"{expected}\"")
+                        expected.to_string()
                     },
                     format!("\"{actual}\""),
                 ));
@@ -292,7 +310,7 @@ impl<'a> LocatorAssertions<'a> {
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the element cannot be queried.
-    pub async fn to_have_id(&self, expected: &str) -> Result<(), AssertionError> {
+    pub async fn to_have_id(&self, expected: impl Into<TextMatcher>) -> Result<(), AssertionError> {
         self.to_have_attribute("id", expected).await
     }
 
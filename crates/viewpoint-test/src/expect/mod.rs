@@ -28,9 +28,12 @@
 //! ```
 
 mod count;
+mod image_diff;
 mod locator;
 mod locator_helpers;
 mod page;
+mod screenshot;
+mod snapshot;
 mod soft;
 mod soft_locator;
 mod soft_page;
@@ -42,6 +45,10 @@ mod tests;
 
 pub use locator::LocatorAssertions;
 pub use page::PageAssertions;
+pub use screenshot::ScreenshotAssertion;
+pub use snapshot::{
+    SNAPSHOT_DIR_ENV, SnapshotUpdateMode, UPDATE_SNAPSHOTS_ENV, default_snapshot_dir,
+};
 pub use soft::{SoftAssertionError, SoftAssertions};
 pub use soft_locator::SoftLocatorAssertions;
 pub use soft_page::SoftPageAssertions;
@@ -2,8 +2,9 @@
 
 use std::time::Duration;
 
-use viewpoint_core::Page;
+use viewpoint_core::{AuditRule, Page, TextMatcher};
 
+use super::screenshot::ScreenshotAssertion;
 use crate::error::AssertionError;
 
 /// Default timeout for assertions.
@@ -57,16 +58,19 @@ impl<'a> PageAssertions<'a> {
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the URL cannot be retrieved.
-    pub async fn to_have_url(&self, expected: &str) -> Result<(), AssertionError> {
+    pub async fn to_have_url(
+        &self,
+        expected: impl Into<TextMatcher>,
+    ) -> Result<(), AssertionError> {
+        let expected = expected.into().resolve(true);
         let start = std::time::Instant::now();
 
         loop {
-            let url =
-                self.page.url().await.map_err(|e| {
-                    AssertionError::new("Failed to get URL", expected, e.to_string())
-                })?;
+            let url = self.page.url().await.map_err(|e| {
+                AssertionError::new("Failed to get URL", expected.to_string(), e.to_string())
+            })?;
 
-            let matches = url == expected;
+            let matches = expected.matches(&url);
             let expected_match = !self.is_negated;
 
             if matches == expected_match {
@@ -81,7 +85,7 @@ impl<'a> PageAssertions<'a> {
                         "Page should have URL"
                     },
                     if self.is_negated {
-                        format!("not \"{expected}\"")
+                        format!("not {expected}")
                     } else {
                         expected.to_string()
                     },
@@ -98,16 +102,19 @@ impl<'a> PageAssertions<'a> {
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the URL cannot be retrieved.
-    pub async fn to_have_url_containing(&self, expected: &str) -> Result<(), AssertionError> {
+    pub async fn to_have_url_containing(
+        &self,
+        expected: impl Into<TextMatcher>,
+    ) -> Result<(), AssertionError> {
+        let expected = expected.into().resolve(false);
         let start = std::time::Instant::now();
 
         loop {
-            let url =
-                self.page.url().await.map_err(|e| {
-                    AssertionError::new("Failed to get URL", expected, e.to_string())
-                })?;
+            let url = self.page.url().await.map_err(|e| {
+                AssertionError::new("Failed to get URL", expected.to_string(), e.to_string())
+            })?;
 
-            let contains = url.contains(expected);
+            let contains = expected.matches(&url);
             let expected_match = !self.is_negated;
 
             if contains == expected_match {
@@ -122,9 +129,9 @@ impl<'a> PageAssertions<'a> {
                         "Page URL should contain"
                     },
                     if self.is_negated {
-                        format!("not containing \"{expected}\"")
+                        format!("not {expected}")
                     } else {
-                        format!("containing \"{expected}\"")
+                        expected.to_string()
                     },
                     url,
                 ));
@@ -139,16 +146,19 @@ impl<'a> PageAssertions<'a> {
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the title cannot be retrieved.
-    pub async fn to_have_title(&self, expected: &str) -> Result<(), AssertionError> {
+    pub async fn to_have_title(
+        &self,
+        expected: impl Into<TextMatcher>,
+    ) -> Result<(), AssertionError> {
+        let expected = expected.into().resolve(true);
         let start = std::time::Instant::now();
 
         loop {
-            let title =
-                self.page.title().await.map_err(|e| {
-                    AssertionError::new("Failed to get title", expected, e.to_string())
-                })?;
+            let title = self.page.title().await.map_err(|e| {
+                AssertionError::new("Failed to get title", expected.to_string(), e.to_string())
+            })?;
 
-            let matches = title == expected;
+            let matches = expected.matches(&title);
             let expected_match = !self.is_negated;
 
             if matches == expected_match {
@@ -163,7 +173,7 @@ impl<'a> PageAssertions<'a> {
                         "Page should have title"
                     },
                     if self.is_negated {
-                        format!("not \"{expected}\"")
+                        format!("not {expected}")
                     } else {
                         expected.to_string()
                     },
@@ -180,16 +190,19 @@ impl<'a> PageAssertions<'a> {
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the title cannot be retrieved.
-    pub async fn to_have_title_containing(&self, expected: &str) -> Result<(), AssertionError> {
+    pub async fn to_have_title_containing(
+        &self,
+        expected: impl Into<TextMatcher>,
+    ) -> Result<(), AssertionError> {
+        let expected = expected.into().resolve(false);
         let start = std::time::Instant::now();
 
         loop {
-            let title =
-                self.page.title().await.map_err(|e| {
-                    AssertionError::new("Failed to get title", expected, e.to_string())
-                })?;
+            let title = self.page.title().await.map_err(|e| {
+                AssertionError::new("Failed to get title", expected.to_string(), e.to_string())
+            })?;
 
-            let contains = title.contains(expected);
+            let contains = expected.matches(&title);
             let expected_match = !self.is_negated;
 
             if contains == expected_match {
@@ -204,9 +217,9 @@ impl<'a> PageAssertions<'a> {
                         "Page title should contain"
                     },
                     if self.is_negated {
-                        format!("not containing \"{expected}\"")
+                        format!("not {expected}")
                     } else {
-                        format!("containing \"{expected}\"")
+                        expected.to_string()
                     },
                     title,
                 ));
@@ -215,4 +228,82 @@ impl<'a> PageAssertions<'a> {
             tokio::time::sleep(Duration::from_millis(100)).await;
         }
     }
+
+    /// Assert that the page passes an accessibility audit.
+    ///
+    /// Runs every [`AuditRule`] and retries until the timeout, so content that
+    /// is still rendering gets a chance to settle. On failure the error lists
+    /// each violation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the audit finds violations or cannot be run.
+    pub async fn to_pass_accessibility_audit(&self) -> Result<(), AssertionError> {
+        self.to_pass_accessibility_audit_with(AuditRule::ALL).await
+    }
+
+    /// Assert that the page passes an accessibility audit limited to the
+    /// given rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the audit finds violations or cannot be run.
+    pub async fn to_pass_accessibility_audit_with(
+        &self,
+        rules: impl IntoIterator<Item = AuditRule>,
+    ) -> Result<(), AssertionError> {
+        let rules: Vec<AuditRule> = rules.into_iter().collect();
+        let start = std::time::Instant::now();
+
+        loop {
+            let report = self
+                .page
+                .accessibility()
+                .audit()
+                .rules(rules.iter().copied())
+                .run()
+                .await
+                .map_err(|e| {
+                    AssertionError::new(
+                        "Failed to run accessibility audit",
+                        "audit to run",
+                        e.to_string(),
+                    )
+                })?;
+
+            let expected_match = !self.is_negated;
+
+            if report.passed() == expected_match {
+                return Ok(());
+            }
+
+            if start.elapsed() >= self.timeout {
+                return Err(AssertionError::new(
+                    if self.is_negated {
+                        "Page should have accessibility violations"
+                    } else {
+                        "Page should pass accessibility audit"
+                    },
+                    if self.is_negated {
+                        "at least one violation"
+                    } else {
+                        "no accessibility violations"
+                    },
+                    report.to_string(),
+                ));
+            }
+
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    /// Assert that the page matches a stored screenshot baseline.
+    ///
+    /// Returns a [`ScreenshotAssertion`] to configure before awaiting. The
+    /// baseline is read from the snapshot directory; see
+    /// [`SnapshotUpdateMode`](super::SnapshotUpdateMode) for how missing
+    /// baselines are handled.
+    pub fn to_have_screenshot(&self, name: &str) -> ScreenshotAssertion<'a> {
+        ScreenshotAssertion::for_page(self.page, name, self.timeout, self.is_negated)
+    }
 }
@@ -0,0 +1,406 @@
+//! Screenshot assertions for visual regression testing.
+//!
+//! `to_have_screenshot` captures a page or element and compares it with a
+//! stored baseline, retrying until the comparison passes or the timeout
+//! expires.
+
+use std::future::{Future, IntoFuture};
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+use viewpoint_core::{Animations, Locator, Page};
+use viewpoint_js::js;
+
+use super::image_diff::{DiffOptions, ImageComparison, RgbaImage, compare};
+use super::locator::LocatorAssertions;
+use super::snapshot::{
+    SnapshotUpdateMode, default_snapshot_dir, snapshot_file_name, write_failure_artifacts,
+    write_file,
+};
+use crate::error::AssertionError;
+
+/// Interval between screenshot attempts.
+const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Default color painted over masked elements.
+const DEFAULT_MASK_COLOR: &str = "#FF00FF";
+
+/// Attribute marking overlays injected to mask elements.
+const MASK_ATTRIBUTE: &str = "data-viewpoint-mask";
+
+/// What a screenshot assertion captures.
+#[derive(Debug, Clone, Copy)]
+enum Target<'a> {
+    Page(&'a Page),
+    Locator(&'a Locator<'a>),
+}
+
+impl Target<'_> {
+    fn page(&self) -> &Page {
+        match self {
+            Self::Page(page) => page,
+            Self::Locator(locator) => locator.page(),
+        }
+    }
+}
+
+/// Builder for a screenshot assertion.
+///
+/// Created by [`LocatorAssertions::to_have_screenshot`] or
+/// [`PageAssertions::to_have_screenshot`](super::PageAssertions::to_have_screenshot)
+/// and run by awaiting it.
+///
+/// # Example
+///
+/// ```no_run
+/// use viewpoint_core::Page;
+/// use viewpoint_test::{expect, expect_page};
+///
+/// # async fn example(page: &Page) -> Result<(), viewpoint_test::AssertionError> {
+/// expect_page(page)
+///     .to_have_screenshot("home.png")
+///     .max_diff_pixel_ratio(0.01)
+///     .mask(&page.locator(".timestamp"))
+///     .await?;
+///
+/// let header = page.locator("header");
+/// expect(&header).to_have_screenshot("header").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ScreenshotAssertion<'a> {
+    target: Target<'a>,
+    name: String,
+    timeout: Duration,
+    is_negated: bool,
+    diff: DiffOptions,
+    masks: Vec<&'a Locator<'a>>,
+    mask_color: String,
+    animations: Animations,
+    full_page: bool,
+    snapshot_dir: Option<PathBuf>,
+    update_mode: Option<SnapshotUpdateMode>,
+}
+
+impl<'a> ScreenshotAssertion<'a> {
+    fn new(target: Target<'a>, name: &str, timeout: Duration, is_negated: bool) -> Self {
+        Self {
+            target,
+            name: name.to_string(),
+            timeout,
+            is_negated,
+            diff: DiffOptions::default(),
+            masks: Vec::new(),
+            mask_color: DEFAULT_MASK_COLOR.to_string(),
+            animations: Animations::Disabled,
+            full_page: false,
+            snapshot_dir: None,
+            update_mode: None,
+        }
+    }
+
+    pub(super) fn for_page(page: &'a Page, name: &str, timeout: Duration, negated: bool) -> Self {
+        Self::new(Target::Page(page), name, timeout, negated)
+    }
+
+    /// Set the maximum number of pixels that may differ.
+    #[must_use]
+    pub fn max_diff_pixels(mut self, max: usize) -> Self {
+        self.diff.max_diff_pixels = Some(max);
+        self
+    }
+
+    /// Set the maximum fraction of pixels (0.0 to 1.0) that may differ.
+    #[must_use]
+    pub fn max_diff_pixel_ratio(mut self, ratio: f64) -> Self {
+        self.diff.max_diff_pixel_ratio = Some(ratio.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the per-channel color tolerance (0.0 to 1.0) for pixels to be equal.
+    ///
+    /// Defaults to 0.2.
+    #[must_use]
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.diff.threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Cover elements matching a locator with a solid box before capturing.
+    #[must_use]
+    pub fn mask(mut self, locator: &'a Locator<'a>) -> Self {
+        self.masks.push(locator);
+        self
+    }
+
+    /// Set the CSS color used for masks. Defaults to `#FF00FF`.
+    #[must_use]
+    pub fn mask_color(mut self, color: impl Into<String>) -> Self {
+        self.mask_color = color.into();
+        self
+    }
+
+    /// Set animation handling. Animations are disabled by default.
+    #[must_use]
+    pub fn animations(mut self, animations: Animations) -> Self {
+        self.animations = animations;
+        self
+    }
+
+    /// Capture the full scrollable page. Ignored for element screenshots.
+    #[must_use]
+    pub fn full_page(mut self, full_page: bool) -> Self {
+        self.full_page = full_page;
+        self
+    }
+
+    /// Set the timeout for the screenshot to match.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the directory baselines are stored in.
+    ///
+    /// Defaults to [`default_snapshot_dir`](super::default_snapshot_dir).
+    #[must_use]
+    pub fn snapshot_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.snapshot_dir = Some(dir.into());
+        self
+    }
+
+    /// Set when baselines are written, overriding `VIEWPOINT_UPDATE_SNAPSHOTS`.
+    #[must_use]
+    pub fn update_snapshots(mut self, mode: SnapshotUpdateMode) -> Self {
+        self.update_mode = Some(mode);
+        self
+    }
+
+    async fn run(self) -> Result<(), AssertionError> {
+        if self.is_negated {
+            return Err(AssertionError::new(
+                "Screenshot assertions cannot be negated",
+                "a non-negated assertion",
+                "negated assertion",
+            ));
+        }
+
+        let snapshot_dir = self
+            .snapshot_dir
+            .clone()
+            .unwrap_or_else(default_snapshot_dir);
+        let file_name = snapshot_file_name(&self.name);
+        let baseline_path = snapshot_dir.join(&file_name);
+        let mode = self
+            .update_mode
+            .unwrap_or_else(SnapshotUpdateMode::from_env);
+
+        let baseline = if mode == SnapshotUpdateMode::All {
+            None
+        } else {
+            std::fs::read(&baseline_path).ok()
+        };
+
+        let Some(expected_png) = baseline else {
+            if mode == SnapshotUpdateMode::None {
+                return Err(AssertionError::new(
+                    "Screenshot baseline is missing",
+                    baseline_path.display().to_string(),
+                    "no baseline file",
+                ));
+            }
+            let actual = self.capture_stable().await?;
+            write_file(&baseline_path, &actual).map_err(|e| {
+                AssertionError::new(
+                    "Failed to write screenshot baseline",
+                    baseline_path.display().to_string(),
+                    e.to_string(),
+                )
+            })?;
+            return if mode == SnapshotUpdateMode::All {
+                Ok(())
+            } else {
+                Err(AssertionError::new(
+                    "Screenshot baseline was missing and has been written",
+                    baseline_path.display().to_string(),
+                    "no baseline file",
+                ))
+            };
+        };
+
+        let expected = RgbaImage::from_png(&expected_png).map_err(|e| {
+            AssertionError::new(
+                "Failed to read screenshot baseline",
+                baseline_path.display().to_string(),
+                e,
+            )
+        })?;
+
+        let start = Instant::now();
+        loop {
+            let actual_png = self.capture().await?;
+            let actual = RgbaImage::from_png(&actual_png)
+                .map_err(|e| AssertionError::new("Failed to decode screenshot", "a PNG", e))?;
+            let comparison = compare(&expected, &actual, self.diff.threshold);
+
+            if comparison.passes(&self.diff) {
+                return Ok(());
+            }
+
+            if start.elapsed() >= self.timeout {
+                let diff_png = match &comparison {
+                    ImageComparison::Compared { diff_image, .. } => diff_image.to_png().ok(),
+                    ImageComparison::SizeMismatch { .. } => None,
+                };
+                let mut actual_message = comparison.describe();
+                match write_failure_artifacts(
+                    &snapshot_dir,
+                    &file_name,
+                    &actual_png,
+                    &expected_png,
+                    diff_png.as_deref(),
+                ) {
+                    Ok(artifacts) => {
+                        actual_message = format!("{actual_message}\n{}", artifacts.describe());
+                    }
+                    Err(e) => {
+                        actual_message =
+                            format!("{actual_message}\nfailed to write diff images: {e}");
+                    }
+                }
+                return Err(AssertionError::new(
+                    format!("Screenshot should match baseline \"{file_name}\""),
+                    baseline_path.display().to_string(),
+                    actual_message,
+                ));
+            }
+
+            tokio::time::sleep(RETRY_INTERVAL).await;
+        }
+    }
+
+    /// Capture until two consecutive screenshots are identical.
+    async fn capture_stable(&self) -> Result<Vec<u8>, AssertionError> {
+        let start = Instant::now();
+        let mut previous = self.capture().await?;
+        loop {
+            tokio::time::sleep(RETRY_INTERVAL).await;
+            let current = self.capture().await?;
+            if current == previous {
+                return Ok(current);
+            }
+            if start.elapsed() >= self.timeout {
+                return Err(AssertionError::new(
+                    "Screenshot did not stabilize",
+                    "two identical consecutive screenshots",
+                    format!("screenshots still changing after {:?}", self.timeout),
+                ));
+            }
+            previous = current;
+        }
+    }
+
+    /// Capture one screenshot with masks applied.
+    async fn capture(&self) -> Result<Vec<u8>, AssertionError> {
+        self.apply_masks().await?;
+        let result = match self.target {
+            Target::Page(page) => page
+                .screenshot()
+                .full_page(self.full_page)
+                .animations(self.animations)
+                .capture()
+                .await
+                .map_err(|e| e.to_string()),
+            Target::Locator(locator) => locator
+                .screenshot()
+                .animations(self.animations)
+                .capture()
+                .await
+                .map_err(|e| e.to_string()),
+        };
+        self.remove_masks().await;
+        result.map_err(|e| AssertionError::new("Failed to capture screenshot", "a screenshot", e))
+    }
+
+    async fn apply_masks(&self) -> Result<(), AssertionError> {
+        if self.masks.is_empty() {
+            return Ok(());
+        }
+        let color = &self.mask_color;
+        let script = js! {
+            (() => {
+                for (const element of elements) {
+                    const rect = element.getBoundingClientRect();
+                    const overlay = document.createElement("div");
+                    overlay.setAttribute(#{MASK_ATTRIBUTE}, "");
+                    Object.assign(overlay.style, {
+                        position: "absolute",
+                        left: (rect.left + window.scrollX) + "px",
+                        top: (rect.top + window.scrollY) + "px",
+                        width: rect.width + "px",
+                        height: rect.height + "px",
+                        background: #{color},
+                        zIndex: "2147483647",
+                        pointerEvents: "none",
+                    });
+                    document.documentElement.appendChild(overlay);
+                }
+                return elements.length;
+            })()
+        };
+        for mask in &self.masks {
+            if let Err(e) = mask.evaluate_all::<serde_json::Value>(&script).await {
+                self.remove_masks().await;
+                return Err(AssertionError::new(
+                    "Failed to mask element",
+                    "a mask overlay",
+                    e.to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    async fn remove_masks(&self) {
+        if self.masks.is_empty() {
+            return;
+        }
+        let selector = format!("[{MASK_ATTRIBUTE}]");
+        let script = js! {
+            document.querySelectorAll(#{selector}).forEach(e => e.remove())
+        };
+        let _ = self
+            .target
+            .page()
+            .evaluate::<serde_json::Value>(&script)
+            .await;
+    }
+}
+
+impl<'a> IntoFuture for ScreenshotAssertion<'a> {
+    type Output = Result<(), AssertionError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.run())
+    }
+}
+
+impl<'a> LocatorAssertions<'a> {
+    /// Assert that the element matches a stored screenshot baseline.
+    ///
+    /// Returns a [`ScreenshotAssertion`] to configure before awaiting. The
+    /// baseline is read from the snapshot directory; see
+    /// [`SnapshotUpdateMode`] for how missing baselines are handled.
+    pub fn to_have_screenshot(&self, name: &str) -> ScreenshotAssertion<'a> {
+        ScreenshotAssertion::new(
+            Target::Locator(self.locator),
+            name,
+            self.timeout,
+            self.is_negated,
+        )
+    }
+}
@@ -0,0 +1,134 @@
+//! Baseline storage for screenshot assertions.
+//!
+//! Baselines are PNG files stored in a snapshot directory. When a comparison
+//! fails, the actual, expected and diff images are written to a
+//! `__diff_output__` directory next to the baselines for inspection.
+
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the snapshot directory.
+pub const SNAPSHOT_DIR_ENV: &str = "VIEWPOINT_SNAPSHOT_DIR";
+
+/// Environment variable controlling when baselines are written.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "VIEWPOINT_UPDATE_SNAPSHOTS";
+
+/// Directory for failure artifacts, relative to the snapshot directory.
+const DIFF_OUTPUT_DIR: &str = "__diff_output__";
+
+/// When screenshot assertions write baselines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SnapshotUpdateMode {
+    /// Overwrite every baseline with the current screenshot.
+    All,
+    /// Write baselines that do not exist yet, failing the assertion.
+    #[default]
+    Missing,
+    /// Never write baselines.
+    None,
+}
+
+impl SnapshotUpdateMode {
+    /// Parse an update mode from an environment variable value.
+    ///
+    /// Accepts `all`, `missing` and `none`, as well as `1`/`true` for `all`
+    /// and `0`/`false` for `none`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "all" | "1" | "true" => Some(Self::All),
+            "missing" => Some(Self::Missing),
+            "none" | "0" | "false" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    /// Read the update mode from `VIEWPOINT_UPDATE_SNAPSHOTS`.
+    ///
+    /// Unset or unrecognized values fall back to [`SnapshotUpdateMode::Missing`].
+    pub fn from_env() -> Self {
+        std::env::var(UPDATE_SNAPSHOTS_ENV)
+            .ok()
+            .and_then(|value| Self::parse(&value))
+            .unwrap_or_default()
+    }
+}
+
+/// Get the default snapshot directory.
+///
+/// Uses `VIEWPOINT_SNAPSHOT_DIR` if set, otherwise `tests/snapshots` in the
+/// crate being tested, falling back to `snapshots` in the working directory.
+pub fn default_snapshot_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os(SNAPSHOT_DIR_ENV) {
+        return PathBuf::from(dir);
+    }
+    std::env::var_os("CARGO_MANIFEST_DIR").map_or_else(
+        || PathBuf::from("snapshots"),
+        |dir| PathBuf::from(dir).join("tests").join("snapshots"),
+    )
+}
+
+/// Get the baseline file name for a snapshot name, adding `.png` if missing.
+pub(super) fn snapshot_file_name(name: &str) -> String {
+    if name.to_ascii_lowercase().ends_with(".png") {
+        name.to_string()
+    } else {
+        format!("{name}.png")
+    }
+}
+
+/// Paths of the images written when a screenshot assertion fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct FailureArtifacts {
+    pub actual: PathBuf,
+    pub expected: PathBuf,
+    pub diff: Option<PathBuf>,
+}
+
+impl FailureArtifacts {
+    /// Describe the artifact paths for assertion messages.
+    pub fn describe(&self) -> String {
+        let mut lines = vec![
+            format!("actual: {}", self.actual.display()),
+            format!("expected: {}", self.expected.display()),
+        ];
+        if let Some(diff) = &self.diff {
+            lines.push(format!("diff: {}", diff.display()));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Write the actual, expected and diff images for a failed comparison.
+pub(super) fn write_failure_artifacts(
+    snapshot_dir: &Path,
+    file_name: &str,
+    actual: &[u8],
+    expected: &[u8],
+    diff: Option<&[u8]>,
+) -> std::io::Result<FailureArtifacts> {
+    let stem = file_name
+        .strip_suffix(".png")
+        .or_else(|| file_name.strip_suffix(".PNG"))
+        .unwrap_or(file_name);
+    let output_dir = snapshot_dir.join(DIFF_OUTPUT_DIR);
+
+    let artifacts = FailureArtifacts {
+        actual: output_dir.join(format!("{stem}-actual.png")),
+        expected: output_dir.join(format!("{stem}-expected.png")),
+        diff: diff.map(|_| output_dir.join(format!("{stem}-diff.png"))),
+    };
+
+    write_file(&artifacts.actual, actual)?;
+    write_file(&artifacts.expected, expected)?;
+    if let (Some(path), Some(data)) = (&artifacts.diff, diff) {
+        write_file(path, data)?;
+    }
+    Ok(artifacts)
+}
+
+/// Write a file, creating parent directories as needed.
+pub(super) fn write_file(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, data)
+}
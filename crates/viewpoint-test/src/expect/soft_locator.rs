@@ -4,7 +4,7 @@
 
 use std::sync::{Arc, Mutex};
 
-use viewpoint_core::AriaSnapshot;
+use viewpoint_core::{AriaSnapshot, TextMatcher};
 
 use super::locator::LocatorAssertions;
 use super::soft::SoftAssertionError;
@@ -42,6 +42,17 @@ macro_rules! soft_assert {
             }
         }
     };
+    ($self:expr, $method:ident, $assertion_name:expr, matcher: $expected:expr) => {
+        match $self.assertions.$method($expected.clone()).await {
+            Ok(()) => {}
+            Err(e) => {
+                $self.errors.lock().unwrap().push(
+                    SoftAssertionError::new($assertion_name, e.to_string())
+                        .with_expected($expected.to_string()),
+                );
+            }
+        }
+    };
 }
 
 impl SoftLocatorAssertions<'_> {
@@ -71,30 +82,30 @@ impl SoftLocatorAssertions<'_> {
     }
 
     /// Assert element has text (soft).
-    pub async fn to_have_text(&self, expected: impl AsRef<str>) {
-        let expected_str = expected.as_ref().to_string();
-        soft_assert!(self, to_have_text, "to_have_text", expected: expected_str);
+    pub async fn to_have_text(&self, expected: impl Into<TextMatcher>) {
+        let expected = expected.into().resolve(true);
+        soft_assert!(self, to_have_text, "to_have_text", matcher: expected);
     }
 
     /// Assert element contains text (soft).
-    pub async fn to_contain_text(&self, expected: impl AsRef<str>) {
-        let expected_str = expected.as_ref().to_string();
-        soft_assert!(self, to_contain_text, "to_contain_text", expected: expected_str);
+    pub async fn to_contain_text(&self, expected: impl Into<TextMatcher>) {
+        let expected = expected.into().resolve(false);
+        soft_assert!(self, to_contain_text, "to_contain_text", matcher: expected);
     }
 
     /// Assert element has value (soft).
-    pub async fn to_have_value(&self, expected: impl AsRef<str>) {
-        let expected_str = expected.as_ref().to_string();
-        soft_assert!(self, to_have_value, "to_have_value", expected: expected_str);
+    pub async fn to_have_value(&self, expected: impl Into<TextMatcher>) {
+        let expected = expected.into().resolve(true);
+        soft_assert!(self, to_have_value, "to_have_value", matcher: expected);
     }
 
     /// Assert element has attribute (soft).
-    pub async fn to_have_attribute(&self, name: impl AsRef<str>, value: impl AsRef<str>) {
+    pub async fn to_have_attribute(&self, name: impl AsRef<str>, value: impl Into<TextMatcher>) {
         let name_str = name.as_ref().to_string();
-        let value_str = value.as_ref().to_string();
+        let value = value.into().resolve(true);
         match self
             .assertions
-            .to_have_attribute(&name_str, &value_str)
+            .to_have_attribute(&name_str, value.clone())
             .await
         {
             Ok(()) => {}
@@ -104,7 +115,7 @@ impl SoftLocatorAssertions<'_> {
                         format!("to_have_attribute({name_str})"),
                         e.to_string(),
                     )
-                    .with_expected(&value_str),
+                    .with_expected(value.to_string()),
                 );
             }
         }
@@ -117,9 +128,9 @@ impl SoftLocatorAssertions<'_> {
     }
 
     /// Assert element has id (soft).
-    pub async fn to_have_id(&self, expected: impl AsRef<str>) {
-        let expected_str = expected.as_ref().to_string();
-        soft_assert!(self, to_have_id, "to_have_id", expected: expected_str);
+    pub async fn to_have_id(&self, expected: impl Into<TextMatcher>) {
+        let expected = expected.into().resolve(true);
+        soft_assert!(self, to_have_id, "to_have_id", matcher: expected);
     }
 
     /// Assert element count (soft).
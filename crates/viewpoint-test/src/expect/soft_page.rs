@@ -4,6 +4,8 @@
 
 use std::sync::{Arc, Mutex};
 
+use viewpoint_core::TextMatcher;
+
 use super::page::PageAssertions;
 use super::soft::SoftAssertionError;
 
@@ -17,30 +19,40 @@ pub struct SoftPageAssertions<'a> {
 
 impl SoftPageAssertions<'_> {
     /// Assert page URL (soft).
-    pub async fn to_have_url(&self, expected: impl AsRef<str>) {
-        let expected_str = expected.as_ref().to_string();
-        match self.assertions.to_have_url(&expected_str).await {
+    pub async fn to_have_url(&self, expected: impl Into<TextMatcher>) {
+        let expected = expected.into().resolve(true);
+        match self.assertions.to_have_url(expected.clone()).await {
             Ok(()) => {}
             Err(e) => {
                 self.errors.lock().unwrap().push(
                     SoftAssertionError::new("to_have_url", e.to_string())
-                        .with_expected(&expected_str),
+                        .with_expected(expected.to_string()),
                 );
             }
         }
     }
 
     /// Assert page title (soft).
-    pub async fn to_have_title(&self, expected: impl AsRef<str>) {
-        let expected_str = expected.as_ref().to_string();
-        match self.assertions.to_have_title(&expected_str).await {
+    pub async fn to_have_title(&self, expected: impl Into<TextMatcher>) {
+        let expected = expected.into().resolve(true);
+        match self.assertions.to_have_title(expected.clone()).await {
             Ok(()) => {}
             Err(e) => {
                 self.errors.lock().unwrap().push(
                     SoftAssertionError::new("to_have_title", e.to_string())
-                        .with_expected(&expected_str),
+                        .with_expected(expected.to_string()),
                 );
             }
         }
     }
+
+    /// Assert that the page passes an accessibility audit (soft).
+    pub async fn to_pass_accessibility_audit(&self) {
+        if let Err(e) = self.assertions.to_pass_accessibility_audit().await {
+            self.errors.lock().unwrap().push(
+                SoftAssertionError::new("to_pass_accessibility_audit", e.to_string())
+                    .with_expected("no accessibility violations"),
+            );
+        }
+    }
 }
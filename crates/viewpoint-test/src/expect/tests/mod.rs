@@ -1,3 +1,4 @@
 //! Tests for expect module.
 
+mod screenshot_tests;
 mod soft_tests;
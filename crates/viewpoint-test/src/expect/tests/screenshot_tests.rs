@@ -0,0 +1,170 @@
+//! Tests for screenshot comparison and baseline storage.
+
+use crate::expect::image_diff::{DiffOptions, ImageComparison, RgbaImage, compare};
+use crate::expect::snapshot::{SnapshotUpdateMode, snapshot_file_name, write_failure_artifacts};
+
+fn solid(width: u32, height: u32, color: [u8; 4]) -> RgbaImage {
+    RgbaImage {
+        width,
+        height,
+        pixels: color.repeat((width * height) as usize),
+    }
+}
+
+fn diff_pixels(comparison: &ImageComparison) -> usize {
+    match comparison {
+        ImageComparison::Compared { diff_pixels, .. } => *diff_pixels,
+        ImageComparison::SizeMismatch { .. } => panic!("unexpected size mismatch"),
+    }
+}
+
+#[test]
+fn test_png_roundtrip() {
+    let mut image = solid(3, 2, [10, 20, 30, 255]);
+    image.pixels[4..8].copy_from_slice(&[200, 100, 0, 128]);
+
+    let png = image.to_png().unwrap();
+    assert_eq!(RgbaImage::from_png(&png).unwrap(), image);
+}
+
+#[test]
+fn test_from_png_rejects_invalid_data() {
+    assert!(RgbaImage::from_png(b"not a png").is_err());
+}
+
+#[test]
+fn test_compare_identical_images() {
+    let image = solid(4, 4, [0, 0, 255, 255]);
+    let comparison = compare(&image, &image.clone(), 0.0);
+
+    assert_eq!(diff_pixels(&comparison), 0);
+    assert!(comparison.passes(&DiffOptions::default()));
+}
+
+#[test]
+fn test_compare_counts_differing_pixels_and_marks_diff() {
+    let expected = solid(2, 2, [255, 255, 255, 255]);
+    let mut actual = expected.clone();
+    actual.pixels[0..4].copy_from_slice(&[0, 0, 0, 255]);
+
+    let comparison = compare(&expected, &actual, 0.2);
+    assert_eq!(diff_pixels(&comparison), 1);
+    assert!(!comparison.passes(&DiffOptions::default()));
+
+    let ImageComparison::Compared { diff_image, .. } = comparison else {
+        panic!("unexpected size mismatch");
+    };
+    assert_eq!(&diff_image.pixels[0..4], &[255, 0, 0, 255]);
+    assert_ne!(&diff_image.pixels[4..8], &[255, 0, 0, 255]);
+}
+
+#[test]
+fn test_compare_threshold_tolerates_small_channel_differences() {
+    let expected = solid(2, 1, [100, 100, 100, 255]);
+    let actual = solid(2, 1, [120, 100, 100, 255]);
+
+    assert_eq!(diff_pixels(&compare(&expected, &actual, 0.1)), 0);
+    assert_eq!(diff_pixels(&compare(&expected, &actual, 0.05)), 2);
+}
+
+#[test]
+fn test_compare_size_mismatch_fails() {
+    let comparison = compare(&solid(2, 2, [0; 4]), &solid(3, 2, [0; 4]), 0.2);
+
+    assert!(matches!(
+        comparison,
+        ImageComparison::SizeMismatch {
+            expected: (2, 2),
+            actual: (3, 2),
+        }
+    ));
+    assert!(!comparison.passes(&DiffOptions {
+        max_diff_pixel_ratio: Some(1.0),
+        ..DiffOptions::default()
+    }));
+    assert!(comparison.describe().contains("3x2"));
+}
+
+#[test]
+fn test_diff_options_limits() {
+    let strict = DiffOptions::default();
+    assert!(strict.allows(0, 100));
+    assert!(!strict.allows(1, 100));
+
+    let by_count = DiffOptions {
+        max_diff_pixels: Some(5),
+        ..DiffOptions::default()
+    };
+    assert!(by_count.allows(5, 100));
+    assert!(!by_count.allows(6, 100));
+
+    let by_ratio = DiffOptions {
+        max_diff_pixel_ratio: Some(0.1),
+        ..DiffOptions::default()
+    };
+    assert!(by_ratio.allows(10, 100));
+    assert!(!by_ratio.allows(11, 100));
+
+    let both = DiffOptions {
+        max_diff_pixels: Some(20),
+        max_diff_pixel_ratio: Some(0.1),
+        ..DiffOptions::default()
+    };
+    assert!(!both.allows(15, 100));
+}
+
+#[test]
+fn test_update_mode_parse() {
+    assert_eq!(
+        SnapshotUpdateMode::parse("all"),
+        Some(SnapshotUpdateMode::All)
+    );
+    assert_eq!(
+        SnapshotUpdateMode::parse("1"),
+        Some(SnapshotUpdateMode::All)
+    );
+    assert_eq!(
+        SnapshotUpdateMode::parse(" Missing "),
+        Some(SnapshotUpdateMode::Missing)
+    );
+    assert_eq!(
+        SnapshotUpdateMode::parse("false"),
+        Some(SnapshotUpdateMode::None)
+    );
+    assert_eq!(SnapshotUpdateMode::parse("sometimes"), None);
+    assert_eq!(SnapshotUpdateMode::default(), SnapshotUpdateMode::Missing);
+}
+
+#[test]
+fn test_snapshot_file_name() {
+    assert_eq!(snapshot_file_name("home"), "home.png");
+    assert_eq!(snapshot_file_name("home.png"), "home.png");
+    assert_eq!(snapshot_file_name("pages/login"), "pages/login.png");
+}
+
+#[test]
+fn test_write_failure_artifacts() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let artifacts = write_failure_artifacts(
+        dir.path(),
+        "home.png",
+        b"actual",
+        b"expected",
+        Some(b"diff"),
+    )
+    .unwrap();
+
+    let output = dir.path().join("__diff_output__");
+    assert_eq!(artifacts.actual, output.join("home-actual.png"));
+    assert_eq!(artifacts.expected, output.join("home-expected.png"));
+    assert_eq!(artifacts.diff, Some(output.join("home-diff.png")));
+    assert_eq!(std::fs::read(&artifacts.actual).unwrap(), b"actual");
+    assert_eq!(std::fs::read(&artifacts.expected).unwrap(), b"expected");
+    assert!(artifacts.describe().contains("home-diff.png"));
+
+    let artifacts =
+        write_failure_artifacts(dir.path(), "size.png", b"actual", b"expected", None).unwrap();
+    assert_eq!(artifacts.diff, None);
+    assert!(!output.join("size-diff.png").exists());
+}
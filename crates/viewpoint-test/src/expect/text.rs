@@ -5,7 +5,7 @@
 
 use std::time::Duration;
 
-use viewpoint_core::Locator;
+use viewpoint_core::{Locator, TextMatcher};
 
 use crate::error::AssertionError;
 
@@ -30,19 +30,30 @@ impl<'a> TextAssertions<'a> {
 
     /// Assert that the element has the exact text content.
     ///
+    /// Plain strings must match the whole trimmed text; pass a
+    /// [`TextMatcher`] for case-insensitive or regex matching.
+    ///
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the element cannot be queried.
-    pub async fn to_have_text(&self, expected: &str) -> Result<(), AssertionError> {
+    pub async fn to_have_text(
+        &self,
+        expected: impl Into<TextMatcher>,
+    ) -> Result<(), AssertionError> {
+        let expected = expected.into().resolve(true);
         let start = std::time::Instant::now();
 
         loop {
             let text = self.locator.text_content().await.map_err(|e| {
-                AssertionError::new("Failed to get text content", expected, e.to_string())
+                AssertionError::new(
+                    "Failed to get text content",
+                    expected.to_string(),
+                    e.to_string(),
+                )
             })?;
 
             let actual = text.as_deref().unwrap_or("");
-            let matches = actual.trim() == expected;
+            let matches = expected.matches(actual.trim());
             let expected_match = !self.is_negated;
 
             if matches == expected_match {
@@ -57,9 +68,9 @@ impl<'a> TextAssertions<'a> {
                         "Element should have text"
                     },
                     if self.is_negated {
-                        format!("not \"{expected}\"")
+                        format!("not {expected}")
                     } else {
-                        format!("\"{expected}\"")
+                        expected.to_string()
                     },
                     format!("\"{actual}\""),
                 ));
@@ -74,16 +85,24 @@ impl<'a> TextAssertions<'a> {
     /// # Errors
     ///
     /// Returns an error if the assertion fails or the element cannot be queried.
-    pub async fn to_contain_text(&self, expected: &str) -> Result<(), AssertionError> {
+    pub async fn to_contain_text(
+        &self,
+        expected: impl Into<TextMatcher>,
+    ) -> Result<(), AssertionError> {
+        let expected = expected.into().resolve(false);
         let start = std::time::Instant::now();
 
         loop {
             let text = self.locator.text_content().await.map_err(|e| {
-                AssertionError::new("Failed to get text content", expected, e.to_string())
+                AssertionError::new(
+                    "Failed to get text content",
+                    expected.to_string(),
+                    e.to_string(),
+                )
             })?;
 
             let actual = text.as_deref().unwrap_or("");
-            let contains = actual.contains(expected);
+            let contains = expected.matches(actual);
             let expected_match = !self.is_negated;
 
             if contains == expected_match {
@@ -98,9 +117,9 @@ impl<'a> TextAssertions<'a> {
                         "Element should contain text"
                     },
                     if self.is_negated {
-                        format!("not containing \"{expected}\"")
+                        format!("not {expected}")
                     } else {
-                        format!("containing \"{expected}\"")
+                        expected.to_string()
                     },
                     format!("\"{actual}\""),
                 ));
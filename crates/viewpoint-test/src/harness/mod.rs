@@ -72,6 +72,7 @@
 //! |--------|---------|---------|------|----------|
 //! | `TestHarness::new()` | New | New | New | Full isolation (default) |
 //! | `TestHarness::from_browser(&browser)` | Shared | New | New | Faster tests, context isolation |
+//! | `TestHarness::from_browser_with_config(&browser, config)` | Shared | New | New | Same, with a base URL |
 //! | `TestHarness::from_context(&context)` | Shared | Shared | New | Share cookies/state across tests |
 //!
 //! # Custom Configuration
@@ -85,6 +86,8 @@
 //!     let harness = TestHarness::builder()
 //!         .headless(false)  // Show browser for debugging
 //!         .timeout(Duration::from_secs(60))
+//!         .base_url("http://localhost:3000")
+//!         .base_url_from_env("BASE_URL")  // Override for staging/preview runs
 //!         .build()
 //!         .await?;
 //!     
@@ -101,7 +104,7 @@
 
 use tracing::{debug, info, instrument, warn};
 
-use crate::config::TestConfig;
+use crate::config::{TestConfig, base_url_from_env};
 use crate::error::TestError;
 use viewpoint_core::{Browser, BrowserContext, Page};
 
@@ -179,10 +182,7 @@ impl TestHarness {
 
         debug!("Browser launched");
 
-        let context = browser
-            .new_context()
-            .await
-            .map_err(|e| TestError::Setup(format!("Failed to create context: {e}")))?;
+        let context = new_context(&browser, &config).await?;
 
         debug!("Context created");
 
@@ -218,14 +218,30 @@ impl TestHarness {
     /// # Errors
     ///
     /// Returns an error if context or page creation fails.
-    #[instrument(level = "info", name = "TestHarness::from_browser", skip(browser))]
     pub async fn from_browser(browser: &Browser) -> Result<Self, TestError> {
+        Self::from_browser_with_config(browser, TestConfig::default()).await
+    }
+
+    /// Create a test harness using an existing browser and custom configuration.
+    ///
+    /// The new context uses the configuration's base URL. Launch options such
+    /// as `headless` are ignored, since the browser is already running.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if context or page creation fails.
+    #[instrument(
+        level = "info",
+        name = "TestHarness::from_browser",
+        skip(browser, config)
+    )]
+    pub async fn from_browser_with_config(
+        browser: &Browser,
+        config: TestConfig,
+    ) -> Result<Self, TestError> {
         info!("Creating test harness from existing browser");
 
-        let context = browser
-            .new_context()
-            .await
-            .map_err(|e| TestError::Setup(format!("Failed to create context: {e}")))?;
+        let context = new_context(browser, &config).await?;
 
         debug!("Context created");
 
@@ -244,7 +260,7 @@ impl TestHarness {
             page,
             owns_browser: false,
             owns_context: true,
-            config: TestConfig::default(),
+            config,
         })
     }
 
@@ -368,6 +384,21 @@ impl TestHarness {
     }
 }
 
+/// Create a context in `browser` configured by `config`.
+async fn new_context(browser: &Browser, config: &TestConfig) -> Result<BrowserContext, TestError> {
+    match config.base_url {
+        Some(ref base_url) => {
+            browser
+                .new_context_builder()
+                .base_url(base_url)
+                .build()
+                .await
+        }
+        None => browser.new_context().await,
+    }
+    .map_err(|e| TestError::Setup(format!("Failed to create context: {e}")))
+}
+
 impl Drop for TestHarness {
     fn drop(&mut self) {
         // We can't do async cleanup in Drop, so we rely on the underlying
@@ -400,6 +431,22 @@ impl TestHarnessBuilder {
         self
     }
 
+    /// Set the base URL for relative navigation and URL patterns.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.config.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Set the base URL from an environment variable.
+    ///
+    /// If the variable is unset or empty, the current base URL is kept.
+    pub fn base_url_from_env(mut self, var: &str) -> Self {
+        if let Some(base_url) = base_url_from_env(var) {
+            self.config.base_url = Some(base_url);
+        }
+        self
+    }
+
     /// Build and initialize the test harness.
     ///
     /// # Errors
@@ -213,8 +213,9 @@ mod harness;
 pub use config::{TestConfig, TestConfigBuilder};
 pub use error::{AssertionError, TestError};
 pub use expect::{
-    Expectable, LocatorAssertions, PageAssertions, SoftAssertionError, SoftAssertions,
-    SoftLocatorAssertions, SoftPageAssertions, expect, expect_page,
+    Expectable, LocatorAssertions, PageAssertions, ScreenshotAssertion, SnapshotUpdateMode,
+    SoftAssertionError, SoftAssertions, SoftLocatorAssertions, SoftPageAssertions, expect,
+    expect_page,
 };
 pub use harness::TestHarness;
 
@@ -222,4 +223,6 @@ pub use harness::TestHarness;
 pub use viewpoint_test_macros::test;
 
 // Re-export core types for convenience
-pub use viewpoint_core::{Browser, BrowserContext, CoreError, DocumentLoadState, Page};
+pub use viewpoint_core::{
+    AuditRule, Browser, BrowserContext, CoreError, DocumentLoadState, Page, TextMatcher,
+};
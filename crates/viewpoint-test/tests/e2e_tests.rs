@@ -447,3 +447,48 @@ async fn e2e_multiple_pages() {
         .await
         .expect("page 2 should have correct content");
 }
+
+/// E2E test: Accessibility audit assertions
+///
+/// This test exercises:
+/// - Auditing an accessible page
+/// - Negated audits on a page with violations
+/// - Limiting the audit to specific rules
+#[tokio::test]
+async fn e2e_accessibility_audit() {
+    init_tracing();
+
+    let harness = TestHarness::new().await.expect("should create harness");
+    let page = harness.page();
+
+    page.set_content(
+        r#"<html lang="en"><body><main><h1>Welcome</h1><button>Continue</button></main></body></html>"#,
+    )
+    .set()
+    .await
+    .expect("should set content");
+
+    expect_page(page)
+        .to_pass_accessibility_audit()
+        .await
+        .expect("accessible page should pass audit");
+
+    page.set_content(r#"<html><body><button></button><div role="bogus">x</div></body></html>"#)
+        .set()
+        .await
+        .expect("should set content");
+
+    expect_page(page)
+        .timeout(Duration::from_millis(500))
+        .not()
+        .to_pass_accessibility_audit()
+        .await
+        .expect("page with violations should fail audit");
+
+    let err = expect_page(page)
+        .timeout(Duration::from_millis(500))
+        .to_pass_accessibility_audit_with([viewpoint_test::AuditRule::MissingName])
+        .await
+        .expect_err("unnamed button should be reported");
+    assert!(err.to_string().contains("missing-name"));
+}
@@ -99,6 +99,70 @@ async fn test_config_builder() {
     assert_eq!(config.timeout, Duration::from_secs(120));
 }
 
+#[tokio::test]
+async fn test_config_base_url() {
+    assert!(TestConfig::default().base_url.is_none());
+
+    let config = TestConfig::builder()
+        .base_url("http://localhost:3000")
+        .base_url_from_env("VIEWPOINT_TEST_UNSET_BASE_URL")
+        .build();
+
+    // An unset variable keeps the configured base URL
+    assert_eq!(config.base_url.as_deref(), Some("http://localhost:3000"));
+}
+
+#[tokio::test]
+async fn test_harness_base_url() {
+    init_tracing();
+
+    let harness = TestHarness::builder()
+        .base_url("data:text/html,<title>Base</title>")
+        .build()
+        .await
+        .expect("should create harness");
+
+    let context = harness.context().expect("should have context");
+    assert_eq!(
+        context.base_url(),
+        Some("data:text/html,<title>Base</title>")
+    );
+
+    harness.close().await.expect("should close harness");
+}
+
+#[tokio::test]
+async fn test_harness_from_browser_base_url() {
+    init_tracing();
+
+    let browser = viewpoint_test::Browser::launch()
+        .headless(true)
+        .launch()
+        .await
+        .expect("should launch browser");
+
+    let config = TestConfig::builder()
+        .base_url("data:text/html,<title>Base</title>")
+        .base_url_from_env("VIEWPOINT_TEST_UNSET_BASE_URL")
+        .build();
+    let harness = TestHarness::from_browser_with_config(&browser, config)
+        .await
+        .expect("should create harness from browser");
+
+    let context = harness.context().expect("should have context");
+    assert_eq!(
+        context.base_url(),
+        Some("data:text/html,<title>Base</title>")
+    );
+    assert_eq!(
+        harness.config().base_url.as_deref(),
+        Some("data:text/html,<title>Base</title>")
+    );
+
+    drop(harness);
+    browser.close().await.expect("should close browser");
+}
+
 #[tokio::test]
 async fn test_harness_from_browser() {
     init_tracing();
@@ -113,6 +113,17 @@ async fn test_macro_combined_configuration(page: Page) {
         .expect("should navigate with combined config");
 }
 
+/// Test that the macro applies the base URL, letting the env var override it.
+#[viewpoint_test::test(
+    base_url = "http://localhost:3000",
+    base_url_env = "VIEWPOINT_TEST_UNSET_BASE_URL"
+)]
+async fn test_macro_base_url(context: BrowserContext) {
+    init_tracing();
+
+    assert_eq!(context.base_url(), Some("http://localhost:3000"));
+}
+
 // =============================================================================
 // Manual TestHarness tests for scope functionality
 // (Scope tests use manual setup since shared fixtures need explicit setup)
@@ -0,0 +1,119 @@
+#![cfg(feature = "integration")]
+
+//! Screenshot assertion tests.
+//!
+//! These tests verify baseline creation, comparison and failure artifacts
+//! for `to_have_screenshot`.
+
+use std::sync::Once;
+use std::time::Duration;
+
+use viewpoint_test::{SnapshotUpdateMode, TestHarness, expect, expect_page};
+
+static TRACING_INIT: Once = Once::new();
+
+fn init_tracing() {
+    TRACING_INIT.call_once(|| {
+        tracing_subscriber::fmt()
+            .with_env_filter(
+                tracing_subscriber::EnvFilter::from_default_env()
+                    .add_directive(tracing::Level::INFO.into()),
+            )
+            .with_test_writer()
+            .try_init()
+            .ok();
+    });
+}
+
+const PAGE: &str = r#"
+<html>
+<body style="margin: 0; background: white;">
+    <div id="box" style="width: 100px; height: 50px; background: rgb(0, 128, 255);"></div>
+    <p id="clock">12:00:00</p>
+</body>
+</html>
+"#;
+
+/// Test that a missing baseline is written and then matched.
+#[tokio::test]
+async fn test_screenshot_baseline_written_then_matched() {
+    init_tracing();
+
+    let harness = TestHarness::new().await.expect("should create harness");
+    let page = harness.page();
+    page.set_content(PAGE)
+        .set()
+        .await
+        .expect("should set content");
+    let dir = tempfile::tempdir().expect("should create temp dir");
+
+    let first = expect_page(page)
+        .to_have_screenshot("page")
+        .snapshot_dir(dir.path())
+        .update_snapshots(SnapshotUpdateMode::Missing)
+        .await;
+    assert!(first.is_err(), "missing baseline should fail");
+    assert!(dir.path().join("page.png").exists());
+
+    expect_page(page)
+        .to_have_screenshot("page")
+        .snapshot_dir(dir.path())
+        .update_snapshots(SnapshotUpdateMode::None)
+        .await
+        .expect("should match written baseline");
+}
+
+/// Test that element differences fail with diff images, and masks hide them.
+#[tokio::test]
+async fn test_screenshot_mismatch_and_mask() {
+    init_tracing();
+
+    let harness = TestHarness::new().await.expect("should create harness");
+    let page = harness.page();
+    page.set_content(PAGE)
+        .set()
+        .await
+        .expect("should set content");
+    let dir = tempfile::tempdir().expect("should create temp dir");
+
+    let body = page.locator("body");
+    let clock = page.locator("#clock");
+    expect(&body)
+        .to_have_screenshot("body.png")
+        .mask(&clock)
+        .snapshot_dir(dir.path())
+        .update_snapshots(SnapshotUpdateMode::All)
+        .await
+        .expect("should write baseline");
+
+    page.evaluate::<serde_json::Value>("document.getElementById('clock').textContent = '12:00:01'")
+        .await
+        .expect("should update clock");
+
+    expect(&body)
+        .to_have_screenshot("body.png")
+        .mask(&clock)
+        .snapshot_dir(dir.path())
+        .update_snapshots(SnapshotUpdateMode::None)
+        .await
+        .expect("masked change should match");
+
+    page.evaluate::<serde_json::Value>(
+        "document.getElementById('box').style.background = 'rgb(255, 0, 0)'",
+    )
+    .await
+    .expect("should recolor box");
+
+    let error = expect(&body)
+        .to_have_screenshot("body.png")
+        .mask(&clock)
+        .timeout(Duration::from_millis(300))
+        .snapshot_dir(dir.path())
+        .update_snapshots(SnapshotUpdateMode::None)
+        .await
+        .expect_err("recolored box should not match");
+
+    assert!(error.actual.contains("body-diff.png"));
+    assert!(dir.path().join("__diff_output__/body-actual.png").exists());
+    assert!(dir.path().join("__diff_output__/body-diff.png").exists());
+}